@@ -0,0 +1,189 @@
+//! ## Errors
+//!
+//! Error types shared by the services and the UI
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::models::status::Severity;
+
+/// Characters of a response body kept in an HTTP error
+#[cfg(feature = "network")]
+const EXCERPT: usize = 300;
+
+/// Errors raised while reading the config or talking to chains and swap providers
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Error, Serialize, Deserialize)]
+pub enum SwapError {
+    /// A remote endpoint could not be reached or answered something wrong
+    #[error(transparent)]
+    Network(#[from] NetworkError),
+    /// A swap provider failed, with the error it failed with
+    #[error("{provider} failed")]
+    Provider {
+        provider: String,
+        #[source]
+        source: Box<SwapError>,
+    },
+    /// The input cannot be handled
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+    /// The configuration file could not be read or is invalid
+    #[error("invalid config: {0}")]
+    Config(String),
+    /// A file could not be written
+    #[error("i/o error: {0}")]
+    Io(String),
+    /// A secret could not be read from or saved to the keyring or the secrets file
+    #[error("secret error: {0}")]
+    Secret(String),
+}
+
+/// Errors talking to a remote endpoint
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Error, Serialize, Deserialize)]
+pub enum NetworkError {
+    /// The request could not reach the endpoint
+    #[error("network error: {0}")]
+    Unreachable(String),
+    /// Requests are disabled by the offline setting
+    #[error("network error: offline mode")]
+    Offline,
+    /// The endpoint answered with an error status, with an excerpt of the response body
+    #[error("network error: HTTP {status}")]
+    Status { status: u16, body: String },
+    /// The endpoint answered with something we could not understand
+    #[error("invalid response: {0}")]
+    InvalidResponse(String),
+}
+
+/// Input an operation cannot handle
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Error, Serialize, Deserialize)]
+pub enum ValidationError {
+    /// The operation is not supported for the given input
+    #[error("unsupported: {0}")]
+    Unsupported(String),
+}
+
+impl SwapError {
+    /// Attach the name of the provider that failed
+    pub fn provider(provider: &str, source: SwapError) -> Self {
+        Self::Provider {
+            provider: provider.to_string(),
+            source: Box::new(source),
+        }
+    }
+
+    /// The error without the provider context
+    pub fn root(&self) -> &SwapError {
+        match self {
+            Self::Provider { source, .. } => source.root(),
+            _ => self,
+        }
+    }
+
+    /// Whether the operation is not supported for the given input
+    pub fn is_unsupported(&self) -> bool {
+        matches!(self.root(), Self::Validation(ValidationError::Unsupported(_)))
+    }
+
+    /// Short description fitting a table cell, e.g. `HTTP 429` or `unsupported pair`
+    pub fn summary(&self) -> String {
+        match self.root() {
+            Self::Network(NetworkError::Unreachable(msg)) => msg.clone(),
+            Self::Network(NetworkError::Offline) => "offline mode".to_string(),
+            Self::Network(NetworkError::Status { status, .. }) => format!("HTTP {status}"),
+            Self::Network(NetworkError::InvalidResponse(_)) => "invalid response".to_string(),
+            Self::Validation(ValidationError::Unsupported(msg)) => format!("unsupported {msg}"),
+            root => root.to_string(),
+        }
+    }
+
+    /// What the user can do about the error
+    pub fn advice(&self) -> &'static str {
+        match self.root() {
+            Self::Network(NetworkError::Unreachable(_)) => "Check the connection and the proxy setting, then retry.",
+            Self::Network(NetworkError::Offline) => "Turn off the offline setting to reach the network.",
+            Self::Network(NetworkError::Status { status: 429, .. }) => {
+                "The provider is rate limiting requests: wait a minute, then retry."
+            }
+            Self::Network(NetworkError::Status { status: 500.., .. }) => {
+                "The provider is having trouble: retry in a moment."
+            }
+            Self::Network(NetworkError::Status { .. }) => {
+                "The provider rejected the request: check the pair and amount, then retry."
+            }
+            Self::Network(NetworkError::InvalidResponse(_)) => {
+                "The provider answered something unexpected, its API may have changed: retry, or disable it in the settings."
+            }
+            Self::Validation(ValidationError::Unsupported(_)) => "Pick another pair or another provider.",
+            Self::Config(_) => "Fix the config file, then restart.",
+            Self::Io(_) => "Check the permissions and free space of the data directory.",
+            Self::Secret(_) => "Check the keyring is unlocked, or the permissions of the secrets file.",
+            Self::Provider { .. } => unreachable!("the root of an error has no provider context"),
+        }
+    }
+
+    /// Id of the advice, naming its translations in the UI
+    pub fn advice_id(&self) -> &'static str {
+        match self.root() {
+            Self::Network(NetworkError::Unreachable(_)) => "advice-unreachable",
+            Self::Network(NetworkError::Offline) => "advice-offline",
+            Self::Network(NetworkError::Status { status: 429, .. }) => "advice-rate-limited",
+            Self::Network(NetworkError::Status { status: 500.., .. }) => "advice-server-error",
+            Self::Network(NetworkError::Status { .. }) => "advice-rejected",
+            Self::Network(NetworkError::InvalidResponse(_)) => "advice-invalid-response",
+            Self::Validation(ValidationError::Unsupported(_)) => "advice-unsupported",
+            Self::Config(_) => "advice-config",
+            Self::Io(_) => "advice-io",
+            Self::Secret(_) => "advice-secret",
+            Self::Provider { .. } => unreachable!("the root of an error has no provider context"),
+        }
+    }
+
+    /// How the error is shown: input that cannot be handled is a warning, anything else an error
+    pub fn severity(&self) -> Severity {
+        match self.root() {
+            Self::Validation(_) => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// Whether asking again may succeed
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.root(),
+            Self::Network(NetworkError::Unreachable(_) | NetworkError::Status { .. } | NetworkError::InvalidResponse(_))
+        )
+    }
+}
+
+#[cfg(feature = "network")]
+impl From<ureq::Error> for SwapError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, response) => {
+                let body = response.into_string().unwrap_or_default();
+                let body = body.trim();
+                let mut excerpt: String = body.chars().take(EXCERPT).collect();
+                if excerpt.len() < body.len() {
+                    excerpt.push('…');
+                }
+                NetworkError::Status { status, body: excerpt }.into()
+            }
+            ureq::Error::Transport(transport) => NetworkError::Unreachable(transport.to_string()).into(),
+        }
+    }
+}
+
+/// Raised while reading a response body
+impl From<std::io::Error> for SwapError {
+    fn from(err: std::io::Error) -> Self {
+        NetworkError::InvalidResponse(err.to_string()).into()
+    }
+}
+
+/// Raised while decoding a response body
+impl From<serde_json::Error> for SwapError {
+    fn from(err: serde_json::Error) -> Self {
+        NetworkError::InvalidResponse(err.to_string()).into()
+    }
+}
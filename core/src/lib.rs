@@ -0,0 +1,8 @@
+//! ## xoswap-core
+//!
+//! Quote aggregation engine: asks every provider for a swap quote, and reads the fees, balances and allowances
+//! a swap needs. It has no UI: the TUI is one frontend built on it.
+
+pub mod errors;
+pub mod models;
+pub mod services;
@@ -0,0 +1,23 @@
+//! ## Models
+//!
+//! Domain types shared by the services and their frontends
+
+pub mod alert;
+pub mod amount;
+pub mod asset;
+pub mod chain;
+pub mod chart;
+pub mod depth;
+pub mod draft;
+pub mod fee;
+pub mod history;
+pub mod limit;
+pub mod order;
+pub mod portfolio;
+pub mod provider;
+pub mod quote;
+pub mod schedule;
+pub mod selection;
+pub mod status;
+pub mod wallet;
+pub mod watchlist;
@@ -0,0 +1,60 @@
+//! ## Alert
+//!
+//! Thresholds on the best rate of a pair, checked while watching its quotes
+
+use std::fmt::{self, Display};
+
+use serde::Deserialize;
+
+use crate::models::asset::AssetId;
+use crate::models::quote::Quote;
+
+/// Alert on the best rate of a pair, as listed in the `[[alerts]]` config section
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Alert {
+    /// FROM asset, by ticker (`BTC`, on any chain) or id (`usdc-arbitrum`)
+    pub from: String,
+    /// TO asset, by ticker or id
+    pub to: String,
+    /// Trigger when the best rate rises above this many TO per FROM
+    #[serde(default)]
+    pub above: Option<f64>,
+    /// Trigger when the best rate falls below this many TO per FROM
+    #[serde(default)]
+    pub below: Option<f64>,
+}
+
+impl Alert {
+    /// Whether the alert watches the pair of a quote
+    pub fn watches(&self, quote: &Quote) -> bool {
+        Self::is(&self.from, &quote.from) && Self::is(&self.to, &quote.to)
+    }
+
+    /// Whether a rate is past a threshold
+    pub fn crossed(&self, rate: f64) -> bool {
+        self.above.is_some_and(|above| rate > above) || self.below.is_some_and(|below| rate < below)
+    }
+
+    /// Whether an asset given by ticker or id is the asset with that id
+    fn is(query: &str, id: &AssetId) -> bool {
+        match query.parse::<AssetId>() {
+            Ok(query) => query == *id,
+            Err(_) => id.ticker.eq_ignore_ascii_case(query),
+        }
+    }
+}
+
+/// Displayed as its condition, e.g. `BTC→ETH > 51`
+impl Display for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}→{}", self.from, self.to)?;
+        if let Some(above) = self.above {
+            write!(f, " > {above}")?;
+        }
+        if let Some(below) = self.below {
+            write!(f, " < {below}")?;
+        }
+        Ok(())
+    }
+}
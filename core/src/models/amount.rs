@@ -0,0 +1,100 @@
+//! ## Amount
+//!
+//! Fixed-point token amounts
+
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A token amount stored as an integer of base units (satoshis, wei, lamports...)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount {
+    raw: u128,
+    decimals: u8,
+}
+
+impl Amount {
+    /// Create an amount from base units
+    pub fn new(raw: u128, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    /// Amount in base units
+    pub fn raw(&self) -> u128 {
+        self.raw
+    }
+
+    /// Number of decimals of the base unit
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Parse a decimal string such as `0.25`; extra precision is rejected
+    pub fn parse(value: &str, decimals: u8) -> Option<Self> {
+        let value = value.trim();
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if (whole.is_empty() && fraction.is_empty())
+            || fraction.len() > decimals as usize
+            || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let scale = 10u128.checked_pow(decimals as u32)?;
+        let whole: u128 = if whole.is_empty() { 0 } else { whole.parse().ok()? };
+        let fraction: u128 = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse::<u128>().ok()? * 10u128.pow((decimals as usize - fraction.len()) as u32)
+        };
+        Some(Self::new(whole.checked_mul(scale)?.checked_add(fraction)?, decimals))
+    }
+
+    /// Approximate amount from a floating point value, rounded down
+    pub fn from_f64(value: f64, decimals: u8) -> Self {
+        Self::new((value.max(0.0) * 10f64.powi(decimals as i32)) as u128, decimals)
+    }
+
+    /// Approximate floating point value, for rates and display only
+    pub fn to_f64(&self) -> f64 {
+        self.raw as f64 / 10f64.powi(self.decimals as i32)
+    }
+
+    /// The given percentage of this amount, rounded down
+    pub fn percent(&self, pct: u8) -> Self {
+        Self::new(self.raw / 100 * pct as u128 + self.raw % 100 * pct as u128 / 100, self.decimals)
+    }
+}
+
+/// Serialized as a decimal string, which keeps every digit
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// (De)serialize an amount as its base units and decimals, e.g. `[10000000, 8]`, which reads back exactly
+pub mod exact {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+        (amount.raw, amount.decimals).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+        let (raw, decimals) = Deserialize::deserialize(deserializer)?;
+        Ok(Amount::new(raw, decimals))
+    }
+}
+
+impl Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = 10u128.pow(self.decimals as u32);
+        let whole = self.raw / scale;
+        let fraction = self.raw % scale;
+        if fraction == 0 {
+            return write!(f, "{whole}");
+        }
+        let fraction = format!("{:0width$}", fraction, width = self.decimals as usize);
+        write!(f, "{whole}.{}", fraction.trim_end_matches('0'))
+    }
+}
@@ -0,0 +1,160 @@
+//! ## Asset
+//!
+//! Assets, identified by ticker and chain
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::models::amount::Amount;
+use crate::models::chain::Chain;
+
+/// Identifier of an asset on a given chain, displayed as `usdc-arbitrum`
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssetId {
+    pub ticker: String,
+    pub chain: Chain,
+}
+
+impl AssetId {
+    /// Create a new asset id
+    pub fn new(ticker: &str, chain: Chain) -> Self {
+        Self {
+            ticker: ticker.to_uppercase(),
+            chain,
+        }
+    }
+
+    /// Ticker followed by the chain badge when the ticker alone is ambiguous
+    pub fn label(&self) -> String {
+        if self.ticker == self.chain.badge() {
+            self.ticker.clone()
+        } else {
+            format!("{} [{}]", self.ticker, self.chain.badge())
+        }
+    }
+}
+
+impl Display for AssetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.ticker.to_lowercase(), self.chain.slug())
+    }
+}
+
+/// Serialized as its id, e.g. `usdc-arbitrum`
+impl Serialize for AssetId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AssetId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromStr for AssetId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ticker, chain) = s
+            .split_once('-')
+            .ok_or_else(|| format!("expected <ticker>-<chain>, got {s}"))?;
+        Ok(Self::new(ticker, chain.parse()?))
+    }
+}
+
+/// An asset that can be swapped, as listed in the `[[assets]]` config section
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Asset {
+    /// Ticker, shared by the variants of the asset on different chains
+    pub ticker: String,
+    /// Chain the asset lives on
+    pub chain: Chain,
+    /// Number of decimals of the base unit
+    pub decimals: u8,
+    /// Token contract (ERC-20) or mint (SPL); `None` for the chain's native asset
+    #[serde(default)]
+    pub contract: Option<String>,
+    /// Displayed price
+    #[serde(default)]
+    pub price: String,
+    /// Amount the amount field goes up and down by, e.g. `0.001`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<String>,
+}
+
+impl Asset {
+    /// Native asset of a chain
+    pub fn native(chain: Chain, price: &str) -> Self {
+        Self {
+            ticker: chain.native_ticker().to_string(),
+            chain,
+            decimals: chain.native_decimals(),
+            contract: None,
+            price: price.to_string(),
+            step: None,
+        }
+    }
+
+    /// Token deployed on a chain
+    pub fn token(ticker: &str, chain: Chain, decimals: u8, contract: &str, price: &str) -> Self {
+        Self {
+            ticker: ticker.to_string(),
+            chain,
+            decimals,
+            contract: Some(contract.to_string()),
+            price: price.to_string(),
+            step: None,
+        }
+    }
+
+    /// Identifier of the asset
+    pub fn id(&self) -> AssetId {
+        AssetId::new(&self.ticker, self.chain)
+    }
+
+    /// Ticker followed by the chain badge when the ticker alone is ambiguous
+    pub fn label(&self) -> String {
+        self.id().label()
+    }
+
+    /// Displayed price as a number, e.g. `100000` for `$100,000`
+    pub fn price_value(&self) -> Option<f64> {
+        let digits: String = self.price.chars().filter(|c| c.is_ascii_digit() || *c == '.').collect();
+        digits.parse().ok().filter(|price| *price > 0.0)
+    }
+
+    /// Amount the amount field goes up and down by: the step set, else the power of ten worth about one unit of the
+    /// fiat currency at the displayed price, else one
+    pub fn step(&self) -> Amount {
+        if let Some(step) = self.step.as_deref().and_then(|step| Amount::parse(step, self.decimals)) {
+            return step;
+        }
+        let exponent = self.price_value().map_or(0, |price| -(price.log10().floor() as i32));
+        let digits = (self.decimals as i32 + exponent).clamp(0, 38) as u32;
+        Amount::new(10u128.pow(digits), self.decimals)
+    }
+}
+
+impl Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.label(), self.price)
+    }
+}
+
+/// Assets listed when the config lists none
+pub fn default_assets() -> Vec<Asset> {
+    vec![
+        Asset::native(Chain::Bitcoin, "$100,000"),
+        Asset::native(Chain::Ethereum, "$2,400"),
+        Asset::native(Chain::Arbitrum, "$2,400"),
+        Asset::native(Chain::Solana, "$145"),
+        Asset::token("USDC", Chain::Ethereum, 6, "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", "$1"),
+        Asset::token("USDC", Chain::Arbitrum, 6, "0xaf88d065e77c8cC2239327C5EDb3A432268e5831", "$1"),
+        Asset::token("USDC", Chain::Solana, 6, "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "$1"),
+    ]
+}
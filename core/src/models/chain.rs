@@ -0,0 +1,142 @@
+//! ## Chain
+//!
+//! Blockchains supported by the application
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// A blockchain network, named by its slug in the config
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Chain {
+    #[default]
+    Bitcoin,
+    Ethereum,
+    Solana,
+    Arbitrum,
+}
+
+impl Chain {
+    /// Every supported chain
+    pub const ALL: [Chain; 4] = [Chain::Bitcoin, Chain::Ethereum, Chain::Solana, Chain::Arbitrum];
+
+    /// Lowercase identifier, as used in asset ids and provider APIs
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Self::Bitcoin => "bitcoin",
+            Self::Ethereum => "ethereum",
+            Self::Solana => "solana",
+            Self::Arbitrum => "arbitrum",
+        }
+    }
+
+    /// Short badge displayed next to assets
+    pub fn badge(&self) -> &'static str {
+        match self {
+            Self::Bitcoin => "BTC",
+            Self::Ethereum => "ETH",
+            Self::Solana => "SOL",
+            Self::Arbitrum => "ARB",
+        }
+    }
+
+    /// Ticker of the chain's native asset
+    pub fn native_ticker(&self) -> &'static str {
+        match self {
+            Self::Bitcoin => "BTC",
+            Self::Ethereum | Self::Arbitrum => "ETH",
+            Self::Solana => "SOL",
+        }
+    }
+
+    /// Number of decimals of the chain's native asset
+    pub fn native_decimals(&self) -> u8 {
+        match self {
+            Self::Bitcoin => 8,
+            Self::Ethereum | Self::Arbitrum => 18,
+            Self::Solana => 9,
+        }
+    }
+
+    /// Average time between two blocks
+    pub fn block_time(&self) -> Duration {
+        match self {
+            Self::Bitcoin => Duration::from_secs(600),
+            Self::Ethereum => Duration::from_secs(12),
+            Self::Solana => Duration::from_millis(400),
+            Self::Arbitrum => Duration::from_millis(250),
+        }
+    }
+
+    /// Confirmations swap services usually wait for before crediting a deposit
+    pub fn deposit_confirmations(&self) -> u32 {
+        match self {
+            Self::Bitcoin => 2,
+            Self::Ethereum => 12,
+            Self::Solana => 32,
+            Self::Arbitrum => 20,
+        }
+    }
+
+    /// Whether the chain runs the EVM (and shares Ethereum's address format)
+    pub fn is_evm(&self) -> bool {
+        matches!(self, Self::Ethereum | Self::Arbitrum)
+    }
+
+    /// EIP-155 chain id of EVM chains
+    pub fn evm_chain_id(&self) -> Option<u64> {
+        match self {
+            Self::Ethereum => Some(1),
+            Self::Arbitrum => Some(42161),
+            Self::Bitcoin | Self::Solana => None,
+        }
+    }
+
+    /// Cheap syntactic check of an address for this chain
+    pub fn is_valid_address(&self, address: &str) -> bool {
+        match self {
+            Self::Bitcoin => {
+                let len = address.len();
+                (address.starts_with("bc1") && (14..=74).contains(&len))
+                    || ((address.starts_with('1') || address.starts_with('3'))
+                        && (26..=35).contains(&len))
+            }
+            Self::Ethereum | Self::Arbitrum => {
+                address.len() == 42
+                    && address.starts_with("0x")
+                    && address[2..].chars().all(|c| c.is_ascii_hexdigit())
+            }
+            Self::Solana => {
+                (32..=44).contains(&address.len())
+                    && address
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() && !"0OIl".contains(c))
+            }
+        }
+    }
+}
+
+impl Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bitcoin => write!(f, "Bitcoin"),
+            Self::Ethereum => write!(f, "Ethereum"),
+            Self::Solana => write!(f, "Solana"),
+            Self::Arbitrum => write!(f, "Arbitrum"),
+        }
+    }
+}
+
+impl FromStr for Chain {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|chain| chain.slug().eq_ignore_ascii_case(s) || chain.badge().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown chain: {s}"))
+    }
+}
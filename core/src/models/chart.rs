@@ -0,0 +1,73 @@
+//! ## Chart
+//!
+//! Price history of an asset or of the rate of a pair, over a timeframe
+
+use std::fmt::{self, Display};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::amount::{self, Amount};
+
+/// Decimals kept of charted values, enough for the rate of a stablecoin in bitcoin
+pub const CHART_DECIMALS: u8 = 12;
+
+/// Span of time charted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub enum Timeframe {
+    Hour,
+    #[default]
+    Day,
+    Week,
+}
+
+impl Timeframe {
+    /// Every timeframe, shortest first
+    pub const ALL: [Timeframe; 3] = [Timeframe::Hour, Timeframe::Day, Timeframe::Week];
+
+    /// Days of history to fetch, the price API having no shorter span
+    pub fn days(&self) -> u32 {
+        match self {
+            Self::Hour | Self::Day => 1,
+            Self::Week => 7,
+        }
+    }
+
+    /// Length of the timeframe, in seconds
+    pub fn seconds(&self) -> i64 {
+        match self {
+            Self::Hour => 3600,
+            Self::Day => 24 * 3600,
+            Self::Week => 7 * 24 * 3600,
+        }
+    }
+}
+
+/// Displayed as its span, e.g. `24h`
+impl Display for Timeframe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hour => write!(f, "1h"),
+            Self::Day => write!(f, "24h"),
+            Self::Week => write!(f, "7d"),
+        }
+    }
+}
+
+/// Value at a point in time, a price in fiat or a rate of a pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct PricePoint {
+    pub at: DateTime<Utc>,
+    #[serde(with = "amount::exact")]
+    pub value: Amount,
+}
+
+impl PricePoint {
+    /// Point of `value` at `at`
+    pub fn new(at: DateTime<Utc>, value: f64) -> Self {
+        Self {
+            at,
+            value: Amount::from_f64(value, CHART_DECIMALS),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! ## Depth
+//!
+//! How the rate of on-chain aggregators degrades as the amount swapped grows
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::quote::{self, Quote};
+
+/// Multiples of the amount entered quoted to preview the depth
+pub const MULTIPLIERS: [u32; 3] = [1, 5, 10];
+
+/// Offer of an aggregator for a multiple of the amount entered
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct DepthLevel {
+    pub multiplier: u32,
+    /// `None` when the aggregator did not quote that amount
+    #[serde(with = "quote::exact::optional")]
+    pub quote: Option<Quote>,
+}
+
+/// Offers of an aggregator for each multiple of the amount entered, smallest first
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct Depth {
+    pub provider: String,
+    pub levels: Vec<DepthLevel>,
+}
+
+impl Depth {
+    /// Change of the rate at `level` from the rate of the smallest amount quoted, in percent, negative when it
+    /// degrades
+    pub fn impact(&self, level: &DepthLevel) -> Option<f64> {
+        let base = self.levels.iter().find_map(|level| level.quote.as_ref())?.rate();
+        let rate = level.quote.as_ref()?.rate();
+        (base > 0.0).then(|| (rate - base) / base * 100.0)
+    }
+}
@@ -0,0 +1,54 @@
+//! ## Draft
+//!
+//! Swap form values saved for later
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::asset::AssetId;
+use crate::models::history::HistoryEntry;
+
+/// Values of a (possibly half-filled) swap form
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Draft {
+    pub from: Option<AssetId>,
+    pub to: Option<AssetId>,
+    /// FROM amount, as typed
+    pub amount: Option<String>,
+    /// Watch-only address on the FROM chain
+    pub address: Option<String>,
+    /// Provider of the chosen quote
+    pub provider: Option<String>,
+}
+
+impl Draft {
+    /// Whether nothing was entered
+    pub fn is_empty(&self) -> bool {
+        self.from.is_none() && self.to.is_none() && self.amount.is_none()
+    }
+
+    /// Short description, e.g. `0.1 BTC -> ETH via sideshift`
+    pub fn summary(&self) -> String {
+        let label = |id: &Option<AssetId>| id.as_ref().map_or("?".to_string(), AssetId::label);
+        let mut summary = format!("{} -> {}", label(&self.from), label(&self.to));
+        if let Some(amount) = &self.amount {
+            summary = format!("{amount} {summary}");
+        }
+        if let Some(provider) = &self.provider {
+            summary.push_str(&format!(" via {provider}"));
+        }
+        summary
+    }
+}
+
+impl From<&HistoryEntry> for Draft {
+    fn from(entry: &HistoryEntry) -> Self {
+        Self {
+            from: Some(entry.from.clone()),
+            to: Some(entry.to.clone()),
+            amount: Some(entry.amount.clone()),
+            address: None,
+            provider: None,
+        }
+    }
+}
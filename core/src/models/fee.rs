@@ -0,0 +1,86 @@
+//! ## Fee
+//!
+//! On-chain fees paid to fund a swap deposit
+
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::amount::{self, Amount};
+use crate::models::chain::Chain;
+
+/// Fee presets offered for EIP-1559 chains
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum FeeSpeed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+}
+
+impl FeeSpeed {
+    /// Every preset, slowest first
+    pub const ALL: [FeeSpeed; 3] = [FeeSpeed::Slow, FeeSpeed::Normal, FeeSpeed::Fast];
+
+    /// Next preset, wrapping around
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Slow => Self::Normal,
+            Self::Normal => Self::Fast,
+            Self::Fast => Self::Slow,
+        }
+    }
+}
+
+impl Display for FeeSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Slow => write!(f, "slow"),
+            Self::Normal => write!(f, "normal"),
+            Self::Fast => write!(f, "fast"),
+        }
+    }
+}
+
+/// Price of block space on a chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub enum FeeRate {
+    /// Bitcoin fee rate, in satoshis per virtual byte
+    SatPerVbyte(u64),
+    /// EIP-1559 base fee and priority tip, in wei per gas
+    Eip1559 { base_fee: u128, priority_fee: u128 },
+    /// Solana fee, in lamports per signature
+    LamportsPerSignature(u64),
+}
+
+impl Display for FeeRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SatPerVbyte(rate) => write!(f, "{rate} sat/vB"),
+            Self::Eip1559 {
+                base_fee,
+                priority_fee,
+            } => write!(
+                f,
+                "{} gwei base + {} gwei tip",
+                Amount::new(*base_fee, 9),
+                Amount::new(*priority_fee, 9)
+            ),
+            Self::LamportsPerSignature(lamports) => write!(f, "{lamports} lamports/sig"),
+        }
+    }
+}
+
+/// Estimated fee of the deposit transaction, paid in the chain's native asset
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct NetworkFee {
+    pub chain: Chain,
+    /// Preset the estimate belongs to, for chains offering several
+    pub speed: Option<FeeSpeed>,
+    pub rate: FeeRate,
+    /// Transaction size the rate applies to (vbytes, gas units or signatures)
+    pub units: u64,
+    /// Total fee
+    #[serde(with = "amount::exact")]
+    pub amount: Amount,
+}
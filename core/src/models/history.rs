@@ -0,0 +1,141 @@
+//! ## History
+//!
+//! Past quotes and swaps
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::asset::AssetId;
+use crate::models::order::Order;
+use crate::models::quote::Quote;
+
+/// What happened to a history entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryStatus {
+    /// Best quote of a request
+    Quoted,
+    /// Swap simulated from a quote
+    Simulated,
+}
+
+impl Display for HistoryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Quoted => write!(f, "quoted"),
+            Self::Simulated => write!(f, "simulated"),
+        }
+    }
+}
+
+/// A past quote or swap
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub date: DateTime<Utc>,
+    pub from: AssetId,
+    pub to: AssetId,
+    /// FROM amount, as typed
+    pub amount: String,
+    pub provider: String,
+    pub status: HistoryStatus,
+    /// Offers made for the request, for quoted entries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offers: Option<usize>,
+    /// Provider offering the best rate, for quoted entries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub winner: Option<String>,
+    /// Gap between the best and the worst offer, in percent of the best, for quoted entries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spread: Option<f64>,
+}
+
+impl HistoryEntry {
+    /// Entry for the quote routed through among the `offers` made for a request
+    pub fn quoted(quote: &Quote, offers: &[Quote]) -> Self {
+        let best = offers.iter().max_by_key(|offer| offer.amount_out.raw());
+        let worst = offers.iter().map(|offer| offer.amount_out.to_f64()).fold(f64::INFINITY, f64::min);
+        Self {
+            offers: Some(offers.len()),
+            winner: best.map(|best| best.provider.clone()),
+            spread: best
+                .map(|best| best.amount_out.to_f64())
+                .filter(|best| *best > 0.0)
+                .map(|best| (best - worst) / best * 100.0),
+            ..Self::new(quote, quote.quoted_at, HistoryStatus::Quoted)
+        }
+    }
+
+    /// Entry for a simulated swap
+    pub fn simulated(order: &Order) -> Self {
+        Self::new(&order.quote, order.created_at, HistoryStatus::Simulated)
+    }
+
+    fn new(quote: &Quote, date: DateTime<Utc>, status: HistoryStatus) -> Self {
+        Self {
+            date,
+            from: quote.from.clone(),
+            to: quote.to.clone(),
+            amount: quote.amount_in.to_string(),
+            provider: quote.provider.clone(),
+            status,
+            offers: None,
+            winner: None,
+            spread: None,
+        }
+    }
+
+    /// Whether the pair, provider or status contains `filter`, ignoring case
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        [self.from.label(), self.to.label(), self.provider.clone(), self.status.to_string()]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&filter))
+    }
+}
+
+/// Summary of history entries, e.g. those of a session
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistorySummary {
+    /// Requests quoted
+    pub requests: usize,
+    /// Offers received across the requests
+    pub offers: usize,
+    /// Swaps simulated
+    pub swaps: usize,
+    /// Requests each provider offered the best rate for
+    pub wins: BTreeMap<String, usize>,
+    /// Mean gap between the best and the worst offer of the requests with several offers, in percent
+    pub average_spread: Option<f64>,
+}
+
+impl HistorySummary {
+    /// Summarize `entries`
+    pub fn new<'a>(entries: impl IntoIterator<Item = &'a HistoryEntry>) -> Self {
+        let mut summary = Self::default();
+        let mut spreads = vec![];
+        for entry in entries {
+            match entry.status {
+                HistoryStatus::Quoted => {
+                    summary.requests += 1;
+                    summary.offers += entry.offers.unwrap_or(1);
+                    let winner = entry.winner.as_ref().unwrap_or(&entry.provider);
+                    *summary.wins.entry(winner.clone()).or_default() += 1;
+                    spreads.extend(entry.spread.filter(|_| entry.offers.unwrap_or_default() > 1));
+                }
+                HistoryStatus::Simulated => summary.swaps += 1,
+            }
+        }
+        summary.average_spread = (!spreads.is_empty()).then(|| spreads.iter().sum::<f64>() / spreads.len() as f64);
+        summary
+    }
+
+    /// Providers with their win counts, the most winning first
+    pub fn ranking(&self) -> Vec<(&str, usize)> {
+        let mut ranking: Vec<_> = self.wins.iter().map(|(provider, wins)| (provider.as_str(), *wins)).collect();
+        ranking.sort_by_key(|(_, wins)| std::cmp::Reverse(*wins));
+        ranking
+    }
+}
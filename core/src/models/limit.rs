@@ -0,0 +1,46 @@
+//! ## Limit
+//!
+//! Target rate of a pair, turning watch mode into a limit order on top of market quotes
+
+use std::fmt::{self, Display};
+
+use crate::models::asset::AssetId;
+use crate::models::quote::Quote;
+
+/// Limit order on a pair, met once its best rate reaches the target
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitOrder {
+    pub from: AssetId,
+    pub to: AssetId,
+    /// Fewest TO received per FROM sent
+    pub rate: f64,
+}
+
+impl LimitOrder {
+    /// Limit order on `from` → `to` at `rate`
+    pub fn new(from: AssetId, to: AssetId, rate: f64) -> Self {
+        Self { from, to, rate }
+    }
+
+    /// Whether the order is on the pair of a quote
+    pub fn watches(&self, quote: &Quote) -> bool {
+        quote.from == self.from && quote.to == self.to
+    }
+
+    /// Whether a rate reaches the target
+    pub fn met(&self, rate: f64) -> bool {
+        rate >= self.rate
+    }
+
+    /// Distance of a rate from the target, in percent, negative while short of it
+    pub fn distance(&self, rate: f64) -> f64 {
+        (rate - self.rate) / self.rate * 100.0
+    }
+}
+
+/// Displayed as its condition, e.g. `BTC→ETH ≥ 16.5`
+impl Display for LimitOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}→{} ≥ {}", self.from.ticker, self.to.ticker, self.rate)
+    }
+}
@@ -0,0 +1,39 @@
+//! ## Order
+//!
+//! Swaps created with a provider
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::models::quote::Quote;
+
+/// A swap created (or simulated) from a quote
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Order {
+    /// Identifier given by the provider, `sim-…` for simulations
+    pub id: String,
+    /// Offer the swap was created from
+    pub quote: Quote,
+    /// Address the FROM asset must be sent to, unknown for simulations
+    pub deposit_address: Option<String>,
+    /// Address the TO asset is sent to
+    pub destination_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Whether the swap only exists locally
+    pub simulated: bool,
+}
+
+impl Order {
+    /// Simulate a swap from a quote, without contacting the provider
+    pub fn simulate(quote: Quote, destination_address: Option<String>) -> Self {
+        let created_at = Utc::now();
+        Self {
+            id: format!("sim-{}", created_at.format("%Y%m%d%H%M%S%3f")),
+            quote,
+            deposit_address: None,
+            destination_address,
+            created_at,
+            simulated: true,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! ## Portfolio
+//!
+//! Holdings valued at the current prices of their assets
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::amount::{self, Amount};
+use crate::models::asset::{Asset, AssetId};
+
+/// Decimals kept of prices
+pub const PRICE_DECIMALS: u8 = 8;
+
+/// Price of an asset in the fiat currency, with its change over the last 24 hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct Price {
+    /// Fiat per unit
+    #[serde(with = "amount::exact")]
+    pub value: Amount,
+    /// Change over the last 24 hours, in hundredths of a percent
+    pub change_24h: Option<i64>,
+}
+
+impl Price {
+    /// Price of `value` fiat per unit, which changed by `change_24h` percent over the last 24 hours
+    pub fn new(value: f64, change_24h: Option<f64>) -> Self {
+        Self {
+            value: Amount::from_f64(value, PRICE_DECIMALS),
+            change_24h: change_24h.map(|change| (change * 100.0).round() as i64),
+        }
+    }
+
+    /// Change over the last 24 hours, in percent
+    pub fn change_percent(&self) -> Option<f64> {
+        self.change_24h.map(|change| change as f64 / 100.0)
+    }
+}
+
+/// Where a holding comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoldingSource {
+    /// Listed in the `[holdings]` config section
+    Recorded,
+    /// Balance of the watched address of the asset's chain
+    Watched,
+}
+
+/// Amount of an asset held
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holding {
+    pub asset: Asset,
+    pub amount: Amount,
+    pub source: HoldingSource,
+}
+
+/// A holding valued at the price of its asset
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub holding: Holding,
+    /// Fiat per unit, the listed one when no current price is known
+    pub price: Option<f64>,
+    /// Whether `price` is the current one rather than the listed one
+    pub live: bool,
+    /// Change of the price over the last 24 hours, in percent
+    pub change_24h: Option<f64>,
+    /// Fiat value of the holding
+    pub value: Option<f64>,
+    /// Share of the total value, in percent
+    pub allocation: Option<f64>,
+}
+
+/// Holdings valued together
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Portfolio {
+    /// Largest value first, holdings without a price last
+    pub positions: Vec<Position>,
+    /// Fiat value of the holdings with a price
+    pub total: f64,
+    /// Change of the total value over the last 24 hours, in percent, from the holdings whose change is known
+    pub change_24h: Option<f64>,
+}
+
+impl Portfolio {
+    /// Value `holdings` at `prices`, falling back to the listed prices
+    pub fn new(holdings: &[Holding], prices: &[(AssetId, Price)]) -> Self {
+        let mut positions: Vec<Position> = holdings
+            .iter()
+            .map(|holding| {
+                let price = prices.iter().find(|(id, _)| *id == holding.asset.id()).map(|(_, price)| price);
+                let value = price.map(|price| price.value.to_f64()).or_else(|| holding.asset.price_value());
+                Position {
+                    holding: holding.clone(),
+                    price: value,
+                    live: price.is_some(),
+                    change_24h: price.and_then(Price::change_percent),
+                    value: value.map(|value| value * holding.amount.to_f64()),
+                    allocation: None,
+                }
+            })
+            .collect();
+        let total: f64 = positions.iter().filter_map(|position| position.value).sum();
+        for position in &mut positions {
+            position.allocation = position.value.filter(|_| total > 0.0).map(|value| value / total * 100.0);
+        }
+        positions.sort_by(|a, b| b.value.unwrap_or(-1.0).total_cmp(&a.value.unwrap_or(-1.0)));
+
+        // Value a day ago of the holdings whose change is known
+        let (now, before) = positions
+            .iter()
+            .filter_map(|position| Some((position.value?, position.change_24h?)))
+            .fold((0.0, 0.0), |(now, before), (value, change)| (now + value, before + value / (1.0 + change / 100.0)));
+        Self {
+            positions,
+            total,
+            change_24h: (before > 0.0).then(|| (now - before) / before * 100.0),
+        }
+    }
+}
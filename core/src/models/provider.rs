@@ -0,0 +1,198 @@
+//! ## Provider
+//!
+//! What a swap provider is and what routing a swap through it entrusts it with
+
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How a provider swaps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProviderKind {
+    /// Routes the swap through on-chain liquidity, from a transaction signed by the user
+    DexAggregator,
+    /// Takes a deposit and pays out the other asset from its own reserves
+    InstantExchange,
+}
+
+/// Who holds the funds while the swap settles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Custody {
+    /// Funds stay in the user's wallet until the swap settles on-chain
+    NonCustodial,
+    /// The provider holds the deposit until it pays out
+    Custodial,
+    /// Not published
+    Unknown,
+}
+
+/// What a provider asks of users before swapping
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Registration {
+    /// Swaps without an account
+    None,
+    /// Swaps without an account, an account or API key lifting limits or lowering fees
+    Optional,
+    /// Swaps only with an account
+    Required,
+    /// Not published
+    Unknown,
+}
+
+/// How a provider charges for a swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FeeModel {
+    /// Takes its fee out of the rate quoted
+    Spread,
+    /// Charges nothing on the rate, the gas of the swap transaction being paid on top
+    Gas,
+    /// Not published
+    Unknown,
+}
+
+/// What a provider is and what routing a swap through it entrusts it with
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderInfo {
+    pub kind: ProviderKind,
+    pub custody: Custody,
+    pub registration: Registration,
+    pub fee_model: FeeModel,
+    /// Value, in the fiat currency of the asset prices, above which the provider may ask for identity verification
+    /// (KYC), when it publishes one
+    pub kyc_threshold: Option<f64>,
+    /// Home page, e.g. to read its terms
+    pub website: Option<String>,
+    /// Page telling whether the provider is up
+    pub status_page: Option<String>,
+    /// Jurisdictions whose residents the provider does not serve, as ISO 3166-1 alpha-2 country codes, e.g. `US`
+    pub restricted: Vec<String>,
+}
+
+impl ProviderInfo {
+    /// Info of a provider publishing nothing but how it swaps
+    pub fn unknown(kind: ProviderKind) -> Self {
+        Self {
+            kind,
+            custody: Custody::Unknown,
+            registration: Registration::Unknown,
+            fee_model: FeeModel::Unknown,
+            kyc_threshold: None,
+            website: None,
+            status_page: None,
+            restricted: Vec::new(),
+        }
+    }
+
+    /// Whether the provider may hold the funds, or ask for identity verification above some value
+    pub fn is_flagged(&self) -> bool {
+        self.custody != Custody::NonCustodial || self.kyc_threshold.is_some()
+    }
+
+    /// Whether swapping `value`, in the fiat currency of the asset prices, crosses the KYC threshold
+    pub fn requires_kyc(&self, value: f64) -> bool {
+        self.kyc_threshold.is_some_and(|threshold| value > threshold)
+    }
+
+    /// Whether the provider does not serve residents of `jurisdiction`, a country code
+    pub fn restricts(&self, jurisdiction: &str) -> bool {
+        self.restricted.iter().any(|code| code.eq_ignore_ascii_case(jurisdiction))
+    }
+}
+
+impl Display for ProviderKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ProviderKind::DexAggregator => "DEX aggregator",
+            ProviderKind::InstantExchange => "instant exchange",
+        })
+    }
+}
+
+impl Display for Custody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Custody::NonCustodial => "non-custodial",
+            Custody::Custodial => "custodial",
+            Custody::Unknown => "unknown",
+        })
+    }
+}
+
+impl Custody {
+    /// What the custody model means for the user's funds
+    pub fn description(&self) -> &'static str {
+        match self {
+            Custody::NonCustodial => "funds stay in your wallet until the swap settles on-chain",
+            Custody::Custodial => "the provider holds your deposit until it pays out",
+            Custody::Unknown => "the provider does not say who holds the funds during the swap",
+        }
+    }
+}
+
+impl Display for Registration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Registration::None => "no account",
+            Registration::Optional => "account optional",
+            Registration::Required => "account required",
+            Registration::Unknown => "unknown",
+        })
+    }
+}
+
+impl Display for FeeModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            FeeModel::Spread => "spread",
+            FeeModel::Gas => "gas on top",
+            FeeModel::Unknown => "unknown",
+        })
+    }
+}
+
+impl FeeModel {
+    /// What the fee model means for the amount received
+    pub fn description(&self) -> &'static str {
+        match self {
+            FeeModel::Spread => "the fee is taken out of the rate quoted",
+            FeeModel::Gas => "no fee in the rate, the gas of the swap is paid on top",
+            FeeModel::Unknown => "the provider does not say how it charges",
+        }
+    }
+}
+
+/// Deposit limits of a pair, as decimal amounts of the FROM asset
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct Limits {
+    pub min: Option<String>,
+    pub max: Option<String>,
+}
+
+/// Outcome of asking a provider for its limits
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct HealthCheck {
+    pub checked_at: DateTime<Utc>,
+    /// Time the provider took to answer
+    pub latency: Duration,
+    /// Why the provider did not answer, `None` when it did
+    pub error: Option<String>,
+}
+
+/// What a provider reports it can do, from capability discovery
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Pairs of the listed assets the provider swaps
+    pub pairs: usize,
+    /// Pair the limits were asked for, e.g. `BTC → ETH`
+    pub pair: Option<String>,
+    /// Deposit limits on `pair`, `None` when the provider publishes none or did not answer
+    pub limits: Option<Limits>,
+    /// `None` when the provider offers nothing to ask
+    pub health: Option<HealthCheck>,
+}
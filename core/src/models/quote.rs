@@ -0,0 +1,261 @@
+//! ## Quote
+//!
+//! Quote requests and the offers providers answer them with
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::errors::SwapError;
+use crate::models::amount::{self, Amount};
+use crate::models::asset::{Asset, AssetId};
+
+/// Age up to which a quote is fresh
+pub const FRESH: Duration = Duration::from_secs(30);
+
+/// Age from which a quote is stale
+pub const STALE: Duration = Duration::from_secs(120);
+
+/// How recent a quote is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freshness {
+    /// Up to `FRESH` old
+    Fresh,
+    /// Between `FRESH` and `STALE` old
+    Aging,
+    /// Older than `STALE`, likely no longer honored
+    Stale,
+}
+
+/// Swap `amount` of `from` into `to`
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct QuoteRequest {
+    pub from: Asset,
+    pub to: Asset,
+    #[serde(with = "amount::exact")]
+    pub amount: Amount,
+}
+
+impl QuoteRequest {
+    /// Key identifying the request, used to discard results of outdated requests
+    pub fn key(&self) -> String {
+        format!("{}>{}:{}", self.from.id(), self.to.id(), self.amount)
+    }
+}
+
+/// Providers that answered a request so far, out of those supporting the pair
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct QuoteProgress {
+    pub answered: usize,
+    /// Answers that were errors
+    pub failed: usize,
+    pub total: usize,
+}
+
+impl QuoteProgress {
+    /// Whether every provider answered
+    pub fn is_done(&self) -> bool {
+        self.answered >= self.total
+    }
+}
+
+/// A provider that did not quote a request, and why
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct QuoteFailure {
+    pub provider: String,
+    /// `Unsupported` when the provider does not support the pair and was not asked
+    pub error: SwapError,
+}
+
+/// An offer from a provider
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize)]
+pub struct Quote {
+    /// Name of the provider making the offer
+    pub provider: String,
+    pub from: AssetId,
+    pub to: AssetId,
+    /// Amount sent
+    pub amount_in: Amount,
+    /// Amount received
+    pub amount_out: Amount,
+    /// Contract the FROM token must be approved for, on routes swapping on-chain
+    pub spender: Option<String>,
+    /// Estimated time until the TO asset is received
+    #[serde(serialize_with = "seconds")]
+    pub eta: Option<Duration>,
+    /// Fees of the route paid on top of the amount sent, e.g. the gas of an on-chain swap, in the TO asset, when the
+    /// provider estimates them
+    pub route_fee: Option<Amount>,
+    /// When the provider made the offer
+    pub quoted_at: DateTime<Utc>,
+}
+
+impl Quote {
+    /// Key of the request the offer answers, as given by [`QuoteRequest::key`]
+    pub fn request_key(&self) -> String {
+        format!("{}>{}:{}", self.from, self.to, self.amount_in)
+    }
+
+    /// Units of `to` received per unit of `from`
+    pub fn rate(&self) -> f64 {
+        let amount_in = self.amount_in.to_f64();
+        if amount_in == 0.0 {
+            0.0
+        } else {
+            self.amount_out.to_f64() / amount_in
+        }
+    }
+
+    /// Least amount received should the rate move by up to `slippage` percent before the swap, rounded down
+    pub fn min_amount_out(&self, slippage: f64) -> Amount {
+        let kept = 10_000 - (slippage.clamp(0.0, 100.0) * 100.0).round() as u128;
+        let raw = self.amount_out.raw();
+        Amount::new(raw / 10_000 * kept + raw % 10_000 * kept / 10_000, self.amount_out.decimals())
+    }
+
+    /// Amount received once the fees of the route are paid, in base units of the TO asset
+    pub fn net_amount_out(&self) -> u128 {
+        let fee = self.route_fee.map_or(0, |fee| fee.raw());
+        self.amount_out.raw().saturating_sub(fee)
+    }
+
+    /// Time since the provider made the offer
+    pub fn age(&self) -> Duration {
+        (Utc::now() - self.quoted_at).to_std().unwrap_or_default()
+    }
+
+    /// How recent the offer is
+    pub fn freshness(&self) -> Freshness {
+        match self.age() {
+            age if age <= FRESH => Freshness::Fresh,
+            age if age < STALE => Freshness::Aging,
+            _ => Freshness::Stale,
+        }
+    }
+
+    /// Time since the provider made the offer, e.g. `45s` or `3m05s`
+    pub fn age_label(&self) -> String {
+        let secs = self.age().as_secs();
+        if secs < 60 {
+            format!("{secs}s")
+        } else {
+            format!("{}m{:02}s", secs / 60, secs % 60)
+        }
+    }
+
+    /// Estimated time until the TO asset is received, e.g. `~25 min`
+    pub fn eta_label(&self) -> String {
+        match self.eta.map(|eta| eta.as_secs()) {
+            None => "?".to_string(),
+            Some(secs) if secs < 60 => format!("~{secs} s"),
+            Some(secs) => format!("~{} min", secs.div_ceil(60)),
+        }
+    }
+}
+
+/// Serialize a duration as whole seconds
+fn seconds<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => serializer.serialize_some(&duration.as_secs()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// (De)serialize quotes with exact amounts, rather than the decimal strings of exports
+pub mod exact {
+    use super::*;
+    use serde::Deserializer;
+
+    /// A quote whose amounts read back exactly
+    #[derive(Serialize, Deserialize)]
+    struct Exact {
+        provider: String,
+        from: AssetId,
+        to: AssetId,
+        #[serde(with = "amount::exact")]
+        amount_in: Amount,
+        #[serde(with = "amount::exact")]
+        amount_out: Amount,
+        spender: Option<String>,
+        eta: Option<Duration>,
+        /// Base units and decimals, missing from quotes saved before fees were estimated
+        #[serde(default)]
+        route_fee: Option<(u128, u8)>,
+        quoted_at: DateTime<Utc>,
+    }
+
+    impl From<Quote> for Exact {
+        fn from(quote: Quote) -> Self {
+            Self {
+                provider: quote.provider,
+                from: quote.from,
+                to: quote.to,
+                amount_in: quote.amount_in,
+                amount_out: quote.amount_out,
+                spender: quote.spender,
+                eta: quote.eta,
+                route_fee: quote.route_fee.map(|fee| (fee.raw(), fee.decimals())),
+                quoted_at: quote.quoted_at,
+            }
+        }
+    }
+
+    impl From<Exact> for Quote {
+        fn from(exact: Exact) -> Self {
+            Self {
+                provider: exact.provider,
+                from: exact.from,
+                to: exact.to,
+                amount_in: exact.amount_in,
+                amount_out: exact.amount_out,
+                spender: exact.spender,
+                eta: exact.eta,
+                route_fee: exact.route_fee.map(|(raw, decimals)| Amount::new(raw, decimals)),
+                quoted_at: exact.quoted_at,
+            }
+        }
+    }
+
+    /// Several quotes
+    pub mod many {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(quotes: &[Quote], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(quotes.iter().cloned().map(Exact::from))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Quote>, D::Error> {
+            let quotes: Vec<Exact> = Deserialize::deserialize(deserializer)?;
+            Ok(quotes.into_iter().map(Quote::from).collect())
+        }
+    }
+
+    /// A quote, if any
+    pub mod optional {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(quote: &Option<Quote>, serializer: S) -> Result<S::Ok, S::Error> {
+            quote.clone().map(Exact::from).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Quote>, D::Error> {
+            let quote: Option<Exact> = Deserialize::deserialize(deserializer)?;
+            Ok(quote.map(Quote::from))
+        }
+    }
+
+    /// A quote or the error asking for it failed with
+    pub mod result {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(quote: &Result<Quote, SwapError>, serializer: S) -> Result<S::Ok, S::Error> {
+            quote.clone().map(Exact::from).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Result<Quote, SwapError>, D::Error> {
+            let quote: Result<Exact, SwapError> = Deserialize::deserialize(deserializer)?;
+            Ok(quote.map(Quote::from))
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! ## Schedule
+//!
+//! Recurring swaps of a saved draft, e.g. `0.05 BTC -> ETH` weekly, to average into an asset over time
+
+use std::fmt::{self, Display};
+
+use chrono::{DateTime, Duration, Months, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How often a scheduled swap comes due
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Frequency {
+    /// Every frequency, in the order the drafts picker cycles through
+    pub const ALL: [Frequency; 3] = [Frequency::Daily, Frequency::Weekly, Frequency::Monthly];
+
+    /// Occurrence following the one at `at`
+    pub fn after(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        self.nth_after(at, 1)
+    }
+
+    /// `n`-th occurrence following the one at `anchor`
+    pub fn nth_after(&self, anchor: DateTime<Utc>, n: u32) -> DateTime<Utc> {
+        match self {
+            Self::Daily => anchor + Duration::days(n.into()),
+            Self::Weekly => anchor + Duration::weeks(n.into()),
+            // Day 31 falls back to the last day of shorter months, and is back on the 31st after them
+            Self::Monthly => anchor
+                .checked_add_months(Months::new(n))
+                .unwrap_or(anchor + Duration::days(30 * i64::from(n))),
+        }
+    }
+}
+
+/// Displayed as its name, e.g. `weekly`
+impl Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Daily => write!(f, "daily"),
+            Self::Weekly => write!(f, "weekly"),
+            Self::Monthly => write!(f, "monthly"),
+        }
+    }
+}
+
+/// Repetition of a saved draft
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Schedule {
+    pub frequency: Frequency,
+    /// When the next swap comes due
+    pub next_at: DateTime<Utc>,
+    /// Occurrence the next ones are counted from, keeping monthly swaps on the day they were set up on; schedules
+    /// saved without one count from their next swap
+    #[serde(default)]
+    pub anchor: Option<DateTime<Utc>>,
+}
+
+impl Schedule {
+    /// Repeat every `frequency` from `now`, the swap of `now` being made by hand
+    pub fn new(frequency: Frequency, now: DateTime<Utc>) -> Self {
+        Self {
+            frequency,
+            next_at: frequency.after(now),
+            anchor: Some(now),
+        }
+    }
+
+    /// Whether a swap is due at `now`
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_at <= now
+    }
+
+    /// Move to the first occurrence after `now`, the ones missed meanwhile being folded into the swap made
+    pub fn advance(&mut self, now: DateTime<Utc>) {
+        let anchor = *self.anchor.get_or_insert(self.next_at);
+        let mut n = 1;
+        while self.next_at <= now {
+            self.next_at = self.frequency.nth_after(anchor, n);
+            n += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn monthly_schedule_keeps_its_day_after_short_months() {
+        let at = |month, day| Utc.with_ymd_and_hms(2026, month, day, 9, 0, 0).unwrap();
+        let mut schedule = Schedule::new(Frequency::Monthly, at(1, 31));
+        assert_eq!(schedule.next_at, at(2, 28));
+        schedule.advance(at(2, 28));
+        assert_eq!(schedule.next_at, at(3, 31));
+        schedule.advance(at(3, 31));
+        assert_eq!(schedule.next_at, at(4, 30));
+        schedule.advance(at(4, 30));
+        assert_eq!(schedule.next_at, at(5, 31));
+    }
+
+    #[test]
+    fn schedule_saved_without_anchor_counts_from_its_next_swap() {
+        let at = |month, day| Utc.with_ymd_and_hms(2026, month, day, 9, 0, 0).unwrap();
+        let mut schedule = Schedule {
+            frequency: Frequency::Weekly,
+            next_at: at(1, 5),
+            anchor: None,
+        };
+        // Missed swaps are folded into the one made
+        schedule.advance(at(1, 20));
+        assert_eq!(schedule.next_at, at(1, 26));
+    }
+}
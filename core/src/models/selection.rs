@@ -0,0 +1,146 @@
+//! ## Selection
+//!
+//! Policies picking the quote routed through among the offers made for a request
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::models::quote::Quote;
+
+/// How the quote routed through is picked, as set in the `[selection]` config section
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Selection {
+    pub policy: Policy,
+    /// Weight of each criterion, for the `weighted` policy
+    pub weights: Weights,
+}
+
+/// Criterion a quote is picked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Policy {
+    /// Most received
+    #[default]
+    MaxOutput,
+    /// Least paid in spread and route fees together, i.e. most received once the fees of the route are paid
+    MinFee,
+    /// Soonest received, quotes without an estimate coming last
+    FastestEta,
+    /// Best score across the criteria, each weighted as configured
+    Weighted,
+}
+
+/// Weights of the criteria of the `weighted` policy, relative to each other
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Weights {
+    pub output: f64,
+    pub fee: f64,
+    pub eta: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            output: 0.5,
+            fee: 0.3,
+            eta: 0.2,
+        }
+    }
+}
+
+impl Policy {
+    /// Every policy
+    pub const ALL: [Policy; 4] = [Policy::MaxOutput, Policy::MinFee, Policy::FastestEta, Policy::Weighted];
+
+    /// Name used in the config file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MaxOutput => "max-output",
+            Self::MinFee => "min-fee",
+            Self::FastestEta => "fastest-eta",
+            Self::Weighted => "weighted",
+        }
+    }
+}
+
+impl Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("expected max-output, min-fee, fastest-eta or weighted, got {s}"))
+    }
+}
+
+impl Selection {
+    /// Index of the quote to route through, the one receiving most on ties; `None` without quotes
+    pub fn select(&self, quotes: &[Quote]) -> Option<usize> {
+        let score = |quote: &Quote| match self.policy {
+            Policy::MaxOutput => quote.amount_out.raw() as f64,
+            Policy::MinFee => quote.net_amount_out() as f64,
+            Policy::FastestEta => -quote.eta.map_or(f64::INFINITY, |eta| eta.as_secs_f64()),
+            Policy::Weighted => self.weighted(quote, quotes),
+        };
+        let mut best: Option<(usize, f64, u128)> = None;
+        for (index, quote) in quotes.iter().enumerate() {
+            let (score, amount_out) = (score(quote), quote.amount_out.raw());
+            let better = best.is_none_or(|(_, best, received)| {
+                score > best || (score == best && amount_out > received)
+            });
+            if better {
+                best = Some((index, score, amount_out));
+            }
+        }
+        best.map(|(index, ..)| index)
+    }
+
+    /// Index in `quotes` of the quote to route through among those of providers other than `restricted`, e.g. those
+    /// not serving the user's jurisdiction
+    pub fn select_allowed(&self, quotes: &[Quote], restricted: &[String]) -> Option<usize> {
+        let (indices, allowed): (Vec<usize>, Vec<Quote>) = quotes
+            .iter()
+            .enumerate()
+            .filter(|(_, quote)| !restricted.contains(&quote.provider))
+            .map(|(i, quote)| (i, quote.clone()))
+            .unzip();
+        self.select(&allowed).map(|i| indices[i])
+    }
+
+    /// Sum of the weighted criteria of `quote`, each scaled between 0 for the worst of `quotes` and 1 for the best
+    fn weighted(&self, quote: &Quote, quotes: &[Quote]) -> f64 {
+        let output = |quote: &Quote| quote.amount_out.raw() as f64;
+        let net = |quote: &Quote| quote.net_amount_out() as f64;
+        // Quotes without an estimate rank with the slowest
+        let slowest = quotes.iter().filter_map(|quote| quote.eta).max().unwrap_or_default().as_secs_f64();
+        let speed = |quote: &Quote| -quote.eta.map_or(slowest, |eta| eta.as_secs_f64());
+        let weights = self.weights;
+        weights.output * scaled(quote, quotes, output)
+            + weights.fee * scaled(quote, quotes, net)
+            + weights.eta * scaled(quote, quotes, speed)
+    }
+}
+
+/// Value of `quote` between 0 for the lowest of `quotes` and 1 for the highest, 1 when they are all equal
+fn scaled(quote: &Quote, quotes: &[Quote], value: impl Fn(&Quote) -> f64) -> f64 {
+    let (low, high) = quotes
+        .iter()
+        .map(&value)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), value| (low.min(value), high.max(value)));
+    if high > low {
+        (value(quote) - low) / (high - low)
+    } else {
+        1.0
+    }
+}
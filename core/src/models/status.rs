@@ -0,0 +1,47 @@
+//! ## Status
+//!
+//! Outcome of an action, shown in the status bar according to its severity
+
+/// How much a status needs the user's attention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Something went as asked; cleared after a few seconds
+    Info,
+    /// Something went wrong that the user can work around; fades out
+    Warning,
+    /// Something failed; stays until acknowledged
+    Error,
+}
+
+/// A message for the status bar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Status {
+    pub severity: Severity,
+    pub text: String,
+}
+
+impl Status {
+    /// Status of something that went as asked
+    pub fn info(text: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Info,
+            text: text.into(),
+        }
+    }
+
+    /// Status of something that went wrong but can be worked around
+    pub fn warning(text: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            text: text.into(),
+        }
+    }
+
+    /// Status of something that failed
+    pub fn error(text: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            text: text.into(),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! ## Wallet
+//!
+//! Watch-only addresses used to look up balances
+
+use std::collections::HashMap;
+
+use crate::models::chain::Chain;
+
+/// Watch-only addresses, at most one per chain
+#[derive(Debug, Default, Clone)]
+pub struct WatchOnlyWallet {
+    addresses: HashMap<Chain, String>,
+}
+
+impl WatchOnlyWallet {
+    /// Create an empty wallet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) the watched address for a chain
+    pub fn register(&mut self, chain: Chain, address: String) {
+        self.addresses.insert(chain, address);
+    }
+
+    /// Watched address for a chain, if any
+    pub fn address(&self, chain: Chain) -> Option<&str> {
+        self.addresses.get(&chain).map(String::as_str)
+    }
+}
@@ -0,0 +1,26 @@
+//! ## Watchlist
+//!
+//! Pairs quoted on a timer, to monitor several markets besides the one of the swap form
+
+use std::fmt::{self, Display};
+
+use serde::Deserialize;
+
+/// Pair on the watchlist, as listed in the `[[watchlist]]` config section
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WatchedPair {
+    /// FROM asset, by ticker (`BTC`, on any chain) or id (`usdc-arbitrum`)
+    pub from: String,
+    /// TO asset, by ticker or id
+    pub to: String,
+    /// FROM amount quoted, as typed
+    pub amount: String,
+}
+
+/// Displayed as its request, e.g. `0.1 BTC→ETH`
+impl Display for WatchedPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}→{}", self.amount, self.from, self.to)
+    }
+}
@@ -0,0 +1,17 @@
+//! ## Services
+//!
+//! Network services of the engine: providers, quoting and chain reads
+
+pub mod allowance;
+pub mod balance;
+pub mod cancel;
+pub mod discovery;
+pub mod ens;
+pub mod export;
+pub mod fees;
+pub mod http;
+pub mod metrics;
+pub mod prices;
+pub mod providers;
+pub mod quote;
+pub mod rpc;
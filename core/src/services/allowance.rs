@@ -0,0 +1,37 @@
+//! ## Allowance
+//!
+//! ERC-20 allowances granted to the contracts of on-chain routes
+
+use crate::errors::{SwapError, ValidationError};
+use crate::models::amount::Amount;
+use crate::models::asset::Asset;
+use crate::services::rpc::{self, RpcEndpoints};
+
+/// `allowance(address,address)` selector
+const ALLOWANCE_SELECTOR: &str = "dd62ed3e";
+
+/// Fetch how much of `asset` `spender` may transfer on behalf of `owner`
+pub fn fetch_allowance(
+    endpoints: &RpcEndpoints,
+    asset: &Asset,
+    owner: &str,
+    spender: &str,
+) -> Result<Amount, SwapError> {
+    let Some(contract) = asset.contract.as_deref().filter(|_| asset.chain.is_evm()) else {
+        return Err(ValidationError::Unsupported(format!("allowance of {}", asset.label())).into());
+    };
+    let data = format!(
+        "0x{ALLOWANCE_SELECTOR}{:0>64}{:0>64}",
+        owner.trim_start_matches("0x"),
+        spender.trim_start_matches("0x")
+    );
+    let word = rpc::eth_call(endpoints.get(asset.chain), contract, &data)?;
+
+    let raw = match rpc::parse_word(&word) {
+        Ok(raw) => raw,
+        // Unlimited approvals do not fit in 128 bits
+        Err(_) if word.trim_start_matches("0x").chars().all(|c| c.is_ascii_hexdigit()) => u128::MAX,
+        Err(err) => return Err(err),
+    };
+    Ok(Amount::new(raw, asset.decimals))
+}
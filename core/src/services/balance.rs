@@ -0,0 +1,75 @@
+//! ## Balance
+//!
+//! Read-only balance lookups for watch-only addresses
+
+use serde_json::{json, Value};
+
+use crate::errors::{NetworkError, SwapError};
+use crate::models::amount::Amount;
+use crate::models::asset::Asset;
+use crate::models::chain::Chain;
+use crate::services::rpc::{self, RpcEndpoints};
+
+/// `balanceOf(address)` selector
+const BALANCE_OF_SELECTOR: &str = "70a08231";
+
+/// Fetch the balance of `asset` held by `address` on the asset's chain
+pub fn fetch_balance(endpoints: &RpcEndpoints, asset: &Asset, address: &str) -> Result<Amount, SwapError> {
+    let url = endpoints.get(asset.chain);
+    let raw = match (asset.chain, asset.contract.as_deref()) {
+        (Chain::Bitcoin, _) => bitcoin_balance(url, address)?,
+        (Chain::Ethereum | Chain::Arbitrum, None) => {
+            rpc::parse_hex_quantity(&rpc::call(url, "eth_getBalance", json!([address, "latest"]))?)?
+        }
+        (Chain::Ethereum | Chain::Arbitrum, Some(contract)) => {
+            let data = format!("0x{BALANCE_OF_SELECTOR}{:0>64}", address.trim_start_matches("0x"));
+            rpc::parse_word(&rpc::eth_call(url, contract, &data)?)?
+        }
+        (Chain::Solana, None) => rpc::call(url, "getBalance", json!([address]))?
+            .get("value")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| NetworkError::InvalidResponse("getBalance: missing value".to_string()))?
+            .into(),
+        (Chain::Solana, Some(mint)) => spl_balance(url, address, mint)?,
+    };
+    Ok(Amount::new(raw, asset.decimals))
+}
+
+/// Confirmed plus unconfirmed balance in satoshis, from an esplora explorer
+fn bitcoin_balance(explorer: &str, address: &str) -> Result<u128, SwapError> {
+    let stats = rpc::get_json(&format!("{explorer}/address/{address}"))?;
+    let sum = |section: &str, field: &str| {
+        stats
+            .get(section)
+            .and_then(|s| s.get(field))
+            .and_then(Value::as_u64)
+            .unwrap_or_default() as u128
+    };
+    let funded = sum("chain_stats", "funded_txo_sum") + sum("mempool_stats", "funded_txo_sum");
+    let spent = sum("chain_stats", "spent_txo_sum") + sum("mempool_stats", "spent_txo_sum");
+    Ok(funded.saturating_sub(spent))
+}
+
+/// Sum of the owner's token accounts for an SPL mint
+fn spl_balance(url: &str, owner: &str, mint: &str) -> Result<u128, SwapError> {
+    let accounts = rpc::call(
+        url,
+        "getTokenAccountsByOwner",
+        json!([owner, { "mint": mint }, { "encoding": "jsonParsed" }]),
+    )?;
+    Ok(accounts
+        .get("value")
+        .and_then(Value::as_array)
+        .map(|accounts| {
+            accounts
+                .iter()
+                .filter_map(|account| {
+                    account
+                        .pointer("/account/data/parsed/info/tokenAmount/amount")
+                        .and_then(Value::as_str)
+                        .and_then(|amount| amount.parse::<u128>().ok())
+                })
+                .sum()
+        })
+        .unwrap_or_default())
+}
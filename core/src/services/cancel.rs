@@ -0,0 +1,40 @@
+//! ## Cancel
+//!
+//! Cancellation tokens shared between the UI and the background requests it started, in scopes: cancelling a token
+//! cancels the tokens made from it with [`CancelToken::child`] too
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Token telling a background request to stop; clones share the same state
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    /// Token whose cancellation cancels this one too
+    parent: Option<Arc<CancelToken>>,
+}
+
+impl CancelToken {
+    /// Create a token that is not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a token cancelled with this one, or on its own
+    pub fn child(&self) -> Self {
+        Self {
+            cancelled: Arc::default(),
+            parent: Some(Arc::new(self.clone())),
+        }
+    }
+
+    /// Ask the request to stop; requests already sent still complete, but their results are dropped
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the request or one of its parents was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.parent.as_ref().is_some_and(|parent| parent.is_cancelled())
+    }
+}
@@ -0,0 +1,57 @@
+//! ## Discovery
+//!
+//! Capability discovery: which of the listed pairs a provider swaps, and what it answers when asked for its limits
+
+use std::time::Instant;
+
+use chrono::Utc;
+use tracing::{debug, warn};
+
+use crate::models::asset::Asset;
+use crate::models::provider::{Capabilities, HealthCheck};
+use crate::services::providers::Provider;
+
+/// Count the pairs of `assets` `provider` swaps, and ask it for its limits on `pair`, or on the first pair it swaps
+/// when it does not swap that one
+pub fn discover(provider: &dyn Provider, assets: &[Asset], pair: Option<(&Asset, &Asset)>) -> Capabilities {
+    let supported: Vec<(&Asset, &Asset)> = assets
+        .iter()
+        .flat_map(|from| assets.iter().map(move |to| (from, to)))
+        .filter(|(from, to)| provider.supports(from, to))
+        .collect();
+    let pair = pair
+        .filter(|(from, to)| provider.supports(from, to))
+        .or_else(|| supported.first().copied());
+    let mut capabilities = Capabilities {
+        pairs: supported.len(),
+        pair: None,
+        limits: None,
+        health: None,
+    };
+    let Some((from, to)) = pair else {
+        return capabilities;
+    };
+    let started = Instant::now();
+    let Some(limits) = provider.limits(from, to) else {
+        return capabilities;
+    };
+    let latency = started.elapsed();
+    capabilities.pair = Some(format!("{} → {}", from.label(), to.label()));
+    let error = match limits {
+        Ok(limits) => {
+            debug!(provider = provider.name(), elapsed_ms = latency.as_millis() as u64, "limits received");
+            capabilities.limits = Some(limits);
+            None
+        }
+        Err(err) => {
+            warn!(provider = provider.name(), %err, "health check failed");
+            Some(err.root().to_string())
+        }
+    };
+    capabilities.health = Some(HealthCheck {
+        checked_at: Utc::now(),
+        latency,
+        error,
+    });
+    capabilities
+}
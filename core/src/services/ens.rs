@@ -0,0 +1,98 @@
+//! ## Ens
+//!
+//! Resolution of ENS names (`vitalik.eth`) to Ethereum addresses
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use tiny_keccak::{Hasher, Keccak};
+
+use crate::errors::{SwapError, ValidationError};
+use crate::services::metrics;
+use crate::services::rpc::{self, RpcEndpoints};
+
+/// ENS registry contract, identical on every network
+const ENS_REGISTRY: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+/// `resolver(bytes32)` selector
+const RESOLVER_SELECTOR: &str = "0178b8bf";
+/// `addr(bytes32)` selector
+const ADDR_SELECTOR: &str = "3b3b57de";
+
+/// Addresses resolved during the session, by node and name
+static RESOLVED: Mutex<Option<HashMap<(String, String), String>>> = Mutex::new(None);
+
+/// Whether the value looks like an ENS name rather than an address
+pub fn is_ens_name(value: &str) -> bool {
+    value.len() > 4
+        && value.ends_with(".eth")
+        && value
+            .split('.')
+            .all(|label| !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-'))
+}
+
+/// Resolve an ENS name to a checksum-free `0x` address using the Ethereum node, once per session
+pub fn resolve(endpoints: &RpcEndpoints, name: &str) -> Result<String, SwapError> {
+    let key = (endpoints.ethereum.clone(), name.to_lowercase());
+    let cached = RESOLVED
+        .lock()
+        .ok()
+        .and_then(|resolved| resolved.as_ref()?.get(&key).cloned());
+    metrics::record_cache(cached.is_some());
+    if let Some(address) = cached {
+        return Ok(address);
+    }
+
+    let address = lookup(endpoints, name)?;
+    if let Ok(mut resolved) = RESOLVED.lock() {
+        resolved.get_or_insert_with(HashMap::new).insert(key, address.clone());
+    }
+    Ok(address)
+}
+
+/// Ask the Ethereum node for the address of an ENS name
+fn lookup(endpoints: &RpcEndpoints, name: &str) -> Result<String, SwapError> {
+    let node = hex(&namehash(&name.to_lowercase()));
+
+    let resolver = word_to_address(&rpc::eth_call(
+        &endpoints.ethereum,
+        ENS_REGISTRY,
+        &format!("0x{RESOLVER_SELECTOR}{node}"),
+    )?)
+    .ok_or_else(|| ValidationError::Unsupported(format!("{name} has no resolver")))?;
+
+    word_to_address(&rpc::eth_call(&endpoints.ethereum, &resolver, &format!("0x{ADDR_SELECTOR}{node}"))?)
+        .ok_or_else(|| ValidationError::Unsupported(format!("{name} does not resolve to an address")).into())
+}
+
+/// EIP-137 namehash of a normalized name
+fn namehash(name: &str) -> [u8; 32] {
+    name.rsplit('.').fold([0u8; 32], |node, label| {
+        let mut hasher = Keccak::v256();
+        hasher.update(&node);
+        hasher.update(&keccak256(label.as_bytes()));
+        let mut out = [0u8; 32];
+        hasher.finalize(&mut out);
+        out
+    })
+}
+
+/// Keccak-256 digest
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    let mut out = [0u8; 32];
+    hasher.finalize(&mut out);
+    out
+}
+
+/// Lowercase hex without prefix
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Address held in the last 20 bytes of an ABI word, `None` for the zero address
+fn word_to_address(word: &str) -> Option<String> {
+    let word = word.trim_start_matches("0x");
+    let address = word.get(word.len().checked_sub(40)?..)?;
+    (!address.chars().all(|c| c == '0')).then(|| format!("0x{address}"))
+}
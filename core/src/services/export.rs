@@ -0,0 +1,162 @@
+//! ## Export
+//!
+//! Files exported for record-keeping
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{SecondsFormat, Utc};
+use serde::Deserialize;
+
+use crate::errors::{SwapError, ValidationError};
+use crate::models::order::Order;
+use crate::models::quote::Quote;
+
+/// Column of the quotes CSV, named by its header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Column {
+    Timestamp,
+    Provider,
+    From,
+    To,
+    AmountIn,
+    AmountOut,
+    Rate,
+    NetworkFee,
+    EtaSeconds,
+}
+
+impl Column {
+    /// Every column, in file order
+    pub const ALL: [Column; 9] = [
+        Column::Timestamp,
+        Column::Provider,
+        Column::From,
+        Column::To,
+        Column::AmountIn,
+        Column::AmountOut,
+        Column::Rate,
+        Column::NetworkFee,
+        Column::EtaSeconds,
+    ];
+
+    /// Header of the column
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Timestamp => "timestamp",
+            Self::Provider => "provider",
+            Self::From => "from",
+            Self::To => "to",
+            Self::AmountIn => "amount_in",
+            Self::AmountOut => "amount_out",
+            Self::Rate => "rate",
+            Self::NetworkFee => "network_fee",
+            Self::EtaSeconds => "eta_seconds",
+        }
+    }
+
+    /// Value of the column for `quote`
+    fn value(self, quote: &Quote, network_fee: Option<&str>) -> String {
+        match self {
+            Self::Timestamp => quote.quoted_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            Self::Provider => quote.provider.clone(),
+            Self::From => quote.from.to_string(),
+            Self::To => quote.to.to_string(),
+            Self::AmountIn => quote.amount_in.to_string(),
+            Self::AmountOut => quote.amount_out.to_string(),
+            Self::Rate => quote.rate().to_string(),
+            Self::NetworkFee => network_fee.unwrap_or_default().to_string(),
+            Self::EtaSeconds => quote.eta.map(|eta| eta.as_secs().to_string()).unwrap_or_default(),
+        }
+    }
+}
+
+/// Write the `columns` of quotes to a timestamped CSV file in `dir`, returning its path
+pub fn write_quotes_csv(
+    dir: &Path,
+    quotes: &[Quote],
+    network_fee: Option<&str>,
+    columns: &[Column],
+) -> Result<PathBuf, SwapError> {
+    let Some(first) = quotes.first() else {
+        return Err(ValidationError::Unsupported("no quotes to export".to_string()).into());
+    };
+    let path = quotes_csv_path(dir, first);
+    write_quotes_csv_to(&path, quotes, network_fee, columns)?;
+    Ok(path)
+}
+
+/// Timestamped CSV file in `dir` quotes on the pair of `quote` are exported to unless told otherwise
+pub fn quotes_csv_path(dir: &Path, quote: &Quote) -> PathBuf {
+    dir.join(format!(
+        "quotes-{}-{}-{}.csv",
+        quote.from,
+        quote.to,
+        Utc::now().format("%Y%m%dT%H%M%SZ")
+    ))
+}
+
+/// Write the `columns` of quotes to the CSV file at `path`, creating its directory
+pub fn write_quotes_csv_to(
+    path: &Path,
+    quotes: &[Quote],
+    network_fee: Option<&str>,
+    columns: &[Column],
+) -> Result<(), SwapError> {
+    if columns.is_empty() {
+        return Err(ValidationError::Unsupported("no columns to export".to_string()).into());
+    }
+    let headers: Vec<&str> = columns.iter().map(|column| column.name()).collect();
+    let mut csv = headers.join(",");
+    csv.push('\n');
+    for quote in quotes {
+        let row: Vec<String> = columns.iter().map(|column| csv_field(&column.value(quote, network_fee))).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    let dir = path.parent().unwrap_or(Path::new(""));
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(path, csv))
+        .map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))
+}
+
+/// Write the receipt of a swap as JSON and text files in `dir`, returning the text file's path
+pub fn write_receipt(dir: &Path, order: &Order) -> Result<PathBuf, SwapError> {
+    let quote = &order.quote;
+    let unknown = || "-".to_string();
+    let text = [
+        format!("Swap receipt{}", if order.simulated { " (simulated)" } else { "" }),
+        String::new(),
+        format!("Order id:            {}", order.id),
+        format!("Provider:            {}", quote.provider),
+        format!("Pair:                {} -> {}", quote.from.label(), quote.to.label()),
+        format!("Sent:                {} {}", quote.amount_in, quote.from.label()),
+        format!("Received:            {} {}", quote.amount_out, quote.to.label()),
+        format!("Rate:                {:.6}", quote.rate()),
+        format!("Deposit address:     {}", order.deposit_address.clone().unwrap_or_else(unknown)),
+        format!("Destination address: {}", order.destination_address.clone().unwrap_or_else(unknown)),
+        format!("Quoted at:           {}", quote.quoted_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        format!("Created at:          {}", order.created_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+    ]
+    .join("\n");
+    let json = serde_json::to_string_pretty(order).map_err(|err| SwapError::Io(err.to_string()))?;
+
+    let path = dir.join(format!("receipt-{}", order.id));
+    let (json_path, text_path) = (path.with_extension("json"), path.with_extension("txt"));
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&json_path, json))
+        .and_then(|_| fs::write(&text_path, text + "\n"))
+        .map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))?;
+    Ok(text_path)
+}
+
+/// Quote a CSV field when it contains a separator, a quote or a line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
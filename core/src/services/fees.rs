@@ -0,0 +1,133 @@
+//! ## Fees
+//!
+//! Network fee estimation from public fee oracles
+
+use serde_json::{json, Value};
+
+use crate::errors::{NetworkError, SwapError};
+use crate::models::amount::Amount;
+use crate::models::asset::Asset;
+use crate::models::chain::Chain;
+use crate::models::fee::{FeeRate, FeeSpeed, NetworkFee};
+use crate::services::rpc::{self, RpcEndpoints};
+
+/// Size of a one-input, two-output P2WPKH transaction
+const BITCOIN_DEPOSIT_VBYTES: u64 = 141;
+/// Gas used by a plain ether transfer
+const NATIVE_TRANSFER_GAS: u64 = 21_000;
+/// Gas usually used by an ERC-20 `transfer`
+const TOKEN_TRANSFER_GAS: u64 = 65_000;
+/// Solana base fee per signature
+const SOLANA_LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+/// Blocks sampled to derive priority tips
+const FEE_HISTORY_BLOCKS: u64 = 20;
+/// Reward percentiles sampled for the slow, normal and fast presets
+const FEE_HISTORY_PERCENTILES: [u8; 3] = [10, 50, 90];
+
+/// Estimate the fee of sending `asset` to a provider's deposit address.
+///
+/// EIP-1559 chains get one estimate per [`FeeSpeed`], slowest first; other chains get a single one.
+pub fn estimate_network_fees(endpoints: &RpcEndpoints, asset: &Asset) -> Result<Vec<NetworkFee>, SwapError> {
+    let chain = asset.chain;
+    let url = endpoints.get(chain);
+    let fees = match chain {
+        Chain::Bitcoin => vec![network_fee(
+            chain,
+            None,
+            FeeRate::SatPerVbyte(bitcoin_fee_rate(url)?),
+            BITCOIN_DEPOSIT_VBYTES,
+        )],
+        Chain::Ethereum | Chain::Arbitrum => {
+            let gas = if asset.contract.is_some() {
+                TOKEN_TRANSFER_GAS
+            } else {
+                NATIVE_TRANSFER_GAS
+            };
+            let (base_fee, tips) = eip1559_fees(url)?;
+            FeeSpeed::ALL
+                .into_iter()
+                .zip(tips)
+                .map(|(speed, priority_fee)| {
+                    network_fee(
+                        chain,
+                        Some(speed),
+                        FeeRate::Eip1559 {
+                            base_fee,
+                            priority_fee,
+                        },
+                        gas,
+                    )
+                })
+                .collect()
+        }
+        Chain::Solana => vec![network_fee(
+            chain,
+            None,
+            FeeRate::LamportsPerSignature(SOLANA_LAMPORTS_PER_SIGNATURE),
+            1,
+        )],
+    };
+    Ok(fees)
+}
+
+/// Fee of a transaction of `units` at `rate`
+fn network_fee(chain: Chain, speed: Option<FeeSpeed>, rate: FeeRate, units: u64) -> NetworkFee {
+    let per_unit = match rate {
+        FeeRate::SatPerVbyte(rate) | FeeRate::LamportsPerSignature(rate) => rate as u128,
+        FeeRate::Eip1559 {
+            base_fee,
+            priority_fee,
+        } => base_fee + priority_fee,
+    };
+    NetworkFee {
+        chain,
+        speed,
+        rate,
+        units,
+        amount: Amount::new(per_unit * units as u128, chain.native_decimals()),
+    }
+}
+
+/// Fee rate targeting confirmation within half an hour, from a mempool.space-compatible API
+fn bitcoin_fee_rate(explorer: &str) -> Result<u64, SwapError> {
+    rpc::get_json(&format!("{explorer}/v1/fees/recommended"))?
+        .get("halfHourFee")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| NetworkError::InvalidResponse("fees/recommended: missing halfHourFee".to_string()).into())
+}
+
+/// Next block's base fee and the average priority tips paid at each sampled percentile
+fn eip1559_fees(url: &str) -> Result<(u128, [u128; 3]), SwapError> {
+    let history = rpc::call(
+        url,
+        "eth_feeHistory",
+        json!([format!("{FEE_HISTORY_BLOCKS:#x}"), "latest", FEE_HISTORY_PERCENTILES]),
+    )?;
+
+    // The last entry is the base fee of the upcoming block
+    let base_fee = history
+        .get("baseFeePerGas")
+        .and_then(Value::as_array)
+        .and_then(|fees| fees.last())
+        .ok_or_else(|| NetworkError::InvalidResponse("eth_feeHistory: missing baseFeePerGas".to_string()).into())
+        .and_then(rpc::parse_hex_quantity)?;
+
+    let rewards = history
+        .get("reward")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let mut tips = [0u128; 3];
+    for (i, tip) in tips.iter_mut().enumerate() {
+        let samples: Vec<u128> = rewards
+            .iter()
+            .filter_map(|block| block.get(i))
+            .filter_map(|reward| rpc::parse_hex_quantity(reward).ok())
+            .collect();
+        if !samples.is_empty() {
+            *tip = samples.iter().sum::<u128>() / samples.len() as u128;
+        }
+    }
+
+    Ok((base_fee, tips))
+}
@@ -0,0 +1,85 @@
+//! ## Http
+//!
+//! Shared HTTP agent honoring the proxy and offline settings. Built without the `network` feature, every
+//! request fails as offline.
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::errors::SwapError;
+#[cfg(not(feature = "network"))]
+use crate::errors::NetworkError;
+
+#[cfg(feature = "network")]
+pub use client::configure;
+
+/// Perform a GET request with the given headers, returning JSON
+#[cfg(feature = "network")]
+pub fn get_json(url: &str, headers: &[(&str, &str)], timeout: Duration) -> Result<Value, SwapError> {
+    let mut request = client::agent()?.get(url).timeout(timeout);
+    for (name, value) in headers {
+        request = request.set(name, value);
+    }
+    Ok(request.call()?.into_json()?)
+}
+
+/// Perform a POST request with a JSON body, returning JSON
+#[cfg(feature = "network")]
+pub fn post_json(url: &str, body: Value, timeout: Duration) -> Result<Value, SwapError> {
+    Ok(client::agent()?.post(url).timeout(timeout).send_json(body)?.into_json()?)
+}
+
+#[cfg(feature = "network")]
+mod client {
+    use std::sync::OnceLock;
+
+    use ureq::{Agent, AgentBuilder, Proxy};
+
+    use crate::errors::{NetworkError, SwapError};
+
+    /// Agent used by every service; `None` in offline mode
+    static AGENT: OnceLock<Option<Agent>> = OnceLock::new();
+
+    /// Set up the shared agent; later calls are ignored
+    pub fn configure(proxy: Option<&str>, offline: bool) -> Result<(), SwapError> {
+        let agent = if offline {
+            None
+        } else {
+            let mut builder = AgentBuilder::new();
+            if let Some(proxy) = proxy {
+                let proxy = Proxy::new(proxy).map_err(|err| SwapError::Config(format!("proxy {proxy}: {err}")))?;
+                builder = builder.proxy(proxy);
+            }
+            Some(builder.build())
+        };
+        let _ = AGENT.set(agent);
+        Ok(())
+    }
+
+    /// The shared agent, a direct one when not configured
+    pub fn agent() -> Result<&'static Agent, SwapError> {
+        AGENT
+            .get_or_init(|| Some(Agent::new()))
+            .as_ref()
+            .ok_or_else(|| NetworkError::Offline.into())
+    }
+}
+
+/// Set up the shared agent; there is none to set up without the `network` feature
+#[cfg(not(feature = "network"))]
+pub fn configure(_proxy: Option<&str>, _offline: bool) -> Result<(), SwapError> {
+    Ok(())
+}
+
+/// Perform a GET request with the given headers, returning JSON
+#[cfg(not(feature = "network"))]
+pub fn get_json(_url: &str, _headers: &[(&str, &str)], _timeout: Duration) -> Result<Value, SwapError> {
+    Err(NetworkError::Offline.into())
+}
+
+/// Perform a POST request with a JSON body, returning JSON
+#[cfg(not(feature = "network"))]
+pub fn post_json(_url: &str, _body: Value, _timeout: Duration) -> Result<Value, SwapError> {
+    Err(NetworkError::Offline.into())
+}
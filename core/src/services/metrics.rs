@@ -0,0 +1,166 @@
+//! ## Metrics
+//!
+//! Request counts, errors, latencies and cache hits of the services, for the current session
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Upper bounds of the latency histogram buckets, in milliseconds; slower requests go to an
+/// extra last bucket
+pub const LATENCY_BUCKETS_MS: [u64; 6] = [100, 250, 500, 1000, 2500, 5000];
+
+/// Latest requests kept per service for the rolling statistics
+pub const ROLLING_WINDOW: usize = 100;
+
+/// A request of the rolling window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    pub ms: u64,
+    pub ok: bool,
+}
+
+/// Requests made to one service, like a provider or a node
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceStats {
+    pub requests: u64,
+    pub errors: u64,
+    /// Total time spent waiting, to average latencies
+    pub total_ms: u64,
+    /// Requests per latency bucket, the last one unbounded
+    pub histogram: [u64; LATENCY_BUCKETS_MS.len() + 1],
+    /// Latest requests, oldest first, at most [`ROLLING_WINDOW`]
+    pub recent: VecDeque<Sample>,
+}
+
+impl ServiceStats {
+    /// Share of requests that failed, in percent
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            self.errors as f64 / self.requests as f64 * 100.0
+        }
+    }
+
+    /// Mean latency, in milliseconds
+    pub fn average_ms(&self) -> u64 {
+        self.total_ms.checked_div(self.requests).unwrap_or_default()
+    }
+
+    /// Upper bound of the bucket holding the given percentile, `None` when in the unbounded one
+    pub fn percentile_ms(&self, percentile: f64) -> Option<u64> {
+        let target = (self.requests as f64 * percentile / 100.0).ceil() as u64;
+        let mut seen = 0;
+        for (index, count) in self.histogram.iter().enumerate() {
+            seen += count;
+            if seen >= target.max(1) {
+                return LATENCY_BUCKETS_MS.get(index).copied();
+            }
+        }
+        None
+    }
+
+    /// Latency of the given percentile over the latest requests, failed ones included; `None` before any request
+    pub fn rolling_percentile_ms(&self, percentile: f64) -> Option<u64> {
+        let mut latencies: Vec<u64> = self.recent.iter().map(|sample| sample.ms).collect();
+        latencies.sort_unstable();
+        // Nearest rank
+        let rank = (latencies.len() as f64 * percentile / 100.0).ceil() as usize;
+        latencies.get(rank.clamp(1, latencies.len().max(1)) - 1).copied()
+    }
+
+    /// Share of the latest requests that succeeded, in percent; `None` before any request
+    pub fn success_rate(&self) -> Option<f64> {
+        let successes = self.recent.iter().filter(|sample| sample.ok).count();
+        (!self.recent.is_empty()).then(|| successes as f64 / self.recent.len() as f64 * 100.0)
+    }
+}
+
+/// Everything measured since the application started
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metrics {
+    /// Per service, by name (`SideShift`, `rpc mempool.space`, ...)
+    pub services: BTreeMap<String, ServiceStats>,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    pub started_at: Instant,
+}
+
+impl Metrics {
+    /// Share of cache lookups answered from the cache, in percent; `None` before any lookup
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        let lookups = self.cache_hits + self.cache_misses;
+        (lookups > 0).then(|| self.cache_hits as f64 / lookups as f64 * 100.0)
+    }
+}
+
+static METRICS: Mutex<Option<Metrics>> = Mutex::new(None);
+
+/// Run `f` on the metrics, starting them on first use
+fn with<R>(f: impl FnOnce(&mut Metrics) -> R) -> R {
+    let mut metrics = METRICS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(metrics.get_or_insert_with(|| Metrics {
+        services: BTreeMap::new(),
+        cache_hits: 0,
+        cache_misses: 0,
+        started_at: Instant::now(),
+    }))
+}
+
+/// Start measuring the session; later calls are ignored
+pub fn start() {
+    with(|_| ());
+}
+
+/// Record a request to a service
+pub fn record(service: &str, elapsed: Duration, ok: bool) {
+    let ms = elapsed.as_millis() as u64;
+    with(|metrics| {
+        let stats = metrics.services.entry(service.to_string()).or_default();
+        stats.requests += 1;
+        stats.errors += u64::from(!ok);
+        stats.total_ms += ms;
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        stats.histogram[bucket] += 1;
+        if stats.recent.len() == ROLLING_WINDOW {
+            stats.recent.pop_front();
+        }
+        stats.recent.push_back(Sample { ms, ok });
+    });
+}
+
+/// Time a request to a service, recording whether it succeeded
+pub fn measure<T, E>(service: &str, request: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+    let started = Instant::now();
+    let result = request();
+    record(service, started.elapsed(), result.is_ok());
+    result
+}
+
+/// Record a cache lookup
+pub fn record_cache(hit: bool) {
+    with(|metrics| {
+        if hit {
+            metrics.cache_hits += 1;
+        } else {
+            metrics.cache_misses += 1;
+        }
+    });
+}
+
+/// Copy of the metrics so far
+pub fn snapshot() -> Metrics {
+    with(|metrics| metrics.clone())
+}
+
+/// Service name of a node, from its host
+pub fn node(url: &str) -> String {
+    let host = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = host.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    format!("rpc {host}")
+}
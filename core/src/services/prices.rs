@@ -0,0 +1,115 @@
+//! ## Prices
+//!
+//! Current prices of the listed assets, their change over the last 24 hours and their history, from the public CoinGecko
+//! API
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::errors::{NetworkError, SwapError, ValidationError};
+use crate::models::asset::{Asset, AssetId};
+use crate::models::chart::{PricePoint, Timeframe};
+use crate::models::portfolio::Price;
+use crate::services::{http, metrics};
+
+/// Public CoinGecko API
+const API: &str = "https://api.coingecko.com/api/v3";
+/// Timeout of price lookups
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// CoinGecko identifier of the coin of `ticker`, bridged tokens sharing the price of their coin
+pub fn coin_id(ticker: &str) -> Option<&'static str> {
+    let id = match ticker.to_uppercase().as_str() {
+        "BTC" => "bitcoin",
+        "WBTC" => "wrapped-bitcoin",
+        "ETH" | "WETH" => "ethereum",
+        "SOL" => "solana",
+        "ARB" => "arbitrum",
+        "USDC" => "usd-coin",
+        "USDT" => "tether",
+        "DAI" => "dai",
+        _ => return None,
+    };
+    Some(id)
+}
+
+/// Fetch the price of `assets` in `fiat` (e.g. `usd`), leaving out the assets CoinGecko does not know
+pub fn fetch_prices(assets: &[Asset], fiat: &str) -> Result<Vec<(AssetId, Price)>, SwapError> {
+    let mut ids: Vec<&str> = assets.iter().filter_map(|asset| coin_id(&asset.ticker)).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let fiat = fiat.to_lowercase();
+    let url = format!(
+        "{API}/simple/price?ids={}&vs_currencies={fiat}&include_24hr_change=true",
+        ids.join(",")
+    );
+    let response = metrics::measure("CoinGecko", || http::get_json(&url, &[], TIMEOUT))?;
+    let prices: Vec<(AssetId, Price)> = assets
+        .iter()
+        .filter_map(|asset| {
+            let coin = response.get(coin_id(&asset.ticker)?)?;
+            let value = coin.get(&fiat).and_then(Value::as_f64)?;
+            let change = coin.get(format!("{fiat}_24h_change")).and_then(Value::as_f64);
+            Some((asset.id(), Price::new(value, change)))
+        })
+        .collect();
+    if prices.is_empty() {
+        return Err(NetworkError::InvalidResponse(format!("simple/price: no price in {fiat}")).into());
+    }
+    Ok(prices)
+}
+
+/// Fetch the price history of `from` in `fiat` over `timeframe`, oldest first, or the rate of `from` in `to` when
+/// given, i.e. the `to` bought by one `from`
+pub fn fetch_history(
+    from: &Asset,
+    to: Option<&Asset>,
+    fiat: &str,
+    timeframe: Timeframe,
+) -> Result<Vec<PricePoint>, SwapError> {
+    let prices = market_chart(from, fiat, timeframe)?;
+    let Some(to) = to else {
+        return Ok(prices.into_iter().map(|(at, price)| PricePoint::new(at, price)).collect());
+    };
+    let quotes = market_chart(to, fiat, timeframe)?;
+    // Pair each price with the price of the other asset closest in time
+    let rates = prices
+        .into_iter()
+        .filter_map(|(at, price)| {
+            let (_, quote) = quotes.iter().min_by_key(|(other, _)| (*other - at).num_seconds().abs())?;
+            (*quote > 0.0).then(|| PricePoint::new(at, price / quote))
+        })
+        .collect();
+    Ok(rates)
+}
+
+/// Prices of `asset` in `fiat` over `timeframe`, oldest first
+fn market_chart(asset: &Asset, fiat: &str, timeframe: Timeframe) -> Result<Vec<(DateTime<Utc>, f64)>, SwapError> {
+    let id = coin_id(&asset.ticker)
+        .ok_or_else(|| ValidationError::Unsupported(format!("price history of {}", asset.ticker)))?;
+    let url = format!(
+        "{API}/coins/{id}/market_chart?vs_currency={}&days={}",
+        fiat.to_lowercase(),
+        timeframe.days()
+    );
+    let response = metrics::measure("CoinGecko", || http::get_json(&url, &[], TIMEOUT))?;
+    let since = Utc::now() - chrono::Duration::seconds(timeframe.seconds());
+    let prices: Vec<(DateTime<Utc>, f64)> = response
+        .get("prices")
+        .and_then(Value::as_array)
+        .ok_or_else(|| NetworkError::InvalidResponse("market_chart: missing prices".to_string()))?
+        .iter()
+        .filter_map(|point| {
+            let at = DateTime::from_timestamp_millis(point.get(0)?.as_f64()? as i64)?;
+            Some((at, point.get(1)?.as_f64()?))
+        })
+        .filter(|(at, _)| *at >= since)
+        .collect();
+    Ok(prices)
+}
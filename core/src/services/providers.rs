@@ -0,0 +1,99 @@
+//! ## Providers
+//!
+//! Swap providers able to quote a pair
+
+pub mod mock;
+pub mod paraswap;
+pub mod plugin;
+pub mod sideshift;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::errors::SwapError;
+use crate::models::asset::Asset;
+use crate::models::provider::{Limits, ProviderInfo, ProviderKind};
+use crate::models::quote::{Quote, QuoteRequest};
+
+/// A swap provider
+pub trait Provider: Send + Sync {
+    /// Name displayed in the quotes table
+    fn name(&self) -> &str;
+
+    /// Whether the provider can swap `from` into `to`
+    fn supports(&self, from: &Asset, to: &Asset) -> bool;
+
+    /// Ask the provider for an offer
+    fn quote(&self, request: &QuoteRequest) -> Result<Quote, SwapError>;
+
+    /// Longest wait for an offer, `None` when unbounded
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether the provider routes through on-chain liquidity, its rate degrading as the amount grows
+    fn is_aggregator(&self) -> bool {
+        false
+    }
+
+    /// What the provider is and what routing a swap through it entrusts it with
+    fn info(&self) -> ProviderInfo {
+        ProviderInfo::unknown(if self.is_aggregator() {
+            ProviderKind::DexAggregator
+        } else {
+            ProviderKind::InstantExchange
+        })
+    }
+
+    /// Ask the provider for its deposit limits on swapping `from` into `to`, its answering at all being its health
+    /// check; `None` when it offers nothing to ask
+    fn limits(&self, _from: &Asset, _to: &Asset) -> Option<Result<Limits, SwapError>> {
+        None
+    }
+}
+
+/// A shared provider, e.g. one given by an application embedding the engine
+impl<P: Provider + ?Sized> Provider for Arc<P> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn supports(&self, from: &Asset, to: &Asset) -> bool {
+        (**self).supports(from, to)
+    }
+
+    fn quote(&self, request: &QuoteRequest) -> Result<Quote, SwapError> {
+        (**self).quote(request)
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        (**self).timeout()
+    }
+
+    fn is_aggregator(&self) -> bool {
+        (**self).is_aggregator()
+    }
+
+    fn info(&self) -> ProviderInfo {
+        (**self).info()
+    }
+
+    fn limits(&self, from: &Asset, to: &Asset) -> Option<Result<Limits, SwapError>> {
+        (**self).limits(from, to)
+    }
+}
+
+/// Names of the available providers, as listed in the config
+pub const NAMES: [&str; 2] = ["sideshift", "paraswap"];
+
+/// Time a provider has to quote unless configured otherwise
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Provider with the given name, authenticating with `api_key` when set and giving up after `timeout`
+pub fn provider(name: &str, api_key: Option<String>, timeout: Duration) -> Option<Box<dyn Provider>> {
+    match name.to_lowercase().as_str() {
+        "sideshift" => Some(Box::new(sideshift::SideShift::new(api_key).with_timeout(timeout))),
+        "paraswap" => Some(Box::new(paraswap::ParaSwap::new(api_key).with_timeout(timeout))),
+        _ => None,
+    }
+}
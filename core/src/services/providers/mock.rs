@@ -0,0 +1,169 @@
+//! ## Mock
+//!
+//! Providers making up quotes from the listed asset prices, with seeded pseudo-random fees, latencies and outages:
+//! the same seed and the same requests give the same session, e.g. for screenshots and demos.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::errors::{NetworkError, SwapError, ValidationError};
+use crate::models::amount::Amount;
+use crate::models::asset::Asset;
+use crate::models::provider::{Custody, FeeModel, Limits, ProviderInfo, ProviderKind, Registration};
+use crate::models::quote::{Quote, QuoteRequest};
+use crate::services::providers::Provider;
+
+/// Names of the mock providers of a demo; the second one only swaps on a single chain
+const NAMES: [&str; 3] = ["MockSwap", "MockDEX", "MockBridge"];
+
+/// Share of the quotes failing, to show how failures look
+const OUTAGE_RATE: f64 = 0.1;
+/// Value above which the mock instant exchanges may ask for identity verification
+const KYC_THRESHOLD: f64 = 10_000.0;
+/// Jurisdiction the mock instant exchanges do not serve, to show how restricted providers look
+const RESTRICTED: &str = "US";
+/// Fiat values of the smallest and largest deposits the mock instant exchanges take
+const DEPOSIT_LIMITS: (f64, f64) = (20.0, 50_000.0);
+/// Fiat value of the pools of single-chain providers: swapping 1% of it costs 1% of the rate in price impact
+const POOL_VALUE: f64 = 5_000_000.0;
+
+/// Mock provider
+#[derive(Debug)]
+pub struct Mock {
+    name: String,
+    seed: u64,
+    /// Whether both assets must live on the same chain
+    same_chain: bool,
+    /// Quotes made so far, so that quoting twice gives two offers
+    calls: AtomicU64,
+}
+
+impl Mock {
+    /// Create a provider drawing its quotes from `seed`
+    pub fn new(name: &str, seed: u64, same_chain: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            seed: mix(seed ^ fnv(name)),
+            same_chain,
+            calls: AtomicU64::new(0),
+        }
+    }
+
+    /// Pseudo-random numbers for the `call`-th quote of `request`
+    fn rng(&self, request: &QuoteRequest, call: u64) -> Rng {
+        Rng(self.seed ^ mix(fnv(&request.key()) ^ call))
+    }
+}
+
+/// Providers of a demo seeded with `seed`
+pub fn demo(seed: u64) -> Vec<Mock> {
+    NAMES.iter().enumerate().map(|(i, name)| Mock::new(name, seed, i == 1)).collect()
+}
+
+impl Provider for Mock {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports(&self, from: &Asset, to: &Asset) -> bool {
+        from.id() != to.id() && (!self.same_chain || from.chain == to.chain)
+    }
+
+    fn is_aggregator(&self) -> bool {
+        self.same_chain
+    }
+
+    fn info(&self) -> ProviderInfo {
+        let (kind, custody, fee_model, kyc_threshold, restricted) = if self.same_chain {
+            (ProviderKind::DexAggregator, Custody::NonCustodial, FeeModel::Gas, None, Vec::new())
+        } else {
+            let restricted = vec![RESTRICTED.to_string()];
+            (ProviderKind::InstantExchange, Custody::Custodial, FeeModel::Spread, Some(KYC_THRESHOLD), restricted)
+        };
+        ProviderInfo {
+            kind,
+            custody,
+            registration: Registration::None,
+            fee_model,
+            kyc_threshold,
+            website: None,
+            status_page: None,
+            restricted,
+        }
+    }
+
+    fn limits(&self, from: &Asset, _to: &Asset) -> Option<Result<Limits, SwapError>> {
+        let mut rng = Rng(self.seed ^ mix(fnv(&from.id().to_string())));
+        thread::sleep(Duration::from_millis(rng.between(100.0, 600.0) as u64));
+        if rng.next() < OUTAGE_RATE {
+            return Some(Err(NetworkError::Unreachable(format!("{} is down for maintenance", self.name)).into()));
+        }
+        // Aggregators swap any amount the pools can take
+        let price = from.price_value().filter(|_| !self.same_chain);
+        let limit = |value: f64| price.map(|price| Amount::from_f64(value / price, from.decimals).to_string());
+        Some(Ok(Limits {
+            min: limit(DEPOSIT_LIMITS.0),
+            max: limit(DEPOSIT_LIMITS.1),
+        }))
+    }
+
+    fn quote(&self, request: &QuoteRequest) -> Result<Quote, SwapError> {
+        let mut rng = self.rng(request, self.calls.fetch_add(1, Ordering::Relaxed));
+        thread::sleep(Duration::from_millis(rng.between(200.0, 1500.0) as u64));
+        if rng.next() < OUTAGE_RATE {
+            return Err(NetworkError::Unreachable(format!("{} is down for maintenance", self.name)).into());
+        }
+
+        let (Some(from), Some(to)) = (request.from.price_value(), request.to.price_value()) else {
+            return Err(ValidationError::Unsupported(format!("{} has no price", request.from.ticker)).into());
+        };
+        // Fees between 0.1% and 2%, plus the price impact of swapping on-chain
+        let impact = if self.same_chain { (request.amount.to_f64() * from / POOL_VALUE).min(0.5) } else { 0.0 };
+        let rate = from / to * (1.0 - rng.between(0.001, 0.02)) * (1.0 - impact);
+        let eta = Duration::from_secs(rng.between(60.0, 1800.0) as u64);
+        // Swapping on-chain costs between $1 and $20 of gas on top
+        let route_fee = self.same_chain.then(|| Amount::from_f64(rng.between(1.0, 20.0) / to, request.to.decimals));
+        Ok(Quote {
+            provider: self.name.clone(),
+            from: request.from.id(),
+            to: request.to.id(),
+            amount_in: request.amount,
+            amount_out: Amount::from_f64(request.amount.to_f64() * rate, request.to.decimals),
+            spender: None,
+            eta: Some(eta),
+            route_fee,
+            quoted_at: Utc::now(),
+        })
+    }
+}
+
+/// SplitMix64 generator
+struct Rng(u64);
+
+impl Rng {
+    /// Next number in `[0, 1)`
+    fn next(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        (mix(self.0) >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Next number in `[low, high)`
+    fn between(&mut self, low: f64, high: f64) -> f64 {
+        low + self.next() * (high - low)
+    }
+}
+
+/// SplitMix64 finalizer, scrambling the bits of `x`
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// FNV-1a hash, stable across builds unlike the standard hasher
+fn fnv(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
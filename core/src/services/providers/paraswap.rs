@@ -0,0 +1,146 @@
+//! ## ParaSwap
+//!
+//! [ParaSwap](https://paraswap.io) DEX aggregator, swapping tokens on a single EVM chain
+
+use std::time::Duration;
+
+use chrono::Utc;
+use serde_json::Value;
+
+use crate::errors::{NetworkError, SwapError, ValidationError};
+use crate::models::amount::Amount;
+use crate::models::asset::Asset;
+use crate::models::provider::{Custody, FeeModel, Limits, ProviderInfo, ProviderKind, Registration};
+use crate::models::quote::{Quote, QuoteRequest};
+use crate::services::http;
+use crate::services::providers::{Provider, DEFAULT_TIMEOUT};
+
+/// Public ParaSwap API
+const API: &str = "https://api.paraswap.io";
+/// Address ParaSwap uses for the chain's native asset
+const NATIVE_TOKEN: &str = "0xEeeeeEeeeEeEeEeEeEeeEEEeeeeEeeeeeeeEEeE";
+
+/// ParaSwap provider, quoting through the prices endpoint
+pub struct ParaSwap {
+    /// Partner API key, sent along every request when set
+    api_key: Option<String>,
+    /// Timeout applied to every request
+    timeout: Duration,
+}
+
+impl Default for ParaSwap {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl ParaSwap {
+    /// Create a provider authenticating with the given API key
+    pub fn new(api_key: Option<String>) -> Self {
+        Self {
+            api_key,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Give up on requests after `timeout` instead of the default
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Token address of an asset, as ParaSwap names it
+    fn token(asset: &Asset) -> &str {
+        asset.contract.as_deref().unwrap_or(NATIVE_TOKEN)
+    }
+}
+
+impl Provider for ParaSwap {
+    fn name(&self) -> &str {
+        "ParaSwap"
+    }
+
+    fn supports(&self, from: &Asset, to: &Asset) -> bool {
+        // Routes stay on the chain they start on
+        from.chain == to.chain && from.chain.evm_chain_id().is_some() && from.id() != to.id()
+    }
+
+    fn quote(&self, request: &QuoteRequest) -> Result<Quote, SwapError> {
+        let network = request
+            .from
+            .chain
+            .evm_chain_id()
+            .ok_or_else(|| ValidationError::Unsupported(format!("ParaSwap on {}", request.from.chain)))?;
+        let url = format!(
+            "{API}/prices?srcToken={}&srcDecimals={}&destToken={}&destDecimals={}&amount={}&side=SELL&network={network}",
+            Self::token(&request.from),
+            request.from.decimals,
+            Self::token(&request.to),
+            request.to.decimals,
+            request.amount.raw()
+        );
+        let headers: Vec<_> = self.api_key.iter().map(|api_key| ("X-API-Key", api_key.as_str())).collect();
+        let prices = http::get_json(&url, &headers, self.timeout)?;
+        let route = prices
+            .get("priceRoute")
+            .ok_or_else(|| NetworkError::InvalidResponse("ParaSwap prices: missing priceRoute".to_string()))?;
+        let amount_out: u128 = route
+            .get("destAmount")
+            .and_then(Value::as_str)
+            .and_then(|amount| amount.parse().ok())
+            .ok_or_else(|| NetworkError::InvalidResponse("ParaSwap prices: missing destAmount".to_string()))?;
+        // Gas of the swap transaction, priced in dollars, converted at the listed price of the TO asset
+        let route_fee = route
+            .get("gasCostUSD")
+            .and_then(Value::as_str)
+            .and_then(|cost| cost.parse::<f64>().ok())
+            .zip(request.to.price_value())
+            .map(|(cost, price)| Amount::from_f64(cost / price, request.to.decimals));
+
+        Ok(Quote {
+            provider: self.name().to_string(),
+            from: request.from.id(),
+            to: request.to.id(),
+            amount_in: request.amount,
+            amount_out: Amount::new(amount_out, request.to.decimals),
+            // Tokens are pulled by the proxy, not by the router called
+            spender: route
+                .get("tokenTransferProxy")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            // A single transaction, included in the next block
+            eta: Some(request.from.chain.block_time()),
+            route_fee,
+            quoted_at: Utc::now(),
+        })
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
+    fn is_aggregator(&self) -> bool {
+        true
+    }
+
+    fn info(&self) -> ProviderInfo {
+        ProviderInfo {
+            kind: ProviderKind::DexAggregator,
+            // The swap is a transaction signed from the user's wallet
+            custody: Custody::NonCustodial,
+            registration: Registration::Optional,
+            fee_model: FeeModel::Gas,
+            kyc_threshold: None,
+            website: Some("https://paraswap.io".to_string()),
+            status_page: None,
+            restricted: Vec::new(),
+        }
+    }
+
+    fn limits(&self, from: &Asset, _to: &Asset) -> Option<Result<Limits, SwapError>> {
+        // Publishes no limits: listing the tokens of the chain only checks it answers
+        let network = from.chain.evm_chain_id()?;
+        let headers: Vec<_> = self.api_key.iter().map(|api_key| ("X-API-Key", api_key.as_str())).collect();
+        Some(http::get_json(&format!("{API}/tokens/{network}"), &headers, self.timeout).map(|_| Limits::default()))
+    }
+}
@@ -0,0 +1,268 @@
+//! ## Plugin
+//!
+//! Providers shipped as standalone executables. Each call runs the executable with one JSON request on its standard
+//! input and reads one JSON answer from its standard output:
+//! - `{"method": "describe"}`, answered with `{"name": "Foo", "chains": ["bitcoin", "ethereum"], "aggregator": true}`,
+//!   `chains` being optional when every chain is supported and `aggregator` telling whether it routes through
+//!   on-chain liquidity, `false` by default; `custody` (`custodial` or `non-custodial`), `registration` (`none`,
+//!   `optional` or `required`), `fee_model` (`spread` or `gas`), `kyc_threshold` (the value above which it may ask
+//!   for identity verification), `website`, `status_page` and `restricted` (the country codes whose residents it
+//!   does not serve, e.g. `["US"]`) may tell users what they trust it with
+//! - `{"method": "quote", "params": {"from": <asset>, "to": <asset>, "amount": "0.1"}}`, answered with
+//!   `{"amount_out": "1.5", "eta_secs": 600, "spender": null, "route_fee": "0.002"}`, only `amount_out` being
+//!   required and `spender` being the `0x` address of the contract to approve, or with
+//!   `{"error": "<reason>"}`
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tracing::{debug, warn};
+
+use crate::errors::{NetworkError, SwapError};
+use crate::models::amount::Amount;
+use crate::models::asset::Asset;
+use crate::models::chain::Chain;
+use crate::models::provider::{Custody, FeeModel, ProviderInfo, ProviderKind, Registration};
+use crate::models::quote::{Quote, QuoteRequest};
+use crate::services::providers::{Provider, DEFAULT_TIMEOUT};
+
+/// Time a plugin has to describe itself
+const DESCRIBE_TIMEOUT: Duration = Duration::from_secs(2);
+/// Interval between checks of a running plugin
+const POLL: Duration = Duration::from_millis(10);
+
+/// Answer to `describe`
+#[derive(Debug, Deserialize)]
+struct Description {
+    name: String,
+    /// Chains swapped between, all when unset
+    #[serde(default)]
+    chains: Option<Vec<Chain>>,
+    /// Routes through on-chain liquidity
+    #[serde(default)]
+    aggregator: bool,
+    #[serde(default)]
+    custody: Option<Custody>,
+    #[serde(default)]
+    registration: Option<Registration>,
+    #[serde(default)]
+    kyc_threshold: Option<f64>,
+    #[serde(default)]
+    fee_model: Option<FeeModel>,
+    #[serde(default)]
+    website: Option<String>,
+    #[serde(default)]
+    status_page: Option<String>,
+    #[serde(default)]
+    restricted: Vec<String>,
+}
+
+/// Answer to `quote`
+#[derive(Debug, Deserialize)]
+struct Offer {
+    amount_out: Option<String>,
+    #[serde(default)]
+    eta_secs: Option<u64>,
+    #[serde(default)]
+    spender: Option<String>,
+    /// In the TO asset
+    #[serde(default)]
+    route_fee: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Provider backed by an executable
+#[derive(Debug)]
+pub struct Plugin {
+    path: PathBuf,
+    name: String,
+    chains: Option<Vec<Chain>>,
+    aggregator: bool,
+    /// What the plugin tells about itself, unknown when it does not
+    info: ProviderInfo,
+    /// Time the plugin has to quote
+    timeout: Duration,
+}
+
+impl Plugin {
+    /// Ask the executable at `path` to describe itself
+    pub fn load(path: &Path) -> Result<Self, SwapError> {
+        let answer = call(path, json!({"method": "describe"}), DESCRIBE_TIMEOUT)?;
+        let description: Description =
+            serde_json::from_value(answer).map_err(|err| invalid(path, &format!("describe: {err}")))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            name: description.name,
+            chains: description.chains,
+            aggregator: description.aggregator,
+            info: ProviderInfo {
+                kind: if description.aggregator {
+                    ProviderKind::DexAggregator
+                } else {
+                    ProviderKind::InstantExchange
+                },
+                custody: description.custody.unwrap_or(Custody::Unknown),
+                registration: description.registration.unwrap_or(Registration::Unknown),
+                fee_model: description.fee_model.unwrap_or(FeeModel::Unknown),
+                kyc_threshold: description.kyc_threshold,
+                website: description.website,
+                status_page: description.status_page,
+                restricted: description.restricted,
+            },
+            timeout: DEFAULT_TIMEOUT,
+        })
+    }
+
+    /// Give up on quotes after `timeout` instead of the default
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Provider for Plugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports(&self, from: &Asset, to: &Asset) -> bool {
+        from.id() != to.id()
+            && self.chains.as_ref().is_none_or(|chains| chains.contains(&from.chain) && chains.contains(&to.chain))
+    }
+
+    fn quote(&self, request: &QuoteRequest) -> Result<Quote, SwapError> {
+        let answer = call(&self.path, json!({"method": "quote", "params": request}), self.timeout)?;
+        let offer: Offer = serde_json::from_value(answer).map_err(|err| invalid(&self.path, &format!("quote: {err}")))?;
+        if let Some(error) = offer.error {
+            return Err(NetworkError::Unreachable(format!("{}: {error}", self.name)).into());
+        }
+        // Rates computed in floating point may carry more decimals than the asset
+        let parse = |amount: String| {
+            Amount::parse(&amount, request.to.decimals)
+                .or_else(|| amount.parse().ok().map(|amount| Amount::from_f64(amount, request.to.decimals)))
+        };
+        let amount_out = offer
+            .amount_out
+            .and_then(parse)
+            .ok_or_else(|| invalid(&self.path, "quote: missing or invalid amount_out"))?;
+        let route_fee = match offer.route_fee {
+            Some(fee) => Some(parse(fee).ok_or_else(|| invalid(&self.path, "quote: invalid route_fee"))?),
+            None => None,
+        };
+        // The spender is shown and asked for allowances, it must be a contract address
+        if offer.spender.as_deref().is_some_and(|spender| !Chain::Ethereum.is_valid_address(spender)) {
+            return Err(invalid(&self.path, "quote: spender is not a 0x-prefixed 20-byte hex address"));
+        }
+
+        Ok(Quote {
+            provider: self.name.clone(),
+            from: request.from.id(),
+            to: request.to.id(),
+            amount_in: request.amount,
+            amount_out,
+            spender: offer.spender,
+            eta: offer.eta_secs.map(Duration::from_secs),
+            route_fee,
+            quoted_at: Utc::now(),
+        })
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
+    fn is_aggregator(&self) -> bool {
+        self.aggregator
+    }
+
+    fn info(&self) -> ProviderInfo {
+        self.info.clone()
+    }
+}
+
+/// Plugins found in `dir`, skipping the executables failing to describe themselves
+pub fn discover(dir: &Path) -> Vec<Plugin> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut paths: Vec<_> = entries.flatten().map(|entry| entry.path()).filter(|path| executable(path)).collect();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|path| match Plugin::load(path) {
+            Ok(plugin) => {
+                debug!(path = %path.display(), name = plugin.name, "plugin loaded");
+                Some(plugin)
+            }
+            Err(err) => {
+                warn!(path = %path.display(), %err, "plugin not loaded");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Whether `path` is a file that can be run
+fn executable(path: &Path) -> bool {
+    let Ok(metadata) = fs::metadata(path) else {
+        return false;
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    metadata.is_file()
+}
+
+/// Run the executable at `path` with `request`, killing it after `timeout`
+fn call(path: &Path, request: Value, timeout: Duration) -> Result<Value, SwapError> {
+    let io = |err: std::io::Error| SwapError::Io(format!("{}: {err}", path.display()));
+    let mut child = Command::new(path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(io)?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A plugin may answer without reading its input
+        let _ = writeln!(stdin, "{request}");
+    }
+    let mut stdout = child.stdout.take().ok_or_else(|| SwapError::Io(format!("{}: no output", path.display())))?;
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(io)? {
+            break status;
+        }
+        if started.elapsed() > timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(NetworkError::Unreachable(format!("{}: timed out", path.display())).into());
+        }
+        thread::sleep(POLL);
+    };
+    let output = reader.join().map_err(|_| invalid(path, "output lost"))?.map_err(io)?;
+    if !status.success() {
+        return Err(SwapError::Io(format!("{}: exited with {status}", path.display())));
+    }
+    serde_json::from_str(output.trim()).map_err(|err| invalid(path, &err.to_string()))
+}
+
+/// A plugin answered something we could not understand
+fn invalid(path: &Path, reason: &str) -> SwapError {
+    NetworkError::InvalidResponse(format!("plugin {}: {reason}", path.display())).into()
+}
@@ -0,0 +1,128 @@
+//! ## SideShift
+//!
+//! [SideShift](https://sideshift.ai) instant exchange
+
+use std::time::Duration;
+
+use chrono::Utc;
+use serde_json::Value;
+
+use crate::errors::{NetworkError, SwapError};
+use crate::models::amount::Amount;
+use crate::models::asset::Asset;
+use crate::models::provider::{Custody, FeeModel, Limits, ProviderInfo, ProviderKind, Registration};
+use crate::models::quote::{Quote, QuoteRequest};
+use crate::services::http;
+use crate::services::providers::{Provider, DEFAULT_TIMEOUT};
+
+/// Public SideShift API
+const API: &str = "https://sideshift.ai/api/v2";
+
+/// SideShift provider, quoting through the public pair endpoint
+pub struct SideShift {
+    /// Account secret, sent along every request when set
+    secret: Option<String>,
+    /// Timeout applied to every request
+    timeout: Duration,
+}
+
+impl Default for SideShift {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl SideShift {
+    /// Create a provider authenticating with the given account secret
+    pub fn new(secret: Option<String>) -> Self {
+        Self {
+            secret,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Give up on requests after `timeout` instead of the default
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Coin and network of an asset, as SideShift names them
+    fn coin(asset: &Asset) -> String {
+        format!("{}-{}", asset.ticker.to_lowercase(), asset.chain.slug())
+    }
+}
+
+impl Provider for SideShift {
+    fn name(&self) -> &str {
+        "SideShift"
+    }
+
+    fn supports(&self, from: &Asset, to: &Asset) -> bool {
+        // SideShift lists every chain we support, on each of them by network name
+        from.id() != to.id()
+    }
+
+    fn quote(&self, request: &QuoteRequest) -> Result<Quote, SwapError> {
+        let url = format!(
+            "{API}/pair/{}/{}?amount={}",
+            Self::coin(&request.from),
+            Self::coin(&request.to),
+            request.amount
+        );
+        let headers: Vec<_> = self.secret.iter().map(|secret| ("x-sideshift-secret", secret.as_str())).collect();
+        let pair = http::get_json(&url, &headers, self.timeout)?;
+        let rate: f64 = pair
+            .get("rate")
+            .and_then(Value::as_str)
+            .and_then(|rate| rate.parse().ok())
+            .ok_or_else(|| NetworkError::InvalidResponse("SideShift pair: missing rate".to_string()))?;
+
+        Ok(Quote {
+            provider: self.name().to_string(),
+            from: request.from.id(),
+            to: request.to.id(),
+            amount_in: request.amount,
+            amount_out: Amount::from_f64(request.amount.to_f64() * rate, request.to.decimals),
+            spender: None,
+            // The deposit must confirm before the payout is sent
+            eta: Some(
+                request.from.chain.block_time() * request.from.chain.deposit_confirmations()
+                    + request.to.chain.block_time(),
+            ),
+            // Taken from the rate
+            route_fee: None,
+            quoted_at: Utc::now(),
+        })
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
+    fn info(&self) -> ProviderInfo {
+        ProviderInfo {
+            kind: ProviderKind::InstantExchange,
+            // Deposits are sent to a SideShift address
+            custody: Custody::Custodial,
+            registration: Registration::Optional,
+            // The network fee of the payout included
+            fee_model: FeeModel::Spread,
+            kyc_threshold: None,
+            website: Some("https://sideshift.ai".to_string()),
+            status_page: None,
+            // Its terms exclude residents of the United States
+            restricted: vec!["US".to_string()],
+        }
+    }
+
+    fn limits(&self, from: &Asset, to: &Asset) -> Option<Result<Limits, SwapError>> {
+        let url = format!("{API}/pair/{}/{}", Self::coin(from), Self::coin(to));
+        let headers: Vec<_> = self.secret.iter().map(|secret| ("x-sideshift-secret", secret.as_str())).collect();
+        let limit = |pair: &Value, key| pair.get(key).and_then(Value::as_str).map(str::to_string);
+        Some(http::get_json(&url, &headers, self.timeout).map(|pair| Limits {
+            min: limit(&pair, "min"),
+            max: limit(&pair, "max"),
+        }))
+    }
+}
@@ -0,0 +1,333 @@
+//! ## Quote
+//!
+//! Quote aggregation across providers
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use tracing::{debug, info, info_span, warn};
+
+use crate::errors::{NetworkError, SwapError, ValidationError};
+use crate::models::amount::Amount;
+use crate::models::depth::{Depth, DepthLevel, MULTIPLIERS};
+use crate::models::quote::{Quote, QuoteFailure, QuoteProgress, QuoteRequest};
+use crate::models::selection::Selection;
+use crate::services::cancel::CancelToken;
+use crate::services::metrics;
+use crate::services::providers::Provider;
+
+/// Provider name and request key
+type QuoteKey = (String, String);
+
+/// Quotes being fetched, awaited by the identical requests made meanwhile
+static IN_FLIGHT: Mutex<Option<HashMap<QuoteKey, Arc<Pending>>>> = Mutex::new(None);
+
+/// How often a request awaiting the same quote in flight checks whether it was cancelled
+const CANCEL_CHECK: Duration = Duration::from_millis(50);
+
+/// Quote being fetched
+#[derive(Default)]
+struct Pending {
+    quote: Mutex<Option<Result<Quote, SwapError>>>,
+    done: Condvar,
+}
+
+impl Pending {
+    /// Block until the quote is fetched, or `cancel` is cancelled
+    fn wait(&self, cancel: &CancelToken) -> Result<Quote, SwapError> {
+        let Ok(mut quote) = self.quote.lock() else {
+            return Err(aborted());
+        };
+        loop {
+            if let Some(quote) = quote.as_ref() {
+                return quote.clone();
+            }
+            if cancel.is_cancelled() {
+                return Err(cancelled());
+            }
+            quote = match self.done.wait_timeout(quote, CANCEL_CHECK) {
+                Ok((quote, _)) => quote,
+                Err(_) => return Err(aborted()),
+            };
+        }
+    }
+}
+
+/// Quote fetched by this thread for the requests waiting on it, handed to them once dropped even on a panic
+struct Fetch {
+    key: QuoteKey,
+    pending: Arc<Pending>,
+    quote: Option<Result<Quote, SwapError>>,
+}
+
+impl Drop for Fetch {
+    fn drop(&mut self) {
+        if let Ok(mut in_flight) = IN_FLIGHT.lock() {
+            if let Some(in_flight) = in_flight.as_mut() {
+                in_flight.remove(&self.key);
+            }
+        }
+        if let Ok(mut quote) = self.pending.quote.lock() {
+            *quote = Some(self.quote.take().unwrap_or_else(|| Err(aborted())));
+        }
+        self.pending.done.notify_all();
+    }
+}
+
+/// A quote awaited from another request that never came
+fn aborted() -> SwapError {
+    NetworkError::Unreachable("quote request aborted".to_string()).into()
+}
+
+/// A quote no longer awaited, its request cancelled
+fn cancelled() -> SwapError {
+    NetworkError::Unreachable("quote request cancelled".to_string()).into()
+}
+
+/// Quotes from every provider supporting the pair, with the index of the one `selection` picks and the
+/// providers that failed or do not support the pair; providers are no longer asked once `cancel` is cancelled.
+/// `on_progress` is called before the first provider is asked and after each one answered.
+pub fn fetch_quotes_from_all_providers(
+    providers: &[Box<dyn Provider>],
+    request: &QuoteRequest,
+    selection: &Selection,
+    cancel: &CancelToken,
+    mut on_progress: impl FnMut(QuoteProgress),
+) -> (Vec<Quote>, Option<usize>, Vec<QuoteFailure>) {
+    let _span = info_span!(
+        "aggregate_quotes",
+        from = %request.from.label(),
+        to = %request.to.label(),
+        amount = %request.amount
+    )
+    .entered();
+
+    let mut failures = vec![];
+    let supported: Vec<&Box<dyn Provider>> = providers
+        .iter()
+        .filter(|provider| {
+            let supported = provider.supports(&request.from, &request.to);
+            if !supported {
+                debug!(provider = provider.name(), "pair not supported");
+                failures.push(QuoteFailure {
+                    provider: provider.name().to_string(),
+                    error: SwapError::provider(
+                        provider.name(),
+                        ValidationError::Unsupported("pair".to_string()).into(),
+                    ),
+                });
+            }
+            supported
+        })
+        .collect();
+    let mut progress = QuoteProgress {
+        total: supported.len(),
+        ..QuoteProgress::default()
+    };
+    on_progress(progress);
+
+    let quotes: Vec<Quote> = supported
+        .into_iter()
+        .take_while(|_| !cancel.is_cancelled())
+        .filter_map(|provider| {
+            let quote = fetch_quote(provider.as_ref(), request, cancel);
+            progress.answered += 1;
+            progress.failed += usize::from(quote.is_err());
+            on_progress(progress);
+            quote
+                .map_err(|error| {
+                    failures.push(QuoteFailure {
+                        provider: provider.name().to_string(),
+                        error,
+                    })
+                })
+                .ok()
+        })
+        .collect();
+
+    let best = selection.select(&quotes);
+    info!(
+        quotes = quotes.len(),
+        policy = %selection.policy,
+        best = best.map(|index| quotes[index].provider.as_str()),
+        "quotes aggregated"
+    );
+    (quotes, best, failures)
+}
+
+/// Offers of every on-chain aggregator supporting the pair for each multiple of the requested amount in
+/// [`MULTIPLIERS`]; aggregators are no longer asked once `cancel` is cancelled
+pub fn fetch_depth(providers: &[Box<dyn Provider>], request: &QuoteRequest, cancel: &CancelToken) -> Vec<Depth> {
+    let _span = info_span!("depth", from = %request.from.label(), to = %request.to.label(), amount = %request.amount)
+        .entered();
+    providers
+        .iter()
+        .filter(|provider| provider.is_aggregator() && provider.supports(&request.from, &request.to))
+        .take_while(|_| !cancel.is_cancelled())
+        .map(|provider| {
+            let levels = MULTIPLIERS
+                .into_iter()
+                .take_while(|_| !cancel.is_cancelled())
+                .map(|multiplier| {
+                    let amount = request.amount.raw().checked_mul(multiplier as u128);
+                    let quote = amount.and_then(|amount| {
+                        let request = QuoteRequest {
+                            amount: Amount::new(amount, request.amount.decimals()),
+                            ..request.clone()
+                        };
+                        fetch_quote(provider.as_ref(), &request, cancel).ok()
+                    });
+                    DepthLevel { multiplier, quote }
+                })
+                .collect();
+            Depth {
+                provider: provider.name().to_string(),
+                levels,
+            }
+        })
+        .collect()
+}
+
+/// Quote from a single provider, its errors carrying the provider name; while the same provider is asked for the
+/// same request, e.g. by a refresh and a retry, the result of that request is awaited instead of asking again, until
+/// `cancel` is cancelled
+pub fn fetch_quote(provider: &dyn Provider, request: &QuoteRequest, cancel: &CancelToken) -> Result<Quote, SwapError> {
+    let key = (provider.name().to_string(), request.key());
+    let (pending, joined) = match IN_FLIGHT.lock() {
+        Ok(mut in_flight) => {
+            let in_flight = in_flight.get_or_insert_with(HashMap::new);
+            match in_flight.get(&key) {
+                Some(pending) => (Arc::clone(pending), true),
+                None => {
+                    let pending = Arc::new(Pending::default());
+                    in_flight.insert(key.clone(), Arc::clone(&pending));
+                    (pending, false)
+                }
+            }
+        }
+        // Without the registry every request asks on its own
+        Err(_) => return ask(provider, request),
+    };
+    if joined {
+        debug!(provider = provider.name(), request = %key.1, "awaiting the same quote in flight");
+        return pending.wait(cancel);
+    }
+
+    let mut fetch = Fetch {
+        key,
+        pending,
+        quote: None,
+    };
+    let quote = ask(provider, request);
+    fetch.quote = Some(quote.clone());
+    quote
+}
+
+/// Ask a provider for a quote, timed for the metrics
+fn ask(provider: &dyn Provider, request: &QuoteRequest) -> Result<Quote, SwapError> {
+    let _span = info_span!("provider_quote", provider = provider.name()).entered();
+    let started = Instant::now();
+    let quote = provider.quote(request);
+    let elapsed = started.elapsed();
+    metrics::record(provider.name(), elapsed, quote.is_ok());
+    let elapsed_ms = elapsed.as_millis() as u64;
+    match &quote {
+        Ok(quote) => debug!(elapsed_ms, amount_out = %quote.amount_out, "quote received"),
+        Err(err) => warn!(elapsed_ms, %err, "quote failed"),
+    }
+    quote.map_err(|err| SwapError::provider(provider.name(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::models::asset::Asset;
+    use crate::models::chain::Chain;
+
+    /// Provider answering after the delay `1`, counting in `0` the quotes it was asked for
+    struct Slow(AtomicUsize, Duration);
+
+    impl Provider for Slow {
+        fn name(&self) -> &str {
+            "Slow"
+        }
+
+        fn supports(&self, _from: &Asset, _to: &Asset) -> bool {
+            true
+        }
+
+        fn quote(&self, request: &QuoteRequest) -> Result<Quote, SwapError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            thread::sleep(self.1);
+            Ok(Quote {
+                provider: self.name().to_string(),
+                from: request.from.id(),
+                to: request.to.id(),
+                amount_in: request.amount,
+                amount_out: Amount::new(15, 1),
+                spender: None,
+                eta: None,
+                route_fee: None,
+                quoted_at: Utc::now(),
+            })
+        }
+    }
+
+    /// Request of `amount` satoshis for ETH; each test asks its own, the requests in flight being shared
+    fn request(amount: u128) -> QuoteRequest {
+        let asset = |ticker: &str, chain, decimals| Asset {
+            ticker: ticker.to_string(),
+            chain,
+            decimals,
+            contract: None,
+            price: String::new(),
+            step: None,
+        };
+        QuoteRequest {
+            from: asset("BTC", Chain::Bitcoin, 8),
+            to: asset("ETH", Chain::Ethereum, 18),
+            amount: Amount::new(amount, 8),
+        }
+    }
+
+    /// Ask `provider` for `request` on another thread, returning once the provider is answering it
+    fn ask_in_flight(provider: &Arc<Slow>, request: &QuoteRequest) -> thread::JoinHandle<Result<Quote, SwapError>> {
+        let asked = provider.0.load(Ordering::SeqCst);
+        let (fetching, request) = (Arc::clone(provider), request.clone());
+        let first = thread::spawn(move || fetch_quote(fetching.as_ref(), &request, &CancelToken::new()));
+        while provider.0.load(Ordering::SeqCst) == asked {
+            thread::sleep(Duration::from_millis(1));
+        }
+        first
+    }
+
+    #[test]
+    fn identical_requests_in_flight_ask_the_provider_once() {
+        let provider = Arc::new(Slow(AtomicUsize::new(0), Duration::from_millis(300)));
+        let request = request(10_000_000);
+        let first = ask_in_flight(&provider, &request);
+        let second = fetch_quote(provider.as_ref(), &request, &CancelToken::new());
+        assert_eq!(first.join().unwrap().unwrap(), second.unwrap());
+        assert_eq!(provider.0.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn cancelled_request_stops_awaiting_the_quote_in_flight() {
+        let provider = Arc::new(Slow(AtomicUsize::new(0), Duration::from_secs(1)));
+        let request = request(20_000_000);
+        let first = ask_in_flight(&provider, &request);
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let started = Instant::now();
+        assert!(fetch_quote(provider.as_ref(), &request, &cancel).is_err());
+        assert!(started.elapsed() < Duration::from_millis(500));
+        assert!(first.join().unwrap().is_ok());
+        assert_eq!(provider.0.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,138 @@
+//! ## Rpc
+//!
+//! Minimal JSON-RPC client used to query chain nodes
+
+use std::env;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::errors::{NetworkError, SwapError};
+use crate::models::chain::Chain;
+use crate::services::{http, metrics};
+
+/// Timeout applied to every node request
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Public Bitcoin explorer API (esplora)
+pub const DEFAULT_BITCOIN_EXPLORER: &str = "https://mempool.space/api";
+/// Public Ethereum JSON-RPC endpoint
+pub const DEFAULT_ETHEREUM_RPC: &str = "https://ethereum-rpc.publicnode.com";
+/// Public Solana JSON-RPC endpoint
+pub const DEFAULT_SOLANA_RPC: &str = "https://api.mainnet-beta.solana.com";
+/// Public Arbitrum One JSON-RPC endpoint
+pub const DEFAULT_ARBITRUM_RPC: &str = "https://arb1.arbitrum.io/rpc";
+
+/// Node endpoints used for balance lookups, ENS resolution and gas estimation
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RpcEndpoints {
+    /// Esplora-compatible Bitcoin explorer API
+    pub bitcoin: String,
+    /// Ethereum JSON-RPC node
+    pub ethereum: String,
+    /// Solana JSON-RPC node
+    pub solana: String,
+    /// Arbitrum One JSON-RPC node
+    pub arbitrum: String,
+}
+
+impl Default for RpcEndpoints {
+    fn default() -> Self {
+        Self {
+            bitcoin: DEFAULT_BITCOIN_EXPLORER.to_string(),
+            ethereum: DEFAULT_ETHEREUM_RPC.to_string(),
+            solana: DEFAULT_SOLANA_RPC.to_string(),
+            arbitrum: DEFAULT_ARBITRUM_RPC.to_string(),
+        }
+    }
+}
+
+impl RpcEndpoints {
+    /// Override endpoints with `XOSWAP_RPC_<CHAIN>` (e.g. `XOSWAP_RPC_ETHEREUM`) when set
+    pub fn apply_env(&mut self) {
+        for chain in Chain::ALL {
+            let var = format!("XOSWAP_RPC_{}", chain.to_string().to_uppercase());
+            if let Ok(url) = env::var(var) {
+                self.set(chain, url);
+            }
+        }
+    }
+
+    /// Endpoint for a chain
+    pub fn get(&self, chain: Chain) -> &str {
+        match chain {
+            Chain::Bitcoin => &self.bitcoin,
+            Chain::Ethereum => &self.ethereum,
+            Chain::Solana => &self.solana,
+            Chain::Arbitrum => &self.arbitrum,
+        }
+    }
+
+    /// Point a chain at another endpoint
+    pub fn set(&mut self, chain: Chain, url: String) {
+        let url = url.trim_end_matches('/').to_string();
+        match chain {
+            Chain::Bitcoin => self.bitcoin = url,
+            Chain::Ethereum => self.ethereum = url,
+            Chain::Solana => self.solana = url,
+            Chain::Arbitrum => self.arbitrum = url,
+        }
+    }
+}
+
+/// Perform a JSON-RPC call and return its `result` field
+pub fn call(url: &str, method: &str, params: Value) -> Result<Value, SwapError> {
+    metrics::measure(&metrics::node(url), || {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let response = http::post_json(url, request, TIMEOUT)?;
+
+        if let Some(error) = response.get("error") {
+            return Err(NetworkError::InvalidResponse(format!("{method}: {error}")).into());
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| NetworkError::InvalidResponse(format!("{method}: missing result")).into())
+    })
+}
+
+/// Perform a plain GET request returning JSON
+pub fn get_json(url: &str) -> Result<Value, SwapError> {
+    metrics::measure(&metrics::node(url), || http::get_json(url, &[], TIMEOUT))
+}
+
+/// Parse a `0x`-prefixed hex quantity as returned by Ethereum nodes
+pub fn parse_hex_quantity(value: &Value) -> Result<u128, SwapError> {
+    value
+        .as_str()
+        .and_then(|hex| u128::from_str_radix(hex.trim_start_matches("0x"), 16).ok())
+        .ok_or_else(|| NetworkError::InvalidResponse(format!("not a hex quantity: {value}")).into())
+}
+
+/// Perform a read-only `eth_call` against a contract and return the raw hex output
+pub fn eth_call(url: &str, to: &str, data: &str) -> Result<String, SwapError> {
+    call(url, "eth_call", json!([{ "to": to, "data": data }, "latest"]))?
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| NetworkError::InvalidResponse("eth_call: result is not a string".to_string()).into())
+}
+
+/// Parse a 32-byte ABI word holding an unsigned integer that fits in 128 bits
+pub fn parse_word(word: &str) -> Result<u128, SwapError> {
+    let hex = word.trim_start_matches("0x");
+    let (high, low) = hex.split_at(hex.len().saturating_sub(32));
+    if high.chars().any(|c| c != '0') {
+        return Err(NetworkError::InvalidResponse(format!("value does not fit in 128 bits: {word}")).into());
+    }
+    if low.is_empty() {
+        return Ok(0);
+    }
+    u128::from_str_radix(low, 16).map_err(|_| NetworkError::InvalidResponse(format!("not an ABI word: {word}")).into())
+}
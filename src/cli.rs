@@ -0,0 +1,261 @@
+//! ## Cli
+//!
+//! Command line arguments
+
+pub mod daemon;
+pub mod play;
+pub mod quote;
+pub mod secret;
+
+use std::path::PathBuf;
+
+use clap::{ArgAction, Args, Parser, Subcommand};
+use tracing_subscriber::filter::LevelFilter;
+
+use crate::models::amount::Amount;
+use crate::models::asset::{Asset, AssetId};
+use crate::models::draft::Draft;
+
+/// Exit code of commands that found no quote
+pub const NO_QUOTES: u8 = 3;
+
+/// Port the daemon serves its API on by default
+pub const DAEMON_PORT: u16 = 9470;
+
+/// Compare swap quotes across providers from the terminal
+#[derive(Debug, Default, Parser)]
+#[command(
+    name = "xoswap",
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    after_help = "Exit codes: 0 on success, 1 on errors, 2 on invalid arguments, 3 when no provider quoted the pair"
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// Config profile to use
+    #[arg(long, global = true, env = "XOSWAP_PROFILE")]
+    pub profile: Option<String>,
+    /// Log more: debug with `-v`, trace with `-vv`
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+    /// Only log errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+    /// Serve metrics for Prometheus on `127.0.0.1:<PORT>/metrics`
+    #[cfg(feature = "prometheus")]
+    #[arg(long, value_name = "PORT", global = true, env = "XOSWAP_METRICS_PORT")]
+    pub metrics_port: Option<u16>,
+    /// FROM asset, by ticker (`BTC`) or id (`usdc-arbitrum`)
+    #[arg(long)]
+    pub from: Option<String>,
+    /// TO asset, by ticker (`ETH`) or id (`eth-arbitrum`)
+    #[arg(long)]
+    pub to: Option<String>,
+    /// FROM amount
+    #[arg(long, requires = "from")]
+    pub amount: Option<String>,
+    /// Watch-only address on the FROM asset's chain, used for balance lookups
+    #[arg(long, requires = "from")]
+    pub address: Option<String>,
+    /// Start in watch mode, showing the best rate of the pair live
+    #[arg(long)]
+    pub watch: bool,
+    /// Record the keys pressed and the results of the services to a file, for `--replay`
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+    /// Replay a session recorded with `--record`, without reaching the network
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["record", "from", "to", "watch"])]
+    pub replay: Option<PathBuf>,
+    /// Write the frames drawn and the state transitions to an asciinema trace, for `xoswap play`
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    pub trace: Option<PathBuf>,
+    /// Accept commands driving the TUI on a Unix socket, e.g. `set pair BTC ETH`, `quote` or `export csv`
+    #[arg(long, value_name = "SOCKET", conflicts_with = "replay")]
+    pub control: Option<PathBuf>,
+    /// Draw ASCII borders and arrows, for terminals and multiplexers mangling Unicode ones
+    #[arg(long)]
+    pub ascii: bool,
+    /// Quote with seeded mock providers only, offline, for reproducible screenshots and demos
+    #[arg(long, global = true)]
+    pub demo: bool,
+    /// Seed of the mock providers in demo mode
+    #[arg(long, value_name = "N", global = true, default_value_t = 0, requires = "demo")]
+    pub seed: u64,
+    /// Run the quote engine without the TUI, serving a JSON-RPC API on `127.0.0.1:<PORT>`
+    #[arg(long, conflicts_with_all = ["from", "to", "watch", "record", "replay", "trace", "control"])]
+    pub daemon: bool,
+    /// Port the daemon serves its API on
+    #[arg(long, value_name = "PORT", default_value_t = DAEMON_PORT, env = "XOSWAP_DAEMON_PORT")]
+    pub daemon_port: u16,
+}
+
+/// Commands run without starting the TUI
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Print the quotes of every provider for a pair
+    Quote(QuoteArgs),
+    /// Manage the secrets the config refers to as `keyring:<name>`
+    #[command(subcommand)]
+    Secret(SecretCommand),
+    /// Play a trace written with `--trace`
+    Play(PlayArgs),
+}
+
+/// Actions of the `secret` command
+#[derive(Debug, Subcommand)]
+pub enum SecretCommand {
+    /// Save a secret read from standard input, in the OS keyring or the secrets file
+    Set {
+        /// Name the config refers to, e.g. `paraswap` for `keyring:paraswap`
+        name: String,
+    },
+    /// Delete a secret
+    Delete {
+        /// Name of the secret
+        name: String,
+    },
+}
+
+/// Arguments of the `quote` command
+#[derive(Debug, Args)]
+pub struct QuoteArgs {
+    /// FROM asset, by ticker (`BTC`) or id (`usdc-arbitrum`)
+    #[arg(long)]
+    pub from: String,
+    /// TO asset, by ticker (`ETH`) or id (`eth-arbitrum`)
+    #[arg(long)]
+    pub to: String,
+    /// FROM amount
+    #[arg(long)]
+    pub amount: String,
+    /// Print a JSON object instead of a table
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Arguments of the `play` command
+#[derive(Debug, Args)]
+pub struct PlayArgs {
+    /// Trace to play
+    pub file: PathBuf,
+    /// Playback speed, `2` playing twice as fast
+    #[arg(long, default_value_t = 1.0)]
+    pub speed: f64,
+    /// Print the state transitions with their time instead of playing the frames
+    #[arg(long)]
+    pub transitions: bool,
+}
+
+/// Form values given on the command line, checked against the listed assets
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Prefill {
+    /// FROM asset and its row in the asset table
+    pub from: Option<(usize, Asset)>,
+    /// TO asset and its row in the asset table
+    pub to: Option<(usize, Asset)>,
+    pub amount: Option<Amount>,
+    pub address: Option<String>,
+    /// Provider whose quote is picked when quotes arrive
+    pub provider: Option<String>,
+}
+
+impl Prefill {
+    /// Values to save, as a draft
+    pub fn draft(&self) -> Draft {
+        Draft {
+            from: self.from.as_ref().map(|(_, asset)| asset.id()),
+            to: self.to.as_ref().map(|(_, asset)| asset.id()),
+            amount: self.amount.map(|amount| amount.to_string()),
+            address: self.address.clone(),
+            provider: self.provider.clone(),
+        }
+    }
+
+    /// Resolve a saved draft among `assets`, leaving out values no longer valid
+    pub fn from_draft(draft: &Draft, assets: &[Asset]) -> Self {
+        let find = |id: &Option<AssetId>| id.as_ref().and_then(|id| find_asset(assets, &id.to_string()).ok());
+        let from = find(&draft.from);
+        let to = find(&draft.to).filter(|(_, to)| from.as_ref().is_none_or(|(_, from)| from.id() != to.id()));
+        let from_asset = from.as_ref().map(|(_, asset)| asset);
+        let amount = draft
+            .amount
+            .as_deref()
+            .zip(from_asset)
+            .and_then(|(amount, asset)| Amount::parse(amount, asset.decimals))
+            .filter(|amount| amount.raw() > 0);
+        let address = draft
+            .address
+            .clone()
+            .filter(|address| from_asset.is_some_and(|asset| asset.chain.is_valid_address(address)));
+
+        Self {
+            from,
+            to,
+            amount,
+            address,
+            provider: draft.provider.clone(),
+        }
+    }
+}
+
+impl Cli {
+    /// Log level asked for with `-v` or `-q`, if any
+    pub fn log_level(&self) -> Option<LevelFilter> {
+        match (self.quiet, self.verbose) {
+            (true, _) => Some(LevelFilter::ERROR),
+            (false, 0) => None,
+            (false, 1) => Some(LevelFilter::DEBUG),
+            (false, _) => Some(LevelFilter::TRACE),
+        }
+    }
+
+    /// Resolve the form values among `assets`
+    pub fn prefill(&self, assets: &[Asset]) -> Result<Prefill, String> {
+        let from = self.from.as_deref().map(|query| find_asset(assets, query)).transpose()?;
+        let to = self.to.as_deref().map(|query| find_asset(assets, query)).transpose()?;
+        if let (Some((_, from)), Some((_, to))) = (&from, &to) {
+            if from.id() == to.id() {
+                return Err(format!("cannot swap {} into itself", from.label()));
+            }
+        }
+
+        let from_asset = from.as_ref().map(|(_, asset)| asset);
+        let amount = match (self.amount.as_deref(), from_asset) {
+            (Some(amount), Some(asset)) => Some(
+                Amount::parse(amount, asset.decimals)
+                    .filter(|amount| amount.raw() > 0)
+                    .ok_or_else(|| format!("invalid {} amount: {amount}", asset.label()))?,
+            ),
+            _ => None,
+        };
+        let address = match (self.address.as_deref(), from_asset) {
+            (Some(address), Some(asset)) if !asset.chain.is_valid_address(address) => {
+                return Err(format!("invalid {} address: {address}", asset.chain));
+            }
+            (address, _) => address.map(str::to_string),
+        };
+
+        Ok(Prefill {
+            from,
+            to,
+            amount,
+            address,
+            provider: None,
+        })
+    }
+}
+
+/// Asset matching an id, or the first one matching a ticker
+pub fn find_asset(assets: &[Asset], query: &str) -> Result<(usize, Asset), String> {
+    let id = query.parse::<AssetId>().ok();
+    assets
+        .iter()
+        .position(|asset| match &id {
+            Some(id) => asset.id() == *id,
+            None => asset.ticker.eq_ignore_ascii_case(query),
+        })
+        .map(|index| (index, assets[index].clone()))
+        .ok_or_else(|| format!("unknown asset: {query}"))
+}
@@ -0,0 +1,309 @@
+//! ## Daemon
+//!
+//! `--daemon` mode: the quote engine without the TUI, answering JSON-RPC 2.0 requests POSTed to
+//! `http://127.0.0.1:<port>/`, for dashboards and bots, and preparing the scheduled swaps as they come due
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tracing::{debug, info, warn};
+
+use crate::cli::quote::{self, Report};
+use crate::cli::QuoteArgs;
+use crate::config::Config;
+use crate::errors::SwapError;
+use crate::models::history::HistoryEntry;
+use crate::models::order::Order;
+use crate::services::providers::Provider;
+use crate::services::{export, history, metrics, schedules};
+
+/// Largest request body accepted, in bytes
+const MAX_BODY: usize = 64 * 1024;
+
+/// Largest request line and headers accepted together, in bytes
+const MAX_HEADER: u64 = 8 * 1024;
+
+/// Time between two checks of the scheduled swaps
+const SCHEDULE_CHECK: Duration = Duration::from_secs(60);
+
+/// Longest wait for a client to send its request or take the answer
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Parameters of the `quote` method
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct QuoteParams {
+    /// FROM asset, by ticker or id
+    from: String,
+    /// TO asset, by ticker or id
+    to: String,
+    /// FROM amount
+    amount: String,
+}
+
+/// Result of the `status` method
+#[derive(Debug, Serialize)]
+struct Status {
+    version: &'static str,
+    uptime_secs: u64,
+    offline: bool,
+    providers: Vec<String>,
+    /// Requests made to each provider and node since the start
+    services: BTreeMap<String, ServiceStatus>,
+}
+
+/// Requests made to a provider or node
+#[derive(Debug, Serialize)]
+struct ServiceStatus {
+    requests: u64,
+    errors: u64,
+    average_ms: u64,
+}
+
+/// What the requests are answered with, shared by the connections
+struct Daemon {
+    config: Config,
+    /// Providers asked for quotes, resolved once at start: discovering the plugins runs each of them
+    providers: Vec<Box<dyn Provider>>,
+}
+
+/// Serve the API on `127.0.0.1:<port>` until the process is stopped
+pub fn run(port: u16, config: Config) -> Result<ExitCode, Box<dyn Error>> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .map_err(|err| SwapError::Io(format!("daemon port {port}: {err}")))?;
+    let addr = listener.local_addr()?;
+    info!(%addr, "daemon listening");
+    eprintln!("Serving JSON-RPC on http://{addr}/ (quote, providers, status)");
+
+    let providers = config.providers();
+    let daemon = Arc::new(Daemon { config, providers });
+    let scheduler = Arc::clone(&daemon);
+    thread::spawn(move || loop {
+        prepare_due(&scheduler);
+        thread::sleep(SCHEDULE_CHECK);
+    });
+    for stream in listener.incoming().flatten() {
+        let daemon = Arc::clone(&daemon);
+        // Quotes take seconds, requests are answered side by side
+        thread::spawn(move || {
+            if let Err(err) = respond(stream, &daemon) {
+                warn!(%err, "daemon request failed");
+            }
+        });
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Answer one HTTP request
+fn respond(stream: TcpStream, daemon: &Daemon) -> io::Result<()> {
+    // An idle client must not hold its thread forever
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut stream = &stream;
+    let (request_line, length) = match read_head(&mut reader)? {
+        Ok(head) => head,
+        Err(status) => return write!(stream, "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"),
+    };
+    debug!(request = request_line.trim_end(), length, "daemon request");
+
+    let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+    if !request_line.starts_with("POST ") || path != "/" {
+        return write!(stream, "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+    }
+    if length > MAX_BODY {
+        return write!(stream, "HTTP/1.1 413 Payload Too Large\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    let response = handle(&body, daemon).to_string();
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+        response.len()
+    )
+}
+
+/// Request line and body length of a request, or the status refusing a head larger than `MAX_HEADER` or cut short
+fn read_head(reader: &mut impl BufRead) -> io::Result<Result<(String, usize), &'static str>> {
+    let mut head = reader.take(MAX_HEADER);
+    let mut request_line = String::new();
+    let mut length = 0;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        // A line without its end ran into the limit or the end of the stream
+        if head.read_line(&mut line)? == 0 || !line.ends_with('\n') {
+            return Ok(Err(if head.limit() == 0 {
+                "431 Request Header Fields Too Large"
+            } else {
+                "400 Bad Request"
+            }));
+        }
+        if request_line.is_empty() {
+            request_line = line.clone();
+        } else if line.trim_end().is_empty() {
+            return Ok(Ok((request_line, length)));
+        } else if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().unwrap_or_default();
+            }
+        }
+    }
+}
+
+/// Response to a JSON-RPC request
+fn handle(body: &[u8], daemon: &Daemon) -> Value {
+    let request: Value = match serde_json::from_slice(body) {
+        Ok(request) => request,
+        Err(err) => return error(Value::Null, PARSE_ERROR, &err.to_string()),
+    };
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        return error(id, INVALID_REQUEST, "missing method");
+    };
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+    debug!(method, "daemon call");
+
+    let result = match method {
+        "quote" => quote_pair(params, daemon),
+        "providers" => Ok(json!(providers(daemon))),
+        "status" => Ok(json!(status(daemon))),
+        _ => return error(id, METHOD_NOT_FOUND, &format!("unknown method {method}")),
+    };
+    match result {
+        Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+        Err(message) => error(id, INVALID_PARAMS, &message),
+    }
+}
+
+/// Ask every provider for a pair, answering like `xoswap quote --json`
+fn quote_pair(params: Value, daemon: &Daemon) -> Result<Value, String> {
+    let params: QuoteParams = serde_json::from_value(params).map_err(|err| err.to_string())?;
+    let args = QuoteArgs {
+        from: params.from,
+        to: params.to,
+        amount: params.amount,
+        json: true,
+    };
+    let report = quote::quote_from(&daemon.providers, &args, &daemon.config)?;
+    serde_json::to_value(report).map_err(|err| err.to_string())
+}
+
+/// Simulate the swap of each scheduled draft come due at its best quote, recording it in the history with a receipt
+fn prepare_due(daemon: &Daemon) {
+    let due = match schedules::due(Utc::now()) {
+        Ok(due) => due,
+        Err(err) => {
+            warn!(%err, "scheduled swaps not checked");
+            return;
+        }
+    };
+    for (name, draft, schedule) in due {
+        let (Some(from), Some(to), Some(amount)) = (&draft.from, &draft.to, &draft.amount) else {
+            debug!(draft = name, "scheduled draft incomplete");
+            continue;
+        };
+        let args = QuoteArgs {
+            from: from.to_string(),
+            to: to.to_string(),
+            amount: amount.clone(),
+            json: true,
+        };
+        // Left due, and tried again at the next check
+        let best = match quote::quote_from(&daemon.providers, &args, &daemon.config) {
+            Ok(Report::Ok { quotes, best, .. }) => best.and_then(|best| quotes.into_iter().nth(best)),
+            Ok(_) => None,
+            Err(err) => {
+                warn!(draft = name, %err, "scheduled swap not quoted");
+                continue;
+            }
+        };
+        let Some(best) = best else {
+            warn!(draft = name, "no quote for the scheduled swap");
+            continue;
+        };
+
+        let order = Order::simulate(best, None);
+        let _ = history::append(&HistoryEntry::simulated(&order));
+        match export::write_receipt(&daemon.config.export.receipts_dir(), &order) {
+            Ok(path) => {
+                info!(draft = name, frequency = %schedule.frequency, receipt = %path.display(), "scheduled swap prepared")
+            }
+            Err(err) => warn!(draft = name, %err, "scheduled swap receipt failed"),
+        }
+        if let Err(err) = schedules::advance(&name, Utc::now()) {
+            warn!(draft = name, %err, "schedule not advanced");
+        }
+    }
+}
+
+/// Names of the providers asked for quotes
+fn providers(daemon: &Daemon) -> Vec<String> {
+    daemon.providers.iter().map(|provider| provider.name().to_string()).collect()
+}
+
+/// Uptime and requests made since the start
+fn status(daemon: &Daemon) -> Status {
+    let metrics = metrics::snapshot();
+    Status {
+        version: env!("CARGO_PKG_VERSION"),
+        uptime_secs: metrics.started_at.elapsed().as_secs(),
+        offline: daemon.config.offline,
+        providers: providers(daemon),
+        services: metrics
+            .services
+            .iter()
+            .map(|(name, stats)| {
+                let status = ServiceStatus {
+                    requests: stats.requests,
+                    errors: stats.errors,
+                    average_ms: stats.average_ms(),
+                };
+                (name.clone(), status)
+            })
+            .collect(),
+    }
+}
+
+/// JSON-RPC error response
+fn error(id: Value, code: i64, message: &str) -> Value {
+    json!({"jsonrpc": "2.0", "id": id, "error": {"code": code, "message": message}})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oversized_header() {
+        let request = "POST / HTTP/1.1\r\nContent-Length: 2\r\n\r\n{}";
+        let head = read_head(&mut request.as_bytes()).unwrap();
+        assert_eq!(head, Ok(("POST / HTTP/1.1\r\n".to_string(), 2)));
+
+        let padding = "a".repeat(MAX_HEADER as usize);
+        let request = format!("POST / HTTP/1.1\r\nX-Padding: {padding}\r\n\r\n{{}}");
+        let head = read_head(&mut request.as_bytes()).unwrap();
+        assert_eq!(head, Err("431 Request Header Fields Too Large"));
+
+        let head = read_head(&mut "POST / HTTP/1.1\r\nContent-Le".as_bytes()).unwrap();
+        assert_eq!(head, Err("400 Bad Request"));
+    }
+}
@@ -0,0 +1,77 @@
+//! ## Play
+//!
+//! `play` subcommand, showing a trace written with `--trace` as it was drawn, or listing its state transitions
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crossterm::cursor::{Hide, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use serde_json::Value;
+
+use crate::cli::PlayArgs;
+use crate::errors::SwapError;
+
+/// An event of the trace: seconds since the start, kind and data
+type TraceEvent = (f64, String, String);
+
+/// Play the trace, or print its transitions
+pub fn run(args: &PlayArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let content = fs::read_to_string(&args.file).map_err(|err| SwapError::Io(format!("{}: {err}", args.file.display())))?;
+    let mut lines = content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+    let invalid = |number: usize, err: serde_json::Error| {
+        SwapError::Io(format!("{} line {}: {err}", args.file.display(), number + 1))
+    };
+    // The header only matters to other players
+    if let Some((number, line)) = lines.next() {
+        serde_json::from_str::<Value>(line).map_err(|err| invalid(number, err))?;
+    }
+    let events = lines
+        .map(|(number, line)| serde_json::from_str(line).map_err(|err| invalid(number, err)))
+        .collect::<Result<Vec<TraceEvent>, _>>()?;
+
+    if args.transitions {
+        for (at, _, action) in events.iter().filter(|(_, kind, _)| kind == "m") {
+            println!("{at:>9.3}s  {action}");
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+    let played = play(&events, args.speed.max(0.01));
+    execute!(io::stdout(), LeaveAlternateScreen, Show)?;
+    disable_raw_mode()?;
+    played?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Write the output of the trace at its pace, until its end or `q` or `Esc` is pressed, then wait for a key
+fn play(events: &[TraceEvent], speed: f64) -> io::Result<()> {
+    let started = Instant::now();
+    let mut stdout = io::stdout();
+    for (at, kind, data) in events {
+        let due = Duration::from_secs_f64(at / speed);
+        while let Some(wait) = due.checked_sub(started.elapsed()).filter(|wait| !wait.is_zero()) {
+            if event::poll(wait)? && quit(event::read()?) {
+                return Ok(());
+            }
+        }
+        if kind == "o" {
+            stdout.write_all(data.as_bytes())?;
+            stdout.flush()?;
+        }
+    }
+    while !matches!(event::read()?, Event::Key(key) if key.kind == KeyEventKind::Press) {}
+    Ok(())
+}
+
+/// Whether `event` asks to stop playing
+fn quit(event: Event) -> bool {
+    matches!(event, Event::Key(key) if key.kind == KeyEventKind::Press && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc))
+}
@@ -0,0 +1,104 @@
+//! ## Quote
+//!
+//! `quote` subcommand, printing the offers of every provider without starting the TUI
+
+use std::error::Error;
+use std::process::ExitCode;
+
+use serde::Serialize;
+
+use crate::cli::{find_asset, QuoteArgs, NO_QUOTES};
+use crate::config::Config;
+use crate::models::amount::Amount;
+use crate::models::asset::AssetId;
+use crate::models::quote::{Quote, QuoteRequest};
+use crate::services::cancel::CancelToken;
+use crate::services::providers::Provider;
+use crate::services::quote::fetch_quotes_from_all_providers;
+
+/// Outcome of the command, as printed with `--json`
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Report {
+    /// At least one provider quoted the pair
+    Ok {
+        from: AssetId,
+        to: AssetId,
+        amount: Amount,
+        quotes: Vec<Quote>,
+        /// Index of the best quote
+        best: Option<usize>,
+    },
+    /// No provider quoted the pair
+    NoQuotes { from: AssetId, to: AssetId, amount: Amount },
+    /// The command failed before asking providers
+    Error { error: String },
+}
+
+impl Report {
+    fn exit_code(&self) -> ExitCode {
+        match self {
+            Self::Ok { .. } => ExitCode::SUCCESS,
+            Self::NoQuotes { .. } => ExitCode::from(NO_QUOTES),
+            Self::Error { .. } => ExitCode::FAILURE,
+        }
+    }
+}
+
+/// Fetch quotes for the pair and print them as a table, or as JSON with `--json`
+pub fn run(args: &QuoteArgs, config: &Config) -> Result<ExitCode, Box<dyn Error>> {
+    let report = match quote(args, config) {
+        Ok(report) => report,
+        Err(error) if args.json => Report::Error { error },
+        Err(error) => return Err(error.into()),
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(report.exit_code());
+    }
+    match &report {
+        Report::Ok { quotes, best, .. } => {
+            println!("  {:<16}{:<32}Rate", "Provider", "You receive");
+            for (index, quote) in quotes.iter().enumerate() {
+                println!(
+                    "{:<2}{:<16}{:<32}{:.6}",
+                    if Some(index) == *best { "*" } else { "" },
+                    quote.provider,
+                    format!("{} {}", quote.amount_out, quote.to.label()),
+                    quote.rate()
+                );
+            }
+        }
+        Report::NoQuotes { from, to, amount } => eprintln!("No quote for {amount} {} -> {}", from.label(), to.label()),
+        Report::Error { error } => eprintln!("{error}"),
+    }
+    Ok(report.exit_code())
+}
+
+/// Ask every provider for the pair
+pub fn quote(args: &QuoteArgs, config: &Config) -> Result<Report, String> {
+    quote_from(&config.providers(), args, config)
+}
+
+/// Ask `providers` for the pair, e.g. those resolved once by a long-running process
+pub fn quote_from(providers: &[Box<dyn Provider>], args: &QuoteArgs, config: &Config) -> Result<Report, String> {
+    let (_, from) = find_asset(&config.assets, &args.from)?;
+    let (_, to) = find_asset(&config.assets, &args.to)?;
+    if from.id() == to.id() {
+        return Err(format!("cannot swap {} into itself", from.label()));
+    }
+    let amount = Amount::parse(&args.amount, from.decimals)
+        .filter(|amount| amount.raw() > 0)
+        .ok_or_else(|| format!("invalid {} amount: {}", from.label(), args.amount))?;
+
+    let request = QuoteRequest { from, to, amount };
+    let (quotes, best, _) =
+        fetch_quotes_from_all_providers(providers, &request, &config.selection, &CancelToken::new(), |_| ());
+    let (from, to) = (request.from.id(), request.to.id());
+    Ok(if quotes.is_empty() {
+        Report::NoQuotes { from, to, amount }
+    } else {
+        Report::Ok { from, to, amount, quotes, best }
+    })
+}
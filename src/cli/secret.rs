@@ -0,0 +1,35 @@
+//! ## Secret
+//!
+//! `secret` subcommand, saving API keys and RPC credentials outside the config file
+
+use std::error::Error;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+use crate::cli::SecretCommand;
+use crate::services::secrets::{self, PREFIX};
+
+/// Save or delete a secret
+pub fn run(command: &SecretCommand) -> Result<ExitCode, Box<dyn Error>> {
+    match command {
+        SecretCommand::Set { name } => {
+            if io::stdin().is_terminal() {
+                eprint!("Secret for {name}: ");
+                io::stderr().flush()?;
+            }
+            let mut secret = String::new();
+            io::stdin().lock().read_line(&mut secret)?;
+            let secret = secret.trim_end_matches(['\r', '\n']);
+            if secret.is_empty() {
+                return Err("empty secret".into());
+            }
+            let store = secrets::set(name, secret)?;
+            eprintln!("Saved to {store}, refer to it as \"{PREFIX}{name}\" in the config");
+        }
+        SecretCommand::Delete { name } => {
+            secrets::delete(name)?;
+            eprintln!("Deleted {name}");
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,1096 @@
+//! ## Config
+//!
+//! User configuration, read from `$XDG_CONFIG_HOME/xoswap-tui/config.toml`
+//!
+//! Every setting is resolved with the following precedence, highest first:
+//! 1. `XOSWAP_*` environment variables (e.g. `XOSWAP_REFRESH_FEES`, `XOSWAP_KEYMAP_QUIT`)
+//! 2. the `[profiles.<name>]` section selected with `--profile` or `XOSWAP_PROFILE`
+//! 3. the config file
+//! 4. the built-in defaults
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Deserialize;
+use toml_edit::{table, value, Array, DocumentMut, Item};
+use tuirealm::utils::parser::parse_color;
+use unic_langid::LanguageIdentifier;
+
+use crate::errors::SwapError;
+use crate::i18n;
+use crate::cli::find_asset;
+use crate::models::alert::Alert;
+use crate::models::amount::Amount;
+use crate::models::asset::{default_assets, Asset};
+use crate::models::portfolio::{Holding, HoldingSource};
+use crate::models::provider::ProviderInfo;
+use crate::models::quote::QuoteRequest;
+use crate::models::selection::Selection;
+use crate::models::watchlist::WatchedPair;
+use crate::services::export::Column;
+use crate::services::providers::{self, mock, plugin, Provider, NAMES};
+use crate::services::rpc::RpcEndpoints;
+use crate::services::secrets::{self, PREFIX};
+use crate::ui::keymap::Keymap;
+use crate::ui::theme::{color_name, Theme};
+
+/// Configuration written on first run, with every default commented
+const DEFAULT_CONFIG: &str = include_str!("config/default.toml");
+
+/// Highest accepted slippage tolerance, in percent
+pub const MAX_SLIPPAGE: f64 = 50.0;
+
+/// Most frames accepted per second
+pub const MAX_FPS: u64 = 1000;
+
+/// Fiat currencies offered by the settings screen
+pub const FIAT_CURRENCIES: [&str; 6] = ["usd", "eur", "gbp", "chf", "jpy", "cad"];
+
+/// Application configuration
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Providers asked for quotes, by name
+    pub providers: Vec<String>,
+    /// Provider API keys, by provider name
+    pub api_keys: BTreeMap<String, String>,
+    /// Seconds a provider has to quote, by provider name, plugins included
+    pub timeouts: BTreeMap<String, u64>,
+    /// Value, in `fiat`, above which a provider may ask for identity verification, by provider name, overriding the
+    /// one it publishes
+    pub kyc_thresholds: BTreeMap<String, f64>,
+    /// HTTP(S) proxy every request goes through
+    pub proxy: Option<String>,
+    /// Never touch the network
+    pub offline: bool,
+    /// Slippage tolerance accepted on swaps, in percent
+    pub slippage: f64,
+    /// Currency asset prices are given in
+    pub fiat: String,
+    /// Language of the UI, as a language tag, e.g. `fr`, translated from `locales/<language>.ftl` next to the config
+    /// file; only changes on restart
+    pub language: String,
+    /// Country the user swaps from, as an ISO 3166-1 alpha-2 code, e.g. `US`; providers not serving it are greyed out
+    pub jurisdiction: Option<String>,
+    /// Background refresh intervals
+    pub refresh: Refresh,
+    /// Quoting as the form is edited
+    pub auto_quote: AutoQuote,
+    /// How the quote routed through is picked among the offers
+    pub selection: Selection,
+    /// How alerts, swaps and failures are signaled
+    pub notifications: Notifications,
+    /// What happens once the target rate of a limit order is met
+    pub limit_orders: LimitOrders,
+    /// How often input is polled, the clock ticks and frames are drawn
+    pub timing: Timing,
+    /// Draw without animations or flashes, for users sensitive to motion and terminals rendering them poorly
+    pub reduce_motion: bool,
+    /// Characters borders, arrows and marks are drawn with
+    pub charset: Charset,
+    /// Draw asset icons from the Nerd Fonts symbols, which the terminal cannot tell whether its font has
+    pub nerd_font: bool,
+    /// Colors
+    pub theme: Theme,
+    /// Order of the sections of the screen, and which are shown
+    pub layout: Layout,
+    /// Key bindings
+    pub keymap: Keymap,
+    /// Popup listing the keys available
+    pub which_key: WhichKey,
+    /// Exported files
+    pub export: Export,
+    /// Node endpoints
+    pub rpc: RpcEndpoints,
+    /// Assets listed in the sidebar
+    pub assets: Vec<Asset>,
+    /// Thresholds on the best rate of pairs, checked in watch mode
+    pub alerts: Vec<Alert>,
+    /// Amounts held, by asset ticker or id, valued on the portfolio screen
+    pub holdings: BTreeMap<String, String>,
+    /// Pairs quoted on the watchlist screen
+    pub watchlist: Vec<WatchedPair>,
+    /// Named sets of settings overriding the top-level ones
+    pub profiles: BTreeMap<String, toml::Table>,
+    /// Profile the settings were read from
+    #[serde(skip)]
+    pub profile: Option<String>,
+    /// Seed of the mock providers answering every quote instead of the configured ones, in demo mode
+    #[serde(skip)]
+    pub demo: Option<u64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            providers: NAMES.iter().map(|name| name.to_string()).collect(),
+            api_keys: BTreeMap::new(),
+            timeouts: BTreeMap::new(),
+            kyc_thresholds: BTreeMap::new(),
+            proxy: None,
+            offline: false,
+            slippage: 0.5,
+            fiat: "usd".to_string(),
+            language: i18n::DEFAULT_LANGUAGE.to_string(),
+            jurisdiction: None,
+            refresh: Refresh::default(),
+            auto_quote: AutoQuote::default(),
+            selection: Selection::default(),
+            notifications: Notifications::default(),
+            limit_orders: LimitOrders::default(),
+            timing: Timing::default(),
+            reduce_motion: false,
+            charset: Charset::default(),
+            nerd_font: false,
+            theme: Theme::default(),
+            layout: Layout::default(),
+            keymap: Keymap::default(),
+            which_key: WhichKey::default(),
+            export: Export::default(),
+            rpc: RpcEndpoints::default(),
+            assets: default_assets(),
+            alerts: vec![],
+            holdings: BTreeMap::new(),
+            watchlist: vec![],
+            profiles: BTreeMap::new(),
+            profile: None,
+            demo: None,
+        }
+    }
+}
+
+/// Seconds between two background refreshes, 0 disabling them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Refresh {
+    /// Balance of the watched address
+    pub balance: u64,
+    /// Network fee estimates
+    pub fees: u64,
+    /// Quotes shown
+    pub quotes: u64,
+    /// Quotes of the watchlist, while its screen is open
+    pub watchlist: u64,
+}
+
+impl Default for Refresh {
+    fn default() -> Self {
+        Self {
+            balance: 60,
+            fees: 30,
+            quotes: 30,
+            watchlist: 60,
+        }
+    }
+}
+
+impl Refresh {
+    /// Interval between balance lookups, `None` when disabled
+    pub fn balance_interval(&self) -> Option<Duration> {
+        Self::every(self.balance)
+    }
+
+    /// Interval between network fee estimates, `None` when disabled
+    pub fn fees_interval(&self) -> Option<Duration> {
+        Self::every(self.fees)
+    }
+
+    /// Interval between quotes of the watchlist, `None` when disabled
+    pub fn watchlist_interval(&self) -> Option<Duration> {
+        Self::every(self.watchlist)
+    }
+
+    fn every(secs: u64) -> Option<Duration> {
+        (secs > 0).then(|| Duration::from_secs(secs))
+    }
+}
+
+/// Quoting as the form is edited, once the edits pause
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AutoQuote {
+    /// Quote the amount typed without waiting for Enter
+    pub enabled: bool,
+    /// Milliseconds without an edit of the pair or amount before quoting
+    pub debounce: u64,
+}
+
+impl Default for AutoQuote {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            debounce: 400,
+        }
+    }
+}
+
+impl AutoQuote {
+    /// Time without an edit before quoting
+    pub fn debounce_interval(&self) -> Duration {
+        Duration::from_millis(self.debounce)
+    }
+}
+
+/// Sections of the screen, stacked top to bottom in the sidebar and the main column; the ones in neither are hidden
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Layout {
+    /// Sections of the sidebar, none giving the whole width to the main column
+    pub sidebar: Vec<Section>,
+    /// Sections of the main column
+    pub main: Vec<Section>,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            sidebar: vec![Section::Assets],
+            main: vec![
+                Section::InstructionsBar,
+                Section::Amount,
+                Section::Instructions,
+                Section::Quotes,
+                Section::SwapPlan,
+                Section::Fees,
+                Section::Status,
+                Section::Summary,
+                Section::Help,
+                Section::Footer,
+            ],
+        }
+    }
+}
+
+/// Section of the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Section {
+    /// Asset table
+    Assets,
+    /// Keys of the form, on one line
+    InstructionsBar,
+    /// FROM amount input
+    Amount,
+    /// Step of the form
+    Instructions,
+    /// Quotes table
+    Quotes,
+    /// Steps of the quote highlighted
+    SwapPlan,
+    /// Network fees
+    Fees,
+    /// Status bar
+    Status,
+    /// Swap summary bar
+    Summary,
+    /// Keys of the component focused
+    Help,
+    /// Clock, network and providers
+    Footer,
+}
+
+impl Section {
+    /// Sections the keyboard moves between, which cannot be hidden
+    pub const FOCUSABLE: [Section; 3] = [Section::Assets, Section::Amount, Section::Quotes];
+
+    /// Name used in the config file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Assets => "assets",
+            Self::InstructionsBar => "instructions_bar",
+            Self::Amount => "amount",
+            Self::Instructions => "instructions",
+            Self::Quotes => "quotes",
+            Self::SwapPlan => "swap_plan",
+            Self::Fees => "fees",
+            Self::Status => "status",
+            Self::Summary => "summary",
+            Self::Help => "help",
+            Self::Footer => "footer",
+        }
+    }
+}
+
+/// Popup listing the keys available where the keyboard is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WhichKey {
+    /// Milliseconds without a keystroke in the asset table before the popup opens, 0 to only open it with `?`
+    pub delay: u64,
+}
+
+impl Default for WhichKey {
+    fn default() -> Self {
+        Self { delay: 2000 }
+    }
+}
+
+impl WhichKey {
+    /// Time without a keystroke before the popup opens, if it opens on its own
+    pub fn idle_delay(&self) -> Option<Duration> {
+        (self.delay > 0).then(|| Duration::from_millis(self.delay))
+    }
+}
+
+/// How alerts, swaps and failures are signaled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Notifications {
+    /// Raise OS desktop notifications
+    pub desktop: bool,
+    /// Ring the terminal bell on triggered alerts and simulated swaps
+    pub bell: Bell,
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Self {
+            desktop: true,
+            bell: Bell::Off,
+        }
+    }
+}
+
+/// What happens once the target rate of a limit order is met
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitOrders {
+    /// Ask to create the swap at the quote meeting the target, besides alerting
+    pub create: bool,
+}
+
+/// Terminal bell, for terminal-only environments without a desktop notifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bell {
+    #[default]
+    Off,
+    /// Beep
+    Audible,
+    /// Flash the screen
+    Visual,
+}
+
+impl Bell {
+    /// Every kind of bell, in the order the settings screen cycles through
+    pub const ALL: [Bell; 3] = [Bell::Off, Bell::Audible, Bell::Visual];
+
+    /// Name used in the config file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Audible => "audible",
+            Self::Visual => "visual",
+        }
+    }
+}
+
+impl FromStr for Bell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|bell| bell.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("expected off, audible or visual, got {s}"))
+    }
+}
+
+/// Characters the UI is drawn with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Charset {
+    /// Unicode, unless the locale or terminal is known not to render it
+    #[default]
+    Auto,
+    /// Rounded box-drawing borders, arrows and braille spinners
+    Unicode,
+    /// `+-|` borders and `->` arrows, for terminals and multiplexers mangling Unicode
+    Ascii,
+}
+
+impl Charset {
+    /// Every charset
+    pub const ALL: [Charset; 3] = [Charset::Auto, Charset::Unicode, Charset::Ascii];
+
+    /// Name used in the config file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Unicode => "unicode",
+            Self::Ascii => "ascii",
+        }
+    }
+}
+
+impl FromStr for Charset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|charset| charset.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("expected auto, unicode or ascii, got {s}"))
+    }
+}
+
+/// How often input is polled, the clock ticks and frames are drawn, starting from a preset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Timing {
+    /// Values of the settings left unset
+    pub preset: Preset,
+    /// Milliseconds between two polls of the terminal for input
+    pub input_poll: Option<u64>,
+    /// Milliseconds between two ticks of the clock animating spinners and countdowns
+    pub tick: Option<u64>,
+    /// Most frames drawn per second
+    pub max_fps: Option<u64>,
+}
+
+impl Timing {
+    /// Interval between two polls of the terminal for input
+    pub fn input_poll_interval(&self) -> Duration {
+        Duration::from_millis(self.input_poll.unwrap_or(self.preset.input_poll()))
+    }
+
+    /// Interval between two ticks of the clock
+    pub fn tick_interval(&self) -> Duration {
+        Duration::from_millis(self.tick.unwrap_or(self.preset.tick()))
+    }
+
+    /// Shortest interval between two frames
+    pub fn frame_interval(&self) -> Duration {
+        let max_fps = self.max_fps.unwrap_or(self.preset.max_fps()).clamp(1, MAX_FPS);
+        Duration::from_secs(1) / max_fps as u32
+    }
+}
+
+/// Timing presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Preset {
+    /// Snappy on a local terminal
+    #[default]
+    Default,
+    /// Fewer wake-ups and redraws, for SSH and tmux sessions where each frame crosses the network
+    LowPower,
+}
+
+impl Preset {
+    /// Every preset
+    pub const ALL: [Preset; 2] = [Preset::Default, Preset::LowPower];
+
+    /// Name used in the config file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::LowPower => "low-power",
+        }
+    }
+
+    fn input_poll(&self) -> u64 {
+        match self {
+            Self::Default => 20,
+            Self::LowPower => 100,
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        match self {
+            Self::Default => 1000,
+            Self::LowPower => 2000,
+        }
+    }
+
+    fn max_fps(&self) -> u64 {
+        match self {
+            Self::Default => 30,
+            Self::LowPower => 10,
+        }
+    }
+}
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("expected default or low-power, got {s}"))
+    }
+}
+
+/// Where exported files go
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Export {
+    /// Directory exported files are written to
+    pub dir: String,
+    /// Directory swap receipts are written to, under the data directory when unset
+    pub receipts: Option<String>,
+    /// Columns of the quotes exported, ticked first in the column chooser
+    pub columns: Vec<Column>,
+}
+
+impl Default for Export {
+    fn default() -> Self {
+        Self {
+            dir: ".".to_string(),
+            receipts: None,
+            columns: Column::ALL.to_vec(),
+        }
+    }
+}
+
+impl Export {
+    /// Directory exported files are written to
+    pub fn dir(&self) -> PathBuf {
+        expand_home(&self.dir)
+    }
+
+    /// Directory swap receipts are written to
+    pub fn receipts_dir(&self) -> PathBuf {
+        match &self.receipts {
+            Some(dir) => expand_home(dir),
+            None => data_dir().unwrap_or_default().join("receipts"),
+        }
+    }
+}
+
+/// Data directory, `$XDG_DATA_HOME/xoswap-tui` or `~/.local/share/xoswap-tui`
+pub fn data_dir() -> Option<PathBuf> {
+    let dir = env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share")))?;
+    Some(dir.join("xoswap-tui"))
+}
+
+/// Directory provider plugins are discovered in, `plugins` in the data directory
+pub fn plugins_dir() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("plugins"))
+}
+
+/// Path with a leading `~` replaced by the home directory
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix('~'), env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest.trim_start_matches('/')),
+        _ => PathBuf::from(path),
+    }
+}
+
+impl Config {
+    /// Path of the config file, `None` when neither `XDG_CONFIG_HOME` nor `HOME` is set
+    pub fn path() -> Option<PathBuf> {
+        let dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(dir.join("xoswap-tui").join("config.toml"))
+    }
+
+    /// Read the config file with a profile, writing the defaults on first run, and apply environment overrides
+    pub fn load(profile: Option<&str>) -> Result<Self, SwapError> {
+        let content = match Self::path() {
+            Some(path) if path.exists() => {
+                fs::read_to_string(&path).map_err(|err| SwapError::Config(format!("{}: {err}", path.display())))?
+            }
+            Some(path) => {
+                // A read-only config directory only costs the commented defaults
+                if let Some(dir) = path.parent() {
+                    let _ = fs::create_dir_all(dir).and_then(|_| fs::write(&path, DEFAULT_CONFIG));
+                }
+                DEFAULT_CONFIG.to_string()
+            }
+            None => DEFAULT_CONFIG.to_string(),
+        };
+        let mut config = Self::read(&content, profile).map_err(|err| match Self::path() {
+            Some(path) => SwapError::Config(format!("{}: {err}", path.display())),
+            None => SwapError::Config(err),
+        })?;
+        config.apply_env()?;
+        config.resolve_secrets()?;
+        config.validate().map_err(SwapError::Config)?;
+        Ok(config)
+    }
+
+    /// Replace `keyring:<name>` references in API keys and RPC endpoints with their secret
+    fn resolve_secrets(&mut self) -> Result<(), SwapError> {
+        let rpc = &mut self.rpc;
+        let values = self
+            .api_keys
+            .iter_mut()
+            .map(|(name, key)| (format!("api_keys.{name}"), key))
+            .chain([
+                ("rpc.bitcoin".to_string(), &mut rpc.bitcoin),
+                ("rpc.ethereum".to_string(), &mut rpc.ethereum),
+                ("rpc.solana".to_string(), &mut rpc.solana),
+                ("rpc.arbitrum".to_string(), &mut rpc.arbitrum),
+            ]);
+        for (key, value) in values {
+            *value = secrets::resolve(value).map_err(|err| SwapError::Config(format!("{key}: {err}")))?;
+        }
+        Ok(())
+    }
+
+    /// Parse and validate a config file's content
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let config = Self::read(content, None)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse a config file's content, with the settings of `profile` over the top-level ones
+    fn read(content: &str, profile: Option<&str>) -> Result<Self, String> {
+        let mut table: toml::Table = toml::from_str(content).map_err(|err| err.to_string())?;
+        if let Some(name) = profile {
+            let overrides = table
+                .get("profiles")
+                .and_then(|profiles| profiles.get(name))
+                .and_then(toml::Value::as_table)
+                .cloned()
+                .ok_or_else(|| format!("unknown profile {name}"))?;
+            if overrides.contains_key("profiles") {
+                return Err(format!("profile {name}: profiles cannot be nested"));
+            }
+            merge(&mut table, overrides);
+        }
+        let mut config: Self = table.try_into().map_err(|err: toml::de::Error| match profile {
+            Some(name) => format!("profile {name}: {err}"),
+            None => err.to_string(),
+        })?;
+        config.profile = profile.map(str::to_string);
+        Ok(config)
+    }
+
+    /// Override settings with the `XOSWAP_*` environment variables that are set: every setting but the layout, the
+    /// assets, alerts, holdings, watchlist and profiles, which only the file sets. Settings in a section are named
+    /// `XOSWAP_<SECTION>_<KEY>`, e.g. `XOSWAP_REFRESH_FEES`, and those by provider `XOSWAP_API_KEY_<PROVIDER>`,
+    /// `XOSWAP_TIMEOUT_<PROVIDER>` and `XOSWAP_KYC_THRESHOLD_<PROVIDER>`, for any provider name set
+    pub fn apply_env(&mut self) -> Result<(), SwapError> {
+        let list = |value: &str| Some(value.split(',').map(str::trim).filter(|v| !v.is_empty()).map(String::from).collect());
+        override_from_env(&mut self.providers, "XOSWAP_PROVIDERS", list)?;
+        let keys = env::vars().filter_map(|(var, _)| Some((var.strip_prefix("XOSWAP_API_KEY_")?.to_lowercase(), var)));
+        for (name, var) in keys.collect::<Vec<_>>() {
+            override_from_env(self.api_keys.entry(name).or_default(), &var, |value| Some(value.to_string()))?;
+        }
+        let timeouts = env::vars().filter_map(|(var, _)| Some((var.strip_prefix("XOSWAP_TIMEOUT_")?.to_lowercase(), var)));
+        for (name, var) in timeouts.collect::<Vec<_>>() {
+            override_from_env(self.timeouts.entry(name).or_default(), &var, |value| value.parse().ok())?;
+        }
+        let thresholds = env::vars()
+            .filter_map(|(var, _)| Some((var.strip_prefix("XOSWAP_KYC_THRESHOLD_")?.to_lowercase(), var)));
+        for (name, var) in thresholds.collect::<Vec<_>>() {
+            override_from_env(self.kyc_thresholds.entry(name).or_default(), &var, |value| value.parse().ok())?;
+        }
+        override_from_env(&mut self.proxy, "XOSWAP_PROXY", |value| Some((!value.is_empty()).then(|| value.to_string())))?;
+        override_from_env(&mut self.offline, "XOSWAP_OFFLINE", parse_bool)?;
+        override_from_env(&mut self.slippage, "XOSWAP_SLIPPAGE", |value| value.parse().ok())?;
+        override_from_env(&mut self.fiat, "XOSWAP_FIAT", |value| Some(value.to_lowercase()))?;
+        override_from_env(&mut self.language, "XOSWAP_LANGUAGE", |value| Some(value.to_string()))?;
+        override_from_env(&mut self.jurisdiction, "XOSWAP_JURISDICTION", |value| {
+            Some((!value.is_empty()).then(|| value.to_uppercase()))
+        })?;
+
+        override_from_env(&mut self.refresh.balance, "XOSWAP_REFRESH_BALANCE", |value| value.parse().ok())?;
+        override_from_env(&mut self.refresh.fees, "XOSWAP_REFRESH_FEES", |value| value.parse().ok())?;
+        override_from_env(&mut self.refresh.quotes, "XOSWAP_REFRESH_QUOTES", |value| value.parse().ok())?;
+        override_from_env(&mut self.refresh.watchlist, "XOSWAP_REFRESH_WATCHLIST", |value| value.parse().ok())?;
+
+        override_from_env(&mut self.auto_quote.enabled, "XOSWAP_AUTO_QUOTE_ENABLED", parse_bool)?;
+        override_from_env(&mut self.auto_quote.debounce, "XOSWAP_AUTO_QUOTE_DEBOUNCE", |value| value.parse().ok())?;
+
+        override_from_env(&mut self.selection.policy, "XOSWAP_SELECTION_POLICY", |value| value.parse().ok())?;
+        let weight = |value: &str| value.parse().ok();
+        override_from_env(&mut self.selection.weights.output, "XOSWAP_SELECTION_WEIGHTS_OUTPUT", weight)?;
+        override_from_env(&mut self.selection.weights.fee, "XOSWAP_SELECTION_WEIGHTS_FEE", weight)?;
+        override_from_env(&mut self.selection.weights.eta, "XOSWAP_SELECTION_WEIGHTS_ETA", weight)?;
+
+        override_from_env(&mut self.notifications.desktop, "XOSWAP_NOTIFICATIONS_DESKTOP", parse_bool)?;
+        override_from_env(&mut self.notifications.bell, "XOSWAP_NOTIFICATIONS_BELL", |value| value.parse().ok())?;
+
+        override_from_env(&mut self.limit_orders.create, "XOSWAP_LIMIT_ORDERS_CREATE", parse_bool)?;
+
+        let number = |value: &str| value.parse().ok().map(Some);
+        override_from_env(&mut self.timing.preset, "XOSWAP_TIMING_PRESET", |value| value.parse().ok())?;
+        override_from_env(&mut self.timing.input_poll, "XOSWAP_TIMING_INPUT_POLL", number)?;
+        override_from_env(&mut self.timing.tick, "XOSWAP_TIMING_TICK", number)?;
+        override_from_env(&mut self.timing.max_fps, "XOSWAP_TIMING_MAX_FPS", number)?;
+        override_from_env(&mut self.reduce_motion, "XOSWAP_REDUCE_MOTION", parse_bool)?;
+        override_from_env(&mut self.charset, "XOSWAP_CHARSET", |value| value.parse().ok())?;
+        override_from_env(&mut self.nerd_font, "XOSWAP_NERD_FONT", parse_bool)?;
+
+        override_from_env(&mut self.theme.primary, "XOSWAP_THEME_PRIMARY", parse_color)?;
+        override_from_env(&mut self.theme.secondary, "XOSWAP_THEME_SECONDARY", parse_color)?;
+        override_from_env(&mut self.theme.text, "XOSWAP_THEME_TEXT", parse_color)?;
+        override_from_env(&mut self.theme.highlight, "XOSWAP_THEME_HIGHLIGHT", parse_color)?;
+
+        let key = |value: &str| value.parse().ok();
+        override_from_env(&mut self.keymap.quit, "XOSWAP_KEYMAP_QUIT", key)?;
+        override_from_env(&mut self.keymap.from_asset, "XOSWAP_KEYMAP_FROM_ASSET", key)?;
+        override_from_env(&mut self.keymap.to_asset, "XOSWAP_KEYMAP_TO_ASSET", key)?;
+        override_from_env(&mut self.keymap.amount, "XOSWAP_KEYMAP_AMOUNT", key)?;
+        override_from_env(&mut self.keymap.chain, "XOSWAP_KEYMAP_CHAIN", key)?;
+        override_from_env(&mut self.keymap.watch_address, "XOSWAP_KEYMAP_WATCH_ADDRESS", key)?;
+        override_from_env(&mut self.keymap.fee_speed, "XOSWAP_KEYMAP_FEE_SPEED", key)?;
+        override_from_env(&mut self.keymap.slippage, "XOSWAP_KEYMAP_SLIPPAGE", key)?;
+        override_from_env(&mut self.keymap.export, "XOSWAP_KEYMAP_EXPORT", key)?;
+        override_from_env(&mut self.keymap.receipt, "XOSWAP_KEYMAP_RECEIPT", key)?;
+        override_from_env(&mut self.keymap.history, "XOSWAP_KEYMAP_HISTORY", key)?;
+        override_from_env(&mut self.keymap.save_draft, "XOSWAP_KEYMAP_SAVE_DRAFT", key)?;
+        override_from_env(&mut self.keymap.drafts, "XOSWAP_KEYMAP_DRAFTS", key)?;
+        override_from_env(&mut self.keymap.settings, "XOSWAP_KEYMAP_SETTINGS", key)?;
+        override_from_env(&mut self.keymap.logs, "XOSWAP_KEYMAP_LOGS", key)?;
+        override_from_env(&mut self.keymap.verbosity, "XOSWAP_KEYMAP_VERBOSITY", key)?;
+        override_from_env(&mut self.keymap.metrics, "XOSWAP_KEYMAP_METRICS", key)?;
+        override_from_env(&mut self.keymap.provider_stats, "XOSWAP_KEYMAP_PROVIDER_STATS", key)?;
+        override_from_env(&mut self.keymap.session_stats, "XOSWAP_KEYMAP_SESSION_STATS", key)?;
+        override_from_env(&mut self.keymap.portfolio, "XOSWAP_KEYMAP_PORTFOLIO", key)?;
+        override_from_env(&mut self.keymap.watchlist, "XOSWAP_KEYMAP_WATCHLIST", key)?;
+        override_from_env(&mut self.keymap.price_chart, "XOSWAP_KEYMAP_PRICE_CHART", key)?;
+        override_from_env(&mut self.keymap.depth, "XOSWAP_KEYMAP_DEPTH", key)?;
+        override_from_env(&mut self.keymap.watch, "XOSWAP_KEYMAP_WATCH", key)?;
+        override_from_env(&mut self.keymap.limit, "XOSWAP_KEYMAP_LIMIT", key)?;
+        override_from_env(&mut self.keymap.pin, "XOSWAP_KEYMAP_PIN", key)?;
+        override_from_env(&mut self.keymap.requote, "XOSWAP_KEYMAP_REQUOTE", key)?;
+        override_from_env(&mut self.keymap.cancel, "XOSWAP_KEYMAP_CANCEL", key)?;
+        override_from_env(&mut self.keymap.dismiss, "XOSWAP_KEYMAP_DISMISS", key)?;
+
+        override_from_env(&mut self.which_key.delay, "XOSWAP_WHICH_KEY_DELAY", |value| value.parse().ok())?;
+
+        override_from_env(&mut self.export.dir, "XOSWAP_EXPORT_DIR", |value| Some(value.to_string()))?;
+        override_from_env(&mut self.export.receipts, "XOSWAP_EXPORT_RECEIPTS", |value| {
+            Some((!value.is_empty()).then(|| value.to_string()))
+        })?;
+
+        self.rpc.apply_env();
+        Ok(())
+    }
+
+    /// Write the settings editable from the TUI that differ from `previous` to the
+    /// config file, in the active profile's section if any, keeping the rest of the file as is
+    pub fn save_settings(&self, previous: &Config) -> Result<(), SwapError> {
+        let path = Self::path().ok_or_else(|| SwapError::Config("no config directory".to_string()))?;
+        let error = |err: &dyn Display| SwapError::Config(format!("{}: {err}", path.display()));
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => DEFAULT_CONFIG.to_string(),
+            Err(err) => return Err(error(&err)),
+        };
+        let mut doc: DocumentMut = content.parse().map_err(|err| error(&err))?;
+        let section = match &self.profile {
+            Some(name) => &mut doc["profiles"][name.as_str()],
+            None => doc.as_item_mut(),
+        };
+
+        let mut set = |keys: &[&str], item: Item| {
+            let target = keys.iter().fold(&mut *section, |item, key| {
+                // Missing sections are added as `[section]` tables rather than inline ones
+                if item.is_none() {
+                    *item = table();
+                }
+                &mut item[*key]
+            });
+            *target = item;
+        };
+        if self.refresh.balance != previous.refresh.balance {
+            set(&["refresh", "balance"], value(self.refresh.balance as i64));
+        }
+        if self.refresh.fees != previous.refresh.fees {
+            set(&["refresh", "fees"], value(self.refresh.fees as i64));
+        }
+        if self.refresh.quotes != previous.refresh.quotes {
+            set(&["refresh", "quotes"], value(self.refresh.quotes as i64));
+        }
+        if self.auto_quote.enabled != previous.auto_quote.enabled {
+            set(&["auto_quote", "enabled"], value(self.auto_quote.enabled));
+        }
+        if self.notifications.desktop != previous.notifications.desktop {
+            set(&["notifications", "desktop"], value(self.notifications.desktop));
+        }
+        if self.notifications.bell != previous.notifications.bell {
+            set(&["notifications", "bell"], value(self.notifications.bell.name()));
+        }
+        if self.reduce_motion != previous.reduce_motion {
+            set(&["reduce_motion"], value(self.reduce_motion));
+        }
+        if self.slippage != previous.slippage {
+            set(&["slippage"], value(self.slippage));
+        }
+        if self.fiat != previous.fiat {
+            set(&["fiat"], value(self.fiat.as_str()));
+        }
+        for (key, color, before) in [
+            ("primary", self.theme.primary, previous.theme.primary),
+            ("secondary", self.theme.secondary, previous.theme.secondary),
+            ("text", self.theme.text, previous.theme.text),
+            ("highlight", self.theme.highlight, previous.theme.highlight),
+        ] {
+            if let Some(name) = color_name(color).filter(|_| color != before) {
+                set(&["theme", key], value(name));
+            }
+        }
+        if self.providers != previous.providers {
+            set(&["providers"], value(self.providers.iter().collect::<Array>()));
+        }
+        // API keys go to the keyring, the config only refers to them
+        for (name, key) in &self.api_keys {
+            if previous.api_keys.get(name) != Some(key) {
+                let secret = format!("{name}-api-key");
+                secrets::set(&secret, key)?;
+                set(&["api_keys", name], value(format!("{PREFIX}{secret}")));
+            }
+        }
+
+        path.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&path, doc.to_string()))
+            .map_err(|err| error(&err))
+    }
+
+    /// Check provider names, the slippage tolerance, timings, selection weights and alerts
+    fn validate(&self) -> Result<(), String> {
+        if !(0.0..=MAX_SLIPPAGE).contains(&self.slippage) {
+            return Err(format!("slippage must be between 0 and {MAX_SLIPPAGE}%, got {}", self.slippage));
+        }
+        let known = |name: &String| NAMES.contains(&name.to_lowercase().as_str());
+        if let Some(name) = self.providers.iter().chain(self.api_keys.keys()).find(|name| !known(name)) {
+            return Err(format!("unknown provider {name}, expected one of {}", NAMES.join(", ")));
+        }
+        for (key, value) in [
+            ("input_poll", self.timing.input_poll),
+            ("tick", self.timing.tick),
+            ("max_fps", self.timing.max_fps),
+        ] {
+            if value == Some(0) {
+                return Err(format!("timing.{key} must be greater than 0"));
+            }
+        }
+        if let Some(max_fps) = self.timing.max_fps.filter(|max_fps| *max_fps > MAX_FPS) {
+            return Err(format!("timing.max_fps must be at most {MAX_FPS}, got {max_fps}"));
+        }
+        if let Some(name) = self.timeouts.iter().find(|(_, secs)| **secs == 0).map(|(name, _)| name) {
+            return Err(format!("timeouts.{name} must be greater than 0"));
+        }
+        let thresholds = self.kyc_thresholds.iter();
+        if let Some((name, value)) = thresholds.clone().find(|(_, value)| !(**value >= 0.0 && value.is_finite())) {
+            return Err(format!("kyc_thresholds.{name} must be 0 or more, got {value}"));
+        }
+        if self.language.parse::<LanguageIdentifier>().is_err() {
+            return Err(format!("language must be a language tag such as fr or pt-BR, got {}", self.language));
+        }
+        if let Some(jurisdiction) = self
+            .jurisdiction
+            .as_ref()
+            .filter(|code| !(code.len() == 2 && code.chars().all(|c| c.is_ascii_alphabetic())))
+        {
+            return Err(format!("jurisdiction must be a two-letter country code, got {jurisdiction}"));
+        }
+        let weights = self.selection.weights;
+        let weights = [("output", weights.output), ("fee", weights.fee), ("eta", weights.eta)];
+        if let Some((key, weight)) = weights.iter().find(|(_, weight)| !(*weight >= 0.0 && weight.is_finite())) {
+            return Err(format!("selection.weights.{key} must be 0 or more, got {weight}"));
+        }
+        if weights.iter().all(|(_, weight)| *weight == 0.0) {
+            return Err("selection.weights cannot all be 0".to_string());
+        }
+        for asset in &self.assets {
+            let Some(step) = &asset.step else {
+                continue;
+            };
+            if Amount::parse(step, asset.decimals).is_none_or(|step| step.raw() == 0) {
+                let decimals = asset.decimals;
+                return Err(format!(
+                    "asset {}: step must be greater than 0 with at most {decimals} decimals, got {step}",
+                    asset.label()
+                ));
+            }
+        }
+        for alert in &self.alerts {
+            find_asset(&self.assets, &alert.from)
+                .and_then(|_| find_asset(&self.assets, &alert.to))
+                .map_err(|err| format!("alert {alert}: {err}"))?;
+            if alert.above.is_none() && alert.below.is_none() {
+                return Err(format!("alert {alert}: set `above` or `below`"));
+            }
+        }
+        let sections: Vec<Section> = self.layout.sidebar.iter().chain(&self.layout.main).copied().collect();
+        let mut seen = sections.iter().enumerate();
+        if let Some((_, section)) = seen.find(|(i, section)| sections[..*i].contains(section)) {
+            return Err(format!("layout: {} is laid out twice", section.name()));
+        }
+        if let Some(section) = Section::FOCUSABLE.iter().find(|section| !sections.contains(section)) {
+            return Err(format!("layout: {} cannot be hidden", section.name()));
+        }
+        if self.export.columns.is_empty() {
+            return Err("export.columns: list at least one column".to_string());
+        }
+        self.holdings()?;
+        self.watchlist().map(|_| ())
+    }
+
+    /// Requests quoting the pairs of the watchlist, in the listed order
+    pub fn watchlist(&self) -> Result<Vec<QuoteRequest>, String> {
+        self.watchlist
+            .iter()
+            .map(|pair| {
+                let (_, from) = find_asset(&self.assets, &pair.from).map_err(|err| format!("watchlist {pair}: {err}"))?;
+                let (_, to) = find_asset(&self.assets, &pair.to).map_err(|err| format!("watchlist {pair}: {err}"))?;
+                let amount = Amount::parse(&pair.amount, from.decimals)
+                    .filter(|amount| amount.raw() > 0)
+                    .ok_or_else(|| format!("watchlist {pair}: invalid amount {}", pair.amount))?;
+                Ok(QuoteRequest { from, to, amount })
+            })
+            .collect()
+    }
+
+    /// Holdings recorded in the `[holdings]` section
+    pub fn holdings(&self) -> Result<Vec<Holding>, String> {
+        self.holdings
+            .iter()
+            .map(|(query, amount)| {
+                let (_, asset) = find_asset(&self.assets, query).map_err(|err| format!("holdings.{query}: {err}"))?;
+                let amount = Amount::parse(amount, asset.decimals)
+                    .ok_or_else(|| format!("holdings.{query}: invalid amount {amount}"))?;
+                Ok(Holding {
+                    asset,
+                    amount,
+                    source: HoldingSource::Recorded,
+                })
+            })
+            .collect()
+    }
+
+    /// Terminal bell rung on alerts and swaps: a visual one flashes the screen, so is silenced when motion is reduced
+    pub fn bell(&self) -> Bell {
+        match self.notifications.bell {
+            Bell::Visual if self.reduce_motion => Bell::Off,
+            bell => bell,
+        }
+    }
+
+    /// Time the provider named `name` has to quote, matching the name in any case
+    pub fn timeout(&self, name: &str) -> Duration {
+        self.timeouts
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map_or(providers::DEFAULT_TIMEOUT, |(_, secs)| Duration::from_secs(*secs))
+    }
+
+    /// What `provider` is, its KYC threshold replaced by the one configured for its name in any case, if any
+    pub fn provider_info(&self, provider: &dyn Provider) -> ProviderInfo {
+        let mut info = provider.info();
+        let name = provider.name();
+        if let Some((_, threshold)) = self.kyc_thresholds.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)) {
+            info.kyc_threshold = Some(*threshold);
+        }
+        info
+    }
+
+    /// Providers to ask for quotes, with their API keys and timeouts, then the plugins installed; only mock ones in
+    /// demo mode
+    pub fn providers(&self) -> Vec<Box<dyn Provider>> {
+        if let Some(seed) = self.demo {
+            return mock::demo(seed).into_iter().map(|mock| Box::new(mock) as Box<dyn Provider>).collect();
+        }
+        let plugins = plugins_dir().map(|dir| plugin::discover(&dir)).unwrap_or_default();
+        self.providers
+            .iter()
+            .filter_map(|name| {
+                providers::provider(name, self.api_keys.get(&name.to_lowercase()).cloned(), self.timeout(name))
+            })
+            .chain(plugins.into_iter().map(|plugin| {
+                let timeout = self.timeout(plugin.name());
+                Box::new(plugin.with_timeout(timeout)) as Box<dyn Provider>
+            }))
+            .collect()
+    }
+}
+
+/// Merge `overrides` into `base`, table by table; other values, arrays included, are replaced
+fn merge(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(value)) => merge(base, value),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Replace `target` with the parsed value of the environment variable `var`, when set
+fn override_from_env<T>(target: &mut T, var: &str, parse: impl FnOnce(&str) -> Option<T>) -> Result<(), SwapError> {
+    let Ok(value) = env::var(var) else {
+        return Ok(());
+    };
+    *target = parse(value.trim()).ok_or_else(|| SwapError::Config(format!("{var}: invalid value {value:?}")))?;
+    Ok(())
+}
+
+/// Parse `true`/`false`, `1`/`0`, `yes`/`no`
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "1" | "yes" => Some(true),
+        "false" | "0" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_beats_the_file() {
+        let mut config = Config::parse(
+            "slippage = 1.0\n[api_keys]\nparaswap = \"from-file\"\n[timeouts]\nsideshift = 20\n[refresh]\nfees = 60\n",
+        )
+        .unwrap();
+        let vars = [
+            ("XOSWAP_SLIPPAGE", "2.5"),
+            ("XOSWAP_API_KEY_PARASWAP", "from-env"),
+            ("XOSWAP_API_KEY_SIDESHIFT", "affiliate"),
+            ("XOSWAP_TIMEOUT_SIDESHIFT", "5"),
+            ("XOSWAP_TIMEOUT_MYPLUGIN", "3"),
+            ("XOSWAP_REFRESH_FEES", "15"),
+        ];
+        for (var, value) in vars {
+            env::set_var(var, value);
+        }
+        let applied = config.apply_env();
+        for (var, _) in vars {
+            env::remove_var(var);
+        }
+        applied.unwrap();
+
+        assert_eq!(config.slippage, 2.5);
+        assert_eq!(config.api_keys["paraswap"], "from-env");
+        assert_eq!(config.api_keys["sideshift"], "affiliate");
+        assert_eq!(config.timeout("SideShift"), Duration::from_secs(5));
+        assert_eq!(config.timeout("MyPlugin"), Duration::from_secs(3));
+        assert_eq!(config.refresh.fees, 15);
+    }
+
+    #[test]
+    fn max_fps_out_of_range() {
+        let err = Config::parse("[timing]\nmax_fps = 4294967296\n").unwrap_err();
+        assert_eq!(err, format!("timing.max_fps must be at most {MAX_FPS}, got 4294967296"));
+        assert!(Config::parse("[timing]\nmax_fps = 0\n").is_err());
+
+        // Unvalidated, the interval stays bounded
+        let timing = Timing {
+            max_fps: Some(u64::MAX),
+            ..Timing::default()
+        };
+        assert_eq!(timing.frame_interval(), Duration::from_millis(1));
+    }
+}
@@ -0,0 +1,80 @@
+//! ## Crash
+//!
+//! Crash reports written on panic, to attach to bug reports
+
+use std::backtrace::Backtrace;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::Local;
+
+use crate::config::data_dir;
+
+/// Summary of the application state, kept up to date by the UI
+static STATE: Mutex<String> = Mutex::new(String::new());
+
+/// Directory holding the crash reports
+pub fn dir() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("crashes"))
+}
+
+/// Remember the application state to report if it crashes; it must not hold secrets
+pub fn set_state(state: String) {
+    if let Ok(mut current) = STATE.lock() {
+        *current = state;
+    }
+}
+
+/// Scheme and host of a URL, leaving out credentials, paths and queries that may hold API keys
+pub fn redact_url(url: &str) -> String {
+    let (scheme, rest) = url.split_once("://").unwrap_or(("", url));
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    let redacted = if host.len() < rest.len() { "/…" } else { "" };
+    if scheme.is_empty() {
+        format!("{host}{redacted}")
+    } else {
+        format!("{scheme}://{host}{redacted}")
+    }
+}
+
+/// Write a report of the panic, returning its path
+pub fn write_report(info: &PanicHookInfo) -> Option<PathBuf> {
+    let now = Local::now();
+    let mut report = String::new();
+    let _ = writeln!(report, "xoswap {} crash report", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(report, "date: {}", now.to_rfc3339());
+    let _ = writeln!(report, "os: {} {}", env::consts::OS, env::consts::ARCH);
+    let _ = writeln!(
+        report,
+        "terminal: TERM={} COLORTERM={} size={}",
+        env::var("TERM").unwrap_or_default(),
+        env::var("COLORTERM").unwrap_or_default(),
+        crossterm::terminal::size().map_or_else(|_| "unknown".to_string(), |(w, h)| format!("{w}x{h}"))
+    );
+    let _ = writeln!(report, "\n{info}");
+    // The lock may be poisoned by the panicking thread itself
+    let state = STATE.lock().map_or_else(|poisoned| poisoned.into_inner().clone(), |state| state.clone());
+    let _ = writeln!(report, "\nstate:\n{state}");
+    let _ = writeln!(report, "\nbacktrace:\n{}", Backtrace::force_capture());
+
+    let dir = dir()?;
+    fs::create_dir_all(&dir).ok()?;
+    let path = dir.join(format!("crash-{}.txt", now.format("%Y%m%d-%H%M%S")));
+    fs::write(&path, report).ok()?;
+    Some(path)
+}
+
+/// Most recent crash report, if any
+pub fn latest_report() -> Option<PathBuf> {
+    // Dated file names sort chronologically
+    fs::read_dir(dir()?)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .max()
+}
@@ -0,0 +1,213 @@
+//! ## I18n
+//!
+//! Translations of the UI strings: fluent messages in the language set in the config, read from
+//! `locales/<language>.ftl` next to the config file, the English ones built in standing in for the messages a
+//! translation lacks. Right-to-left languages align paragraphs to the right and isolate the placeables, e.g. the
+//! addresses and amounts, so that they keep their own direction
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, RwLock};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use tracing::{info, warn};
+use tuirealm::props::Alignment;
+use unic_langid::{CharacterDirection, LanguageIdentifier};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::config::Config;
+use crate::ui::glyphs;
+
+/// Language the messages are written in
+pub const DEFAULT_LANGUAGE: &str = "en-US";
+
+/// Messages in the default language, built in
+const DEFAULT_MESSAGES: &str = include_str!("i18n/en-US.ftl");
+
+type Bundle = FluentBundle<FluentResource>;
+
+/// Messages in the default language
+static DEFAULT: LazyLock<Bundle> = LazyLock::new(|| {
+    let language = DEFAULT_LANGUAGE.parse().expect("valid default language");
+    bundle(language, DEFAULT_MESSAGES.to_string()).expect("valid built-in messages")
+});
+
+/// Messages of the language set, when not the default one
+static TRANSLATION: RwLock<Option<Bundle>> = RwLock::new(None);
+
+/// Whether the language set is written right to left
+static RTL: AtomicBool = AtomicBool::new(false);
+
+/// First strong isolate, opening a run laid out in the direction of its first letter
+const FSI: char = '\u{2068}';
+/// Pop directional isolate, closing the run opened last
+const PDI: char = '\u{2069}';
+
+/// Bundle of the messages of `source`, in `language`
+fn bundle(language: LanguageIdentifier, source: String) -> Result<Bundle, String> {
+    let resource = FluentResource::try_new(source).map_err(|(_, errors)| {
+        let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        errors.join(", ")
+    })?;
+    let rtl = language.character_direction() == CharacterDirection::RTL;
+    let mut bundle = FluentBundle::new_concurrent(vec![language]);
+    // Terminals without bidi support draw the isolation marks around placeables as stray characters, which only
+    // right-to-left text needs
+    bundle.set_use_isolating(rtl);
+    bundle.add_resource(resource).map_err(|errors| {
+        let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        errors.join(", ")
+    })?;
+    Ok(bundle)
+}
+
+/// Directory translations are read from, `locales` next to the config file
+pub fn locales_dir() -> Option<PathBuf> {
+    Config::path().and_then(|path| path.parent().map(|dir| dir.join("locales")))
+}
+
+/// Translate the UI into `language`, a language tag such as `fr` or `pt-BR`, from its file in the locales
+/// directory; English needs none
+pub fn init(language: &str) -> Result<(), String> {
+    let tag: LanguageIdentifier = language
+        .parse()
+        .map_err(|err| format!("language {language} is not a language tag: {err}"))?;
+    let rtl = tag.character_direction() == CharacterDirection::RTL;
+    let path = locales_dir().unwrap_or_default().join(format!("{language}.ftl"));
+    let translation = if path.exists() {
+        let source = fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let translation = bundle(tag, source).map_err(|err| format!("{}: {err}", path.display()))?;
+        info!(language, path = %path.display(), "translation loaded");
+        Some(translation)
+    } else if tag.language == DEFAULT.locales[0].language {
+        None
+    } else {
+        return Err(format!("no {language} translation at {}", path.display()));
+    };
+    if let Ok(mut current) = TRANSLATION.write() {
+        *current = translation;
+    }
+    RTL.store(rtl, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Whether the language set is written right to left
+pub fn is_rtl() -> bool {
+    RTL.load(Ordering::Relaxed)
+}
+
+/// Alignment of the start of a line: left, right in right-to-left languages
+pub fn start() -> Alignment {
+    if is_rtl() {
+        Alignment::Right
+    } else {
+        Alignment::Left
+    }
+}
+
+/// `text` kept in its own direction within right-to-left text, e.g. an address after its label
+pub fn isolate(text: &str) -> String {
+    if is_rtl() {
+        format!("{FSI}{text}{PDI}")
+    } else {
+        text.to_string()
+    }
+}
+
+/// `text` cut to `width` columns, ending with an ellipsis when cut; the isolates cut open are closed, so that the
+/// text after them keeps its direction
+pub fn truncate(text: &str, width: usize) -> String {
+    let total: usize = text.chars().map(|c| c.width().unwrap_or(0)).sum();
+    if total <= width {
+        return text.to_string();
+    }
+    let ellipsis = glyphs::ellipsis();
+    let room = width.saturating_sub(ellipsis.width());
+    let mut cut = String::new();
+    let (mut used, mut open) = (0, 0usize);
+    for c in text.chars() {
+        let columns = c.width().unwrap_or(0);
+        if used + columns > room {
+            break;
+        }
+        match c {
+            '\u{2066}'..='\u{2068}' => open += 1,
+            PDI => open = open.saturating_sub(1),
+            _ => {}
+        }
+        used += columns;
+        cut.push(c);
+    }
+    // Terminals too narrow for the whole ellipsis get part of it
+    cut.extend(ellipsis.chars().take(width));
+    cut.extend(std::iter::repeat_n(PDI, open));
+    cut
+}
+
+/// Message `id` of `bundle` with `args` filled in, if it has it
+fn format(bundle: &Bundle, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    if !errors.is_empty() {
+        warn!(id, ?errors, "message formatted with errors");
+    }
+    Some(text.into_owned())
+}
+
+/// Message `id` with the placeables named in `args` filled in, in the language set, else in the default one, else
+/// the id itself
+pub fn tr_args(id: &str, args: &[(&str, String)]) -> String {
+    let args = (!args.is_empty()).then(|| {
+        let mut fluent = FluentArgs::new();
+        for (name, value) in args {
+            fluent.set(*name, value.clone());
+        }
+        fluent
+    });
+    let translated = TRANSLATION
+        .read()
+        .ok()
+        .and_then(|translation| format(translation.as_ref()?, id, args.as_ref()));
+    translated
+        .or_else(|| format(&DEFAULT, id, args.as_ref()))
+        .unwrap_or_else(|| {
+            warn!(id, "missing message");
+            id.to_string()
+        })
+}
+
+/// Message `id`, without placeables
+pub fn tr(id: &str) -> String {
+    tr_args(id, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_that_fits_is_kept_whole() {
+        assert_eq!(truncate("quote", 5), "quote");
+    }
+
+    #[test]
+    fn text_cut_ends_with_an_ellipsis_within_the_width() {
+        assert_eq!(truncate("quotes loaded", 8), "quotes …");
+        // Wide characters take two columns
+        assert_eq!(truncate("見積もり", 5), "見積…");
+    }
+
+    #[test]
+    fn isolates_cut_open_are_closed() {
+        let text = format!("to {FSI}0x1234567890{PDI} now");
+        assert_eq!(truncate(&text, 8), format!("to {FSI}0x12…{PDI}"));
+    }
+
+    #[test]
+    fn nothing_is_left_without_width() {
+        assert_eq!(truncate("quote", 0), "");
+    }
+}
@@ -0,0 +1,475 @@
+# xoswap-tui messages, in English
+#
+# Copy this file to `locales/<language>.ftl` next to the config file, e.g. `locales/fr.ftl`, translate the messages
+# and set `language = "fr"`: messages left out stay in English. `{ $name }` placeables are filled in by the app.
+
+## Instructions
+
+instructions = Instructions: { $step }
+instructions-select-from = Select FROM asset
+instructions-select-to = Select TO asset
+instructions-set-amount = Set FROM amount
+instructions-compare = Compare quotes and pick a provider
+instructions-bar = ({ $navigate }) Navigate | (Enter) Select | ({ $from }) FROM mode | ({ $to }) TO mode | ({ $quit }) Quit
+
+## Key hints
+
+key-hint = ({ $key }) { $action }
+key-navigate = navigate
+key-choose-from = choose FROM
+key-choose-to = choose TO
+key-choose-quote = pick quote
+key-type-amount = amount
+key-percent = % of balance
+key-max = max
+key-step = step (Shift x10)
+key-submit-amount = quote
+key-back = back
+key-close = close
+key-quit = quit
+key-from = from asset
+key-to = to asset
+key-amount = from amount
+key-chain = chain
+key-watch-address = watch address
+key-fee-speed = gas speed
+key-slippage = slippage
+key-export = export quotes
+key-receipt = receipt
+key-history = history
+key-save-draft = save draft
+key-drafts = drafts
+key-settings = settings
+key-logs = logs
+key-verbosity = verbosity
+key-metrics = metrics
+key-provider-stats = provider stats
+key-session-stats = session stats
+key-portfolio = portfolio
+key-watchlist = watchlist
+key-price-chart = price chart
+key-depth = depth
+key-watch = watch
+key-limit = limit
+key-pin = pin quote
+key-requote = re-quote
+key-cancel = cancel
+key-dismiss = dismiss error
+key-error-detail = select the error
+key-undo = undo
+key-redo = redo
+key-which-key = keys
+which-key-title = Keys | any key to close
+
+## Footer
+
+footer-online = online
+footer-proxy = via proxy
+footer-offline = offline
+footer-providers = providers: { $count }
+
+## Window title
+
+title = xoswap
+title-pair = xoswap: { $pair }
+title-best = xoswap: { $pair } · best { $amount } via { $provider }
+
+## Errors
+
+error-title = Error | Esc to close
+error-title-retry = Error | Enter to retry, Esc to close
+error-provider = Provider
+error-error = Error
+error-caused-by = Caused by
+error-status = Status
+error-response = Response
+advice-unreachable = Check the connection and the proxy setting, then retry.
+advice-offline = Turn off the offline setting to reach the network.
+advice-rate-limited = The provider is rate limiting requests: wait a minute, then retry.
+advice-server-error = The provider is having trouble: retry in a moment.
+advice-rejected = The provider rejected the request: check the pair and amount, then retry.
+advice-invalid-response = The provider answered something unexpected, its API may have changed: retry, or disable it in the settings.
+advice-unsupported = Pick another pair or another provider.
+advice-config = Fix the config file, then restart.
+advice-io = Check the permissions and free space of the data directory.
+advice-secret = Check the keyring is unlocked, or the permissions of the secrets file.
+
+## Status messages
+
+status-provider-quoted = { $provider } quoted
+status-provider-failed = { $provider } failed to quote: { $error }
+status-no-quotes-to-export = no quotes to export
+status-quote-stale = quote is stale ({ $age } old), wait for a refresh
+status-quote-cancelled = quote request cancelled
+status-scripts-not-loaded = scripts not loaded: { $error }
+status-nothing-to-undo = nothing to undo
+status-nothing-to-redo = nothing to redo
+status-limit-met = limit { $order } met: rate { $rate } via { $provider }
+status-limit-cancelled = limit { $order } cancelled
+status-limit-placed = limit { $order } placed, checked on every refresh of watch mode
+status-receipt-saved = receipt saved to { $path }
+status-receipt-failed = receipt failed: { $error }
+status-no-crash-report = no crash report yet
+status-exported = exported to { $path }
+status-export-failed = export failed: { $error }
+status-drafts-failed = drafts not read or saved: { $error }
+status-schedule-failed = schedules not read or saved: { $error }
+status-quote-unpinned = quote unpinned
+status-nothing-to-pin = no quote to pin yet
+status-quote-pinned = pinned { $amount } via { $provider } as the reference
+status-choose-pair = choose a pair first
+status-choose-chart-asset = choose an asset to chart first
+status-choose-pair-amount = choose a pair and an amount first
+status-restricted = { $provider } does not serve users in { $jurisdiction }
+confirm-title = Confirm (y/n)
+confirm-kyc = { $provider } may require KYC above { $threshold } { $fiat }, swap worth ~{ $value }: create?
+confirm-restore-session = Restore the previous session? { $draft }
+confirm-limit-fill = Limit { $order } met: swap { $amount_in } { $from } for { $amount_out } { $to } via { $provider }?
+
+## Desktop notifications
+
+notify-schedule-due = Scheduled swap due
+notify-schedule-due-body = { $frequency } swap due: { $draft }
+notify-schedules-due-body = { $frequency } swap due: { $draft } and { $more } more
+notify-alert = xoswap alert
+notify-alert-body = { $alert }: rate { $rate } via { $provider }
+notify-limit-met = Limit order met
+notify-limit-met-body = { $order }: rate { $rate } via { $provider }
+notify-swap-simulated = Swap simulated
+notify-swap-simulated-body = { $amount_in } { $from } { $arrow } { $amount_out } { $to } via { $provider }
+notify-provider-failed = { $provider } failed to quote
+
+## Scheduled swaps
+
+frequency = { $frequency ->
+    [daily] daily
+    [weekly] weekly
+   *[monthly] monthly
+}
+
+## Dropdowns
+
+dropdown-chain = { $asset } on (Enter to pick, Esc to cancel)
+dropdown-fiat = Fiat currency
+
+## Prompts
+
+prompt-limit = Limit order: fewest { $to } per { $from } (Enter to set, empty to cancel)
+prompt-limit-best = best rate now { $rate }
+prompt-limit-example = e.g. 16.5
+prompt-draft = Save { $draft } as (Enter to save, Esc to cancel)
+prompt-draft-example = e.g. monthly rebalance
+prompt-export = Export quotes to (Enter to export, Esc to cancel)
+prompt-draft-failed = Cannot save draft: { $error }
+prompt-invalid-rate = Invalid rate: enter a positive number
+
+## Slider
+
+slider-title = { $setting } ({ $adjust } to adjust, Enter to set, Esc to cancel)
+slider-slippage = Slippage tolerance
+slider-fee-speed = Gas speed
+
+## Checklists
+
+checklist-providers = Providers (Space to tick, a for all, Enter to set)
+checklist-export-columns = Export columns (Space to tick, a for all, Enter next)
+checklist-no-columns = Tick at least one column to export
+
+## Text viewers
+
+viewer-keys = { $scroll } scroll, / search, Esc to close
+viewer-search-typing = search: { $query } (Enter to find)
+viewer-search-found = search: { $query } { $index }/{ $count } (n/N, Esc to clear)
+viewer-search-missing = search: { $query } not found (Esc to clear)
+viewer-receipt = Receipt { $id }
+viewer-crash-report = Crash report { $name }
+logs-title = Logs | { $level }{ $follow } (Tab level, v verbosity, / search, c crash report, Esc to close)
+logs-title-search = Logs | { $level }{ $follow } | { $search }
+logs-empty = No log file yet
+logs-level = level: { $level }+
+logs-following = {" "}| following
+
+## Onboarding
+
+onboarding-title = Welcome to xoswap | { $step }/4: { $name }
+onboarding-fiat = Fiat currency
+onboarding-providers = Providers
+onboarding-api-key = API key for { $provider } (optional)
+onboarding-theme = Theme
+onboarding-save-failed = Cannot save the config: { $error }
+onboarding-hint-providers = Space to toggle, Enter to continue, Esc to keep the defaults
+onboarding-hint-api-key = Enter to continue, leave empty to skip
+onboarding-hint = { $choose } to choose, Enter to continue, Esc to keep the defaults
+
+## Settings
+
+settings-title = Settings ({ $change } to change, s to save, Esc to cancel)
+settings-title-status = Settings | { $status }
+settings-save-failed = save failed: { $error }
+settings-setting = Setting
+settings-value = Value
+settings-on = on
+settings-off = off
+settings-every = every { $seconds }s
+settings-none = (none)
+settings-profile = Profile
+settings-balance-refresh = Balance refresh
+settings-fee-refresh = Fee refresh
+settings-quote-refresh = Quote refresh
+settings-auto-quote = Quote while typing
+settings-slippage = Slippage
+settings-fiat = Fiat currency
+settings-notifications = Desktop notifications
+settings-bell = Terminal bell
+settings-reduce-motion = Reduce motion
+settings-theme = Theme { $color }
+settings-providers = Providers
+
+## Watch-only address
+
+watch-address-title = Watch-only { $chain } address (Enter to save, Esc to cancel)
+watch-address-placeholder = paste address
+watch-address-resolving = Resolving { $name }{ $ellipsis }
+watch-address-unresolved = Cannot resolve name: { $error }
+watch-address-invalid = Invalid { $chain } address
+
+## Network fees
+
+fee-title = Fees
+fee-select-asset = Select a FROM asset to estimate network fees
+fee-estimating = Network fee: estimating{ $ellipsis }
+fee-failed = Network fee: unavailable ({ $error })
+fee-unavailable = Network fee: unavailable
+fee-network = Network fee: { $amount } { $native } ({ $rate } { $times } { $units } { $unit })
+fee-speeds = Speed (g): { $presets } { $native }
+fee-total-token = Total cost: { $amount } { $asset } + { $fee } { $native }
+fee-total = Total cost: { $total } { $asset } (fee is { $share }% of the amount)
+
+## Quotes
+
+quotes-title = Quotes
+quotes-title-count = Quotes ({ $count })
+quotes-title-fetching = Quotes { $spinner } fetching{ $ellipsis }
+quotes-title-refreshing = Quotes ({ $count }) { $spinner } refreshing{ $ellipsis }
+quotes-providers-failed = { $failed }/{ $total } providers failed
+quotes-responded = { $answered }/{ $total } providers responded
+quotes-responded-failed = { $answered }/{ $total } providers responded, { $failed } failed
+quotes-provider = Provider
+quotes-receive = You receive
+quotes-minimum = Minimum ({ $slippage }%)
+quotes-rate = Rate
+quotes-eta = ETA
+quotes-age = Age
+quotes-age-fresh = fresh { $age }
+quotes-age-aging = aging { $age }
+quotes-age-stale = stale { $age }
+quotes-restricted = not in { $jurisdiction }
+quotes-retrying = retrying{ $ellipsis }
+quotes-pinned = pinned { $amount } { $asset } via { $provider }, best now { $delta }%
+quotes-pinned-alone = pinned { $amount } { $asset } via { $provider }, no quote now
+summary-round-trip = back { $amount } { $asset } via { $provider }: round trip { $spread }%
+summary-no-round-trip = no quote back
+
+## Status bar
+
+status-error = { $error } ({ $key } to dismiss)
+status-error-detail = { $error } (Enter for details, { $key } to dismiss, Esc to go back)
+
+## Depth
+
+depth-title = Depth | { $amount } { $from } { $arrow } { $to } on on-chain aggregators (Esc to close)
+depth-aggregator = Aggregator
+depth-amount = Amount
+depth-receive = You receive
+depth-rate = Rate
+depth-impact = vs 1x
+depth-no-quote = no quote
+depth-quoting = Quoting { $multipliers } the amount{ $ellipsis }
+depth-empty = No on-chain aggregator quotes this pair
+
+## Drafts
+
+drafts-title = Drafts (Enter to load, r to repeat, x to delete, Esc to close)
+drafts-title-empty = Drafts (none saved, Esc to close)
+drafts-name = Name
+drafts-swap = Swap
+drafts-repeats = Repeats
+drafts-due = { $frequency }, due
+drafts-next = { $frequency }, next { $date }
+
+## Provider stats
+
+provider-stats-title = Providers | last { $window } quotes each (Enter for details, Esc to close)
+provider-stats-empty = No provider enabled
+provider-stats-provider = Provider
+provider-stats-type = Type
+provider-stats-quotes = Quotes
+provider-stats-success = Success
+provider-stats-p50 = p50
+provider-stats-p95 = p95
+
+## Watch
+
+watch-title-off = Watch | quote refresh is off (o to stop)
+watch-title-updated = Watch | every { $every }s | updated { $elapsed }s ago, next in { $next }s (o to stop)
+watch-title-waiting = Watch | every { $every }s | waiting for quotes (o to stop)
+watch-empty = No quote yet: choose a pair and an amount to watch its best rate
+watch-change = { $change }% since the last refresh
+watch-first-refresh = First refresh
+watch-trend = , { $change }% since the first
+watch-alert = Alert: { $alert }
+watch-limit = Limit: { $limit }
+watch-best = Best: { $amount } { $asset } via { $provider } (rate { $rate })
+watch-session = Session: low { $low } / high { $high } { $asset } over { $count } refreshes{ $trend }
+watch-alert-holding = { $alert } (rate { $rate } via { $provider })
+
+## Metrics
+
+metrics-title = Metrics | { $elapsed } | cache hit rate: { $cache } (Esc to close)
+metrics-not-available = n/a
+metrics-empty = No request yet
+metrics-service = Service
+metrics-requests = Requests
+metrics-errors = Errors
+metrics-error-rate = Error rate
+metrics-latency = Avg latency
+metrics-p95 = p95
+metrics-timeout = Timeout
+
+## Portfolio
+
+portfolio-title = Portfolio | { $total } { $fiat }
+portfolio-change = ({ $change }% 24h)
+portfolio-fetching = fetching prices...
+portfolio-listed = listed prices, { $error }
+portfolio-pending = { $count } balances loading
+portfolio-close = (Esc to close)
+portfolio-recorded = recorded
+portfolio-watched = watched
+portfolio-price-listed = { $price } (listed)
+portfolio-empty = No holding: record some under [holdings] or watch an address
+portfolio-asset = Asset
+portfolio-holding = Holding
+portfolio-source = Source
+portfolio-price = Price
+portfolio-value = Value
+portfolio-allocation = Allocation
+portfolio-24h = 24h
+
+## Watchlist
+
+watchlist-title = Watchlist | { $count } pairs | { $refresh } (Enter to load, Esc to close)
+watchlist-refresh-off = refresh is off
+watchlist-refresh = every { $every }s
+watchlist-refresh-next = every { $every }s, next in { $next }s
+watchlist-quoting = quoting...
+watchlist-no-quote = no quote
+watchlist-empty = No pair watched: list some under [[watchlist]]
+watchlist-pair = Pair
+watchlist-amount = Amount
+watchlist-best = Best
+watchlist-provider = Provider
+watchlist-rate = Rate
+watchlist-change = Change
+watchlist-age = Age
+
+## History
+
+history-title = History (/ to filter, Enter to reopen, Esc to close)
+history-title-filtering = History | filter: { $filter }{ $cursor } (Enter to apply)
+history-title-filtered = History | filter: { $filter } (/ to edit, Enter to reopen, Esc to clear)
+history-date = Date
+history-pair = Pair
+history-amount = Amount
+history-provider = Provider
+history-status = Status
+
+## Session
+
+session-title = Session | { $spent } spent (Esc to close)
+session-quotes = Quotes fetched: { $offers } offers for { $requests } requests
+session-swaps = Swaps simulated: { $swaps }
+session-average-spread = Average spread: { $spread }
+session-spread = { $spread }% between the best and the worst offer
+session-spread-unknown = n/a, no request got several offers
+session-empty = No quote yet
+session-best-rate-by = Best rate by
+session-requests = Requests
+session-share = Share
+
+## Due swaps
+
+due-title = Swaps due (1-9 or Enter to load and quote, Esc to dismiss)
+due-draft = Draft
+due-swap = Swap
+due-due = Due
+due-since = { $frequency }, since { $date }
+
+## Chart
+
+chart-title = { $subject } in { $unit } | { $timeframes }
+chart-keys = (1-3 or Tab to change the timeframe, Esc to close)
+chart-now = now
+chart-ago = { $minutes }m ago
+chart-hours-ago = { $hours }h ago
+chart-days-ago = { $days }d ago
+chart-fetching = Fetching the { $timeframe } history...
+chart-failed = No history: { $error }
+chart-empty = No history over this timeframe
+
+## Amount
+
+amount-title = Amount
+amount-title-balance = Amount ({ $asset }) | Balance: { $balance }
+amount-balance = { $amount } { $asset } | (%) 25/50/75% | (M)AX
+amount-balance-unknown = add a watch address with (w)
+amount-balance-loading = loading{ $ellipsis }
+amount-balance-failed = unavailable ({ $error })
+
+## Assets
+
+assets-title = Assets
+assets-title-from = Select FROM Asset
+assets-title-to = Select TO Asset
+assets-asset = Asset
+assets-chain = Chain
+assets-price = Price
+assets-price-in = Price ({ $fiat })
+
+## Provider detail
+
+provider-type = Type
+provider-custody = Custody
+provider-fees = Fees
+provider-registration = Registration
+provider-kyc = KYC
+provider-kyc-threshold = may be asked for swaps worth more than { $threshold } { $fiat }
+provider-kyc-none = no threshold published
+provider-restricted = Restricted
+provider-restricted-here = { $restricted }: not available in { $jurisdiction }
+provider-website = Website
+provider-status-page = Status page
+provider-pairs = Pairs
+provider-pairs-listed = { $count } of the listed pairs
+provider-limits = Limits
+provider-limits-bounds = min { $min }, max { $max } on { $pair }
+provider-health = Health
+provider-health-ok = answered in { $latency }ms at { $at }
+provider-health-failed = failed at { $at }: { $error }
+provider-health-none = nothing to check
+provider-checking = checking{ $ellipsis }
+provider-none-published = none published
+provider-unknown = unknown
+
+## Swap plan
+
+swap-plan-title = Swap plan
+swap-plan-empty = Choose a quote to see its steps
+plan-no-watched-address = no watched address
+plan-spender = { $provider } ({ $address }{ $ellipsis })
+plan-checking-allowance = Approve { $from } for { $spender }: checking allowance{ $ellipsis }
+plan-approved = No approval needed: { $spender } may already spend { $allowance } { $from }
+plan-approval-required = Approval required: allow { $spender } to spend { $amount } { $from } (currently { $allowance })
+plan-allowance-unknown = Approval may be required: allowance for { $spender } unknown ({ $error })
+plan-swap = Swap { $amount_in } { $from } for { $amount_out } { $to } via { $provider }
+plan-deposit = Deposit { $amount_in } { $from } with { $provider }, receive { $amount_out } { $to }
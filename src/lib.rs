@@ -1,8 +1,77 @@
-pub mod errors;
+pub mod cli;
+pub mod config;
+pub mod crash;
+pub mod i18n;
+pub mod logging;
 pub mod services;
 pub mod ui;
 
-pub fn run() -> Result<(), Box<dyn std::error::Error>> {
-    ui::app::run()?;
-    Ok(())
+pub use xoswap_core::{errors, models};
+
+pub use crate::ui::builder::AppBuilder;
+
+use std::error::Error;
+use std::process::ExitCode;
+
+use clap::Parser;
+use tracing::info;
+
+use crate::cli::{Cli, Command};
+use crate::config::{Charset, Config};
+use crate::services::{http, metrics};
+
+pub fn run() -> Result<ExitCode, Box<dyn Error>> {
+    let cli = Cli::parse();
+    let _log_guard = logging::init(cli.log_level());
+    info!(version = env!("CARGO_PKG_VERSION"), "starting");
+    metrics::start();
+    #[cfg(feature = "prometheus")]
+    if let Some(port) = cli.metrics_port {
+        let addr = services::prometheus::serve(port)?;
+        info!(%addr, "serving metrics");
+    }
+
+    // Secrets are managed before reading the config, which may refer to missing ones
+    if let Some(Command::Secret(command)) = &cli.command {
+        return cli::secret::run(command);
+    }
+    // Traces play as they were drawn, whatever the config
+    if let Some(Command::Play(args)) = &cli.command {
+        return cli::play::run(args);
+    }
+
+    // Read the config before touching the terminal, so errors are readable
+    let first_run = Config::path().is_some_and(|path| !path.exists());
+    let mut config = Config::load(cli.profile.as_deref().filter(|name| !name.is_empty()))?;
+    if cli.demo {
+        info!(seed = cli.seed, "demo mode");
+        config.demo = Some(cli.seed);
+        config.offline = true;
+    }
+    if cli.ascii {
+        config.charset = Charset::Ascii;
+    }
+    // The commands share the setup of the TUI, which the builder repeats for crates embedding it
+    // A replay answers from the record, never from the network
+    http::configure(config.proxy.as_deref(), config.offline || cli.replay.is_some())?;
+    i18n::init(&config.language)?;
+    ui::glyphs::init(config.charset, config.nerd_font);
+    info!(profile = config.profile.as_deref(), providers = ?config.providers, "config loaded");
+
+    match &cli.command {
+        Some(Command::Quote(args)) => cli::quote::run(args, &config),
+        Some(Command::Secret(command)) => cli::secret::run(command),
+        Some(Command::Play(args)) => cli::play::run(args),
+        None if cli.daemon => cli::daemon::run(cli.daemon_port, config),
+        None => {
+            ui::app::run(cli, config, first_run)?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+/// Run the TUI with `config`, for crates embedding it: the command line and the config file are left alone.
+/// Use [`AppBuilder`] to customize it further.
+pub fn run_with(config: Config) -> Result<(), Box<dyn Error>> {
+    AppBuilder::new(config).run()
 }
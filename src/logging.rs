@@ -0,0 +1,113 @@
+//! ## Logging
+//!
+//! Structured logs written to a daily rotated file, since the TUI owns the terminal
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload, Registry};
+
+use crate::config::data_dir;
+
+/// Log files kept before the oldest is deleted
+const MAX_LOG_FILES: usize = 7;
+
+/// Directory holding the log files
+pub fn dir() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("logs"))
+}
+
+/// Most recent log file, if any
+pub fn latest_file() -> Option<PathBuf> {
+    // Dated file names sort chronologically
+    fs::read_dir(dir()?)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
+        .max()
+}
+
+/// Last complete lines of a log file, reading at most `max_bytes`
+pub fn tail(path: &Path, max_bytes: u64) -> io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let start = file.metadata()?.len().saturating_sub(max_bytes);
+    file.seek(SeekFrom::Start(start))?;
+    let mut content = Vec::new();
+    file.read_to_end(&mut content)?;
+    let mut lines: Vec<String> = String::from_utf8_lossy(&content).lines().map(str::to_string).collect();
+    // The first line is likely cut
+    if start > 0 && !lines.is_empty() {
+        lines.remove(0);
+    }
+    Ok(lines)
+}
+
+/// Levels from the quietest to the most verbose
+const LEVELS: [LevelFilter; 5] = [
+    LevelFilter::ERROR,
+    LevelFilter::WARN,
+    LevelFilter::INFO,
+    LevelFilter::DEBUG,
+    LevelFilter::TRACE,
+];
+
+/// Changes the level of the running logger
+static HANDLE: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+/// Level set by `XOSWAP_LOG` (`error`, `warn`, `info`, `debug`, `trace` or `off`), `info` by default
+fn env_level() -> LevelFilter {
+    env::var("XOSWAP_LOG")
+        .ok()
+        .and_then(|level| level.parse().ok())
+        .unwrap_or(LevelFilter::INFO)
+}
+
+/// Start writing logs at `level`, or the one set by `XOSWAP_LOG`; they are flushed until the
+/// returned guard is dropped. Logging stays off when the log directory cannot be created
+pub fn init(level: Option<LevelFilter>) -> Option<WorkerGuard> {
+    let dir = dir()?;
+    fs::create_dir_all(&dir).ok()?;
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("xoswap")
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(dir)
+        .ok()?;
+    let (writer, guard) = tracing_appender::non_blocking(appender);
+    let (filter, handle) = reload::Layer::new(level.unwrap_or_else(env_level));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt::layer().with_writer(writer).with_ansi(false))
+        .try_init()
+        .ok()?;
+    let _ = HANDLE.set(handle);
+    Some(guard)
+}
+
+/// Current level, `off` when logging is not running
+pub fn level() -> LevelFilter {
+    HANDLE
+        .get()
+        .and_then(|handle| handle.clone_current())
+        .unwrap_or(LevelFilter::OFF)
+}
+
+/// Log more, going back to errors only after `trace`; returns the new level
+pub fn raise_level() -> LevelFilter {
+    let Some(handle) = HANDLE.get() else {
+        return LevelFilter::OFF;
+    };
+    let index = LEVELS.iter().position(|level| *level == self::level());
+    let level = LEVELS[index.map_or(0, |index| (index + 1) % LEVELS.len())];
+    let _ = handle.modify(|filter| *filter = level);
+    level
+}
@@ -1,3 +1,12 @@
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    xoswap::run()
-}
\ No newline at end of file
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match xoswap::run() {
+        Ok(code) => code,
+        // The terminal is restored by now; print the error itself rather than its debug form
+        Err(err) => {
+            eprintln!("xoswap: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
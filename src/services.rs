@@ -0,0 +1,16 @@
+//! ## Services
+//!
+//! Services backing the UI: the engine's, and the ones keeping the user's data
+
+pub use xoswap_core::services::{
+    allowance, balance, cancel, discovery, ens, export, fees, http, metrics, prices, providers, quote, rpc,
+};
+
+pub mod drafts;
+pub mod history;
+pub mod notify;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+pub mod schedules;
+pub mod secrets;
+pub mod session;
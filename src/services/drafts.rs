@@ -0,0 +1,55 @@
+//! ## Drafts
+//!
+//! Named swap drafts, kept in a JSON file under the data directory
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::config::data_dir;
+use crate::errors::SwapError;
+use crate::models::draft::Draft;
+
+/// Path of the drafts file
+fn path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("drafts.json"))
+}
+
+/// Every saved draft, by name, none before the first is saved; a file that cannot be read or parsed is an error, so
+/// that it is never written over
+pub fn load() -> Result<BTreeMap<String, Draft>, SwapError> {
+    let Some(path) = path() else {
+        return Ok(BTreeMap::new());
+    };
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(err) => return Err(SwapError::Io(format!("{}: {err}", path.display()))),
+    };
+    serde_json::from_str(&content).map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))
+}
+
+/// Write every draft
+fn store(drafts: &BTreeMap<String, Draft>) -> Result<(), SwapError> {
+    let path = path().ok_or_else(|| SwapError::Io("no data directory".to_string()))?;
+    let content = serde_json::to_string_pretty(drafts).map_err(|err| SwapError::Io(err.to_string()))?;
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, content))
+        .map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))
+}
+
+/// Save a draft, replacing any draft with the same name
+pub fn save(name: &str, draft: &Draft) -> Result<(), SwapError> {
+    let mut drafts = load()?;
+    drafts.insert(name.to_string(), draft.clone());
+    store(&drafts)
+}
+
+/// Delete a draft
+pub fn remove(name: &str) -> Result<(), SwapError> {
+    let mut drafts = load()?;
+    drafts.remove(name);
+    store(&drafts)
+}
@@ -0,0 +1,37 @@
+//! ## History
+//!
+//! History store, one JSON entry per line under the data directory
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::config::data_dir;
+use crate::errors::SwapError;
+use crate::models::history::HistoryEntry;
+
+/// Path of the history file
+fn path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("history.jsonl"))
+}
+
+/// Record an entry
+pub fn append(entry: &HistoryEntry) -> Result<(), SwapError> {
+    let path = path().ok_or_else(|| SwapError::Io("no data directory".to_string()))?;
+    let line = serde_json::to_string(entry).map_err(|err| SwapError::Io(err.to_string()))?;
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| OpenOptions::new().create(true).append(true).open(&path))
+        .and_then(|mut file| writeln!(file, "{line}"))
+        .map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))
+}
+
+/// Every recorded entry, newest first; unreadable lines are skipped
+pub fn load() -> Vec<HistoryEntry> {
+    let Some(content) = path().and_then(|path| fs::read_to_string(path).ok()) else {
+        return vec![];
+    };
+    let mut entries: Vec<HistoryEntry> = content.lines().filter_map(|line| serde_json::from_str(line).ok()).collect();
+    entries.reverse();
+    entries
+}
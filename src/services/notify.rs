@@ -0,0 +1,81 @@
+//! ## Notify
+//!
+//! Desktop notifications, seen even when the terminal is in the background, and the terminal bell.
+//! Built without the `notifications` feature, desktop notifications are only logged.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::sync::Mutex;
+#[cfg(feature = "notifications")]
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "notifications")]
+use notify_rust::Notification;
+use tracing::{debug, warn};
+
+use crate::config::Bell;
+
+/// Time before a notification with the same key is shown again
+const THROTTLE: Duration = Duration::from_secs(5 * 60);
+
+/// Time the screen stays reversed on a visual bell
+pub const FLASH: Duration = Duration::from_millis(100);
+
+/// Last time each notification key was shown
+static SENT: Mutex<Option<HashMap<String, Instant>>> = Mutex::new(None);
+
+/// Show a notification in the background, unless one with the same key was shown recently
+pub fn send(key: &str, summary: &str, body: &str) {
+    {
+        let mut sent = SENT.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let sent = sent.get_or_insert_with(HashMap::new);
+        if sent.get(key).is_some_and(|at| at.elapsed() < THROTTLE) {
+            debug!(key, "notification throttled");
+            return;
+        }
+        sent.insert(key.to_string(), Instant::now());
+    }
+
+    show(summary.to_string(), body.to_string());
+}
+
+/// Show a desktop notification without waiting for it
+#[cfg(feature = "notifications")]
+fn show(summary: String, body: String) {
+    thread::spawn(move || {
+        if let Err(err) = Notification::new().appname("xoswap").summary(&summary).body(&body).show() {
+            warn!(%err, "desktop notification failed");
+        }
+    });
+}
+
+/// Log the notification, desktop ones not being built in
+#[cfg(not(feature = "notifications"))]
+fn show(summary: String, body: String) {
+    debug!(summary, body, "desktop notifications not built in");
+}
+
+/// Ring the terminal bell; a visual one flashes the screen by reversing its colors until [`unflash`], which the
+/// caller schedules [`FLASH`] later rather than blocking
+pub fn ring(bell: Bell) {
+    let sequence: &[u8] = match bell {
+        Bell::Off => return,
+        Bell::Audible => b"\x07",
+        Bell::Visual => b"\x1b[?5h",
+    };
+    write(sequence);
+}
+
+/// Restore the colors reversed by a visual bell
+pub fn unflash() {
+    write(b"\x1b[?5l");
+}
+
+/// Write a control sequence to the terminal
+fn write(sequence: &[u8]) {
+    let mut stdout = io::stdout();
+    if let Err(err) = stdout.write_all(sequence).and_then(|_| stdout.flush()) {
+        warn!(%err, "terminal bell failed");
+    }
+}
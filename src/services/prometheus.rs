@@ -0,0 +1,121 @@
+//! ## Prometheus
+//!
+//! Local HTTP endpoint exposing the session metrics in the Prometheus text format
+
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use tracing::{debug, warn};
+
+use crate::errors::SwapError;
+use crate::services::metrics::{self, Metrics, LATENCY_BUCKETS_MS};
+
+/// Longest wait for a scraper to send its request or take the answer
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serve the metrics on `127.0.0.1:<port>/metrics` in the background, returning the bound address
+pub fn serve(port: u16) -> Result<SocketAddr, SwapError> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .map_err(|err| SwapError::Io(format!("metrics port {port}: {err}")))?;
+    let addr = listener.local_addr().map_err(|err| SwapError::Io(err.to_string()))?;
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            // A scraper sending nothing must not hold back the next ones
+            thread::spawn(move || {
+                if let Err(err) = respond(stream) {
+                    warn!(%err, "metrics request failed");
+                }
+            });
+        }
+    });
+    Ok(addr)
+}
+
+/// Answer one scrape
+fn respond(stream: TcpStream) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Skip the headers
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+    debug!(request = request_line.trim_end(), "metrics request");
+
+    let mut stream = &stream;
+    let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+    if request_line.starts_with("GET ") && (path == "/metrics" || path.starts_with("/metrics?")) {
+        let body = render(&metrics::snapshot());
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    } else {
+        write!(stream, "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+    }
+}
+
+/// Label value with backslashes, quotes and newlines escaped
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Metrics in the Prometheus text exposition format
+pub fn render(metrics: &Metrics) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# HELP xoswap_requests_total Requests made to a provider or node.");
+    let _ = writeln!(out, "# TYPE xoswap_requests_total counter");
+    for (service, stats) in &metrics.services {
+        let _ = writeln!(out, "xoswap_requests_total{{service=\"{}\"}} {}", escape(service), stats.requests);
+    }
+
+    let _ = writeln!(out, "# HELP xoswap_request_errors_total Requests that failed.");
+    let _ = writeln!(out, "# TYPE xoswap_request_errors_total counter");
+    for (service, stats) in &metrics.services {
+        let _ = writeln!(out, "xoswap_request_errors_total{{service=\"{}\"}} {}", escape(service), stats.errors);
+    }
+
+    let _ = writeln!(out, "# HELP xoswap_request_duration_seconds Latency of the requests.");
+    let _ = writeln!(out, "# TYPE xoswap_request_duration_seconds histogram");
+    for (service, stats) in &metrics.services {
+        let service = escape(service);
+        let mut cumulative = 0;
+        for (bound, count) in LATENCY_BUCKETS_MS.iter().zip(&stats.histogram) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "xoswap_request_duration_seconds_bucket{{service=\"{service}\",le=\"{}\"}} {cumulative}",
+                *bound as f64 / 1000.0
+            );
+        }
+        let _ = writeln!(
+            out,
+            "xoswap_request_duration_seconds_bucket{{service=\"{service}\",le=\"+Inf\"}} {}",
+            stats.requests
+        );
+        let _ = writeln!(
+            out,
+            "xoswap_request_duration_seconds_sum{{service=\"{service}\"}} {}",
+            stats.total_ms as f64 / 1000.0
+        );
+        let _ = writeln!(out, "xoswap_request_duration_seconds_count{{service=\"{service}\"}} {}", stats.requests);
+    }
+
+    let _ = writeln!(out, "# HELP xoswap_cache_lookups_total Cache lookups, by result.");
+    let _ = writeln!(out, "# TYPE xoswap_cache_lookups_total counter");
+    let _ = writeln!(out, "xoswap_cache_lookups_total{{result=\"hit\"}} {}", metrics.cache_hits);
+    let _ = writeln!(out, "xoswap_cache_lookups_total{{result=\"miss\"}} {}", metrics.cache_misses);
+
+    let _ = writeln!(out, "# HELP xoswap_uptime_seconds Time since the application started.");
+    let _ = writeln!(out, "# TYPE xoswap_uptime_seconds gauge");
+    let _ = writeln!(out, "xoswap_uptime_seconds {}", metrics.started_at.elapsed().as_secs());
+    out
+}
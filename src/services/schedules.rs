@@ -0,0 +1,80 @@
+//! ## Schedules
+//!
+//! Repetitions of the saved drafts, by draft name, kept in a JSON file next to the drafts
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+
+use crate::config::data_dir;
+use crate::errors::SwapError;
+use crate::models::draft::Draft;
+use crate::models::schedule::Schedule;
+use crate::services::drafts;
+
+/// Path of the schedules file
+fn path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("schedules.json"))
+}
+
+/// Every schedule, by draft name, none before the first is set; a file that cannot be read or parsed is an error, so
+/// that it is never written over
+pub fn load() -> Result<BTreeMap<String, Schedule>, SwapError> {
+    let Some(path) = path() else {
+        return Ok(BTreeMap::new());
+    };
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(err) => return Err(SwapError::Io(format!("{}: {err}", path.display()))),
+    };
+    serde_json::from_str(&content).map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))
+}
+
+/// Write every schedule
+fn store(schedules: &BTreeMap<String, Schedule>) -> Result<(), SwapError> {
+    let path = path().ok_or_else(|| SwapError::Io("no data directory".to_string()))?;
+    let content = serde_json::to_string_pretty(schedules).map_err(|err| SwapError::Io(err.to_string()))?;
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, content))
+        .map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))
+}
+
+/// Repeat the draft `name` on `schedule`, or stop repeating it
+pub fn set(name: &str, schedule: Option<Schedule>) -> Result<(), SwapError> {
+    let mut schedules = load()?;
+    match schedule {
+        Some(schedule) => schedules.insert(name.to_string(), schedule),
+        None => schedules.remove(name),
+    };
+    store(&schedules)
+}
+
+/// Drafts whose swap is due at `now`, with their schedule; schedules of deleted drafts are left out
+pub fn due(now: DateTime<Utc>) -> Result<Vec<(String, Draft, Schedule)>, SwapError> {
+    let drafts = drafts::load()?;
+    Ok(load()?
+        .into_iter()
+        .filter(|(_, schedule)| schedule.is_due(now))
+        .filter_map(|(name, schedule)| {
+            let draft = drafts.get(&name)?.clone();
+            Some((name, draft, schedule))
+        })
+        .collect())
+}
+
+/// Mark the swap of the draft `name` as made at `now`, if it was due
+pub fn advance(name: &str, now: DateTime<Utc>) -> Result<(), SwapError> {
+    let mut schedules = load()?;
+    match schedules.get_mut(name) {
+        Some(schedule) if schedule.is_due(now) => {
+            schedule.advance(now);
+            store(&schedules)
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,212 @@
+//! ## Secrets
+//!
+//! API keys and RPC credentials kept in the OS keyring, referred to as `keyring:<name>` in the config.
+//! Where no keyring is available, secrets go to a file under the data directory, encrypted with a key
+//! derived from `XOSWAP_SECRETS_PASSPHRASE`. Built without the `keyring` feature, the file is the only store.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+use crate::config::data_dir;
+use crate::errors::SwapError;
+
+/// Prefix of config values referring to a secret
+pub const PREFIX: &str = "keyring:";
+
+/// Environment variable holding the passphrase of the secrets file
+const PASSPHRASE_VAR: &str = "XOSWAP_SECRETS_PASSPHRASE";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Where a secret was saved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Store {
+    Keyring,
+    File(PathBuf),
+}
+
+impl Display for Store {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keyring => write!(f, "the OS keyring"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Config value with a `keyring:<name>` reference replaced by the secret; other values are returned as is
+pub fn resolve(value: &str) -> Result<String, SwapError> {
+    match value.strip_prefix(PREFIX) {
+        Some(name) => get(name),
+        None => Ok(value.to_string()),
+    }
+}
+
+/// Secret saved as `name`, looked up in the keyring then in the secrets file
+pub fn get(name: &str) -> Result<String, SwapError> {
+    if let Some(secret) = os_keyring::get(name)? {
+        return Ok(secret);
+    }
+    if !file_path()?.exists() {
+        return Err(SwapError::Secret(format!("no secret named {name}")));
+    }
+    read_file()?
+        .remove(name)
+        .ok_or_else(|| SwapError::Secret(format!("no secret named {name}")))
+}
+
+/// Save a secret as `name`, in the keyring when there is one
+pub fn set(name: &str, secret: &str) -> Result<Store, SwapError> {
+    if os_keyring::set(name, secret)? {
+        return Ok(Store::Keyring);
+    }
+    let mut secrets = if file_path()?.exists() { read_file()? } else { BTreeMap::new() };
+    secrets.insert(name.to_string(), secret.to_string());
+    write_file(&secrets)?;
+    Ok(Store::File(file_path()?))
+}
+
+/// Delete the secret saved as `name`, wherever it is
+pub fn delete(name: &str) -> Result<(), SwapError> {
+    let in_keyring = os_keyring::delete(name)?;
+    let in_file = file_path()?.exists() && {
+        let mut secrets = read_file()?;
+        let found = secrets.remove(name).is_some();
+        if found {
+            write_file(&secrets)?;
+        }
+        found
+    };
+    if in_keyring || in_file {
+        Ok(())
+    } else {
+        Err(SwapError::Secret(format!("no secret named {name}")))
+    }
+}
+
+/// The OS keyring; a missing or unavailable one leaves the secrets file to look in
+#[cfg(feature = "keyring")]
+mod os_keyring {
+    use keyring::{Entry, Error};
+
+    use crate::errors::SwapError;
+
+    /// Keyring service the secrets are saved under
+    const SERVICE: &str = "xoswap-tui";
+
+    /// Secret saved as `name`, `None` when not in the keyring
+    pub fn get(name: &str) -> Result<Option<String>, SwapError> {
+        match Entry::new(SERVICE, name).and_then(|entry| entry.get_password()) {
+            Ok(secret) => Ok(Some(secret)),
+            Err(Error::NoEntry | Error::NoStorageAccess(_) | Error::PlatformFailure(_)) => Ok(None),
+            Err(err) => Err(SwapError::Secret(format!("{name}: {err}"))),
+        }
+    }
+
+    /// Save a secret as `name`, returning whether there was a keyring to save it in
+    pub fn set(name: &str, secret: &str) -> Result<bool, SwapError> {
+        match Entry::new(SERVICE, name).and_then(|entry| entry.set_password(secret)) {
+            Ok(()) => Ok(true),
+            Err(Error::NoStorageAccess(_) | Error::PlatformFailure(_)) => Ok(false),
+            Err(err) => Err(SwapError::Secret(format!("{name}: {err}"))),
+        }
+    }
+
+    /// Delete the secret saved as `name`, returning whether it was in the keyring
+    pub fn delete(name: &str) -> Result<bool, SwapError> {
+        match Entry::new(SERVICE, name).and_then(|entry| entry.delete_credential()) {
+            Ok(()) => Ok(true),
+            Err(Error::NoEntry | Error::NoStorageAccess(_) | Error::PlatformFailure(_)) => Ok(false),
+            Err(err) => Err(SwapError::Secret(format!("{name}: {err}"))),
+        }
+    }
+}
+
+/// No keyring without the `keyring` feature
+#[cfg(not(feature = "keyring"))]
+mod os_keyring {
+    use crate::errors::SwapError;
+
+    pub fn get(_name: &str) -> Result<Option<String>, SwapError> {
+        Ok(None)
+    }
+
+    pub fn set(_name: &str, _secret: &str) -> Result<bool, SwapError> {
+        Ok(false)
+    }
+
+    pub fn delete(_name: &str) -> Result<bool, SwapError> {
+        Ok(false)
+    }
+}
+
+/// Path of the secrets file
+fn file_path() -> Result<PathBuf, SwapError> {
+    data_dir()
+        .map(|dir| dir.join("secrets.enc"))
+        .ok_or_else(|| SwapError::Secret("no data directory".to_string()))
+}
+
+/// Key of the secrets file, derived from the passphrase
+fn file_key(salt: &[u8]) -> Result<Key, SwapError> {
+    let passphrase = env::var(PASSPHRASE_VAR)
+        .ok()
+        .filter(|passphrase| !passphrase.is_empty())
+        .ok_or_else(|| SwapError::Secret(format!("no keyring available, set {PASSPHRASE_VAR} to use the secrets file")))?;
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|err| SwapError::Secret(err.to_string()))?;
+    Ok(key)
+}
+
+/// Decrypt the secrets file: salt, nonce then the encrypted JSON object of secrets
+fn read_file() -> Result<BTreeMap<String, String>, SwapError> {
+    let path = file_path()?;
+    let content = fs::read(&path).map_err(|err| SwapError::Secret(format!("{}: {err}", path.display())))?;
+    if content.len() < SALT_LEN + NONCE_LEN {
+        return Err(SwapError::Secret(format!("{}: truncated file", path.display())));
+    }
+    let (salt, rest) = content.split_at(SALT_LEN);
+    let (nonce, encrypted) = rest.split_at(NONCE_LEN);
+    let plain = ChaCha20Poly1305::new(&file_key(salt)?)
+        .decrypt(Nonce::from_slice(nonce), encrypted)
+        .map_err(|_| SwapError::Secret(format!("{}: wrong passphrase or corrupted file", path.display())))?;
+    serde_json::from_slice(&plain).map_err(|err| SwapError::Secret(format!("{}: {err}", path.display())))
+}
+
+/// Encrypt the secrets to the secrets file, with a fresh salt and nonce
+fn write_file(secrets: &BTreeMap<String, String>) -> Result<(), SwapError> {
+    let path = file_path()?;
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let plain = serde_json::to_vec(secrets).map_err(|err| SwapError::Secret(err.to_string()))?;
+    let encrypted = ChaCha20Poly1305::new(&file_key(&salt)?)
+        .encrypt(Nonce::from_slice(&nonce), plain.as_slice())
+        .map_err(|err| SwapError::Secret(err.to_string()))?;
+
+    let content = [salt.as_slice(), nonce.as_slice(), encrypted.as_slice()].concat();
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    // Readable by the owner only
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| options.open(&path))
+        .and_then(|mut file| file.write_all(&content))
+        .map_err(|err| SwapError::Secret(format!("{}: {err}", path.display())))
+}
@@ -0,0 +1,44 @@
+//! ## Session
+//!
+//! Form left unfinished on exit, offered back on the next launch
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config::data_dir;
+use crate::errors::SwapError;
+use crate::models::draft::Draft;
+
+/// Path of the session file
+fn path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("session.json"))
+}
+
+/// Save the form, or forget the previous one if it is empty
+pub fn save(draft: &Draft) -> Result<(), SwapError> {
+    let path = path().ok_or_else(|| SwapError::Io("no data directory".to_string()))?;
+    if draft.is_empty() {
+        return clear();
+    }
+    let content = serde_json::to_string_pretty(draft).map_err(|err| SwapError::Io(err.to_string()))?;
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, content))
+        .map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))
+}
+
+/// Form saved by the previous session, if any
+pub fn load() -> Option<Draft> {
+    let content = fs::read_to_string(path()?).ok()?;
+    serde_json::from_str::<Draft>(&content).ok().filter(|draft| !draft.is_empty())
+}
+
+/// Forget the saved form
+pub fn clear() -> Result<(), SwapError> {
+    match path() {
+        Some(path) if path.exists() => {
+            fs::remove_file(&path).map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))
+        }
+        _ => Ok(()),
+    }
+}
@@ -1,6 +1,20 @@
 pub mod app;
+pub mod builder;
 pub mod components;
+pub mod control;
+pub mod event;
+pub mod glyphs;
+pub mod harness;
 pub mod id;
+pub mod keymap;
+pub mod layout;
 pub mod model;
 pub mod msg;
+pub mod port;
+pub mod recording;
+pub mod scripting;
+pub mod state;
 pub mod theme;
+pub mod title;
+pub mod trace;
+pub mod worker;
@@ -1,59 +1,142 @@
 use std::error::Error;
-use std::time::Duration;
+use std::io;
+use std::panic;
+use std::sync::OnceLock;
+use std::thread::{self, ThreadId};
 
-use crossterm::event::{self, Event as CEvent, KeyCode, KeyEvent};
-use tuirealm::terminal::CrosstermTerminalAdapter;
+use crossterm::cursor::Show;
+use crossterm::event::DisableMouseCapture;
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, LeaveAlternateScreen};
+use tracing::{error, info};
+use tuirealm::terminal::TerminalAdapter;
 use tuirealm::PollStrategy;
-use tuirealm::Update;
 
+use crate::cli::{Cli, Prefill};
+use crate::config::Config;
+use crate::crash;
+use crate::ui::builder::AppBuilder;
 use crate::ui::model::Model;
+use crate::ui::recording::{EventSource, Header, Recorder, Replay};
+use crate::ui::scripting;
+use crate::ui::title;
 
-pub fn run() -> Result<(), Box<dyn Error>> {
-    // Create terminal
-    let terminal = CrosstermTerminalAdapter::new()?;
+/// Thread running the UI, whose panics stop the application
+static UI_THREAD: OnceLock<ThreadId> = OnceLock::new();
 
-    // Setup application
-    let mut model = Model::new(terminal);
+pub fn run(cli: Cli, config: Config, first_run: bool) -> Result<(), Box<dyn Error>> {
+    // A replay starts from the form it was recorded with
+    let (prefill, watch, source) = match (&cli.record, &cli.replay) {
+        (_, Some(path)) => {
+            let replay = Replay::load(path)?;
+            info!(path = %path.display(), events = replay.len(), "replaying");
+            let prefill = Prefill::from_draft(&replay.header.form, &config.assets);
+            (prefill, replay.header.watch, EventSource::Replay(replay))
+        }
+        (Some(path), None) => {
+            let prefill = cli.prefill(&config.assets)?;
+            let header = Header {
+                form: prefill.draft(),
+                watch: cli.watch,
+            };
+            info!(path = %path.display(), "recording");
+            (prefill, cli.watch, EventSource::Record(Recorder::create(path, &header)?))
+        }
+        (None, None) => (cli.prefill(&config.assets)?, cli.watch, EventSource::Live),
+    };
 
-    // Enter alternate screen
-    model.terminal.enter_alternate_screen()?;
-    model.terminal.enable_raw_mode()?;
+    // Recorded sessions start from the command line alone, and a replay leaves the saved session alone
+    let recorded = !matches!(source, EventSource::Live);
+    let replaying = matches!(source, EventSource::Replay(_));
+    let mut builder = AppBuilder::new(config);
+    if let Some(path) = &cli.control {
+        builder = builder.control_socket(path);
+    }
+    if let Some(path) = &cli.trace {
+        builder = builder.trace(path);
+    }
+    // Replayed sessions already hold the commands of the scripts; demos are reproducible, without scripts,
+    // onboarding or sessions
+    let demo = cli.demo;
+    if let Some(dir) = scripting::default_dir().filter(|_| !replaying && !demo) {
+        builder = builder.scripts(dir);
+    }
+    builder
+        .events(source)
+        .prefill(prefill)
+        .watch(watch)
+        .onboarding(first_run && !recorded && !demo)
+        .restore_session(!recorded && !demo)
+        .save_session(!replaying && !demo)
+        .remind_schedules(!recorded && !demo)
+        .run()
+}
 
-    // Main loop
-    while !model.quit {
-        // We'll let the component system handle all key events
-        // to avoid conflicting with component-level key handling
-
-        // Tick
-        match model.app.tick(PollStrategy::Once) {
-            Err(err) => {
-                eprintln!("Application error: {}", err);
-                break;
-            }
-            Ok(messages) if !messages.is_empty() => {
-                // Redraw if at least one message was processed
-                model.redraw = true;
-                for msg in messages.into_iter() {
-                    let mut msg = Some(msg);
-                    while msg.is_some() {
-                        msg = model.update(msg);
-                    }
-                }
-            }
-            _ => {}
-        }
+/// Run `model` on its terminal until the user quits, then give the terminal back
+pub(crate) fn launch<T: TerminalAdapter>(model: &mut Model<T>, save_session: bool) -> Result<(), Box<dyn Error>> {
+    // Enter alternate screen, leaving it on any failure from now on
+    install_panic_hook();
+    // Keep the terminal's title to give it back on exit
+    title::save();
+    model.manage_title();
+    let result = model
+        .terminal
+        .enter_alternate_screen()
+        .and_then(|_| model.terminal.enable_raw_mode())
+        .map_err(Box::from)
+        .and_then(|_| run_app(model));
 
-        // Redraw
-        if model.redraw {
-            model.view();
-            model.redraw = false;
-        }
+    // Keep the form for the next launch
+    if save_session {
+        model.save_session();
     }
 
     // Restore terminal
-    model.terminal.leave_alternate_screen()?;
-    model.terminal.disable_raw_mode()?;
-    model.terminal.clear_screen()?;
+    model.unflash();
+    let restored = model
+        .terminal
+        .leave_alternate_screen()
+        .and_then(|_| model.terminal.disable_raw_mode())
+        .and_then(|_| model.terminal.clear_screen());
+    title::restore();
+    if let Err(err) = &result {
+        error!(%err, "application error");
+    }
+    info!("exiting");
+
+    result.and(restored.map_err(Box::from))
+}
+
+/// Main loop, until the user quits or the event listener fails: each turn waits for the next event, and draws only
+/// when handling it changed something
+fn run_app<T: TerminalAdapter>(model: &mut Model<T>) -> Result<(), Box<dyn Error>> {
+    crash::set_state(model.crash_state());
+    while !model.quit {
+        model.tick(PollStrategy::Once)?;
+    }
 
     Ok(())
 }
+
+/// Leave the alternate screen and raw mode and give the title back before a panic on the UI thread is printed, so
+/// that the shell is usable again, and write a crash report; panics of background threads are only logged, since they
+/// do not stop the application
+fn install_panic_hook() {
+    let _ = UI_THREAD.set(thread::current().id());
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if UI_THREAD.get() != Some(&thread::current().id()) {
+            error!(%info, "background thread panicked");
+            return;
+        }
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, Show);
+        title::restore();
+        error!(%info, "panicked");
+        let report = crash::write_report(info);
+        default_hook(info);
+        if let Some(path) = report {
+            eprintln!("A crash report was saved to {}; please attach it to your bug report", path.display());
+        }
+    }));
+}
@@ -0,0 +1,236 @@
+//! ## Builder
+//!
+//! Builder of the application, for crates embedding or customizing the TUI
+
+use std::error::Error;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalAdapter, TerminalResult};
+
+use crate::cli::Prefill;
+use crate::config::Config;
+use crate::i18n;
+use crate::models::asset::Asset;
+use crate::services::providers::Provider;
+use crate::services::{http, metrics};
+use crate::ui::app;
+use crate::ui::glyphs;
+use crate::ui::keymap::Keymap;
+use crate::ui::model::Model;
+use crate::ui::recording::EventSource;
+use crate::ui::theme::Theme;
+
+/// Creates the terminal once the application starts
+type TerminalFactory<T> = Box<dyn FnOnce() -> TerminalResult<T>>;
+
+/// Builder of the application, drawing on `T`
+///
+/// ```no_run
+/// use xoswap::config::Config;
+/// use xoswap::AppBuilder;
+///
+/// AppBuilder::new(Config::default()).providers(["sideshift"]).run().unwrap();
+/// ```
+pub struct AppBuilder<T: TerminalAdapter = CrosstermTerminalAdapter> {
+    config: Config,
+    extra_providers: Vec<Arc<dyn Provider>>,
+    terminal: TerminalFactory<T>,
+    source: EventSource,
+    prefill: Prefill,
+    watch: bool,
+    onboarding: bool,
+    restore_session: bool,
+    save_session: bool,
+    remind_schedules: bool,
+    control: Option<PathBuf>,
+    scripts: Option<PathBuf>,
+    trace: Option<PathBuf>,
+}
+
+impl AppBuilder<CrosstermTerminalAdapter> {
+    /// Start from `config`, drawing on the standard output
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            extra_providers: vec![],
+            terminal: Box::new(CrosstermTerminalAdapter::new),
+            source: EventSource::Live,
+            prefill: Prefill::default(),
+            watch: false,
+            onboarding: false,
+            restore_session: true,
+            save_session: true,
+            remind_schedules: true,
+            control: None,
+            scripts: None,
+            trace: None,
+        }
+    }
+}
+
+impl<T: TerminalAdapter> AppBuilder<T> {
+    /// List `assets` in the sidebar instead of the configured ones
+    pub fn assets(mut self, assets: Vec<Asset>) -> Self {
+        self.config.assets = assets;
+        self
+    }
+
+    /// Ask the built-in providers named in `names` for quotes, instead of the configured ones
+    pub fn providers<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config.providers = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Ask `provider` for quotes as well, e.g. one implemented by the embedding crate
+    pub fn provider(mut self, provider: impl Provider + 'static) -> Self {
+        self.extra_providers.push(Arc::new(provider));
+        self
+    }
+
+    /// Bind keys with `keymap` instead of the configured one
+    pub fn keymap(mut self, keymap: Keymap) -> Self {
+        self.config.keymap = keymap;
+        self
+    }
+
+    /// Color the UI with `theme` instead of the configured one
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.config.theme = theme;
+        self
+    }
+
+    /// Draw on `terminal` instead of the standard output
+    pub fn terminal<U: TerminalAdapter + 'static>(self, terminal: U) -> AppBuilder<U> {
+        AppBuilder {
+            config: self.config,
+            extra_providers: self.extra_providers,
+            terminal: Box::new(move || Ok(terminal)),
+            source: self.source,
+            prefill: self.prefill,
+            watch: self.watch,
+            onboarding: self.onboarding,
+            restore_session: self.restore_session,
+            save_session: self.save_session,
+            remind_schedules: self.remind_schedules,
+            control: self.control,
+            scripts: self.scripts,
+            trace: self.trace,
+        }
+    }
+
+    /// Handle the events of `source` instead of the terminal's and the background services'
+    pub fn events(mut self, source: EventSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Fill the form on start, as the command line would
+    pub fn prefill(mut self, prefill: Prefill) -> Self {
+        self.prefill = prefill;
+        self
+    }
+
+    /// Start in watch mode
+    pub fn watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    /// Show the first run wizard on start
+    pub fn onboarding(mut self, onboarding: bool) -> Self {
+        self.onboarding = onboarding;
+        self
+    }
+
+    /// Offer to restore the form of the previous session, when not prefilled; on by default
+    pub fn restore_session(mut self, restore: bool) -> Self {
+        self.restore_session = restore;
+        self
+    }
+
+    /// Keep the form for the next session on exit; on by default
+    pub fn save_session(mut self, save: bool) -> Self {
+        self.save_session = save;
+        self
+    }
+
+    /// List the scheduled swaps come due on start, instead of offering to restore the session; on by default
+    pub fn remind_schedules(mut self, remind: bool) -> Self {
+        self.remind_schedules = remind;
+        self
+    }
+
+    /// Accept commands on a control socket at `path`, e.g. `set pair BTC ETH`
+    pub fn control_socket(mut self, path: impl Into<PathBuf>) -> Self {
+        self.control = Some(path.into());
+        self
+    }
+
+    /// Load the user scripts of `dir`, when built with the `scripting` feature
+    pub fn scripts(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.scripts = Some(dir.into());
+        self
+    }
+
+    /// Write the frames drawn and the state transitions to an asciinema trace at `path`
+    pub fn trace(mut self, path: impl Into<PathBuf>) -> Self {
+        self.trace = Some(path.into());
+        self
+    }
+
+    /// Set up the process from the config (metrics, HTTP agent, translation and glyphs), then create the terminal and
+    /// the application, ready to run
+    pub fn build(self) -> Result<Model<T>, Box<dyn Error>> {
+        // Set up what the process shares before touching the terminal, so errors are readable, whichever entry
+        // point started the application
+        metrics::start();
+        // A replay answers from the record, never from the network
+        let offline = self.config.offline || matches!(self.source, EventSource::Replay(_));
+        http::configure(self.config.proxy.as_deref(), offline)?;
+        i18n::init(&self.config.language)?;
+        glyphs::init(self.config.charset, self.config.nerd_font);
+
+        let terminal = (self.terminal)()?;
+        let mut model = Model::new(terminal, self.config, self.source);
+        if let Some(path) = &self.control {
+            model.listen_control(path)?;
+        }
+        if let Some(path) = &self.trace {
+            model.trace_to(path)?;
+        }
+        if let Some(dir) = &self.scripts {
+            model.load_scripts(dir);
+        }
+        if !self.extra_providers.is_empty() {
+            model.add_providers(self.extra_providers);
+        }
+        if self.onboarding {
+            model.prefill(self.prefill);
+            model.start_onboarding();
+        } else {
+            let restore = self.restore_session && self.prefill == Prefill::default();
+            model.prefill(self.prefill);
+            // Loading a swap due takes the place of the previous session
+            let reminded = self.remind_schedules && model.remind_schedules();
+            if restore && !reminded {
+                model.offer_session_restore();
+            }
+        }
+        if self.watch {
+            model.start_watch();
+        }
+        Ok(model)
+    }
+
+    /// Run the application until the user quits
+    pub fn run(self) -> Result<(), Box<dyn Error>> {
+        let save_session = self.save_session;
+        let mut model = self.build()?;
+        app::launch(&mut model, save_session)
+    }
+}
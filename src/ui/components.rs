@@ -2,9 +2,39 @@
 //!
 //! UI components
 
+pub mod amount_input;
 pub mod asset_table;
+pub mod confirm_popup;
+pub mod depth_view;
+pub mod draft_picker;
+pub mod dropdown;
+pub mod due_swaps;
+pub mod error_popup;
+pub mod fee_breakdown;
+pub mod footer;
 pub mod header;
 pub mod help_bar;
+pub mod history_table;
 pub mod instructions;
 pub mod instructions_bar;
+pub mod log_viewer;
+pub mod metrics_view;
+pub mod multi_select;
+pub mod onboarding_wizard;
+pub mod portfolio;
+pub mod prompt;
+pub mod price_chart;
+pub mod provider_detail;
+pub mod provider_stats;
+pub mod quotes_table;
+pub mod session_stats;
+pub mod settings_form;
+pub mod slider;
+pub mod status_bar;
 pub mod summary_bar;
+pub mod swap_plan;
+pub mod text_viewer;
+pub mod watch_address_input;
+pub mod watch_panel;
+pub mod watchlist;
+pub mod which_key;
@@ -0,0 +1,258 @@
+//! ## AmountInput
+//!
+//! Amount field for the FROM asset, showing the watched balance
+
+use tui_realm_stdlib::Input;
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::props::{InputType, PropPayload, PropValue, Style};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, State, StateValue};
+
+use crate::i18n::{self, tr, tr_args};
+use crate::models::amount::Amount;
+use crate::models::asset::AssetId;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Percentages cycled through with the `%` key
+const PERCENT_STEPS: [u8; 4] = [25, 50, 75, 100];
+
+/// Balance of the FROM asset as known by the component
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Balance {
+    /// No watch-only address registered for the asset's chain
+    Unknown,
+    /// Lookup in progress
+    Loading,
+    /// Balance fetched
+    Loaded(Amount),
+    /// Lookup failed
+    Failed(String),
+}
+
+/// AmountInput component used to enter the FROM amount
+pub struct AmountInput {
+    component: Input,
+    asset: Option<AssetId>,
+    balance: Balance,
+    percent_step: usize,
+    /// Amount Up and Down add and take away, ten times it with Shift
+    step: Option<Amount>,
+}
+
+impl Default for AmountInput {
+    fn default() -> Self {
+        Self {
+            component: Input::default()
+                .input_type(InputType::Number)
+                .placeholder("0.0", Style::default()),
+            asset: None,
+            balance: Balance::Unknown,
+            percent_step: 0,
+            step: None,
+        }
+    }
+}
+
+impl AmountInput {
+    /// Create a new AmountInput
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Title showing the asset and its available balance
+    fn get_title(&self) -> String {
+        let Some(asset) = &self.asset else {
+            return tr("amount-title");
+        };
+        let ticker = asset.label();
+        let balance = match &self.balance {
+            Balance::Unknown => tr("amount-balance-unknown"),
+            Balance::Loading => tr_args("amount-balance-loading", &[("ellipsis", glyphs::ellipsis().to_string())]),
+            Balance::Loaded(amount) => {
+                let args = [("amount", i18n::isolate(&amount.to_string())), ("asset", ticker.clone())];
+                tr_args("amount-balance", &args)
+            }
+            Balance::Failed(err) => tr_args("amount-balance-failed", &[("error", err.clone())]),
+        };
+        tr_args("amount-title-balance", &[("asset", ticker), ("balance", balance)])
+    }
+
+    /// Fill the field with a share of the balance
+    fn fill_percent(&mut self, pct: u8) {
+        if let Balance::Loaded(balance) = &self.balance {
+            self.component
+                .attr(Attribute::Value, AttrValue::String(balance.percent(pct).to_string()));
+            self.component.perform(Cmd::GoTo(Position::End));
+        }
+    }
+
+    /// Fill the field with the next percentage step
+    fn cycle_percent(&mut self) {
+        self.fill_percent(PERCENT_STEPS[self.percent_step]);
+        self.percent_step = (self.percent_step + 1) % PERCENT_STEPS.len();
+    }
+
+    /// Add `times` the step to the field, or take it away when negative, stopping at zero; unparsable content counts
+    /// as zero
+    fn step_by(&mut self, times: i32) {
+        let Some(step) = self.step else {
+            return;
+        };
+        let value = Amount::parse(&self.value(), step.decimals()).unwrap_or_default().raw();
+        let delta = step.raw().saturating_mul(times.unsigned_abs() as u128);
+        let value = if times < 0 { value.saturating_sub(delta) } else { value.saturating_add(delta) };
+        self.component
+            .attr(Attribute::Value, AttrValue::String(Amount::new(value, step.decimals()).to_string()));
+        self.component.perform(Cmd::GoTo(Position::End));
+    }
+
+    /// Current field content
+    fn value(&self) -> String {
+        match self.component.state() {
+            State::One(StateValue::String(value)) => value,
+            _ => String::new(),
+        }
+    }
+
+    /// Report the field content if it differs from `before`
+    fn edited(&self, before: String) -> Msg {
+        let value = self.value();
+        if value == before {
+            Msg::None
+        } else {
+            Msg::AmountEdited(value)
+        }
+    }
+}
+
+impl MockComponent for AmountInput {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let title = self.get_title();
+        let focus = self.component.query(Attribute::Focus) == Some(AttrValue::Flag(true));
+        theme::style_input(&mut self.component, if focus { Role::Negative } else { Role::Muted });
+        glyphs::view_input(&mut self.component, frame, area, title);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.component.query(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Custom("from_asset") => {
+                if let AttrValue::String(id) = value {
+                    self.asset = id.parse().ok();
+                    self.balance = Balance::Unknown;
+                    self.percent_step = 0;
+                }
+            }
+            Attribute::Custom("step") => {
+                self.step = match value {
+                    AttrValue::Payload(PropPayload::Tup2((PropValue::U128(raw), PropValue::U8(decimals)))) => {
+                        Some(Amount::new(raw, decimals))
+                    }
+                    _ => None,
+                };
+            }
+            Attribute::Custom("balance_loading") => {
+                self.balance = Balance::Loading;
+            }
+            _ => self.component.attr(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        self.component.state()
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        self.component.perform(cmd)
+    }
+}
+
+impl Component<Msg, UserEvent> for AmountInput {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('%'),
+                ..
+            }) => {
+                let before = self.value();
+                self.cycle_percent();
+                Some(self.edited(before))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('M'),
+                ..
+            }) => {
+                let before = self.value();
+                self.fill_percent(100);
+                Some(self.edited(before))
+            }
+            Event::Keyboard(KeyEvent {
+                code: code @ (Key::Up | Key::Down),
+                modifiers,
+            }) => {
+                let before = self.value();
+                let times = if modifiers.contains(KeyModifiers::SHIFT) { 10 } else { 1 };
+                self.step_by(if code == Key::Up { times } else { -times });
+                Some(self.edited(before))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            }) => {
+                let before = self.value();
+                self.perform(Cmd::Type(ch));
+                Some(self.edited(before))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                let before = self.value();
+                self.perform(Cmd::Delete);
+                Some(self.edited(before))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right,
+                ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter,
+                ..
+            }) => Some(Msg::AmountSubmitted(self.value())),
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::ExitAmountMode),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('z'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Undo),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Redo),
+            Event::User(UserEvent::BalanceLoaded(asset, amount)) if Some(&asset) == self.asset.as_ref() => {
+                self.balance = Balance::Loaded(amount);
+                Some(Msg::None)
+            }
+            Event::User(UserEvent::BalanceFailed(asset, err)) if Some(&asset) == self.asset.as_ref() => {
+                self.balance = Balance::Failed(err);
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
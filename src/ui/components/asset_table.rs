@@ -2,16 +2,25 @@
 //! 
 //! Asset table component for displaying asset prices
 
-use std::fmt;
-
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::event::{Key, KeyEvent, KeyModifiers, NoUserEvent};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
 use tuirealm::props::{Color, Style, TextModifiers};
 use tuirealm::ratatui::layout::Rect;
-use tuirealm::ratatui::widgets::{Block, BorderType as RBorderType, Cell, Row, Table, TableState};
+use tuirealm::ratatui::text::{Line, Span};
+use tuirealm::ratatui::widgets::{Block, Cell, Row, Table, TableState};
 use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
 
+use crate::i18n::{tr, tr_args};
+use crate::models::asset::Asset;
+use crate::models::chain::Chain;
+use crate::ui::components::dropdown::Choice;
+use crate::ui::components::slider::Setting;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::keymap::Keymap;
+use crate::ui::layout::table_window;
 use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
 
 /// Selection mode for the asset table
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -21,19 +30,6 @@ pub enum SelectionMode {
     ToAsset,   // Selecting TO asset
 }
 
-/// Asset data structure
-#[derive(Clone, Debug)]
-pub struct Asset {
-    pub name: String,
-    pub price: String,
-}
-
-impl fmt::Display for Asset {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}: {}", self.name, self.price)
-    }
-}
-
 /// Asset table component for displaying and selecting assets
 pub struct AssetTable {
     props: Props,
@@ -42,29 +38,34 @@ pub struct AssetTable {
     from_asset_index: Option<usize>, // FROM asset (red)
     to_asset_index: Option<usize>,   // TO asset (green)
     mode: SelectionMode,        // Current selection mode
+    keymap: Keymap,
+    /// Selection and scroll of the rendered table, kept across frames
+    state: TableState,
 }
 
-impl Default for AssetTable {
-    fn default() -> Self {
+impl AssetTable {
+    /// Create a new asset table listing the given assets
+    pub fn new(assets: Vec<Asset>, keymap: Keymap) -> Self {
         Self {
             props: Props::default(),
-            assets: vec![
-                Asset { name: "BTC".to_string(), price: "$100,000".to_string() },
-                Asset { name: "ETH".to_string(), price: "$2,400".to_string() },
-                Asset { name: "SOL".to_string(), price: "$145".to_string() },
-            ],
+            assets,
             current_index: 0,
             from_asset_index: None,
             to_asset_index: None,
             mode: SelectionMode::FromAsset, // Start in FROM selection mode
+            keymap,
+            state: TableState::default(),
         }
     }
-}
 
-impl AssetTable {
-    /// Create a new asset table
-    pub fn new() -> Self {
-        Self::default()
+    /// Color of a chain badge
+    fn badge_color(chain: Chain) -> Color {
+        match chain {
+            Chain::Bitcoin => Color::Rgb(247, 147, 26),
+            Chain::Ethereum => Color::Rgb(98, 126, 234),
+            Chain::Solana => Color::Rgb(153, 69, 255),
+            Chain::Arbitrum => Color::Rgb(40, 160, 240),
+        }
     }
 
     /// Move to the next asset
@@ -96,16 +97,12 @@ impl AssetTable {
     fn select_as_from_asset(&mut self) {
         // Only set FROM if it's not already the TO asset
         if Some(self.current_index) != self.to_asset_index {
-            let prev_from = self.from_asset_index;
             self.from_asset_index = Some(self.current_index);
             
             // Automatically switch to TO asset mode if TO hasn't been selected yet
             if self.to_asset_index.is_none() {
                 self.enter_to_mode();
             }
-            
-            // Return the selected asset name
-            return;
         }
     }
 
@@ -113,14 +110,10 @@ impl AssetTable {
     fn select_as_to_asset(&mut self) {
         // Only set TO if it's not already the FROM asset
         if Some(self.current_index) != self.from_asset_index {
-            let prev_to = self.to_asset_index;
             self.to_asset_index = Some(self.current_index);
             
             // After selecting TO asset, switch to amount mode
             self.exit_selection_mode();
-            
-            // Return the selected asset name
-            return;
         }
     }
 
@@ -152,55 +145,71 @@ impl MockComponent for AssetTable {
     fn view(&mut self, frame: &mut Frame, area: Rect) {
         // Check if visible
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            // Only the rows in view are built, the list may hold thousands of imported tokens; borders and header
+            // take 3 rows
+            self.state.select(Some(self.current_index));
+            let height = area.height.saturating_sub(3) as usize;
+            let (window, mut state) = table_window(&mut self.state, self.assets.len(), height);
+
             // Create table rows
-            let rows: Vec<Row> = self.assets
+            let rows: Vec<Row> = self.assets[window.clone()]
                 .iter()
                 .enumerate()
-                .map(|(i, asset)| {
+                .map(|(row, asset)| {
+                    let i = window.start + row;
                     let style = if Some(i) == self.from_asset_index {
                         // FROM asset - light red background
+                        let from = theme::style_of(&self.props, Role::From);
                         if i == self.current_index && self.mode == SelectionMode::FromAsset {
                             // Currently highlighted FROM asset
-                            Style::default().bg(Color::Rgb(255, 180, 180)).fg(Color::Black)
+                            from
                         } else {
-                            Style::default().bg(Color::Rgb(255, 200, 200))
+                            Style::default().bg(from.bg.unwrap_or(Color::Reset))
                         }
                     } else if Some(i) == self.to_asset_index {
                         // TO asset - light green background
+                        let to = theme::style_of(&self.props, Role::To);
                         if i == self.current_index && self.mode == SelectionMode::ToAsset {
                             // Currently highlighted TO asset
-                            Style::default().bg(Color::Rgb(180, 255, 180)).fg(Color::Black)
+                            to
                         } else {
-                            Style::default().bg(Color::Rgb(200, 255, 200))
+                            Style::default().bg(to.bg.unwrap_or(Color::Reset))
                         }
                     } else if i == self.current_index {
-                        // Highlighted row (not selected) - light yellow
-                        Style::default().bg(Color::Rgb(255, 255, 220)).fg(Color::Black)
+                        // Highlighted row (not selected) - light yellow, unless themed
+                        theme::highlight(&self.props, theme::style_of(&self.props, Role::Selection))
                     } else {
                         // Normal row
                         Style::default()
                     };
                     
-                    Row::new(vec![
-                        Cell::from(asset.name.clone()),
-                        Cell::from(asset.price.clone()),
+                    // Cells borrow the listed assets, nothing is copied per frame
+                    Row::new([
+                        Cell::from(match glyphs::icon(&asset.ticker) {
+                            Some(icon) => Line::from(vec![Span::raw(icon), Span::raw(" "), Span::raw(&asset.ticker)]),
+                            None => Line::from(asset.ticker.as_str()),
+                        }),
+                        Cell::from(asset.chain.badge())
+                            .style(Style::default().fg(Self::badge_color(asset.chain)).add_modifier(TextModifiers::BOLD)),
+                        Cell::from(asset.price.as_str()),
                     ])
                     .style(style)
                 })
                 .collect();
 
             // Create header row
-            let header_cells = ["Asset", "Price"]
-                .iter()
-                .map(|h| Cell::from(*h).style(
-                    Style::default()
-                        .fg(Color::White)
-                        .bg(Color::DarkGray)
-                        .add_modifier(TextModifiers::BOLD)
+            let price = match self.props.get(Attribute::Custom("fiat")) {
+                Some(AttrValue::String(fiat)) => tr_args("assets-price-in", &[("fiat", fiat.to_uppercase())]),
+                _ => tr("assets-price"),
+            };
+            let header_cells = [tr("assets-asset"), tr("assets-chain"), price]
+                .into_iter()
+                .map(|h| Cell::from(h).style(
+                    theme::style_of(&self.props, Role::Header)
                 ));
             
             let header = Row::new(header_cells)
-                .style(Style::default().bg(Color::DarkGray))
+                .style(theme::style_of(&self.props, Role::Header))
                 .height(1);
 
             // Create bordered block
@@ -208,34 +217,36 @@ impl MockComponent for AssetTable {
                 .get_or(Attribute::Focus, AttrValue::Flag(false))
                 .unwrap_flag();
             
-            let block_title = match self.mode {
-                SelectionMode::Normal => "Assets",
-                SelectionMode::FromAsset => "Select FROM Asset",
-                SelectionMode::ToAsset => "Select TO Asset",
-            };
+            let block_title = tr(match self.mode {
+                SelectionMode::Normal => "assets-title",
+                SelectionMode::FromAsset => "assets-title-from",
+                SelectionMode::ToAsset => "assets-title-to",
+            });
             
             let border_color = match self.mode {
-                SelectionMode::Normal => Color::White,
-                SelectionMode::FromAsset => Color::LightRed,
-                SelectionMode::ToAsset => Color::LightGreen,
+                SelectionMode::Normal => theme::color_of(&self.props, Role::Text),
+                SelectionMode::FromAsset => theme::color_of(&self.props, Role::Negative),
+                SelectionMode::ToAsset => theme::color_of(&self.props, Role::Positive),
             };
             
             let border_style = if focus {
                 Style::default().fg(border_color)
             } else {
-                Style::default().fg(Color::Gray)
+                theme::style_of(&self.props, Role::Muted)
             };
             
             let block = Block::default()
                 .borders(tuirealm::ratatui::widgets::Borders::ALL)
-                .border_type(RBorderType::Rounded)
+                .border_set(glyphs::border())
                 .border_style(border_style)
                 .title(block_title);
+            let block = theme::block(&self.props, block);
 
             // Create table with widths
             let widths = [
-                tuirealm::ratatui::layout::Constraint::Percentage(50),
-                tuirealm::ratatui::layout::Constraint::Percentage(50),
+                tuirealm::ratatui::layout::Constraint::Percentage(35),
+                tuirealm::ratatui::layout::Constraint::Percentage(25),
+                tuirealm::ratatui::layout::Constraint::Percentage(40),
             ];
             
             let table = Table::new(rows, widths)
@@ -243,11 +254,7 @@ impl MockComponent for AssetTable {
                 .block(block)
                 .row_highlight_style(Style::default().add_modifier(TextModifiers::BOLD));
 
-            // Create a mutable table state to track selection
-            let mut state = TableState::default();
-            state.select(Some(self.current_index));
-
-            // Render the table with selection
+            // Render the window with selection, scrolled as on the previous frame
             frame.render_stateful_widget(table, area, &mut state);
         }
     }
@@ -255,25 +262,60 @@ impl MockComponent for AssetTable {
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
         match attr {
             Attribute::Value => {
-                // Return currently highlighted asset name
-                if let Some(asset) = self.assets.get(self.current_index) {
-                    Some(AttrValue::String(asset.name.clone()))
-                } else {
-                    None
-                }
+                // Return currently highlighted asset id
+                self.assets
+                    .get(self.current_index)
+                    .map(|asset| AttrValue::String(asset.id().to_string()))
+            },
+            Attribute::Custom("mode") => {
+                // Return which asset Enter chooses
+                let mode = match self.mode {
+                    SelectionMode::Normal => "normal",
+                    SelectionMode::FromAsset => "from",
+                    SelectionMode::ToAsset => "to",
+                };
+                Some(AttrValue::String(mode.to_string()))
             },
             _ => self.props.get(attr),
         }
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
-        self.props.set(attr, value);
+        match attr {
+            // Rows chosen outside the table, e.g. from the command line
+            // A negative index clears the role, e.g. when undoing its choice
+            Attribute::Custom("from_index") => {
+                if let AttrValue::Number(index) = value {
+                    if index < 0 {
+                        self.from_asset_index = None;
+                        self.enter_from_mode();
+                    } else {
+                        self.current_index = (index as usize).min(self.assets.len().saturating_sub(1));
+                        self.select_as_from_asset();
+                    }
+                }
+            }
+            Attribute::Custom("to_index") => {
+                if let AttrValue::Number(index) = value {
+                    if index < 0 {
+                        self.to_asset_index = None;
+                        if self.from_asset_index.is_some() {
+                            self.enter_to_mode();
+                        }
+                    } else {
+                        self.current_index = (index as usize).min(self.assets.len().saturating_sub(1));
+                        self.select_as_to_asset();
+                    }
+                }
+            }
+            _ => self.props.set(attr, value),
+        }
     }
 
     fn state(&self) -> State {
-        // Return current asset name
+        // Return current asset id
         if let Some(asset) = self.assets.get(self.current_index) {
-            State::One(StateValue::String(asset.name.clone()))
+            State::One(StateValue::String(asset.id().to_string()))
         } else {
             State::None
         }
@@ -303,25 +345,139 @@ impl MockComponent for AssetTable {
     }
 }
 
-impl Component<Msg, NoUserEvent> for AssetTable {
-    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+impl Component<Msg, UserEvent> for AssetTable {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
-                code: Key::Char('f'),
+                code: Key::Char(c),
                 modifiers: KeyModifiers::NONE,
-            }) => {
-                // Always switch to FROM mode on 'f'
+            }) if c == self.keymap.from_asset => {
+                // Always switch to FROM mode
                 self.enter_from_mode();
                 Some(Msg::EnterFromAssetMode)
             },
             Event::Keyboard(KeyEvent {
-                code: Key::Char('t'),
+                code: Key::Char(c),
                 modifiers: KeyModifiers::NONE,
-            }) => {
-                // Always switch to TO mode on 't'
+            }) if c == self.keymap.to_asset => {
+                // Always switch to TO mode
                 self.enter_to_mode();
                 Some(Msg::EnterToAssetMode)
             },
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.amount => Some(Msg::EnterAmountMode),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.fee_speed => Some(Msg::OpenSlider(Setting::FeeSpeed)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.slippage => Some(Msg::OpenSlider(Setting::Slippage)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.history => Some(Msg::OpenHistory),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.save_draft => Some(Msg::OpenDraftNameInput),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.drafts => Some(Msg::OpenDrafts),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.settings => Some(Msg::OpenSettings),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.logs => Some(Msg::OpenLogs),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.verbosity => Some(Msg::RaiseLogLevel),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.metrics => Some(Msg::OpenMetrics),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.provider_stats => Some(Msg::OpenProviderStats),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.session_stats => Some(Msg::OpenSessionStats),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.portfolio => Some(Msg::OpenPortfolio),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.watchlist => Some(Msg::OpenWatchlist),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.price_chart => Some(Msg::OpenPriceChart),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.depth => Some(Msg::OpenDepth),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.watch => Some(Msg::ToggleWatch),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.limit => Some(Msg::OpenLimitInput),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.pin => Some(Msg::PinQuote),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.requote => Some(Msg::Requote),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.cancel => Some(Msg::CancelQuotes),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.dismiss => Some(Msg::DismissStatus),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('z'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Undo),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Redo),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.chain => {
+                // Pick among the chains the highlighted asset is listed on, if more than one
+                let asset = self.assets.get(self.current_index)?;
+                let listings = self.assets.iter().filter(|listed| listed.ticker == asset.ticker).count();
+                (listings > 1).then(|| Msg::OpenDropdown(Choice::Chain(asset.clone())))
+            },
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.watch_address => {
+                // Register a watch-only address for the highlighted asset's chain
+                self.assets
+                    .get(self.current_index)
+                    .map(|asset| Msg::OpenWatchAddressInput(asset.chain))
+            },
             Event::Keyboard(KeyEvent {
                 code: Key::Down,
                 modifiers: KeyModifiers::NONE,
@@ -349,19 +505,15 @@ impl Component<Msg, NoUserEvent> for AssetTable {
                 match self.mode {
                     SelectionMode::Normal | SelectionMode::FromAsset => {
                         self.select_as_from_asset();
-                        if let Some(asset) = self.assets.get(self.current_index) {
-                            Some(Msg::AssetChosenAsFrom(self.current_index, asset.name.clone()))
-                        } else {
-                            Some(Msg::AssetChosenAsFrom(self.current_index, String::new()))
-                        }
+                        self.assets
+                            .get(self.current_index)
+                            .map(|asset| Msg::AssetChosenAsFrom(self.current_index, asset.clone()))
                     },
                     SelectionMode::ToAsset => {
                         self.select_as_to_asset();
-                        if let Some(asset) = self.assets.get(self.current_index) {
-                            Some(Msg::AssetChosenAsTo(self.current_index, asset.name.clone()))
-                        } else {
-                            Some(Msg::AssetChosenAsTo(self.current_index, String::new()))
-                        }
+                        self.assets
+                            .get(self.current_index)
+                            .map(|asset| Msg::AssetChosenAsTo(self.current_index, asset.clone()))
                     },
                 }
             },
@@ -371,11 +523,9 @@ impl Component<Msg, NoUserEvent> for AssetTable {
             }) => {
                 // Tab always selects TO asset
                 self.select_as_to_asset();
-                if let Some(asset) = self.assets.get(self.current_index) {
-                    Some(Msg::AssetChosenAsTo(self.current_index, asset.name.clone()))
-                } else {
-                    Some(Msg::AssetChosenAsTo(self.current_index, String::new()))
-                }
+                self.assets
+                    .get(self.current_index)
+                    .map(|asset| Msg::AssetChosenAsTo(self.current_index, asset.clone()))
             },
             Event::Keyboard(KeyEvent {
                 code: Key::Esc,
@@ -391,10 +541,10 @@ impl Component<Msg, NoUserEvent> for AssetTable {
                 }
             },
             Event::Keyboard(KeyEvent {
-                code: Key::Char('q'),
+                code: Key::Char(c),
                 modifiers: KeyModifiers::NONE,
-            }) => {
-                // 'q' always quits the application
+            }) if c == self.keymap.quit => {
+                // Quit always quits the application
                 Some(Msg::AppClose)
             },
             _ => None,
@@ -0,0 +1,87 @@
+//! ## ConfirmPopup
+//!
+//! Yes/no question popup, mounted while awaiting an answer
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::tr;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// ConfirmPopup component
+pub struct ConfirmPopup {
+    props: Props,
+    question: String,
+    /// Sent when the user answers yes
+    yes: Msg,
+    /// Sent when the user answers no
+    no: Msg,
+}
+
+impl ConfirmPopup {
+    /// Create a new popup asking `question`
+    pub fn new(question: impl Into<String>, yes: Msg, no: Msg) -> Self {
+        Self {
+            props: Props::default(),
+            question: question.into(),
+            yes,
+            no,
+        }
+    }
+}
+
+impl MockComponent for ConfirmPopup {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(self.question.as_str())
+                .style(theme::style_of(&self.props, Role::Text))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_set(glyphs::border())
+                        .border_style(theme::style_of(&self.props, Role::Prompt))
+                        .title(tr("confirm-title")),
+                ),
+            area,
+        );
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for ConfirmPopup {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y' | 'Y') | Key::Enter,
+                ..
+            }) => Some(self.yes.clone()),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('n' | 'N') | Key::Esc,
+                ..
+            }) => Some(self.no.clone()),
+            _ => None,
+        }
+    }
+}
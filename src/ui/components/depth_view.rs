@@ -0,0 +1,162 @@
+//! ## DepthView
+//!
+//! Depth preview, showing how the rate of each on-chain aggregator degrades at multiples of the amount entered,
+//! mounted while open
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{self, tr, tr_args};
+use crate::models::amount::Amount;
+use crate::models::depth::{Depth, MULTIPLIERS};
+use crate::models::quote::QuoteRequest;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// DepthView component
+pub struct DepthView {
+    props: Props,
+    /// Request whose amount is multiplied
+    request: QuoteRequest,
+    /// Offers of each aggregator, `None` while being fetched
+    depth: Option<Vec<Depth>>,
+}
+
+impl DepthView {
+    /// Create a new depth preview of `request`, waiting for the offers of the aggregators
+    pub fn new(request: QuoteRequest) -> Self {
+        Self {
+            props: Props::default(),
+            request,
+            depth: None,
+        }
+    }
+
+    /// Title showing the pair and amount
+    fn get_title(&self) -> String {
+        let request = &self.request;
+        let args = [
+            ("amount", request.amount.to_string()),
+            ("from", request.from.label()),
+            ("arrow", glyphs::arrow().to_string()),
+            ("to", request.to.label()),
+        ];
+        tr_args("depth-title", &args)
+    }
+
+    /// Rows of an aggregator, one per multiple of the amount
+    fn rows(&self, depth: &Depth) -> Vec<Row<'static>> {
+        depth
+            .levels
+            .iter()
+            .map(|level| {
+                let amount = Amount::new(
+                    self.request.amount.raw().saturating_mul(level.multiplier as u128),
+                    self.request.amount.decimals(),
+                );
+                let amount = i18n::isolate(&amount.to_string());
+                let amount = format!("{}x {amount} {}", level.multiplier, self.request.from.ticker);
+                let Some(quote) = &level.quote else {
+                    return Row::new(vec![
+                        Cell::from(depth.provider.clone()),
+                        Cell::from(amount),
+                        Cell::from(tr("depth-no-quote")).style(theme::style_of(&self.props, Role::Muted)),
+                    ]);
+                };
+                let (impact, role) = match depth.impact(level) {
+                    Some(impact) if impact <= -1.0 => (format!("{impact:+.2}%"), Role::Negative),
+                    Some(impact) if impact < -0.1 => (format!("{impact:+.2}%"), Role::Warning),
+                    Some(impact) => (format!("{impact:+.2}%"), Role::Positive),
+                    None => ("-".to_string(), Role::Muted),
+                };
+                let out = quote.amount_out.to_string();
+                Row::new(vec![
+                    Cell::from(depth.provider.clone()),
+                    Cell::from(amount),
+                    Cell::from(format!("{} {}", i18n::isolate(&out), self.request.to.ticker)),
+                    Cell::from(format!("{:.6}", quote.rate())),
+                    Cell::from(impact).style(theme::style_of(&self.props, role)),
+                ])
+            })
+            .collect()
+    }
+
+    /// Table of `rows` under the column headers
+    fn table(&self, rows: Vec<Row<'static>>) -> Table<'static> {
+        let headers = ["depth-aggregator", "depth-amount", "depth-receive", "depth-rate", "depth-impact"];
+        let header = Row::new(headers.map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let widths = [
+            Constraint::Percentage(18),
+            Constraint::Percentage(22),
+            Constraint::Percentage(28),
+            Constraint::Percentage(17),
+            Constraint::Percentage(15),
+        ];
+        Table::new(rows, widths).header(header)
+    }
+}
+
+impl MockComponent for DepthView {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, Role::Border))
+            .title(self.get_title());
+        frame.render_widget(Clear, area);
+        let message = match &self.depth {
+            None => {
+                let multipliers: Vec<String> = MULTIPLIERS.iter().map(|multiplier| format!("{multiplier}x")).collect();
+                let args = [("multipliers", multipliers.join(", ")), ("ellipsis", glyphs::ellipsis().to_string())];
+                tr_args("depth-quoting", &args)
+            }
+            Some(depth) if depth.is_empty() => tr("depth-empty"),
+            Some(depth) => {
+                let rows: Vec<Row> = depth.iter().flat_map(|depth| self.rows(depth)).collect();
+                frame.render_widget(self.table(rows).block(block), area);
+                return;
+            }
+        };
+        let message = Paragraph::new(message).style(theme::style_of(&self.props, Role::Muted));
+        frame.render_widget(message.block(block), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for DepthView {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::User(UserEvent::DepthLoaded(key, depth)) if key == self.request.key() => {
+                self.depth = Some(depth);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::CloseDepth),
+            _ => None,
+        }
+    }
+}
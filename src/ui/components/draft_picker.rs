@@ -0,0 +1,168 @@
+//! ## DraftPicker
+//!
+//! Picker listing the saved drafts and their repetition, mounted while open
+
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table, TableState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::draft::Draft;
+use crate::models::schedule::{Frequency, Schedule};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// DraftPicker component
+pub struct DraftPicker {
+    props: Props,
+    drafts: Vec<(String, Draft)>,
+    /// Repetition of the drafts, by name
+    schedules: BTreeMap<String, Schedule>,
+    current_index: usize,
+    /// Selection and scroll of the rendered table, kept across frames
+    state: TableState,
+}
+
+impl DraftPicker {
+    /// Create a new picker listing `drafts` by name, with their `schedules`
+    pub fn new(drafts: BTreeMap<String, Draft>, schedules: BTreeMap<String, Schedule>) -> Self {
+        Self {
+            props: Props::default(),
+            drafts: drafts.into_iter().collect(),
+            schedules,
+            current_index: 0,
+            state: TableState::default(),
+        }
+    }
+
+    /// Repetition of the draft `name`, e.g. `weekly, next 2024-06-07`
+    fn repeats(&self, name: &str) -> Cell<'static> {
+        match self.schedules.get(name) {
+            Some(schedule) if schedule.is_due(Utc::now()) => {
+                let frequency = tr_args("frequency", &[("frequency", schedule.frequency.to_string())]);
+                let due = tr_args("drafts-due", &[("frequency", frequency)]);
+                Cell::from(due).style(theme::style_of(&self.props, Role::Warning))
+            }
+            Some(schedule) => {
+                let args = [
+                    ("frequency", tr_args("frequency", &[("frequency", schedule.frequency.to_string())])),
+                    ("date", schedule.next_at.format("%Y-%m-%d").to_string()),
+                ];
+                Cell::from(tr_args("drafts-next", &args))
+            }
+            None => Cell::from("-").style(theme::style_of(&self.props, Role::Muted)),
+        }
+    }
+
+    /// Frequency following the one of the draft `name`, none after the longest
+    fn next_frequency(&self, name: &str) -> Option<Frequency> {
+        match self.schedules.get(name).map(|schedule| schedule.frequency) {
+            None => Some(Frequency::ALL[0]),
+            Some(frequency) => {
+                let index = Frequency::ALL.iter().position(|other| *other == frequency)?;
+                Frequency::ALL.get(index + 1).copied()
+            }
+        }
+    }
+}
+
+impl MockComponent for DraftPicker {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row> = self
+            .drafts
+            .iter()
+            .map(|(name, draft)| Row::new([Cell::from(name.clone()), Cell::from(draft.summary()), self.repeats(name)]))
+            .collect();
+
+        let header = Row::new(["drafts-name", "drafts-swap", "drafts-repeats"].map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let title = tr(if self.drafts.is_empty() { "drafts-title-empty" } else { "drafts-title" });
+        let widths = [Constraint::Percentage(25), Constraint::Percentage(45), Constraint::Percentage(30)];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, Role::Border))
+                    .title(title),
+            )
+            .row_highlight_style(theme::style_of(&self.props, Role::Selection));
+
+        self.state.select((!self.drafts.is_empty()).then_some(self.current_index));
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        if self.drafts.is_empty() {
+            State::None
+        } else {
+            State::One(StateValue::Usize(self.current_index))
+        }
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for DraftPicker {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, .. }) = ev else {
+            return None;
+        };
+        let len = self.drafts.len();
+        match code {
+            Key::Down | Key::Char('j') if len > 0 => {
+                self.current_index = (self.current_index + 1) % len;
+                Some(Msg::None)
+            }
+            Key::Up | Key::Char('k') if len > 0 => {
+                self.current_index = (self.current_index + len - 1) % len;
+                Some(Msg::None)
+            }
+            Key::Enter => self
+                .drafts
+                .get(self.current_index)
+                .map(|(name, draft)| Msg::LoadDraft(name.clone(), draft.clone())),
+            Key::Char('r') if len > 0 => {
+                let (name, _) = &self.drafts[self.current_index];
+                let frequency = self.next_frequency(name);
+                match frequency {
+                    Some(frequency) => self.schedules.insert(name.clone(), Schedule::new(frequency, Utc::now())),
+                    None => self.schedules.remove(name),
+                };
+                Some(Msg::ScheduleDraft(name.clone(), frequency))
+            }
+            Key::Char('x') | Key::Delete if len > 0 => {
+                let (name, _) = self.drafts.remove(self.current_index);
+                self.schedules.remove(&name);
+                self.current_index = self.current_index.min(self.drafts.len().saturating_sub(1));
+                Some(Msg::DeleteDraft(name))
+            }
+            Key::Esc => Some(Msg::CloseDrafts),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! ## Dropdown
+//!
+//! Small list popup picking one of a few options, e.g. the chain of an asset listed on several or the fiat currency,
+//! jumping to the first option starting with the letters typed; it hands the index of the option back with what it
+//! was picking
+
+use std::time::{Duration, Instant};
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::models::asset::Asset;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::layout::popup_area;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Time without typing after which the letters typed start a new search
+const TYPE_AHEAD_RESET: Duration = Duration::from_secs(1);
+
+/// What a dropdown picks
+#[derive(Debug, Clone, PartialEq)]
+pub enum Choice {
+    /// Chain of the asset highlighted in the asset table, among those its ticker is listed on
+    Chain(Asset),
+    /// Fiat currency of the settings screen, among `FIAT_CURRENCIES`, from the one set
+    Fiat(String),
+}
+
+/// Dropdown component, mounted while open
+pub struct Dropdown {
+    props: Props,
+    choice: Choice,
+    title: String,
+    options: Vec<String>,
+    /// Option highlighted, and scroll of the rendered list
+    state: ListState,
+    /// Letters typed so far, matched against the start of the options
+    typed: String,
+    typed_at: Instant,
+}
+
+impl Dropdown {
+    /// Create a new Dropdown titled `title` picking one of `options` for `choice`, the first highlighted
+    pub fn new(choice: Choice, title: impl Into<String>, options: Vec<String>) -> Self {
+        Self {
+            props: Props::default(),
+            choice,
+            title: title.into(),
+            options,
+            state: ListState::default().with_selected(Some(0)),
+            typed: String::new(),
+            typed_at: Instant::now(),
+        }
+    }
+
+    /// Highlight option `index` instead of the first
+    pub fn selected(mut self, index: usize) -> Self {
+        self.state.select(Some(index.min(self.options.len().saturating_sub(1))));
+        self
+    }
+
+    /// Index of the option highlighted
+    fn index(&self) -> usize {
+        self.state.selected().unwrap_or_default()
+    }
+
+    /// Highlight the option `step` rows away, wrapping around
+    fn go_by(&mut self, step: isize) -> Option<Msg> {
+        let len = self.options.len().max(1) as isize;
+        self.state.select(Some((self.index() as isize + step).rem_euclid(len) as usize));
+        self.typed.clear();
+        Some(Msg::None)
+    }
+
+    /// Add `ch` to the letters typed, or start over after a pause, highlighting the first option starting with them
+    fn type_ahead(&mut self, ch: char) -> Option<Msg> {
+        if self.typed_at.elapsed() >= TYPE_AHEAD_RESET {
+            self.typed.clear();
+        }
+        self.typed_at = Instant::now();
+        self.typed.push(ch);
+        let typed = self.typed.to_lowercase();
+        if let Some(index) = self.options.iter().position(|option| option.to_lowercase().starts_with(&typed)) {
+            self.state.select(Some(index));
+        }
+        Some(Msg::None)
+    }
+}
+
+impl MockComponent for Dropdown {
+    /// Draw in the middle of `area`, as tall as the options
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let area = popup_area(area, 100, (self.options.len() as u16 + 2).min(area.height));
+        let title = if self.typed.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{} | {}{}", self.title, self.typed, glyphs::cursor())
+        };
+        let items: Vec<ListItem> = self.options.iter().map(|option| ListItem::new(option.as_str())).collect();
+        let list = List::new(items)
+            .style(theme::style_of(&self.props, Role::Text))
+            .highlight_style(theme::style_of(&self.props, Role::Selection))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, Role::Prompt))
+                    .title(title),
+            );
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut self.state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Usize(self.index()))
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for Dropdown {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, modifiers }) = ev else {
+            return None;
+        };
+        match code {
+            Key::Down => self.go_by(1),
+            Key::Up => self.go_by(-1),
+            Key::Home => self.go_by(-(self.index() as isize)),
+            Key::End => self.go_by(self.options.len() as isize - 1 - self.index() as isize),
+            Key::Backspace => {
+                self.typed.pop();
+                Some(Msg::None)
+            }
+            Key::Char(ch) if !modifiers.contains(KeyModifiers::CONTROL) => self.type_ahead(ch),
+            Key::Enter if !self.options.is_empty() => Some(Msg::DropdownSubmitted(self.choice.clone(), self.index())),
+            Key::Esc => Some(Msg::CloseDropdown),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! ## DueSwaps
+//!
+//! Panel shown on start, listing the scheduled swaps come due since the last launch, mounted until one is loaded or
+//! it is dismissed
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table, TableState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::draft::Draft;
+use crate::models::schedule::Schedule;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// DueSwaps component
+pub struct DueSwaps {
+    props: Props,
+    /// Drafts due, by name, with their schedule
+    due: Vec<(String, Draft, Schedule)>,
+    current_index: usize,
+    /// Selection and scroll of the rendered table, kept across frames
+    state: TableState,
+}
+
+impl DueSwaps {
+    /// Create a new panel listing the drafts `due`
+    pub fn new(due: Vec<(String, Draft, Schedule)>) -> Self {
+        Self {
+            props: Props::default(),
+            due,
+            current_index: 0,
+            state: TableState::default(),
+        }
+    }
+
+    /// Message loading the draft at `index`, if any
+    fn load(&self, index: usize) -> Option<Msg> {
+        self.due
+            .get(index)
+            .map(|(name, draft, _)| Msg::LoadDraft(name.clone(), draft.clone()))
+    }
+}
+
+impl MockComponent for DueSwaps {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row> = self
+            .due
+            .iter()
+            .enumerate()
+            .map(|(index, (name, draft, schedule))| {
+                Row::new([
+                    Cell::from(if index < 9 { (index + 1).to_string() } else { String::new() }),
+                    Cell::from(name.clone()),
+                    Cell::from(draft.summary()),
+                    Cell::from(tr_args(
+                        "due-since",
+                        &[
+                            ("frequency", tr_args("frequency", &[("frequency", schedule.frequency.to_string())])),
+                            ("date", schedule.next_at.format("%Y-%m-%d").to_string()),
+                        ],
+                    )),
+                ])
+            })
+            .collect();
+
+        let header = Row::new([String::new(), tr("due-draft"), tr("due-swap"), tr("due-due")].map(|h| {
+            Cell::from(h).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Percentage(25),
+            Constraint::Percentage(40),
+            Constraint::Percentage(35),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, Role::Border))
+                    .title(tr("due-title")),
+            )
+            .row_highlight_style(theme::style_of(&self.props, Role::Selection));
+
+        self.state.select(Some(self.current_index));
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Usize(self.current_index))
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for DueSwaps {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, .. }) = ev else {
+            return None;
+        };
+        let len = self.due.len();
+        match code {
+            Key::Down | Key::Char('j') if len > 0 => {
+                self.current_index = (self.current_index + 1) % len;
+                Some(Msg::None)
+            }
+            Key::Up | Key::Char('k') if len > 0 => {
+                self.current_index = (self.current_index + len - 1) % len;
+                Some(Msg::None)
+            }
+            Key::Enter => self.load(self.current_index),
+            Key::Char(c @ '1'..='9') => self.load(c as usize - '1' as usize),
+            Key::Esc => Some(Msg::CloseDueSwaps),
+            _ => None,
+        }
+    }
+}
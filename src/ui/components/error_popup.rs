@@ -0,0 +1,134 @@
+//! ## ErrorPopup
+//!
+//! Details of a provider failure, mounted while open
+
+use std::error::Error;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::props::{Style, TextModifiers};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::{Line, Span};
+use tuirealm::ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::errors::{NetworkError, SwapError};
+use crate::i18n::{self, tr};
+use crate::models::quote::QuoteFailure;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// ErrorPopup component
+pub struct ErrorPopup {
+    props: Props,
+    failure: QuoteFailure,
+}
+
+impl ErrorPopup {
+    /// Create a new popup detailing `failure`
+    pub fn new(failure: QuoteFailure) -> Self {
+        Self {
+            props: Props::default(),
+            failure,
+        }
+    }
+
+    /// Reason phrase of the common HTTP statuses
+    fn reason(status: u16) -> &'static str {
+        match status {
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            429 => "Too Many Requests",
+            500 => "Internal Server Error",
+            502 => "Bad Gateway",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            _ => "",
+        }
+    }
+
+    /// Labelled line of the details
+    fn field(label: String, value: String) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(format!("{label:<10}"), Style::default().add_modifier(TextModifiers::BOLD)),
+            Span::raw(i18n::isolate(&value)),
+        ])
+    }
+
+    /// The provider, the error and its causes, then what to do about it
+    fn details(&self) -> Vec<Line<'static>> {
+        let error = &self.failure.error;
+        let mut lines = vec![
+            Self::field(tr("error-provider"), self.failure.provider.clone()),
+            Self::field(tr("error-error"), error.to_string()),
+        ];
+        let mut source = error.source();
+        while let Some(cause) = source {
+            lines.push(Self::field(tr("error-caused-by"), cause.to_string()));
+            source = cause.source();
+        }
+        if let SwapError::Network(NetworkError::Status { status, body }) = error.root() {
+            let status = format!("{status} {}", Self::reason(*status)).trim_end().to_string();
+            lines.push(Self::field(tr("error-status"), status));
+            if !body.is_empty() {
+                lines.push(Self::field(tr("error-response"), body.clone()));
+            }
+        }
+        lines.push(Line::default());
+        lines.push(Line::styled(tr(error.advice_id()), theme::style_of(&self.props, Role::Warning)));
+        lines
+    }
+}
+
+impl MockComponent for ErrorPopup {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let title = tr(if self.failure.error.is_retryable() { "error-title-retry" } else { "error-title" });
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(self.details()).alignment(i18n::start()).wrap(Wrap { trim: false }).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, Role::Negative))
+                    .title(title),
+            ),
+            area,
+        );
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for ErrorPopup {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter,
+                modifiers: KeyModifiers::NONE,
+            }) if self.failure.error.is_retryable() => Some(Msg::RetryProvider(self.failure.provider.clone())),
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::CloseErrorDetail),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! ## FeeBreakdown
+//!
+//! Fee breakdown component showing what funding the deposit costs on-chain
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::widgets::{Block, Borders, Paragraph};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::amount::Amount;
+use crate::models::asset::AssetId;
+use crate::models::fee::{FeeRate, FeeSpeed, NetworkFee};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Network fee as known by the component
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Estimate {
+    /// No FROM asset selected yet
+    Unknown,
+    /// Estimation in progress
+    Loading,
+    /// Fee estimated, once per preset on EIP-1559 chains
+    Loaded(Vec<NetworkFee>),
+    /// Estimation failed
+    Failed(String),
+}
+
+/// FeeBreakdown component
+pub struct FeeBreakdown {
+    props: Props,
+    asset: Option<AssetId>,
+    amount: String,
+    estimate: Estimate,
+    speed: FeeSpeed,
+}
+
+impl Default for FeeBreakdown {
+    fn default() -> Self {
+        Self {
+            props: Props::default(),
+            asset: None,
+            amount: String::new(),
+            estimate: Estimate::Unknown,
+            speed: FeeSpeed::default(),
+        }
+    }
+}
+
+impl FeeBreakdown {
+    /// Create a new FeeBreakdown
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Unit the fee rate applies to
+    fn unit(rate: &FeeRate) -> &'static str {
+        match rate {
+            FeeRate::SatPerVbyte(_) => "vB",
+            FeeRate::Eip1559 { .. } => "gas",
+            FeeRate::LamportsPerSignature(_) => "sig",
+        }
+    }
+
+    /// Estimate of the selected preset, or the only one on chains without presets
+    fn selected_fee(&self) -> Option<&NetworkFee> {
+        let Estimate::Loaded(fees) = &self.estimate else {
+            return None;
+        };
+        fees.iter().find(|fee| fee.speed == Some(self.speed)).or_else(|| fees.first())
+    }
+
+    /// Lines describing the fee and the total cost of the swap
+    fn get_lines(&self) -> Vec<String> {
+        let Some(asset) = &self.asset else {
+            return vec![tr("fee-select-asset")];
+        };
+        let fees = match &self.estimate {
+            Estimate::Unknown => return vec![],
+            Estimate::Loading => {
+                return vec![tr_args("fee-estimating", &[("ellipsis", glyphs::ellipsis().to_string())])];
+            }
+            Estimate::Failed(err) => return vec![tr_args("fee-failed", &[("error", err.to_string())])],
+            Estimate::Loaded(fees) => fees,
+        };
+        let Some(fee) = self.selected_fee() else {
+            return vec![tr("fee-unavailable")];
+        };
+        let native = fee.chain.native_ticker();
+
+        let mut lines = vec![tr_args(
+            "fee-network",
+            &[
+                ("amount", fee.amount.to_string()),
+                ("native", native.to_string()),
+                ("rate", fee.rate.to_string()),
+                ("times", glyphs::times().to_string()),
+                ("units", fee.units.to_string()),
+                ("unit", Self::unit(&fee.rate).to_string()),
+            ],
+        )];
+
+        // Presets, the selected one in brackets
+        if fees.len() > 1 {
+            let presets: Vec<String> = fees
+                .iter()
+                .filter_map(|preset| {
+                    let speed = preset.speed?;
+                    Some(if speed == self.speed {
+                        format!("[{speed} {}]", preset.amount)
+                    } else {
+                        format!("{speed} {}", preset.amount)
+                    })
+                })
+                .collect();
+            let args = [("presets", presets.join(" | ")), ("native", native.to_string())];
+            lines.push(tr_args("fee-speeds", &args));
+        }
+
+        if asset.ticker != native {
+            // Token deposits pay the fee in the chain's native asset
+            if !self.amount.is_empty() {
+                let args = [
+                    ("amount", self.amount.clone()),
+                    ("asset", asset.label()),
+                    ("fee", fee.amount.to_string()),
+                    ("native", native.to_string()),
+                ];
+                lines.push(tr_args("fee-total-token", &args));
+            }
+        } else if let Some(amount) = Amount::parse(&self.amount, fee.amount.decimals()).filter(|a| a.raw() > 0) {
+            let total = Amount::new(amount.raw() + fee.amount.raw(), amount.decimals());
+            let share = fee.amount.to_f64() / amount.to_f64() * 100.0;
+            let args = [("total", total.to_string()), ("asset", asset.label()), ("share", format!("{share:.2}"))];
+            lines.push(tr_args("fee-total", &args));
+        }
+        lines
+    }
+}
+
+impl MockComponent for FeeBreakdown {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        // Check if visible
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let lines: Vec<Line> = self.get_lines().into_iter().map(Line::from).collect();
+
+            frame.render_widget(
+                Paragraph::new(lines)
+                    .style(theme::style_of(&self.props, Role::Text))
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_set(glyphs::border())
+                            .border_style(theme::style_of(&self.props, Role::Muted))
+                            .title(tr("fee-title")),
+                    ),
+                area,
+            );
+        }
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Custom("from_asset") => {
+                if let AttrValue::String(id) = value {
+                    self.asset = id.parse().ok();
+                    self.estimate = Estimate::Loading;
+                }
+            }
+            Attribute::Custom("fee_speed") => {
+                if let AttrValue::Number(index) = value {
+                    self.speed = FeeSpeed::ALL[index.clamp(0, 2) as usize];
+                }
+            }
+            Attribute::Custom("from_amount") => {
+                if let AttrValue::String(amount) = value {
+                    self.amount = amount;
+                }
+            }
+            _ => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        // Selected fee, e.g. `0.00021 ETH`
+        match self.selected_fee() {
+            Some(fee) => State::One(StateValue::String(format!("{} {}", fee.amount, fee.chain.native_ticker()))),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for FeeBreakdown {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::User(UserEvent::NetworkFeeLoaded(asset, fees)) if Some(&asset) == self.asset.as_ref() => {
+                self.estimate = Estimate::Loaded(fees);
+                Some(Msg::None)
+            }
+            Event::User(UserEvent::NetworkFeeFailed(asset, err)) if Some(&asset) == self.asset.as_ref() => {
+                self.estimate = Estimate::Failed(err);
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! ## Footer
+//!
+//! Footer showing the local time, how the network is reached and how many providers are asked for quotes
+
+use chrono::Local;
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::Alignment;
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::{Line, Span};
+use tuirealm::ratatui::widgets::Paragraph;
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{tr, tr_args};
+use crate::ui::event::UserEvent;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// How the network is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Network {
+    /// Directly
+    #[default]
+    Online,
+    /// Through the proxy set
+    Proxy,
+    /// Not at all
+    Offline,
+}
+
+/// Footer component, right-aligned; the `network` (`online`, `proxy` or `offline`) and `providers` count are set by
+/// the model, the clock is read on every tick
+#[derive(Default)]
+pub struct Footer {
+    props: Props,
+    network: Network,
+    providers: usize,
+    /// Time last drawn, redrawn once it changes
+    time: String,
+}
+
+impl Footer {
+    /// Create a new Footer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Local time, to the minute
+    fn now() -> String {
+        Local::now().format("%H:%M").to_string()
+    }
+}
+
+impl MockComponent for Footer {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        self.time = Self::now();
+        let (network, role) = match self.network {
+            Network::Online => (tr("footer-online"), Role::Positive),
+            Network::Proxy => (tr("footer-proxy"), Role::Accent),
+            Network::Offline => (tr("footer-offline"), Role::Warning),
+        };
+        let muted = theme::style_of(&self.props, Role::Muted);
+        let providers = tr_args("footer-providers", &[("count", self.providers.to_string())]);
+        let line = Line::from(vec![
+            Span::styled(format!("{} | ", self.time), muted),
+            Span::styled(network, theme::style_of(&self.props, role)),
+            Span::styled(format!(" | {providers} "), muted),
+        ]);
+        frame.render_widget(Paragraph::new(line).alignment(Alignment::Right), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (Attribute::Custom("network"), AttrValue::String(network)) => {
+                self.network = match network.as_str() {
+                    "offline" => Network::Offline,
+                    "proxy" => Network::Proxy,
+                    _ => Network::Online,
+                };
+            }
+            (Attribute::Custom("providers"), AttrValue::Length(count)) => self.providers = count,
+            (attr, value) => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for Footer {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            // Redraw once the minute turns
+            Event::Tick => (Self::now() != self.time).then_some(Msg::None),
+            _ => None,
+        }
+    }
+}
@@ -3,12 +3,13 @@
 //! Header component for the application
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::event::{Key, KeyEvent, KeyModifiers, NoUserEvent};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
 use tuirealm::props::{Alignment, Color, Style, TextModifiers};
 use tuirealm::ratatui::layout::Rect;
 use tuirealm::ratatui::widgets::Paragraph;
 use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
 
+use crate::ui::event::UserEvent;
 use crate::ui::msg::Msg;
 
 /// Header component that displays the application title
@@ -31,9 +32,21 @@ impl MockComponent for Header {
         // Check if visible
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             // Get properties
-            let title = "XOSwap TUI";
+            let mut title = match self.props.get(Attribute::Custom("profile")) {
+                Some(AttrValue::String(profile)) if !profile.is_empty() => format!("XOSwap TUI ({profile})"),
+                _ => "XOSwap TUI".to_string(),
+            };
+            // Log level, when not the default one
+            if let Some(AttrValue::String(level)) = self.props.get(Attribute::Custom("log_level")) {
+                if !level.is_empty() {
+                    title.push_str(&format!(" [log: {level}]"));
+                }
+            }
             let alignment = Alignment::Center;
-            let foreground = Color::Cyan;
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Cyan))
+                .unwrap_color();
             let background = Color::Reset;
             let modifiers = TextModifiers::BOLD;
 
@@ -68,8 +81,8 @@ impl MockComponent for Header {
     }
 }
 
-impl Component<Msg, NoUserEvent> for Header {
-    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+impl Component<Msg, UserEvent> for Header {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
         match ev {
             Event::Keyboard(KeyEvent {
                 code: Key::Char('q'),
@@ -79,6 +92,8 @@ impl Component<Msg, NoUserEvent> for Header {
                 code: Key::Esc,
                 modifiers: KeyModifiers::NONE,
             }) => Some(Msg::AppClose),
+            // Nothing changes but the size of the terminal, which only a redraw takes into account
+            Event::WindowResize(..) => Some(Msg::None),
             _ => None,
         }
     }
@@ -3,28 +3,46 @@
 //! Help bar component for the application
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::event::NoUserEvent;
 use tuirealm::props::{Alignment, Color, Style, TextModifiers};
 use tuirealm::ratatui::layout::Rect;
 use tuirealm::ratatui::widgets::Paragraph;
 use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
 
+use crate::i18n::{self, tr, tr_args};
+use crate::ui::event::UserEvent;
+use crate::ui::keymap::{Context, Keymap};
 use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
 
-/// HelpBar component that displays help information
+/// HelpBar component that displays the keys doing something where the keyboard is, set as the `context` attribute
 /// This is a visual-only component that doesn't handle any events
 #[derive(Default)]
 pub struct HelpBar {
     props: Props,
+    keymap: Keymap,
+    context: Context,
 }
 
 impl HelpBar {
-    /// Create a new HelpBar
-    pub fn new() -> Self {
+    /// Create a new HelpBar describing the given keys
+    pub fn new(keymap: &Keymap) -> Self {
         Self {
             props: Props::default(),
+            keymap: keymap.clone(),
+            context: Context::default(),
         }
     }
+
+    /// Hints of the keys bound in the current context
+    fn text(&self) -> String {
+        let hints: Vec<String> = self
+            .keymap
+            .hints(self.context)
+            .into_iter()
+            .map(|binding| tr_args("key-hint", &[("key", binding.key), ("action", tr(binding.action))]))
+            .collect();
+        hints.join(" | ")
+    }
 }
 
 impl MockComponent for HelpBar {
@@ -32,10 +50,9 @@ impl MockComponent for HelpBar {
         // Check if visible
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             // Get properties
-            let status_text =
-                "(q)uit | (f)rom asset | (t)o asset | to a(m)ount | receive (a)address";
+            let status_text = i18n::truncate(&self.text(), area.width as usize);
             let alignment = Alignment::Center;
-            let foreground = Color::Gray;
+            let foreground = theme::color_of(&self.props, Role::Muted);
             let background = Color::Reset;
             let modifiers = TextModifiers::empty();
 
@@ -58,7 +75,12 @@ impl MockComponent for HelpBar {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
-        self.props.set(attr, value);
+        match (attr, value) {
+            (Attribute::Custom("context"), AttrValue::String(context)) => {
+                self.context = context.parse().unwrap_or_default();
+            }
+            (attr, value) => self.props.set(attr, value),
+        }
     }
 
     fn state(&self) -> State {
@@ -70,8 +92,8 @@ impl MockComponent for HelpBar {
     }
 }
 
-impl Component<Msg, NoUserEvent> for HelpBar {
-    fn on(&mut self, _: Event<NoUserEvent>) -> Option<Msg> {
+impl Component<Msg, UserEvent> for HelpBar {
+    fn on(&mut self, _: Event<UserEvent>) -> Option<Msg> {
         // This component doesn't react to events
         None
     }
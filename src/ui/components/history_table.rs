@@ -0,0 +1,211 @@
+//! ## HistoryTable
+//!
+//! History screen listing past quotes and swaps, mounted while open
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table, TableState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::i18n::{self, tr, tr_args};
+use crate::models::history::HistoryEntry;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::layout::table_window;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// HistoryTable component
+pub struct HistoryTable {
+    props: Props,
+    entries: Vec<HistoryEntry>,
+    /// Date, pair and status of each entry, formatted once
+    labels: Vec<[String; 3]>,
+    /// Indices of the entries matching the filter
+    visible: Vec<usize>,
+    current_index: usize,
+    filter: String,
+    /// Whether typed characters go to the filter
+    filtering: bool,
+    /// Selection and scroll of the rendered table, kept across frames
+    state: TableState,
+}
+
+impl HistoryTable {
+    /// Create a new history table listing `entries`, newest first
+    pub fn new(entries: Vec<HistoryEntry>) -> Self {
+        let labels = entries
+            .iter()
+            .map(|entry| {
+                [
+                    entry.date.format("%Y-%m-%d %H:%M").to_string(),
+                    format!("{} -> {}", entry.from.label(), entry.to.label()),
+                    entry.status.to_string(),
+                ]
+            })
+            .collect();
+        Self {
+            props: Props::default(),
+            visible: (0..entries.len()).collect(),
+            entries,
+            labels,
+            current_index: 0,
+            filter: String::new(),
+            filtering: false,
+            state: TableState::default(),
+        }
+    }
+
+    /// Apply the filter once edited, back to the first entry
+    fn refilter(&mut self) {
+        self.visible = (0..self.entries.len()).filter(|&i| self.entries[i].matches(&self.filter)).collect();
+        self.current_index = 0;
+    }
+
+    /// Move to the next entry
+    fn next_entry(&mut self) {
+        let len = self.visible.len();
+        if len > 0 {
+            self.current_index = (self.current_index + 1) % len;
+        }
+    }
+
+    /// Move to the previous entry
+    fn prev_entry(&mut self) {
+        let len = self.visible.len();
+        if len > 0 {
+            self.current_index = (self.current_index + len - 1) % len;
+        }
+    }
+
+    /// Title showing the filter and the keys
+    fn get_title(&self) -> String {
+        if self.filtering {
+            let args = [("filter", self.filter.clone()), ("cursor", glyphs::cursor().to_string())];
+            tr_args("history-title-filtering", &args)
+        } else if !self.filter.is_empty() {
+            tr_args("history-title-filtered", &[("filter", self.filter.clone())])
+        } else {
+            tr("history-title")
+        }
+    }
+}
+
+impl MockComponent for HistoryTable {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        // Only the rows in view are built, the history grows with every request; borders and header take 3 rows
+        self.state.select((!self.visible.is_empty()).then_some(self.current_index));
+        let height = area.height.saturating_sub(3) as usize;
+        let (window, mut state) = table_window(&mut self.state, self.visible.len(), height);
+        let rows: Vec<Row> = self.visible[window]
+            .iter()
+            .map(|&i| {
+                let (entry, [date, pair, status]) = (&self.entries[i], &self.labels[i]);
+                Row::new([
+                    Cell::from(date.as_str()),
+                    Cell::from(pair.as_str()),
+                    Cell::from(i18n::isolate(&entry.amount)),
+                    Cell::from(entry.provider.as_str()),
+                    Cell::from(status.as_str()),
+                ])
+            })
+            .collect();
+
+        let headers = ["history-date", "history-pair", "history-amount", "history-provider", "history-status"];
+        let header = Row::new(headers.map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let widths = [
+            Constraint::Percentage(22),
+            Constraint::Percentage(30),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, Role::Border))
+                    .title(self.get_title()),
+            )
+            .row_highlight_style(theme::style_of(&self.props, Role::Selection));
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(table, area, &mut state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        if self.visible.is_empty() {
+            State::None
+        } else {
+            State::One(StateValue::Usize(self.current_index))
+        }
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for HistoryTable {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, modifiers }) = ev else {
+            return None;
+        };
+
+        // Typing a filter
+        if self.filtering {
+            match code {
+                Key::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => self.filter.push(c),
+                Key::Backspace => {
+                    self.filter.pop();
+                }
+                Key::Enter | Key::Esc => self.filtering = false,
+                _ => return None,
+            }
+            self.refilter();
+            return Some(Msg::None);
+        }
+
+        match code {
+            Key::Char('/') => {
+                self.filtering = true;
+                Some(Msg::None)
+            }
+            Key::Down | Key::Char('j') => {
+                self.next_entry();
+                Some(Msg::None)
+            }
+            Key::Up | Key::Char('k') => {
+                self.prev_entry();
+                Some(Msg::None)
+            }
+            Key::Enter => self
+                .visible
+                .get(self.current_index)
+                .map(|&i| Msg::ReopenHistoryEntry(self.entries[i].clone())),
+            Key::Esc if !self.filter.is_empty() => {
+                self.filter.clear();
+                self.refilter();
+                Some(Msg::None)
+            }
+            Key::Esc => Some(Msg::CloseHistory),
+            _ => None,
+        }
+    }
+}
@@ -3,46 +3,36 @@
 //! Dynamic instructions component that updates based on app state
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::event::NoUserEvent;
-use tuirealm::props::{Alignment, Color, Style, TextModifiers};
+use tuirealm::props::{Color, Style, TextModifiers};
 use tuirealm::ratatui::layout::Rect;
 use tuirealm::ratatui::widgets::Paragraph;
 use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
 
+use crate::i18n::{self, tr, tr_args};
+use crate::ui::event::UserEvent;
 use crate::ui::msg::Msg;
 
 /// Instructions state for the component
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum InstructionsState {
     /// Need to select FROM asset
+    #[default]
     SelectFromAsset,
     /// Need to select TO asset
     SelectToAsset,
     /// Need to select FROM amount
     SelectFromAmount,
-}
-
-impl Default for InstructionsState {
-    fn default() -> Self {
-        Self::SelectFromAsset
-    }
+    /// Need to pick one of the quotes
+    CompareQuotes,
 }
 
 /// Instructions component that provides contextual guidance
+#[derive(Default)]
 pub struct Instructions {
     props: Props,
     state: InstructionsState,
 }
 
-impl Default for Instructions {
-    fn default() -> Self {
-        Self {
-            props: Props::default(),
-            state: InstructionsState::default(),
-        }
-    }
-}
-
 impl Instructions {
     /// Create a new Instructions component
     pub fn new() -> Self {
@@ -56,11 +46,12 @@ impl Instructions {
     
     /// Get instruction text based on current state
     fn get_instruction_text(&self) -> String {
-        match self.state {
-            InstructionsState::SelectFromAsset => "Select FROM asset".to_string(),
-            InstructionsState::SelectToAsset => "Select TO asset".to_string(),
-            InstructionsState::SelectFromAmount => "Set FROM amount".to_string(),
-        }
+        tr(match self.state {
+            InstructionsState::SelectFromAsset => "instructions-select-from",
+            InstructionsState::SelectToAsset => "instructions-select-to",
+            InstructionsState::SelectFromAmount => "instructions-set-amount",
+            InstructionsState::CompareQuotes => "instructions-compare",
+        })
     }
 }
 
@@ -70,13 +61,16 @@ impl MockComponent for Instructions {
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             // Get properties
             let instruction_text = self.get_instruction_text();
-            let alignment = Alignment::Left;
-            let foreground = Color::Green;
+            let alignment = i18n::start();
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Green))
+                .unwrap_color();
             let background = Color::Reset;
             let modifiers = TextModifiers::BOLD;
 
             frame.render_widget(
-                Paragraph::new(format!("Instructions: {}", instruction_text))
+                Paragraph::new(tr_args("instructions", &[("step", instruction_text)]))
                     .style(
                         Style::default()
                             .fg(foreground)
@@ -95,12 +89,13 @@ impl MockComponent for Instructions {
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
         match attr {
-            Attribute::Custom(state_str) if state_str == "state" || state_str == "state" => {
+            Attribute::Custom("state") => {
                 if let AttrValue::Number(state_num) = value {
                     match state_num {
                         0 => self.set_state(InstructionsState::SelectFromAsset),
                         1 => self.set_state(InstructionsState::SelectToAsset),
                         2 => self.set_state(InstructionsState::SelectFromAmount),
+                        3 => self.set_state(InstructionsState::CompareQuotes),
                         _ => {}
                     }
                 }
@@ -118,8 +113,8 @@ impl MockComponent for Instructions {
     }
 }
 
-impl Component<Msg, NoUserEvent> for Instructions {
-    fn on(&mut self, _: Event<NoUserEvent>) -> Option<Msg> {
+impl Component<Msg, UserEvent> for Instructions {
+    fn on(&mut self, _: Event<UserEvent>) -> Option<Msg> {
         // This component doesn't react to events
         None
     }
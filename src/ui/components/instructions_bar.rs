@@ -3,12 +3,15 @@
 //! Instructions bar component for the application
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::event::NoUserEvent;
 use tuirealm::props::{Alignment, Color, Style, TextModifiers};
 use tuirealm::ratatui::layout::Rect;
 use tuirealm::ratatui::widgets::Paragraph;
 use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
 
+use crate::i18n::tr_args;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::keymap::Keymap;
 use crate::ui::msg::Msg;
 
 /// InstructionsBar component that displays user instructions
@@ -16,13 +19,24 @@ use crate::ui::msg::Msg;
 #[derive(Default)]
 pub struct InstructionsBar {
     props: Props,
+    text: String,
 }
 
 impl InstructionsBar {
-    /// Create a new InstructionsBar
-    pub fn new() -> Self {
+    /// Create a new InstructionsBar describing the given keys
+    pub fn new(keymap: &Keymap) -> Self {
+        let text = tr_args(
+            "instructions-bar",
+            &[
+                ("navigate", glyphs::up_down().to_string()),
+                ("from", keymap.from_asset.to_string()),
+                ("to", keymap.to_asset.to_string()),
+                ("quit", keymap.quit.to_string()),
+            ],
+        );
         Self {
             props: Props::default(),
+            text,
         }
     }
 }
@@ -32,9 +46,12 @@ impl MockComponent for InstructionsBar {
         // Check if visible
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             // Get properties
-            let instructions_text = "(↑/↓) Navigate | (Enter) Select | (f) FROM mode | (t) TO mode | (q) Quit";
+            let instructions_text = self.text.as_str();
             let alignment = Alignment::Center;
-            let foreground = Color::Yellow;
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Yellow))
+                .unwrap_color();
             let background = Color::Reset;
             let modifiers = TextModifiers::BOLD;
 
@@ -69,8 +86,8 @@ impl MockComponent for InstructionsBar {
     }
 }
 
-impl Component<Msg, NoUserEvent> for InstructionsBar {
-    fn on(&mut self, _: Event<NoUserEvent>) -> Option<Msg> {
+impl Component<Msg, UserEvent> for InstructionsBar {
+    fn on(&mut self, _: Event<UserEvent>) -> Option<Msg> {
         // This component doesn't react to events
         None
     }
@@ -0,0 +1,190 @@
+//! ## LogViewer
+//!
+//! Log screen tailing the application log, mounted while open
+
+use std::path::PathBuf;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::props::{Color, Style};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::widgets::{Block, Borders, Clear};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{tr, tr_args};
+use crate::logging;
+use crate::ui::components::text_viewer::TextView;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Levels, most severe first
+const LEVELS: [&str; 5] = ["ERROR", "WARN", "INFO", "DEBUG", "TRACE"];
+
+/// Bytes read from the end of the log file
+const TAIL_BYTES: u64 = 256 * 1024;
+
+/// A log line with its level, by index in `LEVELS`
+struct LogLine {
+    level: usize,
+    text: String,
+}
+
+/// LogViewer component
+pub struct LogViewer {
+    props: Props,
+    path: Option<PathBuf>,
+    lines: Vec<LogLine>,
+    /// Least severe level shown, by index in `LEVELS`
+    level: usize,
+    /// Scroll and search of the lines shown, following new lines from the end
+    view: TextView,
+}
+
+impl LogViewer {
+    /// Create a new log screen showing the most recent log file, down to the current log level
+    pub fn new() -> Self {
+        let mut viewer = Self {
+            props: Props::default(),
+            path: logging::latest_file(),
+            lines: vec![],
+            level: Self::level_index(&logging::level().to_string()),
+            view: TextView::new().following(),
+        };
+        viewer.reload();
+        viewer
+    }
+
+    /// Index in `LEVELS` of a level name, the most verbose if unknown
+    fn level_index(level: &str) -> usize {
+        LEVELS
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(level))
+            .unwrap_or(LEVELS.len() - 1)
+    }
+
+    /// Read the end of the log file again; true if it changed
+    fn reload(&mut self) -> bool {
+        let Some(lines) = self.path.as_deref().and_then(|path| logging::tail(path, TAIL_BYTES).ok()) else {
+            return false;
+        };
+        if lines.len() == self.lines.len() && lines.last() == self.lines.last().map(|line| &line.text) {
+            return false;
+        }
+        // Lines without a level continue the previous one
+        let mut level = 2;
+        self.lines = lines
+            .into_iter()
+            .map(|text| {
+                if let Some(index) = text.split_whitespace().nth(1).and_then(|l| LEVELS.iter().position(|x| *x == l)) {
+                    level = index;
+                }
+                LogLine { level, text }
+            })
+            .collect();
+        true
+    }
+
+    /// Lines at or above the level
+    fn visible(&self) -> Vec<&LogLine> {
+        self.lines.iter().filter(|line| line.level <= self.level).collect()
+    }
+
+    /// Color of a level
+    fn color(&self, level: usize) -> Color {
+        match level {
+            0 => theme::color_of(&self.props, Role::Error),
+            1 => theme::color_of(&self.props, Role::Warning),
+            2 => theme::color_of(&self.props, Role::Text),
+            _ => theme::color_of(&self.props, Role::Muted),
+        }
+    }
+
+    /// Title showing the level, the search and the keys
+    fn get_title(&self) -> String {
+        let level = tr_args("logs-level", &[("level", LEVELS[self.level].to_string())]);
+        let follow = if self.view.is_following() { tr("logs-following") } else { String::new() };
+        match self.view.status() {
+            Some(search) => tr_args("logs-title-search", &[("level", level), ("follow", follow), ("search", search)]),
+            None => tr_args("logs-title", &[("level", level), ("follow", follow)]),
+        }
+    }
+}
+
+impl Default for LogViewer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockComponent for LogViewer {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line<'static>> = match &self.path {
+            None => vec![Line::from(tr("logs-empty"))],
+            Some(_) => self
+                .visible()
+                .into_iter()
+                .map(|line| Line::styled(line.text.clone(), Style::default().fg(self.color(line.level))))
+                .collect(),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, Role::Border))
+            .title(self.get_title());
+
+        frame.render_widget(Clear, area);
+        self.view.render(frame, area, lines, block, &self.props);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            // Show what is logged from now on
+            Attribute::Custom("log_level") => {
+                if let AttrValue::String(level) = value {
+                    self.level = Self::level_index(&level);
+                }
+            }
+            _ => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for LogViewer {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, modifiers }) = ev else {
+            // Follow the file while open
+            return (ev == Event::Tick && self.reload()).then_some(Msg::None);
+        };
+
+        if self.view.on_key(code, modifiers) {
+            return Some(Msg::None);
+        }
+        if self.view.is_searching() {
+            return None;
+        }
+        match code {
+            Key::Char('v') => return Some(Msg::RaiseLogLevel),
+            Key::Char('c') => return Some(Msg::OpenCrashReport),
+            Key::Tab => self.level = (self.level + 1) % LEVELS.len(),
+            Key::BackTab => self.level = (self.level + LEVELS.len() - 1) % LEVELS.len(),
+            Key::Esc => return Some(Msg::CloseLogs),
+            _ => return None,
+        }
+        Some(Msg::None)
+    }
+}
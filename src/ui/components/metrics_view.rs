@@ -0,0 +1,151 @@
+//! ## MetricsView
+//!
+//! Metrics screen summarizing the requests of the session, mounted while open
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{tr, tr_args};
+use crate::services::metrics::{self, Metrics};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// MetricsView component
+pub struct MetricsView {
+    props: Props,
+    metrics: Metrics,
+    /// Time each provider has to quote, by name
+    timeouts: BTreeMap<String, Duration>,
+}
+
+impl MetricsView {
+    /// Create a new metrics screen, showing the timeouts of the providers next to their requests
+    pub fn new(timeouts: BTreeMap<String, Duration>) -> Self {
+        Self {
+            props: Props::default(),
+            metrics: metrics::snapshot(),
+            timeouts,
+        }
+    }
+
+    /// Title showing the session length and the cache hit rate
+    fn get_title(&self) -> String {
+        let elapsed = self.metrics.started_at.elapsed().as_secs();
+        let cache = match self.metrics.cache_hit_rate() {
+            Some(rate) => format!(
+                "{rate:.0}% ({}/{})",
+                self.metrics.cache_hits,
+                self.metrics.cache_hits + self.metrics.cache_misses
+            ),
+            None => tr("metrics-not-available"),
+        };
+        let args = [
+            ("elapsed", format!("{}m{:02}s", elapsed / 60, elapsed % 60)),
+            ("cache", cache),
+        ];
+        tr_args("metrics-title", &args)
+    }
+}
+
+impl MockComponent for MetricsView {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let mut rows: Vec<Row> = self
+            .metrics
+            .services
+            .iter()
+            .map(|(name, stats)| {
+                let p95 = match stats.percentile_ms(95.0) {
+                    Some(ms) => format!("{}{ms}ms", glyphs::at_most()),
+                    None => format!(">{}ms", metrics::LATENCY_BUCKETS_MS[metrics::LATENCY_BUCKETS_MS.len() - 1]),
+                };
+                // Only the providers give up after some time
+                let timeout = self.timeouts.get(name).map(|timeout| format!("{}s", timeout.as_secs())).unwrap_or_default();
+                Row::new(vec![
+                    Cell::from(name.clone()),
+                    Cell::from(stats.requests.to_string()),
+                    Cell::from(stats.errors.to_string()),
+                    Cell::from(format!("{:.0}%", stats.error_rate())),
+                    Cell::from(format!("{}ms", stats.average_ms())),
+                    Cell::from(p95),
+                    Cell::from(timeout),
+                ])
+            })
+            .collect();
+        if rows.is_empty() {
+            rows.push(Row::new(vec![Cell::from(tr("metrics-empty"))]));
+        }
+
+        let headers = [
+            "metrics-service",
+            "metrics-requests",
+            "metrics-errors",
+            "metrics-error-rate",
+            "metrics-latency",
+            "metrics-p95",
+            "metrics-timeout",
+        ];
+        let header = Row::new(headers.map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let widths = [
+            Constraint::Percentage(28),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+        ];
+        let table = Table::new(rows, widths).header(header).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_set(glyphs::border())
+                .border_style(theme::style_of(&self.props, Role::Border))
+                .title(self.get_title()),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(table, area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for MetricsView {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            // Keep counting while open
+            Event::Tick => {
+                self.metrics = metrics::snapshot();
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::CloseMetrics),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! ## MultiSelect
+//!
+//! Checklist popup ticking any of a few options, e.g. the providers asked for quotes or the columns of an export,
+//! handing the indexes of those ticked back with what they were for; the model checks them and sets the `error`
+//! attribute when it turns them down
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::models::quote::Quote;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::layout::popup_area;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// What a checklist ticks
+#[derive(Debug, Clone, PartialEq)]
+pub enum Checklist {
+    /// Providers asked for quotes in the settings screen, from those enabled there
+    Providers(Vec<String>),
+    /// Columns of the quotes exported, before the file is asked for
+    ExportColumns(Vec<Quote>),
+}
+
+/// MultiSelect component, mounted while open
+pub struct MultiSelect {
+    props: Props,
+    checklist: Checklist,
+    title: String,
+    /// Options, and whether each is ticked
+    options: Vec<(String, bool)>,
+    /// Option highlighted, and scroll of the rendered list
+    state: ListState,
+    /// Why the last options submitted were turned down, shown as the title until they change
+    error: Option<String>,
+}
+
+impl MultiSelect {
+    /// Create a new MultiSelect titled `title` over `options`, each ticked or not, for `checklist`
+    pub fn new(checklist: Checklist, title: impl Into<String>, options: Vec<(String, bool)>) -> Self {
+        Self {
+            props: Props::default(),
+            checklist,
+            title: title.into(),
+            options,
+            state: ListState::default().with_selected(Some(0)),
+            error: None,
+        }
+    }
+
+    /// Index of the option highlighted
+    fn index(&self) -> usize {
+        self.state.selected().unwrap_or_default()
+    }
+
+    /// Indexes of the options ticked
+    fn ticked(&self) -> Vec<usize> {
+        self.options.iter().enumerate().filter(|(_, (_, ticked))| *ticked).map(|(index, _)| index).collect()
+    }
+
+    /// Highlight the option `step` rows away, wrapping around
+    fn go_by(&mut self, step: isize) -> Option<Msg> {
+        let len = self.options.len().max(1) as isize;
+        self.state.select(Some((self.index() as isize + step).rem_euclid(len) as usize));
+        Some(Msg::None)
+    }
+
+    /// Tick or untick the option highlighted
+    fn toggle(&mut self) -> Option<Msg> {
+        let index = self.index();
+        let (_, ticked) = self.options.get_mut(index)?;
+        *ticked = !*ticked;
+        self.error = None;
+        Some(Msg::None)
+    }
+
+    /// Tick every option, or untick them all when they all are
+    fn toggle_all(&mut self) -> Option<Msg> {
+        let tick = !self.options.iter().all(|(_, ticked)| *ticked);
+        self.options.iter_mut().for_each(|(_, ticked)| *ticked = tick);
+        self.error = None;
+        Some(Msg::None)
+    }
+}
+
+impl MockComponent for MultiSelect {
+    /// Draw in the middle of `area`, as tall as the options
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let area = popup_area(area, 100, (self.options.len() as u16 + 2).min(area.height));
+        let title = self.error.clone().unwrap_or_else(|| self.title.clone());
+        let role = if self.error.is_some() { Role::Negative } else { Role::Prompt };
+        let items: Vec<ListItem> = self
+            .options
+            .iter()
+            .map(|(option, ticked)| {
+                let check = if *ticked { glyphs::check() } else { " " };
+                ListItem::new(format!("[{check}] {option}"))
+            })
+            .collect();
+        let list = List::new(items)
+            .style(theme::style_of(&self.props, Role::Text))
+            .highlight_style(theme::style_of(&self.props, Role::Selection))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, role))
+                    .title(title),
+            );
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut self.state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (Attribute::Custom("error"), AttrValue::String(err)) => self.error = Some(err),
+            (attr, value) => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Vec(self.ticked().into_iter().map(StateValue::Usize).collect())
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for MultiSelect {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, .. }) = ev else {
+            return None;
+        };
+        match code {
+            Key::Down | Key::Char('j') => self.go_by(1),
+            Key::Up | Key::Char('k') => self.go_by(-1),
+            Key::Char(' ') => self.toggle(),
+            Key::Char('a') => self.toggle_all(),
+            Key::Enter => Some(Msg::MultiSelectSubmitted(self.checklist.clone(), self.ticked())),
+            Key::Esc => Some(Msg::CloseMultiSelect),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! ## OnboardingWizard
+//!
+//! First-run wizard choosing the fiat currency, providers, API keys and theme
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::config::{Config, FIAT_CURRENCIES};
+use crate::i18n::{tr, tr_args};
+use crate::services::providers::NAMES;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role, PRESETS};
+
+/// Step of the wizard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Fiat,
+    Providers,
+    /// API key of the n-th enabled provider
+    ApiKey(usize),
+    Theme,
+}
+
+/// OnboardingWizard component, mounted on first run
+pub struct OnboardingWizard {
+    props: Props,
+    config: Config,
+    step: Step,
+    /// Highlighted line of list steps
+    cursor: usize,
+    /// API key being typed
+    key: String,
+    /// Last save error
+    error: Option<String>,
+}
+
+impl OnboardingWizard {
+    /// Create a new wizard starting from the default `config`
+    pub fn new(config: Config) -> Self {
+        Self {
+            props: Props::default(),
+            config,
+            step: Step::Fiat,
+            cursor: 0,
+            key: String::new(),
+            error: None,
+        }
+    }
+
+    /// Number of lines to pick from on the current step
+    fn choices(&self) -> usize {
+        match self.step {
+            Step::Fiat => FIAT_CURRENCIES.len(),
+            Step::Providers => NAMES.len(),
+            Step::ApiKey(_) => 0,
+            Step::Theme => PRESETS.len(),
+        }
+    }
+
+    /// Title of the current step
+    fn title(&self) -> String {
+        let (number, name) = match self.step {
+            Step::Fiat => (1, tr("onboarding-fiat")),
+            Step::Providers => (2, tr("onboarding-providers")),
+            Step::ApiKey(index) => {
+                let provider = self.config.providers[index].clone();
+                (3, tr_args("onboarding-api-key", &[("provider", provider)]))
+            }
+            Step::Theme => (4, tr("onboarding-theme")),
+        };
+        tr_args("onboarding-title", &[("step", number.to_string()), ("name", name)])
+    }
+
+    /// Lines of the current step
+    fn lines(&self) -> Vec<String> {
+        let pointer = |index: usize| if index == self.cursor { ">" } else { " " };
+        let mut lines: Vec<String> = match self.step {
+            Step::Fiat => FIAT_CURRENCIES
+                .iter()
+                .enumerate()
+                .map(|(index, fiat)| format!("{} {}", pointer(index), fiat.to_uppercase()))
+                .collect(),
+            Step::Providers => NAMES
+                .iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    let enabled = self.config.providers.iter().any(|p| p == name);
+                    format!("{} [{}] {name}", pointer(index), if enabled { "x" } else { " " })
+                })
+                .collect(),
+            Step::ApiKey(_) => vec![format!("> {}{}", "*".repeat(self.key.chars().count()), glyphs::cursor())],
+            Step::Theme => PRESETS
+                .iter()
+                .enumerate()
+                .map(|(index, (name, _))| format!("{} {name}", pointer(index)))
+                .collect(),
+        };
+        lines.push(String::new());
+        lines.push(match &self.error {
+            Some(err) => tr_args("onboarding-save-failed", &[("error", err.clone())]),
+            None => match self.step {
+                Step::Providers => tr("onboarding-hint-providers"),
+                Step::ApiKey(_) => tr("onboarding-hint-api-key"),
+                _ => tr_args("onboarding-hint", &[("choose", glyphs::up_down().to_string())]),
+            },
+        });
+        lines
+    }
+
+    /// Step after the providers, skipping API keys when no provider is enabled
+    fn after_providers(&self) -> Step {
+        if self.config.providers.is_empty() {
+            Step::Theme
+        } else {
+            Step::ApiKey(0)
+        }
+    }
+
+    /// Confirm the current step
+    fn confirm(&mut self) -> Option<Msg> {
+        self.step = match self.step {
+            Step::Fiat => {
+                self.config.fiat = FIAT_CURRENCIES[self.cursor].to_string();
+                Step::Providers
+            }
+            Step::Providers if self.config.providers.is_empty() => return Some(Msg::None),
+            Step::Providers => self.after_providers(),
+            Step::ApiKey(index) => {
+                let key = std::mem::take(&mut self.key);
+                let name = self.config.providers[index].clone();
+                if !key.trim().is_empty() {
+                    self.config.api_keys.insert(name, key.trim().to_string());
+                }
+                if index + 1 < self.config.providers.len() {
+                    Step::ApiKey(index + 1)
+                } else {
+                    Step::Theme
+                }
+            }
+            Step::Theme => {
+                self.config.theme = PRESETS[self.cursor].1;
+                return Some(Msg::FinishOnboarding(Box::new(self.config.clone())));
+            }
+        };
+        self.cursor = 0;
+        Some(Msg::None)
+    }
+
+    /// Enable or disable the highlighted provider, keeping the usual order
+    fn toggle_provider(&mut self) {
+        let name = NAMES[self.cursor];
+        let enabled = self.config.providers.iter().any(|p| p == name);
+        self.config.providers = NAMES
+            .iter()
+            .filter(|n| if **n == name { !enabled } else { self.config.providers.iter().any(|p| p == *n) })
+            .map(|n| n.to_string())
+            .collect();
+    }
+}
+
+impl MockComponent for OnboardingWizard {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = self.lines().into_iter().map(Line::from).collect();
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .style(theme::style_of(&self.props, Role::Text))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_set(glyphs::border())
+                        .border_style(theme::style_of(&self.props, Role::Border))
+                        .title(self.title()),
+                ),
+            area,
+        );
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Custom("error") => {
+                if let AttrValue::String(err) = value {
+                    self.error = Some(err);
+                }
+            }
+            _ => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for OnboardingWizard {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, modifiers }) = ev else {
+            return None;
+        };
+
+        // Typing an API key
+        if let Step::ApiKey(_) = self.step {
+            match code {
+                Key::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => self.key.push(c),
+                Key::Backspace => {
+                    self.key.pop();
+                }
+                Key::Enter => return self.confirm(),
+                Key::Esc => return Some(Msg::SkipOnboarding),
+                _ => return None,
+            }
+            return Some(Msg::None);
+        }
+
+        let choices = self.choices();
+        match code {
+            Key::Down | Key::Char('j') => {
+                self.cursor = (self.cursor + 1) % choices;
+                Some(Msg::None)
+            }
+            Key::Up | Key::Char('k') => {
+                self.cursor = (self.cursor + choices - 1) % choices;
+                Some(Msg::None)
+            }
+            Key::Char(' ') if self.step == Step::Providers => {
+                self.toggle_provider();
+                Some(Msg::None)
+            }
+            Key::Enter => self.confirm(),
+            Key::Esc => Some(Msg::SkipOnboarding),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! ## Portfolio
+//!
+//! Portfolio screen, valuing the recorded holdings and the balances of the watched addresses at the current prices,
+//! mounted while open
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{self, tr, tr_args};
+use crate::models::asset::{Asset, AssetId};
+use crate::models::portfolio::{Holding, HoldingSource, Portfolio as Valuation, Price};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Lookup of the current prices
+#[derive(Debug, Clone, PartialEq)]
+enum Prices {
+    Loading,
+    Loaded(Vec<(AssetId, Price)>),
+    Failed(String),
+}
+
+/// Portfolio component
+pub struct Portfolio {
+    props: Props,
+    /// Currency values are given in
+    fiat: String,
+    /// Holdings recorded in the config and balances loaded so far
+    holdings: Vec<Holding>,
+    /// Assets whose balance is still being looked up
+    pending: Vec<Asset>,
+    prices: Prices,
+    valuation: Valuation,
+}
+
+impl Portfolio {
+    /// Create a new portfolio screen of the `recorded` holdings, waiting for the balances of `watched` assets and the
+    /// prices in `fiat`
+    pub fn new(recorded: Vec<Holding>, watched: Vec<Asset>, fiat: &str) -> Self {
+        let mut portfolio = Self {
+            props: Props::default(),
+            fiat: fiat.to_uppercase(),
+            holdings: recorded,
+            pending: watched,
+            prices: Prices::Loading,
+            valuation: Valuation::default(),
+        };
+        portfolio.revalue();
+        portfolio
+    }
+
+    /// Value the holdings at the prices known so far
+    fn revalue(&mut self) {
+        let prices = match &self.prices {
+            Prices::Loaded(prices) => prices.as_slice(),
+            Prices::Loading | Prices::Failed(_) => &[],
+        };
+        self.valuation = Valuation::new(&self.holdings, prices);
+    }
+
+    /// Title showing the total value, its change and how the prices were obtained
+    fn get_title(&self) -> String {
+        let total = i18n::isolate(&format!("{:.2}", self.valuation.total));
+        let mut title = tr_args("portfolio-title", &[("total", total), ("fiat", self.fiat.clone())]);
+        if let Some(change) = self.valuation.change_24h {
+            title.push(' ');
+            title.push_str(&tr_args("portfolio-change", &[("change", format!("{change:+.2}"))]));
+        }
+        match &self.prices {
+            Prices::Loading => title.push_str(&format!(" | {}", tr("portfolio-fetching"))),
+            Prices::Failed(err) => {
+                title.push_str(&format!(" | {}", tr_args("portfolio-listed", &[("error", err.clone())])));
+            }
+            Prices::Loaded(_) => {}
+        }
+        if !self.pending.is_empty() {
+            let pending = tr_args("portfolio-pending", &[("count", self.pending.len().to_string())]);
+            title.push_str(&format!(" | {pending}"));
+        }
+        title.push(' ');
+        title.push_str(&tr("portfolio-close"));
+        title
+    }
+}
+
+impl MockComponent for Portfolio {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let fiat = |value: Option<f64>| value.map(|value| format!("{value:.2}")).unwrap_or_else(|| "-".to_string());
+        let mut rows: Vec<Row> = self
+            .valuation
+            .positions
+            .iter()
+            .map(|position| {
+                let holding = &position.holding;
+                let source = match holding.source {
+                    HoldingSource::Recorded => tr("portfolio-recorded"),
+                    HoldingSource::Watched => tr("portfolio-watched"),
+                };
+                // Listed prices are only indicative
+                let price = match position.price {
+                    Some(_) if !position.live => tr_args("portfolio-price-listed", &[("price", fiat(position.price))]),
+                    _ => fiat(position.price),
+                };
+                let (change, role) = match position.change_24h {
+                    Some(change) if change < 0.0 => (format!("{change:.2}%"), Role::Negative),
+                    Some(change) => (format!("+{change:.2}%"), Role::Positive),
+                    None => ("-".to_string(), Role::Muted),
+                };
+                Row::new(vec![
+                    Cell::from(holding.asset.label()),
+                    Cell::from(i18n::isolate(&holding.amount.to_string())),
+                    Cell::from(source),
+                    Cell::from(price),
+                    Cell::from(fiat(position.value)),
+                    Cell::from(position.allocation.map_or("-".to_string(), |share| format!("{share:.1}%"))),
+                    Cell::from(change).style(theme::style_of(&self.props, role)),
+                ])
+            })
+            .collect();
+        if rows.is_empty() {
+            rows.push(Row::new(vec![Cell::from(tr("portfolio-empty"))]));
+        }
+
+        let headers = [
+            "portfolio-asset",
+            "portfolio-holding",
+            "portfolio-source",
+            "portfolio-price",
+            "portfolio-value",
+            "portfolio-allocation",
+            "portfolio-24h",
+        ];
+        let header = Row::new(headers.map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let widths = [
+            Constraint::Percentage(14),
+            Constraint::Percentage(18),
+            Constraint::Percentage(11),
+            Constraint::Percentage(18),
+            Constraint::Percentage(15),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+        ];
+        let table = Table::new(rows, widths).header(header).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_set(glyphs::border())
+                .border_style(theme::style_of(&self.props, Role::Border))
+                .title(self.get_title()),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(table, area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for Portfolio {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::User(UserEvent::BalanceLoaded(id, amount)) => {
+                let index = self.pending.iter().position(|asset| asset.id() == id)?;
+                let asset = self.pending.remove(index);
+                // Empty balances are not worth listing
+                if amount.raw() > 0 {
+                    self.holdings.push(Holding {
+                        asset,
+                        amount,
+                        source: HoldingSource::Watched,
+                    });
+                    self.revalue();
+                }
+                Some(Msg::None)
+            }
+            Event::User(UserEvent::BalanceFailed(id, _)) => {
+                let index = self.pending.iter().position(|asset| asset.id() == id)?;
+                self.pending.remove(index);
+                Some(Msg::None)
+            }
+            Event::User(UserEvent::PricesLoaded(prices)) => {
+                self.prices = Prices::Loaded(prices);
+                self.revalue();
+                Some(Msg::None)
+            }
+            Event::User(UserEvent::PricesFailed(err)) => {
+                self.prices = Prices::Failed(err);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::ClosePortfolio),
+            _ => None,
+        }
+    }
+}
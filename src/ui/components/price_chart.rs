@@ -0,0 +1,200 @@
+//! ## PriceChart
+//!
+//! Full-screen chart of the price of an asset, or of the rate of a pair, over a selectable timeframe, mounted while
+//! open
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::Span;
+use tuirealm::ratatui::widgets::{Axis, Block, Borders, Chart, Clear, Dataset, GraphType, Paragraph};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::chart::{PricePoint, Timeframe};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Lookup of the history charted
+#[derive(Debug, Clone, PartialEq)]
+enum History {
+    Loading,
+    Loaded(Vec<PricePoint>),
+    Failed(String),
+}
+
+/// PriceChart component
+pub struct PriceChart {
+    props: Props,
+    /// Asset charted, e.g. `BTC`
+    subject: String,
+    /// Unit of the values, e.g. `USD` or `ETH`
+    unit: String,
+    timeframe: Timeframe,
+    history: History,
+}
+
+impl PriceChart {
+    /// Create a new chart of `subject`, valued in `unit`, over `timeframe`
+    pub fn new(subject: String, unit: String, timeframe: Timeframe) -> Self {
+        Self {
+            props: Props::default(),
+            subject,
+            unit,
+            timeframe,
+            history: History::Loading,
+        }
+    }
+
+    /// Title showing the timeframe and the change over it
+    fn get_title(&self) -> String {
+        let timeframes: Vec<String> = Timeframe::ALL
+            .iter()
+            .map(|timeframe| {
+                if *timeframe == self.timeframe {
+                    format!("[{timeframe}]")
+                } else {
+                    timeframe.to_string()
+                }
+            })
+            .collect();
+        let args = [
+            ("subject", self.subject.clone()),
+            ("unit", self.unit.clone()),
+            ("timeframes", timeframes.join(" ")),
+        ];
+        let mut title = tr_args("chart-title", &args);
+        if let History::Loaded(points) = &self.history {
+            let first = points.first().map(|point| point.value.to_f64()).filter(|first| *first > 0.0);
+            if let (Some(first), Some(last)) = (first, points.last()) {
+                title.push_str(&format!(" | {:+.2}%", (last.value.to_f64() - first) / first * 100.0));
+            }
+        }
+        title.push(' ');
+        title.push_str(&tr("chart-keys"));
+        title
+    }
+
+    /// Values charted, hours ago on the x axis
+    fn points(points: &[PricePoint]) -> Vec<(f64, f64)> {
+        let Some(now) = points.last().map(|point| point.at) else {
+            return vec![];
+        };
+        points
+            .iter()
+            .map(|point| (-(now - point.at).num_seconds() as f64 / 3600.0, point.value.to_f64()))
+            .collect()
+    }
+
+    /// Line chart of `points`, spanning the timeframe
+    fn chart<'a>(&self, points: &'a [(f64, f64)]) -> Chart<'a> {
+        let span = self.timeframe.seconds() as f64 / 3600.0;
+        let low = points.iter().map(|(_, value)| *value).fold(f64::INFINITY, f64::min);
+        let high = points.iter().map(|(_, value)| *value).fold(f64::NEG_INFINITY, f64::max);
+        // Keep a flat line off the edges
+        let margin = if high > low { (high - low) * 0.1 } else { high.abs() * 0.001 + f64::EPSILON };
+        let label = |value: f64| Span::styled(format!("{value:.6}"), theme::style_of(&self.props, Role::Muted));
+        let ago = |hours: f64| {
+            if hours >= 24.0 {
+                tr_args("chart-days-ago", &[("days", format!("{:.0}", hours / 24.0))])
+            } else if hours >= 1.0 {
+                tr_args("chart-hours-ago", &[("hours", format!("{hours:.0}"))])
+            } else {
+                tr_args("chart-ago", &[("minutes", format!("{:.0}", hours * 60.0))])
+            }
+        };
+
+        let dataset = Dataset::default()
+            .marker(glyphs::marker())
+            .graph_type(GraphType::Line)
+            .style(theme::style_of(&self.props, Role::Accent))
+            .data(points);
+        Chart::new(vec![dataset])
+            .x_axis(
+                Axis::default()
+                    .bounds([-span, 0.0])
+                    .labels([ago(span), ago(span / 2.0), tr("chart-now")])
+                    .style(theme::style_of(&self.props, Role::Muted)),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([low - margin, high + margin])
+                    .labels([label(low), label((low + high) / 2.0), label(high)])
+                    .style(theme::style_of(&self.props, Role::Muted)),
+            )
+    }
+}
+
+impl MockComponent for PriceChart {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, Role::Accent))
+            .title(self.get_title());
+        let inner = block.inner(area);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+        let message = match &self.history {
+            History::Loading => tr_args("chart-fetching", &[("timeframe", self.timeframe.to_string())]),
+            History::Failed(err) => tr_args("chart-failed", &[("error", err.clone())]),
+            History::Loaded(points) if points.is_empty() => tr("chart-empty"),
+            History::Loaded(points) => {
+                let points = Self::points(points);
+                frame.render_widget(self.chart(&points), inner);
+                return;
+            }
+        };
+        frame.render_widget(Paragraph::new(message).style(theme::style_of(&self.props, Role::Muted)), inner);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for PriceChart {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let timeframe = match ev {
+            Event::User(UserEvent::PriceHistoryLoaded(timeframe, points)) if timeframe == self.timeframe => {
+                self.history = History::Loaded(points);
+                return Some(Msg::None);
+            }
+            Event::User(UserEvent::PriceHistoryFailed(timeframe, err)) if timeframe == self.timeframe => {
+                self.history = History::Failed(err);
+                return Some(Msg::None);
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::ClosePriceChart),
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                let index = Timeframe::ALL.iter().position(|timeframe| *timeframe == self.timeframe);
+                Timeframe::ALL[index.map_or(0, |index| (index + 1) % Timeframe::ALL.len())]
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c @ '1'..='3'),
+                ..
+            }) => Timeframe::ALL[c as usize - '1' as usize],
+            _ => return None,
+        };
+        if timeframe == self.timeframe {
+            return Some(Msg::None);
+        }
+        self.timeframe = timeframe;
+        self.history = History::Loading;
+        Some(Msg::ChartTimeframeChanged(timeframe))
+    }
+}
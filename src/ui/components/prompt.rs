@@ -0,0 +1,141 @@
+//! ## Prompt
+//!
+//! Popup asking for one line of text, e.g. the name of a draft or the file quotes are exported to, handing it back
+//! with what it was asked for; the model checks the answer and sets the `error` attribute when it turns it down
+
+use tui_realm_stdlib::Input;
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::props::{InputType, Style};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::widgets::Clear;
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, State, StateValue};
+
+use crate::models::quote::Quote;
+use crate::services::export::Column;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// What a prompt asks for
+#[derive(Debug, Clone, PartialEq)]
+pub enum Purpose {
+    /// Name of the draft the form is saved as
+    DraftName,
+    /// Target rate of the limit order on the pair, none cancelling it
+    LimitRate,
+    /// File the columns of the quotes are exported to
+    ExportPath(Vec<Quote>, Vec<Column>),
+}
+
+/// Prompt component, mounted while a line is being typed
+pub struct Prompt {
+    component: Input,
+    purpose: Purpose,
+    title: String,
+    /// Why the last answer was turned down, shown as the title until the line is edited
+    error: Option<String>,
+}
+
+impl Prompt {
+    /// Create a new empty Prompt for `purpose`, titled `title`
+    pub fn new(purpose: Purpose, title: String) -> Self {
+        Self {
+            component: Input::default().input_type(InputType::Text),
+            purpose,
+            title,
+            error: None,
+        }
+    }
+
+    /// Fill the line with `value`, the cursor at its end
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.component.attr(Attribute::Value, AttrValue::String(value.into()));
+        self.component.perform(Cmd::GoTo(Position::End));
+        self
+    }
+
+    /// Hint `placeholder` while the line is empty
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.component = self.component.placeholder(placeholder, Style::default());
+        self
+    }
+
+    /// Current line, trimmed
+    fn line(&self) -> String {
+        match self.component.state() {
+            State::One(StateValue::String(value)) => value.trim().to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// Edit the line with `cmd`, clearing the error
+    fn edit(&mut self, cmd: Cmd) -> Option<Msg> {
+        self.error = None;
+        self.perform(cmd);
+        Some(Msg::None)
+    }
+
+    /// Move the cursor with `cmd`
+    fn cursor(&mut self, cmd: Cmd) -> Option<Msg> {
+        self.perform(cmd);
+        Some(Msg::None)
+    }
+}
+
+impl MockComponent for Prompt {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let title = self.error.clone().unwrap_or_else(|| self.title.clone());
+        let role = if self.error.is_some() { Role::Negative } else { Role::Prompt };
+        frame.render_widget(Clear, area);
+        theme::style_input(&mut self.component, role);
+        glyphs::view_input(&mut self.component, frame, area, title);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.component.query(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (Attribute::Custom("error"), AttrValue::String(err)) => self.error = Some(err),
+            (attr, value) => self.component.attr(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        self.component.state()
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        self.component.perform(cmd)
+    }
+}
+
+impl Component<Msg, UserEvent> for Prompt {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Paste(text) => {
+                self.error = None;
+                text.trim().chars().for_each(|ch| {
+                    self.perform(Cmd::Type(ch));
+                });
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code, modifiers }) => match code {
+                Key::Char(ch) if !modifiers.contains(KeyModifiers::CONTROL) => self.edit(Cmd::Type(ch)),
+                Key::Backspace => self.edit(Cmd::Delete),
+                Key::Delete => self.edit(Cmd::Cancel),
+                Key::Left => self.cursor(Cmd::Move(Direction::Left)),
+                Key::Right => self.cursor(Cmd::Move(Direction::Right)),
+                Key::Home => self.cursor(Cmd::GoTo(Position::Begin)),
+                Key::End => self.cursor(Cmd::GoTo(Position::End)),
+                Key::Enter => Some(Msg::PromptSubmitted(self.purpose.clone(), self.line())),
+                Key::Esc => Some(Msg::ClosePrompt),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
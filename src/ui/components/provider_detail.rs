@@ -0,0 +1,210 @@
+//! ## ProviderDetail
+//!
+//! What a provider is, where it serves and what routing a swap through it entrusts it with, completed with what
+//! capability discovery tells of its pairs, limits and health, mounted while open
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::props::{Style, TextModifiers};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::{Line, Span};
+use tuirealm::ratatui::widgets::{Block, Borders, Clear};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{self, tr, tr_args};
+use crate::models::provider::{Capabilities, Custody, ProviderInfo};
+use crate::ui::components::text_viewer::TextView;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// ProviderDetail component
+pub struct ProviderDetail {
+    props: Props,
+    name: String,
+    info: ProviderInfo,
+    /// Currency the KYC threshold is given in, e.g. `USD`
+    fiat: String,
+    /// Country code of the user, highlighted among the restricted ones
+    jurisdiction: Option<String>,
+    /// What the provider reports it can do, `None` while being discovered
+    capabilities: Option<Capabilities>,
+    /// Scroll and search of the details, wrapped
+    view: TextView,
+}
+
+impl ProviderDetail {
+    /// Create a new popup detailing the provider `name`, its KYC threshold given in `fiat`, warning when it does not
+    /// serve `jurisdiction`
+    pub fn new(name: String, info: ProviderInfo, fiat: String, jurisdiction: Option<String>) -> Self {
+        Self {
+            props: Props::default(),
+            name,
+            info,
+            fiat,
+            jurisdiction,
+            capabilities: None,
+            view: TextView::new().wrapped(),
+        }
+    }
+
+    /// Line styled as `role` after its label, translated from the message `label`
+    fn colored(&self, label: &'static str, value: String, role: Role) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(format!("{:<14}", tr(label)), Style::default().add_modifier(TextModifiers::BOLD)),
+            Span::styled(i18n::isolate(&value), theme::style_of(&self.props, role)),
+        ])
+    }
+
+    /// Pairs swapped, limits and health check, from capability discovery
+    fn discovered(&self) -> Vec<Line<'static>> {
+        let Some(capabilities) = &self.capabilities else {
+            let checking = tr_args("provider-checking", &[("ellipsis", glyphs::ellipsis().to_string())]);
+            return ["provider-pairs", "provider-limits", "provider-health"]
+                .map(|label| self.colored(label, checking.clone(), Role::Muted))
+                .into();
+        };
+        let pair = capabilities.pair.as_deref().unwrap_or_default();
+        let limits = match &capabilities.limits {
+            Some(limits) if limits.min.is_some() || limits.max.is_some() => {
+                let bound = |limit: &Option<String>| limit.clone().unwrap_or_else(|| "-".to_string());
+                let args = [("min", bound(&limits.min)), ("max", bound(&limits.max)), ("pair", pair.to_string())];
+                tr_args("provider-limits-bounds", &args)
+            }
+            Some(_) => tr("provider-none-published"),
+            None => tr("provider-unknown"),
+        };
+        let health = match &capabilities.health {
+            Some(check) => {
+                let at = check.checked_at.format("%H:%M:%S UTC");
+                match &check.error {
+                    None => {
+                        let args = [("latency", check.latency.as_millis().to_string()), ("at", at.to_string())];
+                        self.colored("provider-health", tr_args("provider-health-ok", &args), Role::Positive)
+                    }
+                    Some(error) => {
+                        let args = [("at", at.to_string()), ("error", error.clone())];
+                        self.colored("provider-health", tr_args("provider-health-failed", &args), Role::Negative)
+                    }
+                }
+            }
+            None => self.colored("provider-health", tr("provider-health-none"), Role::Muted),
+        };
+        let pairs = capabilities.pairs.to_string();
+        vec![
+            Self::field("provider-pairs", tr_args("provider-pairs-listed", &[("count", pairs)])),
+            Self::field("provider-limits", limits),
+            health,
+        ]
+    }
+
+    /// Line listing the jurisdictions the provider does not serve, in red when the user's is one of them
+    fn restrictions(&self) -> Line<'static> {
+        if self.info.restricted.is_empty() {
+            return Self::field("provider-restricted", tr("provider-none-published"));
+        }
+        let restricted = self.info.restricted.join(", ");
+        match self.jurisdiction.as_deref().filter(|code| self.info.restricts(code)) {
+            Some(code) => {
+                let args = [("restricted", restricted), ("jurisdiction", code.to_string())];
+                self.colored("provider-restricted", tr_args("provider-restricted-here", &args), Role::Negative)
+            }
+            None => Self::field("provider-restricted", restricted),
+        }
+    }
+
+    /// Labelled line of the details, translated from the message `label`
+    fn field(label: &'static str, value: String) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(format!("{:<14}", tr(label)), Style::default().add_modifier(TextModifiers::BOLD)),
+            Span::raw(i18n::isolate(&value)),
+        ])
+    }
+
+    /// How the provider swaps, who holds the funds and what it asks of users
+    fn details(&self) -> Vec<Line<'static>> {
+        let info = &self.info;
+        let custody = match info.custody {
+            Custody::NonCustodial => Role::Positive,
+            Custody::Custodial => Role::Warning,
+            Custody::Unknown => Role::Muted,
+        };
+        let mut details = vec![
+            Self::field("provider-type", info.kind.to_string()),
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<14}", tr("provider-custody")),
+                    Style::default().add_modifier(TextModifiers::BOLD),
+                ),
+                Span::styled(info.custody.to_string(), theme::style_of(&self.props, custody)),
+                Span::raw(format!(": {}", info.custody.description())),
+            ]),
+            Self::field("provider-fees", format!("{}: {}", info.fee_model, info.fee_model.description())),
+            Self::field("provider-registration", info.registration.to_string()),
+            Self::field(
+                "provider-kyc",
+                match info.kyc_threshold {
+                    Some(threshold) => {
+                        let args = [("threshold", format!("{threshold:.0}")), ("fiat", self.fiat.clone())];
+                        tr_args("provider-kyc-threshold", &args)
+                    }
+                    None => tr("provider-kyc-none"),
+                },
+            ),
+            self.restrictions(),
+            Self::field("provider-website", info.website.clone().unwrap_or_else(|| "-".to_string())),
+            Self::field("provider-status-page", info.status_page.clone().unwrap_or_else(|| "-".to_string())),
+        ];
+        details.extend(self.discovered());
+        details
+    }
+}
+
+impl MockComponent for ProviderDetail {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let keys = tr_args("viewer-keys", &[("scroll", glyphs::up_down().to_string())]);
+        let title = format!("{} | {}", self.name, self.view.status().unwrap_or(keys));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, Role::Border))
+            .title(title);
+        let lines = self.details().into_iter().map(|line| line.alignment(i18n::start())).collect();
+        frame.render_widget(Clear, area);
+        self.view.render(frame, area, lines, block, &self.props);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for ProviderDetail {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::User(UserEvent::ProviderDiscovered(name, capabilities)) if name == self.name => {
+                self.capabilities = Some(capabilities);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code, modifiers }) if self.view.on_key(code, modifiers) => Some(Msg::None),
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::CloseProviderDetail),
+            _ => None,
+        }
+    }
+}
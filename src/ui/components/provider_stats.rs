@@ -0,0 +1,192 @@
+//! ## ProviderStats
+//!
+//! Provider statistics screen, ranking the enabled providers by success rate and latency over their latest quotes,
+//! detailing the one selected on Enter, mounted while open
+
+use std::cmp::Ordering;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table, TableState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::provider::ProviderInfo;
+use crate::services::metrics::{self, ServiceStats, ROLLING_WINDOW};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// ProviderStats component
+pub struct ProviderStats {
+    props: Props,
+    /// Names of the providers asked for quotes, with what they are
+    providers: Vec<(String, ProviderInfo)>,
+    /// Statistics of each provider, in the order shown
+    stats: Vec<(String, ServiceStats)>,
+    current_index: usize,
+    /// Selection and scroll of the rendered table, kept across frames
+    state: TableState,
+}
+
+impl ProviderStats {
+    /// Create a new statistics screen for the providers named in `providers`
+    pub fn new(providers: Vec<(String, ProviderInfo)>) -> Self {
+        let mut screen = Self {
+            props: Props::default(),
+            providers,
+            stats: vec![],
+            current_index: 0,
+            state: TableState::default(),
+        };
+        screen.reload();
+        screen
+    }
+
+    /// Take the latest statistics, the most reliable then fastest providers first, those not asked yet last
+    fn reload(&mut self) {
+        let mut services = metrics::snapshot().services;
+        self.stats = self
+            .providers
+            .iter()
+            .map(|(name, _)| (name.clone(), services.remove(name).unwrap_or_default()))
+            .collect();
+        self.stats.sort_by(|(_, a), (_, b)| {
+            let rank = |stats: &ServiceStats| {
+                let success = stats.success_rate().unwrap_or_default();
+                (stats.recent.is_empty(), -success, stats.rolling_percentile_ms(50.0))
+            };
+            rank(a).partial_cmp(&rank(b)).unwrap_or(Ordering::Equal)
+        });
+    }
+
+    /// Message opening the details of the provider selected
+    fn detail(&self) -> Option<Msg> {
+        let (name, _) = self.stats.get(self.current_index)?;
+        let (_, info) = self.providers.iter().find(|(provider, _)| provider == name)?;
+        Some(Msg::OpenProviderDetail(name.clone(), info.clone()))
+    }
+}
+
+impl MockComponent for ProviderStats {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let latency = |ms: Option<u64>| ms.map(|ms| format!("{ms}ms")).unwrap_or_else(|| "-".to_string());
+        let mut rows: Vec<Row> = self
+            .stats
+            .iter()
+            .map(|(name, stats)| {
+                let kind = self
+                    .providers
+                    .iter()
+                    .find(|(provider, _)| provider == name)
+                    .map(|(_, info)| info.kind.to_string())
+                    .unwrap_or_default();
+                let success = match stats.success_rate() {
+                    Some(rate) => format!("{rate:.0}%"),
+                    None => "-".to_string(),
+                };
+                let role = match stats.success_rate() {
+                    Some(rate) if rate < 50.0 => Role::Negative,
+                    Some(rate) if rate < 90.0 => Role::Warning,
+                    Some(_) => Role::Positive,
+                    None => Role::Muted,
+                };
+                Row::new(vec![
+                    Cell::from(name.clone()),
+                    Cell::from(kind),
+                    Cell::from(stats.recent.len().to_string()),
+                    Cell::from(success).style(theme::style_of(&self.props, role)),
+                    Cell::from(latency(stats.rolling_percentile_ms(50.0))),
+                    Cell::from(latency(stats.rolling_percentile_ms(95.0))),
+                ])
+            })
+            .collect();
+        if rows.is_empty() {
+            rows.push(Row::new(vec![Cell::from(tr("provider-stats-empty"))]));
+        }
+
+        let headers = [
+            "provider-stats-provider",
+            "provider-stats-type",
+            "provider-stats-quotes",
+            "provider-stats-success",
+            "provider-stats-p50",
+            "provider-stats-p95",
+        ];
+        let header = Row::new(headers.map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let widths = [
+            Constraint::Percentage(22),
+            Constraint::Percentage(26),
+            Constraint::Percentage(13),
+            Constraint::Percentage(13),
+            Constraint::Percentage(13),
+            Constraint::Percentage(13),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, Role::Border))
+                    .title(tr_args("provider-stats-title", &[("window", ROLLING_WINDOW.to_string())])),
+            )
+            .row_highlight_style(theme::style_of(&self.props, Role::Selection));
+
+        self.state.select((!self.stats.is_empty()).then_some(self.current_index));
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Usize(self.current_index))
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for ProviderStats {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            // Keep counting while open
+            Event::Tick => {
+                self.reload();
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down | Key::Char('j'),
+                ..
+            }) if !self.stats.is_empty() => {
+                self.current_index = (self.current_index + 1) % self.stats.len();
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Up | Key::Char('k'),
+                ..
+            }) if !self.stats.is_empty() => {
+                self.current_index = (self.current_index + self.stats.len() - 1) % self.stats.len();
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Enter, .. }) => self.detail(),
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::CloseProviderStats),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,655 @@
+//! ## QuotesTable
+//!
+//! Quotes table component comparing the offers of every provider
+
+use std::time::Instant;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::props::{PropPayload, PropValue, Style};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::text::{Line, Span};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, LineGauge, Row, Table, TableState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::errors::SwapError;
+use crate::i18n::{tr, tr_args};
+use crate::models::quote::{Freshness, Quote, QuoteFailure, QuoteProgress};
+use crate::models::selection::Selection;
+use crate::models::status::Status;
+use crate::ui::components::multi_select::Checklist;
+use crate::ui::components::slider::Setting;
+use crate::ui::control::Control;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::keymap::Keymap;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Quotes table component
+#[derive(Default)]
+pub struct QuotesTable {
+    props: Props,
+    quotes: Vec<Quote>,
+    /// Text of the cells of each quote that only change with it: amount received, least amount received, rate and ETA
+    cells: Vec<[String; 4]>,
+    /// Providers that did not quote, listed below the quotes
+    failures: Vec<QuoteFailure>,
+    best: Option<usize>,
+    /// Row selected, quotes first then failures
+    current_index: usize,
+    /// Key of the request whose quotes are shown
+    key: Option<String>,
+    /// Providers asked again, with when
+    retrying: Vec<(String, Instant)>,
+    /// Key of the request whose quotes are awaited
+    pending: Option<String>,
+    /// When the quotes awaited were requested, to animate the spinner
+    pending_since: Option<Instant>,
+    /// Providers that answered the last request so far
+    progress: Option<QuoteProgress>,
+    /// Provider to route through when the next quotes arrive, instead of the best one
+    preferred_provider: Option<String>,
+    /// Providers that may hold the funds or ask for identity verification, marked in their rows
+    flagged: Vec<String>,
+    /// Providers not serving the user's jurisdiction, greyed out and never picked as the best
+    restricted: Vec<String>,
+    /// Country code of the user, noted in the rows of the restricted providers
+    jurisdiction: String,
+    /// Best quote against the pinned one, shown in the title
+    pinned: Option<String>,
+    /// When the quotes shown arrived
+    fetched_at: Option<Instant>,
+    /// Seconds between two refreshes of the quotes shown, 0 when disabled
+    refresh_interval: u64,
+    /// Slippage tolerance, in percent, the least amount received is given for
+    slippage: f64,
+    /// Draw the spinner still
+    reduce_motion: bool,
+    /// Picks the best quote again once a provider asked again answers
+    selection: Selection,
+    keymap: Keymap,
+    /// Selection and scroll of the rendered table, kept across frames
+    state: TableState,
+}
+
+impl QuotesTable {
+    /// Create a new quotes table, marking the quote `selection` picks as the best
+    pub fn new(keymap: Keymap, selection: Selection) -> Self {
+        Self {
+            keymap,
+            selection,
+            ..Self::default()
+        }
+    }
+
+    /// Format the cells of the quotes once, rather than on every frame
+    fn cache_cells(&mut self) {
+        self.cells = self
+            .quotes
+            .iter()
+            .map(|quote| {
+                [
+                    format!("{} {}", quote.amount_out, quote.to.label()),
+                    format!("{} {} {}", glyphs::at_least(), quote.min_amount_out(self.slippage), quote.to.label()),
+                    format!("{:.6}", quote.rate()),
+                    quote.eta_label(),
+                ]
+            })
+            .collect();
+    }
+
+    /// Best of `quotes` among the providers serving the user's jurisdiction
+    fn select(&self, quotes: &[Quote]) -> Option<usize> {
+        self.selection.select_allowed(quotes, &self.restricted)
+    }
+
+    /// Rows shown, quotes and failures
+    fn rows(&self) -> usize {
+        self.quotes.len() + self.failures.len()
+    }
+
+    /// Move to the next row
+    fn next_quote(&mut self) {
+        if self.rows() > 0 {
+            self.current_index = (self.current_index + 1) % self.rows();
+        }
+    }
+
+    /// Move to the previous row
+    fn prev_quote(&mut self) {
+        if self.rows() > 0 {
+            self.current_index = (self.current_index + self.rows() - 1) % self.rows();
+        }
+    }
+
+    /// Frame of a spinner started at `since`
+    fn spinner(&self, since: Option<Instant>) -> char {
+        if self.reduce_motion {
+            return glyphs::still();
+        }
+        let elapsed = since.map_or(0, |since| since.elapsed().as_millis() / 250);
+        let frames = glyphs::spinner();
+        frames[elapsed as usize % frames.len()]
+    }
+
+    /// Replace the row of a provider asked again with its answer, selecting it
+    fn retried(&mut self, provider: String, quote: Result<Quote, SwapError>) -> Msg {
+        self.retrying.retain(|(retrying, _)| *retrying != provider);
+        self.quotes.retain(|quote| quote.provider != provider);
+        self.failures.retain(|failure| failure.provider != provider);
+        let msg = match quote {
+            Ok(quote) => {
+                self.quotes.push(quote.clone());
+                self.current_index = self.quotes.len() - 1;
+                // Without quotes before, there was no route yet
+                if self.quotes.len() == 1 {
+                    Msg::QuoteChosen(quote)
+                } else {
+                    Msg::ShowStatus(Status::info(tr_args("status-provider-quoted", &[("provider", provider)])))
+                }
+            }
+            // Reported by the worker as any failure
+            Err(error) => {
+                self.failures.insert(0, QuoteFailure { provider, error });
+                self.current_index = self.quotes.len();
+                Msg::None
+            }
+        };
+        self.cache_cells();
+        self.best = self.select(&self.quotes);
+        msg
+    }
+
+    /// Title reflecting the fetch status
+    fn get_title(&self) -> String {
+        let spinner = || self.spinner(self.pending_since).to_string();
+        let ellipsis = || glyphs::ellipsis().to_string();
+        let mut title = match (self.pending.is_some(), self.quotes.len()) {
+            (true, 0) => tr_args("quotes-title-fetching", &[("spinner", spinner()), ("ellipsis", ellipsis())]),
+            (true, count) => tr_args(
+                "quotes-title-refreshing",
+                &[("count", count.to_string()), ("spinner", spinner()), ("ellipsis", ellipsis())],
+            ),
+            (false, 0) => tr("quotes-title"),
+            (false, count) => tr_args("quotes-title-count", &[("count", count.to_string())]),
+        };
+        if self.pending.is_none() {
+            if let Some(progress) = self.progress.filter(|progress| progress.failed > 0) {
+                let args = [("failed", progress.failed.to_string()), ("total", progress.total.to_string())];
+                title.push_str(&format!(" | {}", tr_args("quotes-providers-failed", &args)));
+            }
+        }
+        if let Some(pinned) = &self.pinned {
+            title.push_str(&format!(" | {pinned}"));
+        }
+        title
+    }
+
+    /// Gauge of the providers that answered, drawn over the last row of the table while fetching
+    fn render_progress(&self, frame: &mut Frame, area: Rect) {
+        let Some(progress) = self.progress.filter(|progress| self.pending.is_some() && progress.total > 0) else {
+            return;
+        };
+        if area.height < 3 || area.width < 3 {
+            return;
+        }
+        let role = if progress.failed > 0 { Role::Warning } else { Role::Positive };
+        let color = theme::color_of(&self.props, role);
+        let args = [
+            ("answered", progress.answered.to_string()),
+            ("total", progress.total.to_string()),
+            ("failed", progress.failed.to_string()),
+        ];
+        let label = tr_args(if progress.failed > 0 { "quotes-responded-failed" } else { "quotes-responded" }, &args);
+        let row = Rect::new(area.x + 1, area.y + area.height - 2, area.width - 2, 1);
+        frame.render_widget(Clear, row);
+        frame.render_widget(
+            LineGauge::default()
+                .line_set(glyphs::line())
+                .ratio(progress.answered as f64 / progress.total as f64)
+                .label(label)
+                .filled_style(Style::default().fg(color))
+                .unfilled_style(theme::style_of(&self.props, Role::Disabled)),
+            row,
+        );
+    }
+}
+
+impl MockComponent for QuotesTable {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        // Check if visible
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let highlight = theme::style_of(&self.props, Role::Selection);
+            let highlight = theme::highlight(&self.props, highlight);
+
+            let mut rows: Vec<Row> = self
+                .quotes
+                .iter()
+                .zip(&self.cells)
+                .enumerate()
+                .map(|(i, (quote, [receive, min, rate, eta]))| {
+                    let best = Some(i) == self.best;
+                    let restricted = self.restricted.contains(&quote.provider);
+                    let style = if focus && i == self.current_index {
+                        highlight
+                    } else if restricted {
+                        theme::style_of(&self.props, Role::Disabled)
+                    } else if best {
+                        theme::style_of(&self.props, Role::Positive)
+                    } else {
+                        Style::default()
+                    };
+                    let (freshness, color) = match quote.freshness() {
+                        Freshness::Fresh => ("quotes-age-fresh", theme::color_of(&self.props, Role::Positive)),
+                        Freshness::Aging => ("quotes-age-aging", theme::color_of(&self.props, Role::Warning)),
+                        Freshness::Stale => ("quotes-age-stale", theme::color_of(&self.props, Role::Negative)),
+                    };
+                    // The age does not matter for a quote that cannot be taken
+                    let age = if restricted {
+                        let style = theme::style_of(&self.props, Role::Negative);
+                        let jurisdiction = self.jurisdiction.clone();
+                        Cell::from(tr_args("quotes-restricted", &[("jurisdiction", jurisdiction)])).style(style)
+                    } else {
+                        let age = tr_args(freshness, &[("age", quote.age_label())]);
+                        Cell::from(age).style(Style::default().fg(color))
+                    };
+                    // Only the age changes from frame to frame
+                    Row::new([
+                        Cell::from(if best { "*" } else { "" }),
+                        Cell::from(if self.flagged.contains(&quote.provider) {
+                            Line::from(vec![
+                                Span::raw(quote.provider.as_str()),
+                                Span::styled(
+                                    format!(" {}", glyphs::warning()),
+                                    theme::style_of(&self.props, Role::Warning),
+                                ),
+                            ])
+                        } else {
+                            Line::from(quote.provider.as_str())
+                        }),
+                        Cell::from(receive.as_str()),
+                        Cell::from(min.as_str()),
+                        Cell::from(rate.as_str()),
+                        Cell::from(eta.as_str()),
+                        age,
+                    ])
+                    .style(style)
+                })
+                .collect();
+            rows.extend(self.failures.iter().enumerate().map(|(i, failure)| {
+                let retrying = self
+                    .retrying
+                    .iter()
+                    .find(|(provider, _)| *provider == failure.provider)
+                    .map(|(_, since)| *since);
+                let (marker, reason) = match retrying {
+                    Some(since) => {
+                        let retrying = tr_args("quotes-retrying", &[("ellipsis", glyphs::ellipsis().to_string())]);
+                        (self.spinner(Some(since)).to_string(), retrying)
+                    }
+                    None => (glyphs::cross().to_string(), failure.error.summary()),
+                };
+                let style = if focus && self.quotes.len() + i == self.current_index {
+                    highlight
+                } else {
+                    theme::style_of(&self.props, Role::Disabled)
+                };
+                Row::new([
+                    Cell::from(marker).style(theme::style_of(&self.props, Role::Negative)),
+                    Cell::from(failure.provider.as_str()),
+                    Cell::from(reason),
+                ])
+                .style(style)
+            }));
+
+            let min = tr_args("quotes-minimum", &[("slippage", self.slippage.to_string())]);
+            let headers = ["quotes-provider", "quotes-receive", "quotes-rate", "quotes-eta", "quotes-age"].map(tr);
+            let [provider, receive, rate, eta, age] = headers;
+            let header = Row::new([String::new(), provider, receive, min, rate, eta, age].map(|h| {
+                Cell::from(h).style(theme::style_of(&self.props, Role::Header))
+            }))
+            .style(theme::style_of(&self.props, Role::Header))
+            .height(1);
+
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_set(glyphs::border())
+                .border_style(theme::style_of(&self.props, if focus { Role::Border } else { Role::Muted }))
+                .title(self.get_title());
+            let block = theme::block(&self.props, block);
+
+            let widths = [
+                Constraint::Length(1),
+                Constraint::Percentage(15),
+                Constraint::Percentage(24),
+                Constraint::Percentage(22),
+                Constraint::Percentage(14),
+                Constraint::Percentage(11),
+                Constraint::Percentage(14),
+            ];
+
+            let table = Table::new(rows, widths).header(header).block(block);
+
+            self.state.select(focus.then_some(self.current_index));
+            frame.render_stateful_widget(table, area, &mut self.state);
+            self.render_progress(frame, area);
+        }
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Custom("pending") => {
+                if let AttrValue::String(key) = value {
+                    self.pending = Some(key);
+                    self.pending_since = Some(Instant::now());
+                    self.progress = None;
+                    self.quotes.clear();
+                    self.cells.clear();
+                    self.failures.clear();
+                    self.retrying.clear();
+                    self.best = None;
+                    self.current_index = 0;
+                }
+            }
+            // Fetch the same request again, keeping the quotes shown meanwhile
+            Attribute::Custom("refreshing") => {
+                if let AttrValue::String(key) = value {
+                    self.pending = Some(key);
+                    self.pending_since = Some(Instant::now());
+                    self.progress = None;
+                }
+            }
+            // A provider that failed is asked again
+            Attribute::Custom("retrying") => {
+                if let AttrValue::String(provider) = value {
+                    if !self.retrying.iter().any(|(retrying, _)| *retrying == provider) {
+                        self.retrying.push((provider, Instant::now()));
+                    }
+                }
+            }
+            // The form no longer makes a request, e.g. after an undo
+            Attribute::Custom("clear") => {
+                self.pending = None;
+                self.pending_since = None;
+                self.progress = None;
+                self.key = None;
+                self.quotes.clear();
+                self.cells.clear();
+                self.failures.clear();
+                self.retrying.clear();
+                self.best = None;
+                self.current_index = 0;
+            }
+            // The quotes awaited will not come
+            Attribute::Custom("cancelled") => {
+                if self.pending.take().is_some() {
+                    self.preferred_provider = None;
+                }
+            }
+            // Empty once unpinned
+            Attribute::Custom("pinned") => {
+                if let AttrValue::String(pinned) = value {
+                    self.pinned = (!pinned.is_empty()).then_some(pinned);
+                }
+            }
+            Attribute::Custom("preferred_provider") => {
+                if let AttrValue::String(provider) = value {
+                    self.preferred_provider = Some(provider);
+                }
+            }
+            Attribute::Custom("refresh_interval") => {
+                if let AttrValue::Number(interval) = value {
+                    self.refresh_interval = interval.max(0) as u64;
+                }
+            }
+            Attribute::Custom("flagged") => {
+                if let AttrValue::Payload(PropPayload::Vec(names)) = value {
+                    self.flagged = names.into_iter().map(PropValue::unwrap_str).collect();
+                }
+            }
+            Attribute::Custom("jurisdiction") => {
+                if let AttrValue::String(jurisdiction) = value {
+                    self.jurisdiction = jurisdiction;
+                }
+            }
+            Attribute::Custom("restricted") => {
+                if let AttrValue::Payload(PropPayload::Vec(names)) = value {
+                    self.restricted = names.into_iter().map(PropValue::unwrap_str).collect();
+                    self.best = self.select(&self.quotes);
+                }
+            }
+            Attribute::Custom("reduce_motion") => self.reduce_motion = value == AttrValue::Flag(true),
+            Attribute::Custom("slippage") => {
+                if let AttrValue::Payload(PropPayload::One(PropValue::F64(slippage))) = value {
+                    self.slippage = slippage;
+                    self.cache_cells();
+                }
+            }
+            _ => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        if self.quotes.is_empty() {
+            State::None
+        } else {
+            State::One(StateValue::Usize(self.current_index))
+        }
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for QuotesTable {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::User(UserEvent::QuotesLoaded(request, quotes, best, failures))
+                if self.pending.as_ref() == Some(&request.key()) =>
+            {
+                self.pending = None;
+                self.key = Some(request.key());
+                self.retrying.clear();
+                self.fetched_at = Some(Instant::now());
+                let preferred = self
+                    .preferred_provider
+                    .take()
+                    .and_then(|provider| quotes.iter().position(|quote| quote.provider == provider));
+                // The restrictions may have changed since the request was sent
+                let best = if self.restricted.is_empty() { best } else { self.select(&quotes) };
+                let chosen = preferred.or(best);
+                self.current_index = chosen.unwrap_or_default();
+                self.quotes = quotes;
+                self.cache_cells();
+                self.failures = failures;
+                self.best = best;
+                Some(Msg::QuotesReceived(chosen.and_then(|i| self.quotes.get(i).cloned()), self.quotes.clone()))
+            }
+            Event::User(UserEvent::QuoteProgress(key, progress)) if self.pending.as_ref() == Some(&key) => {
+                self.progress = Some(progress);
+                Some(Msg::None)
+            }
+            Event::User(UserEvent::ProviderFailed(failure)) => Some(Msg::ProviderFailed(failure)),
+            Event::User(UserEvent::ProviderRetried(key, provider, quote)) if self.key.as_ref() == Some(&key) => {
+                Some(self.retried(provider, quote))
+            }
+            // The quotes to export are the ones shown here
+            Event::User(UserEvent::Control(Control::ExportCsv)) => Some(if self.quotes.is_empty() {
+                Msg::ShowStatus(Status::warning(tr("status-no-quotes-to-export")))
+            } else {
+                Msg::ExportQuotes(self.quotes.clone())
+            }),
+            Event::User(UserEvent::Control(control)) => Some(Msg::Control(control)),
+            Event::Tick => {
+                let due = self.pending.is_none()
+                    && self.refresh_interval > 0
+                    && self
+                        .fetched_at
+                        .is_some_and(|at| at.elapsed().as_secs() >= self.refresh_interval);
+                // Redraw anyway for the spinner and the age of the quotes to move
+                (self.pending.is_some() || !self.quotes.is_empty() || !self.retrying.is_empty()).then_some(if due {
+                    Msg::RefreshQuotes
+                } else {
+                    Msg::None
+                })
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down | Key::Char('j'),
+                modifiers: KeyModifiers::NONE,
+            }) => {
+                self.next_quote();
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Up | Key::Char('k'),
+                modifiers: KeyModifiers::NONE,
+            }) => {
+                self.prev_quote();
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter,
+                modifiers: KeyModifiers::NONE,
+            }) => match self.quotes.get(self.current_index) {
+                Some(quote) => Some(Msg::QuoteChosen(quote.clone())),
+                None => self
+                    .failures
+                    .get(self.current_index - self.quotes.len())
+                    .cloned()
+                    .map(Msg::OpenErrorDetail),
+            },
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.fee_speed => Some(Msg::OpenSlider(Setting::FeeSpeed)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.slippage => Some(Msg::OpenSlider(Setting::Slippage)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.history => Some(Msg::OpenHistory),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.save_draft => Some(Msg::OpenDraftNameInput),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.drafts => Some(Msg::OpenDrafts),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.settings => Some(Msg::OpenSettings),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.logs => Some(Msg::OpenLogs),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.verbosity => Some(Msg::RaiseLogLevel),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.metrics => Some(Msg::OpenMetrics),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.provider_stats => Some(Msg::OpenProviderStats),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.session_stats => Some(Msg::OpenSessionStats),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.portfolio => Some(Msg::OpenPortfolio),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.watchlist => Some(Msg::OpenWatchlist),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.price_chart => Some(Msg::OpenPriceChart),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.depth => Some(Msg::OpenDepth),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.watch => Some(Msg::ToggleWatch),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.limit => Some(Msg::OpenLimitInput),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.pin => Some(Msg::PinQuote),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.requote => Some(Msg::Requote),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.cancel => Some(Msg::CancelQuotes),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.dismiss => Some(Msg::DismissStatus),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('z'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Undo),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::CONTROL,
+            }) => Some(Msg::Redo),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.export && !self.quotes.is_empty() => {
+                Some(Msg::OpenMultiSelect(Checklist::ExportColumns(self.quotes.clone())))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.receipt => match self.quotes.get(self.current_index) {
+                // Never swap at a rate the provider likely no longer honors
+                Some(quote) if quote.freshness() == Freshness::Stale => Some(Msg::ShowStatus(Status::warning(tr_args(
+                    "status-quote-stale",
+                    &[("age", quote.age_label())],
+                )))),
+                quote => quote.cloned().map(Msg::SimulateSwap),
+            },
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc,
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::ExitQuotesMode),
+            Event::Keyboard(KeyEvent {
+                code: Key::Tab,
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::FocusStatus),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.keymap.quit => Some(Msg::AppClose),
+            _ => None,
+        }
+    }
+}
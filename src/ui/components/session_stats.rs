@@ -0,0 +1,134 @@
+//! ## SessionStats
+//!
+//! Session statistics screen, summarizing the history entries recorded since the application started, mounted while
+//! open
+
+use chrono::{DateTime, Utc};
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Direction, Layout, Rect};
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::history::HistorySummary;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// SessionStats component
+pub struct SessionStats {
+    props: Props,
+    summary: HistorySummary,
+    started_at: DateTime<Utc>,
+}
+
+impl SessionStats {
+    /// Create a new statistics screen for the session started at `started_at`, summarized by `summary`
+    pub fn new(summary: HistorySummary, started_at: DateTime<Utc>) -> Self {
+        Self {
+            props: Props::default(),
+            summary,
+            started_at,
+        }
+    }
+
+    /// Title showing the time spent
+    fn get_title(&self) -> String {
+        let elapsed = (Utc::now() - self.started_at).num_seconds().max(0);
+        let spent = format!("{}h{:02}m{:02}s", elapsed / 3600, elapsed / 60 % 60, elapsed % 60);
+        tr_args("session-title", &[("spent", spent)])
+    }
+
+    /// Lines counting the requests, offers and swaps
+    fn totals(&self) -> Vec<Line<'static>> {
+        let summary = &self.summary;
+        let spread = match summary.average_spread {
+            Some(spread) => tr_args("session-spread", &[("spread", format!("{spread:.2}"))]),
+            None => tr("session-spread-unknown"),
+        };
+        let quotes = [("offers", summary.offers.to_string()), ("requests", summary.requests.to_string())];
+        vec![
+            Line::from(tr_args("session-quotes", &quotes)),
+            Line::from(tr_args("session-swaps", &[("swaps", summary.swaps.to_string())])),
+            Line::from(tr_args("session-average-spread", &[("spread", spread)])),
+        ]
+    }
+}
+
+impl MockComponent for SessionStats {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, Role::Border))
+            .title(self.get_title());
+        let inner = block.inner(area);
+        let totals = self.totals();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(totals.len() as u16 + 1), Constraint::Min(0)])
+            .split(inner);
+
+        let requests = self.summary.requests.max(1) as f64;
+        let mut rows: Vec<Row> = self
+            .summary
+            .ranking()
+            .into_iter()
+            .map(|(provider, wins)| {
+                Row::new(vec![
+                    Cell::from(provider.to_string()),
+                    Cell::from(wins.to_string()),
+                    Cell::from(format!("{:.0}%", wins as f64 / requests * 100.0)),
+                ])
+            })
+            .collect();
+        if rows.is_empty() {
+            rows.push(Row::new(vec![Cell::from(tr("session-empty"))]));
+        }
+        let header = Row::new(["session-best-rate-by", "session-requests", "session-share"].map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+        let widths = [
+            Constraint::Percentage(50),
+            Constraint::Percentage(25),
+            Constraint::Percentage(25),
+        ];
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+        frame.render_widget(Paragraph::new(totals), chunks[0]);
+        frame.render_widget(Table::new(rows, widths).header(header), chunks[1]);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for SessionStats {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            // Count the time spent
+            Event::Tick => Some(Msg::None),
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::CloseSessionStats),
+            _ => None,
+        }
+    }
+}
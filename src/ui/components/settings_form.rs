@@ -0,0 +1,283 @@
+//! ## SettingsForm
+//!
+//! Settings screen editing a copy of the configuration, mounted while open
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::props::{Color, PropPayload, PropValue};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table, TableState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::config::{Bell, Config, FIAT_CURRENCIES, MAX_SLIPPAGE};
+use crate::i18n::{tr, tr_args};
+use crate::ui::components::dropdown::Choice;
+use crate::ui::components::multi_select::Checklist;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, color_name, Role, PALETTE};
+
+/// Seconds added or removed per step on refresh intervals
+const REFRESH_STEP: i64 = 5;
+
+/// Percent added or removed per step on the slippage tolerance
+const SLIPPAGE_STEP: f64 = 0.1;
+
+/// An editable setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Profile,
+    BalanceRefresh,
+    FeesRefresh,
+    QuotesRefresh,
+    AutoQuote,
+    Slippage,
+    Fiat,
+    Notifications,
+    Bell,
+    ReduceMotion,
+    /// Theme color, by index in `THEME_KEYS`
+    Theme(usize),
+    /// Providers enabled, ticked in a checklist
+    Providers,
+}
+
+/// Theme colors, in display order
+const THEME_KEYS: [&str; 4] = ["primary", "secondary", "text", "highlight"];
+
+/// SettingsForm component
+pub struct SettingsForm {
+    props: Props,
+    config: Config,
+    current_index: usize,
+    /// Outcome of the last save or profile switch, shown in the title
+    status: Option<String>,
+    /// Selection and scroll of the rendered table, kept across frames
+    state: TableState,
+}
+
+impl SettingsForm {
+    /// Create a new settings screen starting from `config`
+    pub fn new(config: Config) -> Self {
+        Self {
+            props: Props::default(),
+            config,
+            current_index: 0,
+            status: None,
+            state: TableState::default(),
+        }
+    }
+
+    /// Every field, in display order
+    fn fields() -> Vec<Field> {
+        let mut fields = vec![Field::Profile, Field::BalanceRefresh, Field::FeesRefresh, Field::QuotesRefresh, Field::AutoQuote];
+        fields.extend([Field::Slippage, Field::Fiat]);
+        fields.extend([Field::Notifications, Field::Bell, Field::ReduceMotion]);
+        fields.extend((0..THEME_KEYS.len()).map(Field::Theme));
+        fields.push(Field::Providers);
+        fields
+    }
+
+    /// Theme color edited by a field
+    fn theme_color(&mut self, index: usize) -> &mut Color {
+        match index {
+            0 => &mut self.config.theme.primary,
+            1 => &mut self.config.theme.secondary,
+            2 => &mut self.config.theme.text,
+            _ => &mut self.config.theme.highlight,
+        }
+    }
+
+    /// Label and value of a field
+    fn row(&mut self, field: Field) -> (String, String) {
+        let seconds = |secs: u64| {
+            if secs == 0 {
+                tr("settings-off")
+            } else {
+                tr_args("settings-every", &[("seconds", secs.to_string())])
+            }
+        };
+        let flag = |on: bool| tr(if on { "settings-on" } else { "settings-off" });
+        match field {
+            Field::Profile => (
+                tr("settings-profile"),
+                self.config.profile.clone().unwrap_or_else(|| tr("settings-none")),
+            ),
+            Field::BalanceRefresh => (tr("settings-balance-refresh"), seconds(self.config.refresh.balance)),
+            Field::FeesRefresh => (tr("settings-fee-refresh"), seconds(self.config.refresh.fees)),
+            Field::QuotesRefresh => (tr("settings-quote-refresh"), seconds(self.config.refresh.quotes)),
+            Field::AutoQuote => (tr("settings-auto-quote"), flag(self.config.auto_quote.enabled)),
+            Field::Slippage => (tr("settings-slippage"), format!("{:.1}%", self.config.slippage)),
+            Field::Fiat => (tr("settings-fiat"), self.config.fiat.to_uppercase()),
+            Field::Notifications => (tr("settings-notifications"), flag(self.config.notifications.desktop)),
+            Field::Bell => (tr("settings-bell"), self.config.notifications.bell.name().to_string()),
+            Field::ReduceMotion => (tr("settings-reduce-motion"), flag(self.config.reduce_motion)),
+            Field::Theme(index) => {
+                let color = *self.theme_color(index);
+                (
+                    tr_args("settings-theme", &[("color", THEME_KEYS[index].to_string())]),
+                    color_name(color).map_or_else(|| format!("{color}"), str::to_string),
+                )
+            }
+            Field::Providers => {
+                let providers = if self.config.providers.is_empty() {
+                    tr("settings-none")
+                } else {
+                    self.config.providers.join(", ")
+                };
+                (tr("settings-providers"), providers)
+            }
+        }
+    }
+
+    /// Move a field's value `step` positions forward (or backward when negative)
+    fn adjust(&mut self, field: Field, step: isize) -> Option<Msg> {
+        let cycle = |len: usize, index: Option<usize>| match index {
+            Some(index) => (index as isize + step).rem_euclid(len as isize) as usize,
+            None => 0,
+        };
+        match field {
+            Field::Profile => {
+                let mut profiles: Vec<Option<String>> = vec![None];
+                profiles.extend(self.config.profiles.keys().cloned().map(Some));
+                let index = cycle(profiles.len(), profiles.iter().position(|p| *p == self.config.profile));
+                return Some(Msg::SwitchProfile(profiles.swap_remove(index)));
+            }
+            Field::BalanceRefresh => {
+                self.config.refresh.balance = (self.config.refresh.balance as i64 + step as i64 * REFRESH_STEP).max(0) as u64;
+            }
+            Field::FeesRefresh => {
+                self.config.refresh.fees = (self.config.refresh.fees as i64 + step as i64 * REFRESH_STEP).max(0) as u64;
+            }
+            Field::QuotesRefresh => {
+                self.config.refresh.quotes = (self.config.refresh.quotes as i64 + step as i64 * REFRESH_STEP).max(0) as u64;
+            }
+            Field::AutoQuote => self.config.auto_quote.enabled = !self.config.auto_quote.enabled,
+            Field::Slippage => {
+                let slippage = self.config.slippage + step as f64 * SLIPPAGE_STEP;
+                self.config.slippage = ((slippage * 10.0).round() / 10.0).clamp(0.0, MAX_SLIPPAGE);
+            }
+            Field::Fiat => {
+                let index = FIAT_CURRENCIES.iter().position(|fiat| *fiat == self.config.fiat);
+                self.config.fiat = FIAT_CURRENCIES[cycle(FIAT_CURRENCIES.len(), index)].to_string();
+            }
+            Field::Notifications => self.config.notifications.desktop = !self.config.notifications.desktop,
+            Field::Bell => {
+                let bell = &mut self.config.notifications.bell;
+                *bell = Bell::ALL[cycle(Bell::ALL.len(), Bell::ALL.iter().position(|b| b == bell))];
+            }
+            Field::ReduceMotion => self.config.reduce_motion = !self.config.reduce_motion,
+            Field::Theme(index) => {
+                let color = self.theme_color(index);
+                let position = PALETTE.iter().position(|(_, c)| c == color);
+                *color = PALETTE[cycle(PALETTE.len(), position)].1;
+            }
+            Field::Providers => {
+                return Some(Msg::OpenMultiSelect(Checklist::Providers(self.config.providers.clone())));
+            }
+        }
+        self.status = None;
+        Some(Msg::None)
+    }
+}
+
+impl MockComponent for SettingsForm {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row> = Self::fields()
+            .into_iter()
+            .map(|field| {
+                let (label, value) = self.row(field);
+                Row::new([Cell::from(label), Cell::from(value)])
+            })
+            .collect();
+
+        let header = Row::new(["settings-setting", "settings-value"].map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let title = match &self.status {
+            Some(status) => tr_args("settings-title-status", &[("status", status.clone())]),
+            None => tr_args("settings-title", &[("change", glyphs::left_right().to_string())]),
+        };
+        let table = Table::new(rows, [Constraint::Percentage(50), Constraint::Percentage(50)])
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, Role::Border))
+                    .title(title),
+            )
+            .row_highlight_style(theme::style_of(&self.props, Role::Selection));
+
+        self.state.select(Some(self.current_index));
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Custom("status") => {
+                if let AttrValue::String(status) = value {
+                    self.status = Some(status);
+                }
+            }
+            // Picked in the dropdown opened from the field
+            Attribute::Custom("fiat") => {
+                if let AttrValue::String(fiat) = value {
+                    self.config.fiat = fiat;
+                    self.status = None;
+                }
+            }
+            // Ticked in the checklist opened from the field
+            Attribute::Custom("providers") => {
+                if let AttrValue::Payload(PropPayload::Vec(providers)) = value {
+                    self.config.providers = providers.into_iter().map(PropValue::unwrap_str).collect();
+                    self.status = None;
+                }
+            }
+            _ => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Usize(self.current_index))
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for SettingsForm {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, .. }) = ev else {
+            return None;
+        };
+        let fields = Self::fields();
+        let field = fields[self.current_index];
+        match code {
+            Key::Down | Key::Char('j') => {
+                self.current_index = (self.current_index + 1) % fields.len();
+                Some(Msg::None)
+            }
+            Key::Up | Key::Char('k') => {
+                self.current_index = (self.current_index + fields.len() - 1) % fields.len();
+                Some(Msg::None)
+            }
+            Key::Enter if field == Field::Fiat => Some(Msg::OpenDropdown(Choice::Fiat(self.config.fiat.clone()))),
+            Key::Right | Key::Char('l') | Key::Enter | Key::Char(' ') => self.adjust(field, 1),
+            Key::Left | Key::Char('h') => self.adjust(field, -1),
+            Key::Char('s') => Some(Msg::SaveSettings(Box::new(self.config.clone()))),
+            Key::Esc => Some(Msg::CloseSettings),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! ## Slider
+//!
+//! Popup adjusting a setting along a gauge with ←/→, bounded and snapping to the stops of the setting, e.g. the
+//! slippage tolerance or the network fee preset, handing the value picked back with the setting it was for
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::widgets::{Block, Borders, Clear, LineGauge};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::fee::FeeSpeed;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Slippage tolerances offered, in percent, closer together where they are usually set
+const SLIPPAGE_STOPS: [f64; 13] = [0.1, 0.2, 0.3, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0, 20.0, 50.0];
+
+/// What a slider adjusts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    /// Slippage tolerance of the session, in percent
+    Slippage,
+    /// Network fee preset, by index in `FeeSpeed::ALL`
+    FeeSpeed,
+}
+
+impl Setting {
+    /// Values the slider stops at, lowest first
+    fn stops(self) -> Vec<f64> {
+        match self {
+            Self::Slippage => SLIPPAGE_STOPS.to_vec(),
+            Self::FeeSpeed => (0..FeeSpeed::ALL.len()).map(|index| index as f64).collect(),
+        }
+    }
+
+    /// Value at a stop, as shown on the gauge
+    fn label(self, value: f64) -> String {
+        match self {
+            Self::Slippage => format!("{value}%"),
+            Self::FeeSpeed => FeeSpeed::ALL[(value as usize).min(FeeSpeed::ALL.len() - 1)].to_string(),
+        }
+    }
+
+    /// Name of the setting, titling the popup
+    fn name(self) -> String {
+        tr(match self {
+            Self::Slippage => "slider-slippage",
+            Self::FeeSpeed => "slider-fee-speed",
+        })
+    }
+}
+
+/// Slider component, mounted while a setting is adjusted
+pub struct Slider {
+    props: Props,
+    setting: Setting,
+    stops: Vec<f64>,
+    /// Stop the slider is at
+    index: usize,
+}
+
+impl Slider {
+    /// Create a new Slider for `setting`, at the stop closest to `value`
+    pub fn new(setting: Setting, value: f64) -> Self {
+        let stops = setting.stops();
+        let index = (0..stops.len())
+            .min_by(|a, b| (stops[*a] - value).abs().total_cmp(&(stops[*b] - value).abs()))
+            .unwrap_or_default();
+        Self {
+            props: Props::default(),
+            setting,
+            stops,
+            index,
+        }
+    }
+
+    /// Move to stop `index`, kept within the ends
+    fn go_to(&mut self, index: isize) -> Option<Msg> {
+        self.index = index.clamp(0, self.stops.len() as isize - 1) as usize;
+        Some(Msg::None)
+    }
+}
+
+impl MockComponent for Slider {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let args = [("setting", self.setting.name()), ("adjust", glyphs::left_right().to_string())];
+        let title = tr_args("slider-title", &args);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, Role::Prompt))
+            .title(title);
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+        let ratio = match self.stops.len() {
+            0 | 1 => 1.0,
+            len => self.index as f64 / (len - 1) as f64,
+        };
+        frame.render_widget(
+            LineGauge::default()
+                .line_set(glyphs::line())
+                .ratio(ratio)
+                .label(format!("{:>8} ", self.setting.label(self.stops[self.index])))
+                .style(theme::style_of(&self.props, Role::Text))
+                .filled_style(theme::style_of(&self.props, Role::Accent))
+                .unfilled_style(theme::style_of(&self.props, Role::Disabled)),
+            inner,
+        );
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::F64(self.stops[self.index]))
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for Slider {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, .. }) = ev else {
+            return None;
+        };
+        let index = self.index as isize;
+        match code {
+            Key::Left | Key::Char('h') => self.go_to(index - 1),
+            Key::Right | Key::Char('l') => self.go_to(index + 1),
+            Key::Home => self.go_to(0),
+            Key::End => self.go_to(isize::MAX),
+            Key::Enter => Some(Msg::SliderSubmitted(self.setting, self.stops[self.index])),
+            Key::Esc => Some(Msg::CloseSlider),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! ## StatusBar
+//!
+//! Status bar showing the outcome of the last actions, styled by severity; an error reporting a provider failure
+//! is selected with Tab from the quotes and detailed on Enter
+
+use std::time::{Duration, Instant};
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::props::{Style, TextModifiers};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::{Line, Span};
+use tuirealm::ratatui::widgets::{Block, Paragraph};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::i18n::{self, tr_args};
+use crate::models::quote::QuoteFailure;
+use crate::models::status::{Severity, Status};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Time an info status stays shown
+const INFO: Duration = Duration::from_secs(4);
+
+/// Time a warning stays shown, fading out over its second half
+const WARNING: Duration = Duration::from_secs(10);
+
+/// StatusBar component
+#[derive(Default)]
+pub struct StatusBar {
+    props: Props,
+    /// Last error, shown until acknowledged
+    error: Option<String>,
+    /// Provider failure the error reports, if it does
+    failure: Option<QuoteFailure>,
+    /// Last info or warning, with when it was shown
+    notice: Option<(Status, Instant)>,
+    /// Key acknowledging the error
+    dismiss: char,
+}
+
+impl StatusBar {
+    /// Create a new status bar, `dismiss` acknowledging errors
+    pub fn new(dismiss: char) -> Self {
+        Self {
+            dismiss,
+            ..Self::default()
+        }
+    }
+
+    /// Show a status; an error replaces the previous one, anything else the previous notice
+    fn show(&mut self, status: Status) {
+        match status.severity {
+            Severity::Error => {
+                self.error = Some(status.text);
+                self.failure = None;
+            }
+            _ => self.notice = Some((status, Instant::now())),
+        }
+    }
+
+    /// Show the failure of a provider, kept for its details when it is an error
+    fn show_failure(&mut self, failure: QuoteFailure) {
+        let args = [("provider", failure.provider.clone()), ("error", failure.error.root().to_string())];
+        let severity = failure.error.severity();
+        self.show(Status {
+            severity,
+            text: tr_args("status-provider-failed", &args),
+        });
+        if severity == Severity::Error {
+            self.failure = Some(failure);
+        }
+    }
+
+    /// Drop the notice once its time is up, returning whether it was
+    fn expire(&mut self) -> bool {
+        let expired = self.notice.as_ref().is_some_and(|(status, at)| {
+            at.elapsed()
+                >= match status.severity {
+                    Severity::Info => INFO,
+                    _ => WARNING,
+                }
+        });
+        if expired {
+            self.notice = None;
+        }
+        expired
+    }
+
+    /// Spans of the error then the notice
+    fn spans(&self) -> Vec<Span<'static>> {
+        let mut spans = vec![];
+        if let Some(error) = &self.error {
+            let style = Style::default()
+                .fg(theme::color_of(&self.props, Role::Text))
+                .bg(theme::color_of(&self.props, Role::Error))
+                .add_modifier(TextModifiers::BOLD);
+            let focused = self.props.get_or(Attribute::Focus, AttrValue::Flag(false)) == AttrValue::Flag(true);
+            let (id, style) = if focused {
+                ("status-error-detail", style.add_modifier(TextModifiers::REVERSED))
+            } else {
+                ("status-error", style)
+            };
+            let text = tr_args(id, &[("error", error.clone()), ("key", self.dismiss.to_string())]);
+            spans.push(Span::styled(
+                format!(" {} {text} ", glyphs::cross()),
+                theme::highlight(&self.props, style),
+            ));
+        }
+        if let Some((status, at)) = &self.notice {
+            let style = match status.severity {
+                Severity::Info => theme::style_of(&self.props, Role::Positive),
+                _ if at.elapsed() < WARNING / 2 => theme::style_of(&self.props, Role::Warning),
+                _ => theme::style_of(&self.props, Role::Warning).add_modifier(TextModifiers::DIM),
+            };
+            let icon = if status.severity == Severity::Info { glyphs::check() } else { "!" };
+            spans.push(Span::styled(format!(" {icon} {}", status.text), style));
+        }
+        spans
+    }
+}
+
+impl MockComponent for StatusBar {
+    fn view(&mut self, frame: &mut Frame, mut area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            // Bordered only when the theme gives it a border
+            if self.props.get(Attribute::Borders).is_some() {
+                let block = theme::block(&self.props, Block::bordered().border_set(glyphs::border()));
+                let inner = block.inner(area);
+                frame.render_widget(block, area);
+                area = inner;
+            }
+            let alignment = match self.props.get(Attribute::Custom("title_alignment")) {
+                Some(AttrValue::Alignment(alignment)) => alignment,
+                _ => i18n::start(),
+            };
+            // Cut here rather than by the paragraph, which may cut a right-to-left status inside an isolate
+            let mut left = area.width as usize;
+            let spans: Vec<Span> = self
+                .spans()
+                .into_iter()
+                .map(|span| {
+                    let content = i18n::truncate(&span.content, left);
+                    left = left.saturating_sub(content.width());
+                    Span::styled(content, span.style)
+                })
+                .collect();
+            frame.render_widget(Paragraph::new(Line::from(spans)).alignment(alignment), area);
+        }
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        match attr {
+            // Whether the error has details to select it for
+            Attribute::Custom("detail") => Some(AttrValue::Flag(self.failure.is_some())),
+            attr => self.props.get(attr),
+        }
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (Attribute::Custom("info"), AttrValue::String(text)) => self.show(Status::info(text)),
+            (Attribute::Custom("warning"), AttrValue::String(text)) => self.show(Status::warning(text)),
+            (Attribute::Custom("error"), AttrValue::String(text)) => self.show(Status::error(text)),
+            (Attribute::Custom("dismiss"), _) => {
+                self.error = None;
+                self.failure = None;
+            }
+            (attr, value) => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for StatusBar {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            // Redraw while a notice is shown, for it to fade and clear
+            Event::Tick => {
+                let expired = self.expire();
+                (expired || self.notice.is_some()).then_some(Msg::None)
+            }
+            Event::User(UserEvent::ProviderFailed(failure)) => {
+                self.show_failure(failure);
+                Some(Msg::None)
+            }
+            // Selected from the quotes
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter,
+                modifiers: KeyModifiers::NONE,
+            }) => self.failure.clone().map(Msg::OpenErrorDetail),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }) if c == self.dismiss => Some(Msg::DismissStatus),
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Tab,
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::BlurStatus),
+            _ => None,
+        }
+    }
+}
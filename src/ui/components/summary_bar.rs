@@ -3,12 +3,12 @@
 //! Summary bar component for displaying transaction summary
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::event::NoUserEvent;
 use tuirealm::props::{Alignment, Color, Style, TextModifiers};
 use tuirealm::ratatui::layout::Rect;
 use tuirealm::ratatui::widgets::Paragraph;
 use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
 
+use crate::ui::event::UserEvent;
 use crate::ui::msg::Msg;
 
 /// SummaryBar component that displays transaction summary
@@ -20,6 +20,8 @@ pub struct SummaryBar {
     to_ticker: Option<String>,
     from_amount: String,
     to_amount: String,
+    /// Amount the best quote returns once swapped back, with the round-trip spread
+    round_trip: Option<String>,
 }
 
 impl SummaryBar {
@@ -30,18 +32,34 @@ impl SummaryBar {
             from_ticker: None,
             to_ticker: None,
             from_amount: "1.0".to_string(), // Hardcoded for now
-            to_amount: "123.45".to_string(), // Hardcoded for now
+            to_amount: "?".to_string(), // Filled in by the best quote
+            round_trip: None,
         }
     }
 
-    /// Update from asset ticker
+    /// Update from asset ticker, an empty one clearing it
     pub fn set_from_ticker(&mut self, ticker: String) {
-        self.from_ticker = Some(ticker);
+        self.from_ticker = Some(ticker).filter(|ticker| !ticker.is_empty());
     }
 
-    /// Update to asset ticker
+    /// Update to asset ticker, an empty one clearing it
     pub fn set_to_ticker(&mut self, ticker: String) {
-        self.to_ticker = Some(ticker);
+        self.to_ticker = Some(ticker).filter(|ticker| !ticker.is_empty());
+    }
+
+    /// Update to amount
+    pub fn set_to_amount(&mut self, amount: String) {
+        self.to_amount = amount;
+    }
+
+    /// Update the round trip of the best quote, an empty one clearing it
+    pub fn set_round_trip(&mut self, round_trip: String) {
+        self.round_trip = Some(round_trip).filter(|round_trip| !round_trip.is_empty());
+    }
+
+    /// Update from amount
+    pub fn set_from_amount(&mut self, amount: String) {
+        self.from_amount = amount;
     }
 
     /// Get formatted summary text
@@ -52,7 +70,10 @@ impl SummaryBar {
         let from_display = self.from_ticker.as_ref().map_or("{from_amount}".to_string(), |ticker| format!("{} {}", from_amount, ticker));
         let to_display = self.to_ticker.as_ref().map_or("{to_amount}".to_string(), |ticker| format!("{} {}", to_amount, ticker));
         
-        format!("{} -> {}", from_display, to_display)
+        match &self.round_trip {
+            Some(round_trip) => format!("{} -> {} | {}", from_display, to_display, round_trip),
+            None => format!("{} -> {}", from_display, to_display),
+        }
     }
 }
 
@@ -63,7 +84,10 @@ impl MockComponent for SummaryBar {
             // Get properties
             let summary_text = self.get_summary_text();
             let alignment = Alignment::Center;
-            let foreground = Color::White;
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::White))
+                .unwrap_color();
             let background = Color::Reset;
             let modifiers = TextModifiers::BOLD;
 
@@ -87,16 +111,31 @@ impl MockComponent for SummaryBar {
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
         match attr {
-            Attribute::Custom(custom) if custom == "from_ticker" || custom == "from_ticker" => {
+            Attribute::Custom("from_ticker") => {
                 if let AttrValue::String(ticker) = value {
                     self.set_from_ticker(ticker);
                 }
             },
-            Attribute::Custom(custom) if custom == "to_ticker" || custom == "to_ticker" => {
+            Attribute::Custom("from_amount") => {
+                if let AttrValue::String(amount) = value {
+                    self.set_from_amount(amount);
+                }
+            },
+            Attribute::Custom("to_amount") => {
+                if let AttrValue::String(amount) = value {
+                    self.set_to_amount(amount);
+                }
+            },
+            Attribute::Custom("to_ticker") => {
                 if let AttrValue::String(ticker) = value {
                     self.set_to_ticker(ticker);
                 }
             },
+            Attribute::Custom("round_trip") => {
+                if let AttrValue::String(round_trip) = value {
+                    self.set_round_trip(round_trip);
+                }
+            },
             _ => self.props.set(attr, value),
         }
     }
@@ -110,9 +149,12 @@ impl MockComponent for SummaryBar {
     }
 }
 
-impl Component<Msg, NoUserEvent> for SummaryBar {
-    fn on(&mut self, _: Event<NoUserEvent>) -> Option<Msg> {
-        // This component doesn't react to events
-        None
+impl Component<Msg, UserEvent> for SummaryBar {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        // Only the quotes back of the best quote, for the model to check they are still current
+        match ev {
+            Event::User(UserEvent::ReverseQuoted(key, back)) => Some(Msg::ReverseQuoted(key, back)),
+            _ => None,
+        }
     }
 }
\ No newline at end of file
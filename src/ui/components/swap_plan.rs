@@ -0,0 +1,97 @@
+//! ## SwapPlan
+//!
+//! Swap plan component listing the steps of the selected route
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{PropPayload, PropValue};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::widgets::{Block, Borders, Paragraph};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::tr;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// SwapPlan component
+#[derive(Default)]
+pub struct SwapPlan {
+    props: Props,
+    steps: Vec<String>,
+}
+
+impl SwapPlan {
+    /// Create a new SwapPlan
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MockComponent for SwapPlan {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        // Check if visible
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let lines: Vec<Line> = if self.steps.is_empty() {
+                vec![Line::from(tr("swap-plan-empty"))]
+            } else {
+                self.steps
+                    .iter()
+                    .enumerate()
+                    .map(|(index, step)| Line::from(format!("{}. {step}", index + 1)))
+                    .collect()
+            };
+
+            frame.render_widget(
+                Paragraph::new(lines)
+                    .style(theme::style_of(&self.props, Role::Text))
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_set(glyphs::border())
+                            .border_style(theme::style_of(&self.props, Role::Muted))
+                            .title(tr("swap-plan-title")),
+                    ),
+                area,
+            );
+        }
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Custom("steps") => {
+                if let AttrValue::Payload(PropPayload::Vec(steps)) = value {
+                    self.steps = steps.into_iter().map(PropValue::unwrap_str).collect();
+                }
+            }
+            _ => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for SwapPlan {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::User(UserEvent::AllowanceLoaded(asset, spender, allowance)) => {
+                Some(Msg::AllowanceChecked(asset, spender, Ok(allowance)))
+            }
+            Event::User(UserEvent::AllowanceFailed(asset, spender, err)) => {
+                Some(Msg::AllowanceChecked(asset, spender, Err(err)))
+            }
+            _ => None,
+        }
+    }
+}
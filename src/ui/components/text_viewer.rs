@@ -0,0 +1,267 @@
+//! ## TextViewer
+//!
+//! Scrolling and searching of long text: `TextView` keeps the position and search of the screens drawing some, e.g.
+//! the logs or the details of a provider, and `TextViewer` is the popup showing a text file, e.g. a swap receipt or a
+//! crash report
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::i18n::tr_args;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Position and search of text drawn in a block, moved with j/k, PgUp/PgDn, g/G and searched with `/`, then `n`/`N`
+#[derive(Debug, Default)]
+pub struct TextView {
+    /// First row shown
+    offset: usize,
+    /// Rows shown on the last frame, scrolled per page
+    height: usize,
+    /// Rows of the text on the last frame
+    rows: usize,
+    /// Plain text of the lines drawn on the last frame, searched
+    text: Vec<String>,
+    /// Whether long lines wrap instead of being cut
+    wrap: bool,
+    /// Whether the view may stick to the end as lines are added
+    tail: bool,
+    /// Whether the end is kept in view
+    follow: bool,
+    query: String,
+    /// Line of the match found last
+    found: Option<usize>,
+    /// Whether typed characters go to the search
+    searching: bool,
+}
+
+impl TextView {
+    /// Create a new view at the top of the text
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap long lines
+    pub fn wrapped(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Start at the end of the text, keeping it in view as lines are added until scrolled up
+    pub fn following(mut self) -> Self {
+        self.tail = true;
+        self.follow = true;
+        self
+    }
+
+    /// Whether the end of the text is kept in view
+    pub fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Whether typed characters go to the search, and not to the keys of the screen
+    pub fn is_searching(&self) -> bool {
+        self.searching
+    }
+
+    /// Search typed or found, for the title
+    pub fn status(&self) -> Option<String> {
+        if self.searching {
+            let typed = format!("{}{}", self.query, glyphs::cursor());
+            return Some(tr_args("viewer-search-typing", &[("query", typed)]));
+        }
+        if self.query.is_empty() {
+            return None;
+        }
+        let matches = self.matches();
+        Some(match matches.iter().position(|line| Some(*line) == self.found) {
+            Some(index) => {
+                let args = [
+                    ("query", self.query.clone()),
+                    ("index", (index + 1).to_string()),
+                    ("count", matches.len().to_string()),
+                ];
+                tr_args("viewer-search-found", &args)
+            }
+            None => tr_args("viewer-search-missing", &[("query", self.query.clone())]),
+        })
+    }
+
+    /// Indexes of the lines holding the query
+    fn matches(&self) -> Vec<usize> {
+        let query = self.query.to_lowercase();
+        if query.is_empty() {
+            return vec![];
+        }
+        self.text.iter().enumerate().filter(|(_, line)| line.to_lowercase().contains(&query)).map(|(i, _)| i).collect()
+    }
+
+    /// Last first row, showing the end of the text
+    fn max_offset(&self) -> usize {
+        self.rows.saturating_sub(self.height)
+    }
+
+    /// Scroll to row `offset`, following when it shows the end of a text that may grow
+    fn scroll_to(&mut self, offset: usize) {
+        self.offset = offset.min(self.max_offset());
+        self.follow = self.tail && self.offset == self.max_offset();
+    }
+
+    /// Scroll to the first line holding the query from line `from`, wrapping around, or the last one before it when
+    /// `back`
+    fn find(&mut self, from: usize, back: bool) {
+        let matches = self.matches();
+        let found = if back {
+            matches.iter().rev().find(|line| **line < from).or(matches.last())
+        } else {
+            matches.iter().find(|line| **line >= from).or(matches.first())
+        };
+        self.found = found.copied();
+        if let Some(line) = self.found {
+            self.scroll_to(line);
+        }
+    }
+
+    /// Draw `lines` in `block` over `area`, highlighting those holding the query in the style of `props`
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, lines: Vec<Line<'static>>, block: Block, props: &Props) {
+        let inner = block.inner(area);
+        self.text = lines.iter().map(Line::to_string).collect();
+        self.height = inner.height as usize;
+        self.rows = if self.wrap {
+            let width = inner.width.max(1) as usize;
+            lines.iter().map(|line| line.width().div_ceil(width).max(1)).sum()
+        } else {
+            lines.len()
+        };
+        if self.follow {
+            self.offset = self.max_offset();
+        }
+        self.offset = self.offset.min(self.max_offset());
+
+        let matches = self.matches();
+        let found = theme::style_of(props, Role::Selection);
+        let lines: Vec<Line> = lines
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| if matches.contains(&index) { line.patch_style(found) } else { line })
+            .collect();
+        let mut paragraph = Paragraph::new(lines).block(block).scroll((self.offset as u16, 0));
+        if self.wrap {
+            paragraph = paragraph.wrap(Wrap { trim: false });
+        }
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Scroll or search with the key `code`, true if it did
+    pub fn on_key(&mut self, code: Key, modifiers: KeyModifiers) -> bool {
+        // Typing a search
+        if self.searching {
+            match code {
+                Key::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => self.query.push(c),
+                Key::Backspace => {
+                    self.query.pop();
+                }
+                Key::Enter => {
+                    self.searching = false;
+                    self.find(self.offset, false);
+                }
+                Key::Esc => {
+                    self.searching = false;
+                    self.query.clear();
+                }
+                _ => return false,
+            }
+            return true;
+        }
+
+        match code {
+            Key::Down | Key::Char('j') => self.scroll_to(self.offset.saturating_add(1)),
+            Key::Up | Key::Char('k') => self.scroll_to(self.offset.saturating_sub(1)),
+            Key::PageDown => self.scroll_to(self.offset.saturating_add(self.height.max(1))),
+            Key::PageUp => self.scroll_to(self.offset.saturating_sub(self.height.max(1))),
+            Key::Char('g') | Key::Home => self.scroll_to(0),
+            Key::Char('G') | Key::End => self.scroll_to(usize::MAX),
+            Key::Char('/') => {
+                self.searching = true;
+                self.query.clear();
+            }
+            Key::Char('n') if !self.query.is_empty() => self.find(self.found.map_or(self.offset, |line| line + 1), false),
+            Key::Char('N') if !self.query.is_empty() => self.find(self.found.unwrap_or(self.offset), true),
+            Key::Esc if !self.query.is_empty() => self.query.clear(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// TextViewer component, mounted while open
+pub struct TextViewer {
+    props: Props,
+    title: String,
+    lines: Vec<String>,
+    view: TextView,
+}
+
+impl TextViewer {
+    /// Create a new popup titled `title` showing `text` from its top
+    pub fn new(title: impl Into<String>, text: &str) -> Self {
+        Self {
+            props: Props::default(),
+            title: title.into(),
+            lines: text.lines().map(str::to_string).collect(),
+            view: TextView::new(),
+        }
+    }
+}
+
+impl MockComponent for TextViewer {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let keys = tr_args("viewer-keys", &[("scroll", glyphs::up_down().to_string())]);
+        let title = format!("{} | {}", self.title, self.view.status().unwrap_or(keys));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, Role::Border))
+            .title(title);
+        let lines = self.lines.iter().map(|line| Line::from(line.clone())).collect();
+        frame.render_widget(Clear, area);
+        self.view.render(frame, area, lines, block.style(theme::style_of(&self.props, Role::Text)), &self.props);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Usize(self.view.offset))
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for TextViewer {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let Event::Keyboard(KeyEvent { code, modifiers }) = ev else {
+            return None;
+        };
+        if self.view.on_key(code, modifiers) {
+            return Some(Msg::None);
+        }
+        match code {
+            Key::Esc => Some(Msg::CloseTextViewer),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! ## WatchAddressInput
+//!
+//! Popup used to register a watch-only address for a chain
+
+use tui_realm_stdlib::Input;
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::props::{InputType, Style};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::widgets::Clear;
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, State, StateValue};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::chain::Chain;
+use crate::services::ens;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// WatchAddressInput component, mounted while an address is being entered
+pub struct WatchAddressInput {
+    component: Input,
+    chain: Chain,
+    invalid: bool,
+    /// ENS name being resolved, if any
+    resolving: Option<String>,
+    /// Last resolution error
+    error: Option<String>,
+}
+
+impl WatchAddressInput {
+    /// Create a new WatchAddressInput for the given chain
+    pub fn new(chain: Chain, current: Option<&str>) -> Self {
+        Self {
+            component: Input::default()
+                .input_type(InputType::Text)
+                .value(current.unwrap_or_default())
+                .placeholder(tr("watch-address-placeholder"), Style::default()),
+            chain,
+            invalid: false,
+            resolving: None,
+            error: None,
+        }
+    }
+
+    /// Current field content
+    fn value(&self) -> String {
+        match self.component.state() {
+            State::One(StateValue::String(value)) => value.trim().to_string(),
+            _ => String::new(),
+        }
+    }
+}
+
+impl MockComponent for WatchAddressInput {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let title = if let Some(name) = &self.resolving {
+            tr_args("watch-address-resolving", &[("name", name.clone()), ("ellipsis", glyphs::ellipsis().to_string())])
+        } else if let Some(err) = &self.error {
+            tr_args("watch-address-unresolved", &[("error", err.clone())])
+        } else if self.invalid {
+            tr_args("watch-address-invalid", &[("chain", self.chain.to_string())])
+        } else {
+            tr_args("watch-address-title", &[("chain", self.chain.to_string())])
+        };
+        frame.render_widget(Clear, area);
+        theme::style_input(&mut self.component, Role::Prompt);
+        glyphs::view_input(&mut self.component, frame, area, title);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.component.query(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.component.attr(attr, value);
+    }
+
+    fn state(&self) -> State {
+        self.component.state()
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        self.component.perform(cmd)
+    }
+}
+
+impl Component<Msg, UserEvent> for WatchAddressInput {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            }) => {
+                self.invalid = false;
+                self.error = None;
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Paste(text) => {
+                self.invalid = false;
+                text.trim().chars().for_each(|ch| {
+                    self.perform(Cmd::Type(ch));
+                });
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.invalid = false;
+                self.perform(Cmd::Delete);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right,
+                ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter,
+                ..
+            }) => {
+                let address = self.value();
+                if self.chain.is_valid_address(&address) {
+                    Some(Msg::WatchAddressSubmitted(self.chain, address))
+                } else if self.chain == Chain::Ethereum && ens::is_ens_name(&address) {
+                    self.error = None;
+                    self.resolving = Some(address.clone());
+                    Some(Msg::ResolveEnsName(address))
+                } else {
+                    self.invalid = true;
+                    Some(Msg::None)
+                }
+            }
+            Event::User(UserEvent::EnsResolved(name, address)) if self.resolving.as_ref() == Some(&name) => {
+                self.resolving = None;
+                Some(Msg::WatchAddressSubmitted(self.chain, address))
+            }
+            Event::User(UserEvent::EnsFailed(name, err)) if self.resolving.as_ref() == Some(&name) => {
+                self.resolving = None;
+                self.error = Some(err);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::CloseWatchAddressInput),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! ## WatchPanel
+//!
+//! Live view of the best rate of the pair, charting it over the session, mounted while in watch mode
+
+use std::time::Instant;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::TextModifiers;
+use tuirealm::ratatui::layout::{Constraint, Direction, Layout, Rect};
+use tuirealm::ratatui::text::{Line, Span};
+use tuirealm::ratatui::widgets::{Axis, Block, Borders, Chart, Clear, Dataset, GraphType, Paragraph};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::amount::Amount;
+use crate::models::quote::Quote;
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// Best amounts kept for the chart, 6 hours of refreshes every 30 seconds
+const HISTORY: usize = 720;
+
+/// WatchPanel component
+#[derive(Default)]
+pub struct WatchPanel {
+    props: Props,
+    /// Key of the request being watched
+    key: Option<String>,
+    /// Best quote of the last refresh
+    best: Option<Quote>,
+    /// Best amount received at the refresh before
+    previous: Option<Amount>,
+    /// Best amount received at each refresh, with when, oldest first
+    history: Vec<(Instant, Amount)>,
+    /// Best quotes of the session before watching, charted once their request is watched
+    earlier: Vec<(Instant, Quote)>,
+    updated_at: Option<Instant>,
+    /// Seconds between two refreshes, 0 when disabled
+    interval: u64,
+    /// Alert triggered by the best rate, highlighted until it no longer holds
+    alert: Option<String>,
+    /// Limit order on the pair waiting for its target, with how far the rate is from it
+    limit: Option<String>,
+}
+
+impl WatchPanel {
+    /// Create a new watch panel refreshing every `interval` seconds, charting the best quotes of the session so far
+    /// along with the ones to come
+    pub fn new(interval: u64, earlier: Vec<(Instant, Quote)>) -> Self {
+        Self {
+            interval,
+            earlier,
+            ..Self::default()
+        }
+    }
+
+    /// Record the results of a refresh, starting over when the pair or amount changed
+    fn record(&mut self, key: String, best: Option<Quote>) {
+        if self.key.as_ref() != Some(&key) {
+            self.best = None;
+            self.previous = None;
+            self.history = self
+                .earlier
+                .iter()
+                .filter(|(_, quote)| quote.request_key() == key)
+                .map(|(at, quote)| (*at, quote.amount_out))
+                .collect();
+            self.history.drain(..self.history.len().saturating_sub(HISTORY));
+            self.key = Some(key);
+        }
+        self.updated_at = Some(Instant::now());
+        let Some(best) = best else {
+            return;
+        };
+        self.previous = self.history.last().map(|(_, amount)| *amount);
+        self.history.push((Instant::now(), best.amount_out));
+        if self.history.len() > HISTORY {
+            self.history.remove(0);
+        }
+        self.best = Some(best);
+    }
+
+    /// Title showing the interval and when the next refresh is due
+    fn get_title(&self) -> String {
+        if self.interval == 0 {
+            return tr("watch-title-off");
+        }
+        match self.updated_at.map(|at| at.elapsed().as_secs()) {
+            Some(elapsed) => {
+                let args = [
+                    ("every", self.interval.to_string()),
+                    ("elapsed", elapsed.to_string()),
+                    ("next", self.interval.saturating_sub(elapsed).to_string()),
+                ];
+                tr_args("watch-title-updated", &args)
+            }
+            None => tr_args("watch-title-waiting", &[("every", self.interval.to_string())]),
+        }
+    }
+
+    /// Lines describing the best rate and how it moved, after the triggered alert and the limit order if any
+    fn summary(&self) -> Vec<Line<'static>> {
+        let Some(best) = &self.best else {
+            return vec![Line::from(tr("watch-empty"))];
+        };
+        let amount = best.amount_out.to_f64();
+        let to = best.to.label();
+
+        let change = match self.previous.map(|previous| previous.to_f64()).filter(|previous| *previous > 0.0) {
+            Some(previous) => {
+                let percent = (amount - previous) / previous * 100.0;
+                let (arrow, role) = if percent > 0.0 {
+                    (glyphs::trend(true), Role::Positive)
+                } else if percent < 0.0 {
+                    (glyphs::trend(false), Role::Negative)
+                } else {
+                    ("=", Role::Muted)
+                };
+                let style = theme::style_of(&self.props, role);
+                let change = tr_args("watch-change", &[("change", format!("{percent:+.2}"))]);
+                Line::styled(format!("{arrow} {change}"), style)
+            }
+            None => Line::styled(tr("watch-first-refresh"), theme::style_of(&self.props, Role::Muted)),
+        };
+
+        let amounts = || self.history.iter().map(|(_, amount)| amount);
+        let low = amounts().min().unwrap_or(&best.amount_out);
+        let high = amounts().max().unwrap_or(&best.amount_out);
+        let trend = match self.history.first().map(|(_, first)| first.to_f64()).filter(|first| *first > 0.0) {
+            Some(first) if self.history.len() > 1 => {
+                let change = format!("{:+.2}", (amount - first) / first * 100.0);
+                tr_args("watch-trend", &[("change", change)])
+            }
+            _ => String::new(),
+        };
+        let mut lines = vec![];
+        if let Some(alert) = &self.alert {
+            let style = theme::style_of(&self.props, Role::Negative);
+            lines.push(Line::styled(
+                tr_args("watch-alert", &[("alert", alert.clone())]),
+                style.add_modifier(TextModifiers::REVERSED | TextModifiers::BOLD),
+            ));
+        }
+        if let Some(limit) = &self.limit {
+            let limit = tr_args("watch-limit", &[("limit", limit.clone())]);
+            lines.push(Line::styled(limit, theme::style_of(&self.props, Role::Warning)));
+        }
+        let best_args = [
+            ("amount", best.amount_out.to_string()),
+            ("asset", to.clone()),
+            ("provider", best.provider.clone()),
+            ("rate", format!("{:.6}", best.rate())),
+        ];
+        let session_args = [
+            ("low", low.to_string()),
+            ("high", high.to_string()),
+            ("asset", to),
+            ("count", self.history.len().to_string()),
+            ("trend", trend),
+        ];
+        lines.extend([
+            Line::styled(tr_args("watch-best", &best_args), theme::style_of(&self.props, Role::Positive)),
+            change,
+            Line::from(tr_args("watch-session", &session_args)),
+        ]);
+        lines
+    }
+
+    /// Best amounts over the session, minutes ago on the x axis
+    fn points(&self) -> Vec<(f64, f64)> {
+        let Some((now, _)) = self.history.last() else {
+            return vec![];
+        };
+        self.history
+            .iter()
+            .map(|(at, amount)| (-now.duration_since(*at).as_secs_f64() / 60.0, amount.to_f64()))
+            .collect()
+    }
+
+    /// Line chart of `points`
+    fn chart<'a>(&self, points: &'a [(f64, f64)]) -> Chart<'a> {
+        let span = points.first().map_or(0.0, |(minutes, _)| -minutes).max(1.0);
+        let low = points.iter().map(|(_, amount)| *amount).fold(f64::INFINITY, f64::min);
+        let high = points.iter().map(|(_, amount)| *amount).fold(f64::NEG_INFINITY, f64::max);
+        // Keep a flat line off the edges
+        let margin = if high > low { (high - low) * 0.1 } else { high.abs() * 0.001 + f64::EPSILON };
+        let label = |amount: f64| Span::styled(format!("{amount:.6}"), theme::style_of(&self.props, Role::Muted));
+
+        let dataset = Dataset::default()
+            .marker(glyphs::marker())
+            .graph_type(GraphType::Line)
+            .style(theme::style_of(&self.props, Role::Accent))
+            .data(points);
+        Chart::new(vec![dataset])
+            .x_axis(
+                Axis::default()
+                    .bounds([-span, 0.0])
+                    .labels([tr_args("chart-ago", &[("minutes", format!("{span:.0}"))]), tr("chart-now")])
+                    .style(theme::style_of(&self.props, Role::Muted)),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([low - margin, high + margin])
+                    .labels([label(low), label(high)])
+                    .style(theme::style_of(&self.props, Role::Muted)),
+            )
+    }
+}
+
+impl MockComponent for WatchPanel {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let border = if self.alert.is_some() { Role::Negative } else { Role::Accent };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, border))
+            .title(self.get_title());
+        let inner = block.inner(area);
+        let summary = self.summary();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(summary.len() as u16), Constraint::Min(0)])
+            .split(inner);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+        frame.render_widget(Paragraph::new(summary), chunks[0]);
+
+        let points = self.points();
+        if !points.is_empty() {
+            frame.render_widget(self.chart(&points), chunks[1]);
+        }
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Custom("refresh_interval") => {
+                if let AttrValue::Number(interval) = value {
+                    self.interval = interval.max(0) as u64;
+                }
+            }
+            // Empty once the alert no longer holds
+            Attribute::Custom("alert") => {
+                if let AttrValue::String(alert) = value {
+                    self.alert = (!alert.is_empty()).then_some(alert);
+                }
+            }
+            // Empty once the limit order is met or cancelled
+            Attribute::Custom("limit") => {
+                if let AttrValue::String(limit) = value {
+                    self.limit = (!limit.is_empty()).then_some(limit);
+                }
+            }
+            _ => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for WatchPanel {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::User(UserEvent::QuotesLoaded(request, quotes, best, _)) => {
+                let best = best.and_then(|index| quotes.get(index).cloned());
+                self.record(request.key(), best.clone());
+                Some(best.map_or(Msg::None, Msg::BestQuoteWatched))
+            }
+            // Count down to the next refresh
+            Event::Tick => Some(Msg::None),
+            _ => None,
+        }
+    }
+}
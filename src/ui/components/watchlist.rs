@@ -0,0 +1,232 @@
+//! ## Watchlist
+//!
+//! Watchlist screen, showing the best quote of each watched pair, refreshed on a timer while mounted
+
+use std::time::Instant;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Rect};
+use tuirealm::ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table, TableState};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State, StateValue};
+
+use crate::i18n::{self, tr, tr_args};
+use crate::models::amount::Amount;
+use crate::models::draft::Draft;
+use crate::models::quote::{Quote, QuoteRequest};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// A watched pair and its latest best quote
+struct Entry {
+    request: QuoteRequest,
+    best: Option<Quote>,
+    /// Best amount received at the refresh before
+    previous: Option<Amount>,
+    /// Whether its quotes are being fetched
+    loading: bool,
+}
+
+/// Watchlist component
+pub struct Watchlist {
+    props: Props,
+    entries: Vec<Entry>,
+    /// Seconds between two refreshes, 0 when off
+    interval: u64,
+    /// Last refresh of the pairs
+    refreshed_at: Option<Instant>,
+    current_index: usize,
+    /// Selection and scroll of the rendered table, kept across frames
+    state: TableState,
+}
+
+impl Watchlist {
+    /// Create a new watchlist of the pairs quoted by `requests`, refreshed every `interval` seconds
+    pub fn new(requests: Vec<QuoteRequest>, interval: u64) -> Self {
+        Self {
+            props: Props::default(),
+            entries: requests
+                .into_iter()
+                .map(|request| Entry {
+                    request,
+                    best: None,
+                    previous: None,
+                    loading: false,
+                })
+                .collect(),
+            interval,
+            refreshed_at: None,
+            current_index: 0,
+            state: TableState::default(),
+        }
+    }
+
+    /// Title showing when the next refresh is due
+    fn get_title(&self) -> String {
+        let refresh = match self.refreshed_at.map(|at| at.elapsed().as_secs()) {
+            _ if self.interval == 0 => tr("watchlist-refresh-off"),
+            Some(elapsed) => {
+                let next = self.interval.saturating_sub(elapsed);
+                tr_args("watchlist-refresh-next", &[("every", self.interval.to_string()), ("next", next.to_string())])
+            }
+            None => tr_args("watchlist-refresh", &[("every", self.interval.to_string())]),
+        };
+        tr_args("watchlist-title", &[("count", self.entries.len().to_string()), ("refresh", refresh)])
+    }
+
+    /// Cells of a pair, its best quote and how it moved since the refresh before
+    fn cells(&self, entry: &Entry) -> Vec<Cell<'static>> {
+        let request = &entry.request;
+        let mut cells = vec![
+            Cell::from(format!("{} {} {}", request.from.label(), glyphs::arrow(), request.to.label())),
+            Cell::from(format!("{} {}", i18n::isolate(&request.amount.to_string()), request.from.ticker)),
+        ];
+        let Some(best) = &entry.best else {
+            let status = tr(if entry.loading { "watchlist-quoting" } else { "watchlist-no-quote" });
+            cells.push(Cell::from(status).style(theme::style_of(&self.props, Role::Muted)));
+            return cells;
+        };
+        let (change, role) = match entry.previous.map(|previous| previous.to_f64()).filter(|previous| *previous > 0.0) {
+            Some(previous) => {
+                let percent = (best.amount_out.to_f64() - previous) / previous * 100.0;
+                let role = if percent > 0.0 {
+                    Role::Positive
+                } else if percent < 0.0 {
+                    Role::Negative
+                } else {
+                    Role::Muted
+                };
+                (format!("{percent:+.2}%"), role)
+            }
+            None => ("-".to_string(), Role::Muted),
+        };
+        let age = if entry.loading { tr("watchlist-quoting") } else { best.age_label() };
+        cells.extend([
+            Cell::from(format!("{} {}", i18n::isolate(&best.amount_out.to_string()), request.to.ticker)),
+            Cell::from(best.provider.clone()),
+            Cell::from(format!("{:.6}", best.rate())),
+            Cell::from(change).style(theme::style_of(&self.props, role)),
+            Cell::from(age),
+        ]);
+        cells
+    }
+}
+
+impl MockComponent for Watchlist {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let mut rows: Vec<Row> = self.entries.iter().map(|entry| Row::new(self.cells(entry))).collect();
+        if rows.is_empty() {
+            rows.push(Row::new(vec![Cell::from(tr("watchlist-empty"))]));
+        }
+
+        let headers = [
+            "watchlist-pair",
+            "watchlist-amount",
+            "watchlist-best",
+            "watchlist-provider",
+            "watchlist-rate",
+            "watchlist-change",
+            "watchlist-age",
+        ];
+        let header = Row::new(headers.map(|h| {
+            Cell::from(tr(h)).style(theme::style_of(&self.props, Role::Header))
+        }))
+        .style(theme::style_of(&self.props, Role::Header))
+        .height(1);
+
+        let widths = [
+            Constraint::Percentage(20),
+            Constraint::Percentage(15),
+            Constraint::Percentage(20),
+            Constraint::Percentage(12),
+            Constraint::Percentage(13),
+            Constraint::Percentage(10),
+            Constraint::Percentage(10),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(glyphs::border())
+                    .border_style(theme::style_of(&self.props, Role::Border))
+                    .title(self.get_title()),
+            )
+            .row_highlight_style(theme::style_of(&self.props, Role::Selection));
+
+        self.state.select((!self.entries.is_empty()).then_some(self.current_index));
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        // The pairs are being quoted again
+        if attr == Attribute::Custom("refreshing") {
+            self.refreshed_at = Some(Instant::now());
+            for entry in &mut self.entries {
+                entry.loading = true;
+            }
+        }
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        if self.entries.is_empty() {
+            State::None
+        } else {
+            State::One(StateValue::Usize(self.current_index))
+        }
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for Watchlist {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let len = self.entries.len();
+        match ev {
+            Event::User(UserEvent::WatchlistQuoted(request, best)) => {
+                let key = request.key();
+                let entry = self.entries.iter_mut().find(|entry| entry.request.key() == key)?;
+                entry.loading = false;
+                // A pair nobody quoted this time keeps its last quote
+                if best.is_some() {
+                    entry.previous = entry.best.as_ref().map(|quote| quote.amount_out);
+                    entry.best = best;
+                }
+                Some(Msg::None)
+            }
+            // Count down to the next refresh
+            Event::Tick => Some(Msg::None),
+            Event::Keyboard(KeyEvent { code, .. }) => match code {
+                Key::Down | Key::Char('j') if len > 0 => {
+                    self.current_index = (self.current_index + 1) % len;
+                    Some(Msg::None)
+                }
+                Key::Up | Key::Char('k') if len > 0 => {
+                    self.current_index = (self.current_index + len - 1) % len;
+                    Some(Msg::None)
+                }
+                Key::Enter => self.entries.get(self.current_index).map(|entry| {
+                    Msg::LoadWatchedPair(Draft {
+                        from: Some(entry.request.from.id()),
+                        to: Some(entry.request.to.id()),
+                        amount: Some(entry.request.amount.to_string()),
+                        ..Draft::default()
+                    })
+                }),
+                Key::Esc => Some(Msg::CloseWatchlist),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
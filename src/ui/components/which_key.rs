@@ -0,0 +1,146 @@
+//! ## WhichKey
+//!
+//! Popup listing the keys available where the keyboard is, opened with `?` or after a pause in the asset table
+
+use std::time::{Duration, Instant};
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::ratatui::layout::{Constraint, Layout, Rect};
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tuirealm::{AttrValue, Attribute, Component, Event, Frame, MockComponent, Props, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::i18n::{tr, tr_args};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
+use crate::ui::keymap::{Context, Keymap};
+use crate::ui::msg::Msg;
+use crate::ui::theme::{self, Role};
+
+/// WhichKey component, never focused: it sees the keys pressed through its subscription, and the context of the
+/// keyboard as its `context` attribute
+pub struct WhichKey {
+    props: Props,
+    keymap: Keymap,
+    /// Time without a keystroke before opening, if it opens on its own
+    delay: Option<Duration>,
+    context: Context,
+    /// Last keystroke, or start
+    idle_since: Instant,
+    open: bool,
+}
+
+impl WhichKey {
+    /// Create a new closed popup listing the keys of `keymap`, opening after `delay` without a keystroke
+    pub fn new(keymap: &Keymap, delay: Option<Duration>) -> Self {
+        Self {
+            props: Props::default(),
+            keymap: keymap.clone(),
+            delay,
+            context: Context::default(),
+            idle_since: Instant::now(),
+            open: false,
+        }
+    }
+
+    /// Whether `?` lists the keys in the current context, where it types nothing
+    fn on_demand(&self) -> bool {
+        !matches!(self.context, Context::Amount | Context::Popup)
+    }
+}
+
+impl MockComponent for WhichKey {
+    /// Draw over the bottom of `area`, in as many columns as fit
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+        let hints: Vec<String> = self
+            .keymap
+            .hints(self.context)
+            .into_iter()
+            .map(|binding| tr_args("key-hint", &[("key", binding.key), ("action", tr(binding.action))]))
+            .collect();
+        let width = hints.iter().map(|hint| hint.width()).max().unwrap_or(0) as u16 + 2;
+        let columns = (area.width.saturating_sub(2) / width.max(1)).max(1) as usize;
+        let rows = hints.len().div_ceil(columns);
+        let height = (rows as u16 + 2).min(area.height);
+        let area = Rect {
+            y: area.bottom() - height,
+            height,
+            ..area
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(glyphs::border())
+            .border_style(theme::style_of(&self.props, Role::Prompt))
+            .title(tr("which-key-title"));
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+        let cells = Layout::horizontal(vec![Constraint::Length(width); columns]).split(inner);
+        for (column, area) in hints.chunks(rows.max(1)).zip(cells.iter()) {
+            let lines: Vec<Line> = column.iter().map(|hint| Line::from(hint.as_str())).collect();
+            frame.render_widget(Paragraph::new(lines).style(theme::style_of(&self.props, Role::Text)), *area);
+        }
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (Attribute::Custom("context"), AttrValue::String(context)) => {
+                let context = context.parse().unwrap_or_default();
+                // The keys listed no longer apply once the keyboard moved
+                if context != self.context {
+                    self.open = false;
+                    self.idle_since = Instant::now();
+                }
+                self.context = context;
+            }
+            (attr, value) => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for WhichKey {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('?'),
+                ..
+            }) if !self.open && self.on_demand() => {
+                self.open = true;
+                Some(Msg::None)
+            }
+            Event::Keyboard(_) => {
+                // Any key closes it, and still does what it does
+                self.idle_since = Instant::now();
+                self.open.then(|| {
+                    self.open = false;
+                    Msg::None
+                })
+            }
+            Event::Tick => {
+                let idle = self.delay.is_some_and(|delay| self.idle_since.elapsed() >= delay);
+                (!self.open && idle && self.context == Context::Assets).then(|| {
+                    self.open = true;
+                    Msg::None
+                })
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! ## Control
+//!
+//! Local Unix socket driving the running TUI, one command per line, e.g. from a script or a second terminal:
+//! `set pair BTC ETH`, `set amount 0.1`, `quote`, `export csv`, `quit`
+
+use std::fmt::{self, Display};
+use std::path::Path;
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli::find_asset;
+use crate::errors::SwapError;
+use crate::models::asset::Asset;
+use crate::ui::event::UserEvent;
+
+/// Command received on the control socket, assets given by id once checked
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Control {
+    /// Choose the FROM and TO assets
+    SetPair(String, String),
+    /// Choose the FROM asset
+    SetFrom(String),
+    /// Choose the TO asset
+    SetTo(String),
+    /// Submit a FROM amount
+    SetAmount(String),
+    /// Fetch the quotes of the form
+    Quote,
+    /// Export the quotes shown to CSV
+    ExportCsv,
+    /// Quit the application
+    Quit,
+}
+
+impl Control {
+    /// Parse a command line, checking its assets against the listed ones
+    pub fn parse(line: &str, assets: &[Asset]) -> Result<Self, String> {
+        let id = |query: &str| find_asset(assets, query).map(|(_, asset)| asset.id().to_string());
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            ["set", "pair", from, to] => Ok(Self::SetPair(id(from)?, id(to)?)),
+            ["set", "from", asset] => Ok(Self::SetFrom(id(asset)?)),
+            ["set", "to", asset] => Ok(Self::SetTo(id(asset)?)),
+            ["set", "amount", amount] => Ok(Self::SetAmount(amount.to_string())),
+            ["quote"] => Ok(Self::Quote),
+            ["export", "csv"] => Ok(Self::ExportCsv),
+            ["export", format] => Err(format!("unsupported export format: {format}")),
+            ["quit"] => Ok(Self::Quit),
+            [] => Err("empty command".to_string()),
+            _ => Err(format!("unknown command: {line}")),
+        }
+    }
+}
+
+impl Display for Control {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SetPair(from, to) => write!(f, "set pair {from} {to}"),
+            Self::SetFrom(asset) => write!(f, "set from {asset}"),
+            Self::SetTo(asset) => write!(f, "set to {asset}"),
+            Self::SetAmount(amount) => write!(f, "set amount {amount}"),
+            Self::Quote => write!(f, "quote"),
+            Self::ExportCsv => write!(f, "export csv"),
+            Self::Quit => write!(f, "quit"),
+        }
+    }
+}
+
+/// Control socket being listened on, removed once dropped
+#[derive(Debug)]
+pub struct ControlSocket {
+    #[cfg(unix)]
+    path: PathBuf,
+}
+
+impl Drop for ControlSocket {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Listen on a socket at `path` in the background, sending each valid command to the UI on `tx`
+///
+/// Each line is answered with `ok` once the command is queued, or `error: <reason>`.
+#[cfg(unix)]
+pub fn listen(path: &Path, assets: Vec<Asset>, tx: Sender<UserEvent>) -> Result<ControlSocket, SwapError> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::fs::{self, DirBuilder, Permissions};
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::thread;
+
+    use tracing::{debug, info};
+
+    // A socket left by a crashed session is replaced, one still answering is not
+    if path.exists() {
+        if UnixStream::connect(path).is_ok() {
+            return Err(SwapError::Io(format!("{}: control socket already in use", path.display())));
+        }
+        fs::remove_file(path).map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))?;
+    }
+    // Only the user may drive the session, whatever the umask: the socket is bound and restricted in a directory
+    // only the user may enter, then moved in place, never reachable by others in between
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let private = path.with_file_name(format!(".{name}.{}", std::process::id()));
+    let staged = private.join("socket");
+    let bound = DirBuilder::new()
+        .mode(0o700)
+        .create(&private)
+        .and_then(|()| UnixListener::bind(&staged))
+        .and_then(|listener| fs::set_permissions(&staged, Permissions::from_mode(0o600)).map(|()| listener))
+        .and_then(|listener| fs::rename(&staged, path).map(|()| listener));
+    let _ = fs::remove_file(&staged);
+    let _ = fs::remove_dir(&private);
+    let listener = bound.map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))?;
+    info!(path = %path.display(), "control socket listening");
+
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let assets = assets.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut writer = &stream;
+                for line in BufReader::new(&stream).lines().map_while(Result::ok) {
+                    let reply = match Control::parse(&line, &assets) {
+                        Ok(control) => {
+                            debug!(%control, "control command");
+                            match tx.send(UserEvent::Control(control)) {
+                                Ok(()) => "ok".to_string(),
+                                // The UI is gone
+                                Err(_) => return,
+                            }
+                        }
+                        Err(err) => format!("error: {err}"),
+                    };
+                    if writeln!(writer, "{reply}").is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    Ok(ControlSocket { path: path.to_path_buf() })
+}
+
+/// Control sockets are Unix sockets
+#[cfg(not(unix))]
+pub fn listen(path: &Path, _assets: Vec<Asset>, _tx: Sender<UserEvent>) -> Result<ControlSocket, SwapError> {
+    Err(SwapError::Io(format!("{}: control sockets need a Unix system", path.display())))
+}
@@ -0,0 +1,69 @@
+//! ## Event
+//!
+//! User events produced by background services
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::SwapError;
+use crate::models::amount::{self, Amount};
+use crate::models::asset::AssetId;
+use crate::models::chart::{PricePoint, Timeframe};
+use crate::models::depth::Depth;
+use crate::models::fee::NetworkFee;
+use crate::models::portfolio::Price;
+use crate::models::provider::Capabilities;
+use crate::models::quote::{self, Quote, QuoteFailure, QuoteProgress, QuoteRequest};
+use crate::ui::control::Control;
+
+/// Events sent by background services to the UI, serialized in recordings
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Serialize, Deserialize)]
+pub enum UserEvent {
+    /// Balance of an asset held by the watched address was fetched
+    BalanceLoaded(AssetId, #[serde(with = "amount::exact")] Amount),
+    /// Balance lookup for an asset failed
+    BalanceFailed(AssetId, String),
+    /// Network fees of a deposit of the asset were estimated, once per preset when available
+    NetworkFeeLoaded(AssetId, Vec<NetworkFee>),
+    /// Network fee estimation for the asset failed
+    NetworkFeeFailed(AssetId, String),
+    /// ENS name resolved to an address
+    EnsResolved(String, String),
+    /// ENS name could not be resolved
+    EnsFailed(String, String),
+    /// Allowance granted by the watched address to a spender of the asset was fetched
+    AllowanceLoaded(AssetId, String, #[serde(with = "amount::exact")] Amount),
+    /// Allowance lookup for the asset and spender failed
+    AllowanceFailed(AssetId, String, String),
+    /// Current prices of the listed assets were fetched
+    PricesLoaded(Vec<(AssetId, Price)>),
+    /// Price lookup failed
+    PricesFailed(String),
+    /// Price history over the timeframe was fetched, oldest first
+    PriceHistoryLoaded(Timeframe, Vec<PricePoint>),
+    /// Price history lookup over the timeframe failed
+    PriceHistoryFailed(Timeframe, String),
+    /// On-chain aggregators quoted multiples of the amount of a request, by key
+    DepthLoaded(String, Vec<Depth>),
+    /// Capabilities of a provider, by name, were discovered
+    ProviderDiscovered(String, Capabilities),
+    /// Pair of the watchlist was quoted, with its best quote if any provider quoted it
+    WatchlistQuoted(QuoteRequest, #[serde(with = "quote::exact::optional")] Option<Quote>),
+    /// Amount out of the best quote of a request, by key, was quoted back, with the best quote back if any provider
+    /// quoted it
+    ReverseQuoted(String, #[serde(with = "quote::exact::optional")] Option<Quote>),
+    /// Quotes for a request were fetched, with the index of the best one and the providers that did not quote
+    QuotesLoaded(
+        QuoteRequest,
+        #[serde(with = "quote::exact::many")] Vec<Quote>,
+        Option<usize>,
+        Vec<QuoteFailure>,
+    ),
+    /// Providers answered a request, by key
+    QuoteProgress(String, QuoteProgress),
+    /// A provider failed to quote
+    ProviderFailed(QuoteFailure),
+    /// A single provider was asked again for a request, by key
+    ProviderRetried(String, String, #[serde(with = "quote::exact::result")] Result<Quote, SwapError>),
+    /// A command was received on the control socket
+    Control(Control),
+}
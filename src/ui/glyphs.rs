@@ -0,0 +1,238 @@
+//! ## Glyphs
+//!
+//! Characters the UI is drawn with: rounded box-drawing borders, arrows, braille spinners and marks, or ASCII stand-ins
+//! on terminals and multiplexers known to mangle them, detected from the locale and `TERM` unless set in the config
+
+use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tracing::info;
+use tui_realm_stdlib::Input;
+use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, Style};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::symbols::{border, line, Marker};
+use tuirealm::ratatui::widgets::Block;
+use tuirealm::{AttrValue, Attribute, Frame, MockComponent};
+
+use crate::config::Charset;
+
+/// Whether the UI is drawn with ASCII characters only
+static ASCII: AtomicBool = AtomicBool::new(false);
+
+/// Whether the terminal font has the Nerd Fonts symbols
+static NERD_FONT: AtomicBool = AtomicBool::new(false);
+
+/// Terminals without glyphs for rounded corners, arrows or braille
+const ASCII_TERMS: [&str; 4] = ["dumb", "linux", "vt100", "vt220"];
+
+/// Borders drawn with `+`, `-` and `|`
+const ASCII_BORDER: border::Set = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+/// Lines drawn with `-` and `|`
+const ASCII_LINE: line::Set = line::Set {
+    vertical: "|",
+    horizontal: "-",
+    top_right: "+",
+    top_left: "+",
+    bottom_right: "+",
+    bottom_left: "+",
+    vertical_left: "+",
+    vertical_right: "+",
+    horizontal_down: "+",
+    horizontal_up: "+",
+    cross: "+",
+};
+
+/// Frames of the braille spinner, and of its ASCII stand-in
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const ASCII_SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+
+/// Icons of the assets by ticker: Unicode, and Nerd Fonts when it has one
+const ICONS: [(&str, &str, Option<&str>); 4] = [
+    ("BTC", "₿", Some("\u{f15a}")),
+    ("ETH", "Ξ", Some("\u{f086a}")),
+    ("SOL", "◎", None),
+    ("USDC", "$", Some("\u{f155}")),
+];
+
+/// Draw the UI with the characters of `charset`, detecting whether the terminal renders Unicode when `auto`, and
+/// the Nerd Fonts symbols when `nerd_font`
+pub fn init(charset: Charset, nerd_font: bool) {
+    let ascii = match charset {
+        Charset::Auto => !detect(),
+        Charset::Unicode => false,
+        Charset::Ascii => true,
+    };
+    info!(charset = charset.name(), ascii, nerd_font, "glyphs picked");
+    ASCII.store(ascii, Ordering::Relaxed);
+    NERD_FONT.store(nerd_font, Ordering::Relaxed);
+}
+
+/// Whether the terminal likely renders Unicode: a UTF-8 locale, the first of `LC_ALL`, `LC_CTYPE` and `LANG` set
+/// winning as in `setlocale`, on a terminal other than the Linux console and the old hardware ones
+fn detect() -> bool {
+    let term = env::var("TERM").unwrap_or_default();
+    if ASCII_TERMS.contains(&term.as_str()) {
+        return false;
+    }
+    // Windows Terminal renders Unicode, the legacy console does not, and neither sets a locale
+    if cfg!(windows) {
+        return env::var_os("WT_SESSION").is_some();
+    }
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+        .is_some_and(|locale| {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+/// Whether the UI is drawn with ASCII characters only
+pub fn is_ascii() -> bool {
+    ASCII.load(Ordering::Relaxed)
+}
+
+/// Pick `unicode`, or `ascii` when drawing with ASCII characters only
+fn pick<T>(unicode: T, ascii: T) -> T {
+    if is_ascii() {
+        ascii
+    } else {
+        unicode
+    }
+}
+
+/// Characters of block borders
+pub fn border() -> border::Set {
+    border_of(BorderType::Rounded)
+}
+
+/// Characters of block borders of type `kind`, themes may pick
+pub fn border_of(kind: BorderType) -> border::Set {
+    pick(kind.to_border_set(), ASCII_BORDER)
+}
+
+/// Characters of gauges
+pub fn line() -> line::Set {
+    pick(line::NORMAL, ASCII_LINE)
+}
+
+/// Points of charts; the ASCII fallback keeps a dot, charts having no ASCII marker
+pub fn marker() -> Marker {
+    pick(Marker::Braille, Marker::Dot)
+}
+
+/// Arrow between the assets of a pair
+pub fn arrow() -> &'static str {
+    pick("→", "->")
+}
+
+/// Up and down arrow keys
+pub fn up_down() -> &'static str {
+    pick("↑/↓", "Up/Down")
+}
+
+/// Left and right arrow keys
+pub fn left_right() -> &'static str {
+    pick("←/→", "Left/Right")
+}
+
+/// Mark of a text cut short or of a wait
+pub fn ellipsis() -> &'static str {
+    pick("…", "...")
+}
+
+/// Cursor at the end of a text being typed
+pub fn cursor() -> &'static str {
+    pick("▏", "_")
+}
+
+/// Frames of the spinner
+pub fn spinner() -> &'static [char] {
+    pick(&SPINNER, &ASCII_SPINNER)
+}
+
+/// Frame standing in for the spinner when motion is reduced
+pub fn still() -> char {
+    pick('⠿', '*')
+}
+
+/// Sign before a least amount
+pub fn at_least() -> &'static str {
+    pick("≥", ">=")
+}
+
+/// Sign before a greatest amount
+pub fn at_most() -> &'static str {
+    pick("≤", "<=")
+}
+
+/// Sign of a product
+pub fn times() -> &'static str {
+    pick("×", "x")
+}
+
+/// Mark of a success
+pub fn check() -> &'static str {
+    pick("✓", "v")
+}
+
+/// Mark of a failure
+pub fn cross() -> &'static str {
+    pick("✗", "x")
+}
+
+/// Mark of a warning
+pub fn warning() -> &'static str {
+    pick("⚠", "!")
+}
+
+/// Marks of a rise and a fall
+pub fn trend(rising: bool) -> &'static str {
+    if rising {
+        pick("▲", "^")
+    } else {
+        pick("▼", "v")
+    }
+}
+
+/// Icon of the asset with `ticker`, from the Nerd Fonts when the font has them, none in ASCII or for assets without
+pub fn icon(ticker: &str) -> Option<&'static str> {
+    if is_ascii() {
+        return None;
+    }
+    let (_, unicode, nerd) = ICONS.iter().find(|(listed, _, _)| *listed == ticker)?;
+    Some(nerd.filter(|_| NERD_FONT.load(Ordering::Relaxed)).unwrap_or(unicode))
+}
+
+/// Draw `input` in `area` under `title`; inputs only take ratatui's border types, so their ASCII border is drawn
+/// around them, in the color of theirs
+pub fn view_input(input: &mut Input, frame: &mut Frame, area: Rect, title: String) {
+    if !is_ascii() {
+        input.attr(Attribute::Title, AttrValue::Title((title, Alignment::Left)));
+        input.view(frame, area);
+        return;
+    }
+    let color = match input.query(Attribute::Borders) {
+        Some(AttrValue::Borders(borders)) => borders.color,
+        _ => Color::Reset,
+    };
+    let block = Block::bordered()
+        .border_set(ASCII_BORDER)
+        .border_style(Style::default().fg(color))
+        .title(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    input.attr(Attribute::Borders, AttrValue::Borders(Borders::default().sides(BorderSides::NONE).color(color)));
+    input.attr(Attribute::Title, AttrValue::Title((String::new(), Alignment::Left)));
+    input.view(frame, inner);
+}
@@ -0,0 +1,201 @@
+//! ## Harness
+//!
+//! Drives the application on an in-memory terminal with scripted events, for tests
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+use tuirealm::listener::{ListenerResult, Poll};
+use tuirealm::ratatui::backend::TestBackend;
+use tuirealm::ratatui::buffer::Buffer;
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::{CompletedFrame, Frame, Terminal};
+use tuirealm::terminal::{TerminalAdapter, TerminalError, TerminalResult};
+use tuirealm::{Event, PollStrategy};
+
+use crate::cli::Prefill;
+use crate::config::{Config, Section};
+use crate::services::http;
+use crate::ui::event::UserEvent;
+use crate::ui::model::Model;
+use crate::ui::recording::EventSource;
+
+/// Events queued by the harness, not yet taken by the application
+type Queue = Arc<Mutex<VecDeque<Event<UserEvent>>>>;
+
+/// Terminal adapter drawing to ratatui's `TestBackend`, keeping the last frame drawn
+pub struct TestTerminalAdapter {
+    terminal: Terminal<TestBackend>,
+    screen: Arc<Mutex<Buffer>>,
+}
+
+impl TestTerminalAdapter {
+    /// Create a `width` x `height` terminal, with the buffer its frames are copied to
+    pub fn new(width: u16, height: u16) -> (Self, Arc<Mutex<Buffer>>) {
+        let terminal = Terminal::new(TestBackend::new(width, height)).expect("the test backend cannot fail");
+        let screen = Arc::new(Mutex::new(Buffer::empty(Rect::new(0, 0, width, height))));
+        let adapter = Self {
+            terminal,
+            screen: Arc::clone(&screen),
+        };
+        (adapter, screen)
+    }
+}
+
+impl TerminalAdapter for TestTerminalAdapter {
+    fn draw<F>(&mut self, render_callback: F) -> TerminalResult<CompletedFrame<'_>>
+    where
+        F: FnOnce(&mut Frame<'_>),
+    {
+        let frame = self.terminal.draw(render_callback).map_err(|_| TerminalError::CannotDrawFrame)?;
+        if let Ok(mut screen) = self.screen.lock() {
+            *screen = frame.buffer.clone();
+        }
+        Ok(frame)
+    }
+
+    fn clear_screen(&mut self) -> TerminalResult<()> {
+        self.terminal.clear().map_err(|_| TerminalError::CannotClear)
+    }
+
+    fn enable_raw_mode(&mut self) -> TerminalResult<()> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> TerminalResult<()> {
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> TerminalResult<()> {
+        Ok(())
+    }
+
+    fn leave_alternate_screen(&mut self) -> TerminalResult<()> {
+        Ok(())
+    }
+
+    fn enable_mouse_capture(&mut self) -> TerminalResult<()> {
+        Ok(())
+    }
+
+    fn disable_mouse_capture(&mut self) -> TerminalResult<()> {
+        Ok(())
+    }
+}
+
+/// Port yielding the events queued by the harness, in order
+struct ScriptPort {
+    queue: Queue,
+}
+
+impl Poll<UserEvent> for ScriptPort {
+    fn poll(&mut self) -> ListenerResult<Option<Event<UserEvent>>> {
+        Ok(self.queue.lock().ok().and_then(|mut queue| queue.pop_front()))
+    }
+}
+
+/// The application on a test terminal, handling only the events it is sent
+///
+/// Background services run offline and their results are dropped: tests send the results they need
+/// with [`Harness::service`], so that every run renders the same.
+pub struct Harness {
+    pub model: Model<TestTerminalAdapter>,
+    queue: Queue,
+    screen: Arc<Mutex<Buffer>>,
+}
+
+impl Harness {
+    /// Start the application with `config` on a `width` x `height` terminal
+    pub fn new(mut config: Config, width: u16, height: u16) -> Self {
+        // The first configuration wins: no test reaches the network
+        let _ = http::configure(None, true);
+        // Pauses depend on the speed of the machine: tests open the keys popup with `?`
+        config.which_key.delay = 0;
+        // Neither is the clock: tests leave the footer out
+        config.layout.main.retain(|section| *section != Section::Footer);
+
+        let (adapter, screen) = TestTerminalAdapter::new(width, height);
+        let queue = Queue::default();
+        let port = ScriptPort {
+            queue: Arc::clone(&queue),
+        };
+        let mut harness = Self {
+            model: Model::new(adapter, config, EventSource::Port(Box::new(port))),
+            queue,
+            screen,
+        };
+        harness.model.view();
+        harness
+    }
+
+    /// Fill the form as the command line would
+    pub fn prefill(&mut self, prefill: Prefill) {
+        self.model.prefill(prefill);
+        self.settle();
+    }
+
+    /// Handle an event, then redraw
+    pub fn send(&mut self, event: Event<UserEvent>) {
+        if let Ok(mut queue) = self.queue.lock() {
+            queue.push_back(event);
+        }
+        self.settle();
+    }
+
+    /// Press a key
+    pub fn press(&mut self, key: Key) {
+        self.send(Event::Keyboard(KeyEvent::new(key, KeyModifiers::NONE)));
+    }
+
+    /// Press a character with Ctrl held
+    pub fn press_ctrl(&mut self, c: char) {
+        self.send(Event::Keyboard(KeyEvent::new(Key::Char(c), KeyModifiers::CONTROL)));
+    }
+
+    /// Type each character of `text`
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.press(Key::Char(c));
+        }
+    }
+
+    /// Deliver the result of a background service
+    pub fn service(&mut self, event: UserEvent) {
+        self.send(Event::User(event));
+    }
+
+    /// Let a tick of the UI clock pass
+    pub fn tick(&mut self) {
+        self.send(Event::Tick);
+    }
+
+    /// Handle every queued event, then draw the screen
+    fn settle(&mut self) {
+        while self.queue.lock().is_ok_and(|queue| !queue.is_empty()) {
+            self.model.tick(PollStrategy::TryFor(Duration::from_millis(5))).expect("the listener failed");
+        }
+        // The last event taken from the queue may still be on its way
+        self.model.tick(PollStrategy::TryFor(Duration::from_millis(10))).expect("the listener failed");
+        self.model.view();
+    }
+
+    /// The last frame drawn
+    pub fn buffer(&self) -> Buffer {
+        self.screen.lock().map(|screen| screen.clone()).unwrap_or_default()
+    }
+
+    /// Text of the last frame drawn, one line per row, without trailing spaces
+    pub fn screen(&self) -> String {
+        let buffer = self.buffer();
+        let area = buffer.area;
+        (area.top()..area.bottom())
+            .map(|y| {
+                let row: String = (area.left()..area.right()).map(|x| buffer[(x, y)].symbol()).collect();
+                row.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
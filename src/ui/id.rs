@@ -19,6 +19,108 @@ pub enum Id {
     SummaryBar,
     /// Help bar component
     HelpBar,
+    /// Clock, network and providers, in the footer
+    Footer,
+    /// Keys available, popping up over the body
+    WhichKey,
+    /// FROM amount input component
+    AmountInput,
+    /// Watch-only address popup
+    WatchAddressInput,
+    /// Quotes comparison table
+    QuotesTable,
+    /// Fee breakdown panel
+    FeeBreakdown,
+    /// Steps of the selected route
+    SwapPlan,
+    /// History screen
+    HistoryTable,
+    /// Yes/no question popup
+    ConfirmPopup,
+    /// Saved drafts picker
+    DraftPicker,
+    /// Scheduled swaps due, shown on start
+    DueSwaps,
+    /// Settings screen
+    SettingsForm,
+    /// First-run wizard
+    OnboardingWizard,
+    /// Log screen
+    LogViewer,
+    /// Metrics screen
+    MetricsView,
+    /// Provider statistics screen
+    ProviderStats,
+    /// Details of a provider, over its statistics
+    ProviderDetail,
+    /// Session statistics screen
+    SessionStats,
+    /// Portfolio screen
+    Portfolio,
+    /// Watchlist screen
+    Watchlist,
+    /// Price chart screen
+    PriceChart,
+    /// Depth preview
+    DepthView,
+    /// Popup asking for a line of text, e.g. the name of a draft
+    Prompt,
+    /// Popup adjusting a setting along a gauge, e.g. the slippage tolerance
+    Slider,
+    /// List popup picking one of a few options, e.g. the fiat currency
+    Dropdown,
+    /// Checklist popup ticking any of a few options, e.g. the providers
+    MultiSelect,
+    /// Popup scrolling through a text file, e.g. a crash report
+    TextViewer,
+    /// Live best rate, in watch mode
+    WatchPanel,
+    /// Details of a provider failure
+    ErrorPopup,
+    /// Outcome of the last actions
+    StatusBar,
+}
+
+impl Id {
+    /// Every component
+    pub const ALL: [Id; 36] = [
+        Id::Header,
+        Id::InstructionsBar,
+        Id::Instructions,
+        Id::AssetTable,
+        Id::SummaryBar,
+        Id::HelpBar,
+        Id::Footer,
+        Id::WhichKey,
+        Id::AmountInput,
+        Id::WatchAddressInput,
+        Id::QuotesTable,
+        Id::FeeBreakdown,
+        Id::SwapPlan,
+        Id::HistoryTable,
+        Id::ConfirmPopup,
+        Id::DraftPicker,
+        Id::DueSwaps,
+        Id::SettingsForm,
+        Id::OnboardingWizard,
+        Id::LogViewer,
+        Id::MetricsView,
+        Id::ProviderStats,
+        Id::ProviderDetail,
+        Id::SessionStats,
+        Id::Portfolio,
+        Id::Watchlist,
+        Id::PriceChart,
+        Id::DepthView,
+        Id::Prompt,
+        Id::Slider,
+        Id::Dropdown,
+        Id::MultiSelect,
+        Id::TextViewer,
+        Id::WatchPanel,
+        Id::ErrorPopup,
+        Id::StatusBar,
+    ];
 }
 
 impl Display for Id {
@@ -30,6 +132,36 @@ impl Display for Id {
             Self::AssetTable => write!(f, "asset_table"),
             Self::SummaryBar => write!(f, "summary_bar"),
             Self::HelpBar => write!(f, "help_bar"),
+            Self::Footer => write!(f, "footer"),
+            Self::WhichKey => write!(f, "which_key"),
+            Self::AmountInput => write!(f, "amount_input"),
+            Self::WatchAddressInput => write!(f, "watch_address_input"),
+            Self::QuotesTable => write!(f, "quotes_table"),
+            Self::FeeBreakdown => write!(f, "fee_breakdown"),
+            Self::SwapPlan => write!(f, "swap_plan"),
+            Self::HistoryTable => write!(f, "history_table"),
+            Self::ConfirmPopup => write!(f, "confirm_popup"),
+            Self::DraftPicker => write!(f, "draft_picker"),
+            Self::DueSwaps => write!(f, "due_swaps"),
+            Self::SettingsForm => write!(f, "settings_form"),
+            Self::OnboardingWizard => write!(f, "onboarding_wizard"),
+            Self::LogViewer => write!(f, "log_viewer"),
+            Self::MetricsView => write!(f, "metrics_view"),
+            Self::ProviderStats => write!(f, "provider_stats"),
+            Self::ProviderDetail => write!(f, "provider_detail"),
+            Self::SessionStats => write!(f, "session_stats"),
+            Self::Portfolio => write!(f, "portfolio"),
+            Self::Watchlist => write!(f, "watchlist"),
+            Self::PriceChart => write!(f, "price_chart"),
+            Self::DepthView => write!(f, "depth_view"),
+            Self::Prompt => write!(f, "prompt"),
+            Self::Slider => write!(f, "slider"),
+            Self::Dropdown => write!(f, "dropdown"),
+            Self::MultiSelect => write!(f, "multi_select"),
+            Self::TextViewer => write!(f, "text_viewer"),
+            Self::WatchPanel => write!(f, "watch_panel"),
+            Self::ErrorPopup => write!(f, "error_popup"),
+            Self::StatusBar => write!(f, "status_bar"),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,263 @@
+//! ## Keymap
+//!
+//! Keys bound to the application's actions
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::ui::glyphs;
+
+/// Keys bound to actions, configurable in the `[keymap]` section
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Keymap {
+    /// Quit the application
+    pub quit: char,
+    /// Select the FROM asset
+    pub from_asset: char,
+    /// Select the TO asset
+    pub to_asset: char,
+    /// Edit the FROM amount
+    pub amount: char,
+    /// Pick the chain of the highlighted asset among those it is listed on
+    pub chain: char,
+    /// Register a watch-only address
+    pub watch_address: char,
+    /// Pick the network fee preset on a slider
+    pub fee_speed: char,
+    /// Adjust the slippage tolerance of the session on a slider
+    pub slippage: char,
+    /// Export the displayed quotes to CSV
+    pub export: char,
+    /// Simulate a swap with the highlighted quote and save its receipt
+    pub receipt: char,
+    /// Open the history screen
+    pub history: char,
+    /// Save the form as a named draft
+    pub save_draft: char,
+    /// Open the drafts picker
+    pub drafts: char,
+    /// Open the settings screen
+    pub settings: char,
+    /// Open the log screen
+    pub logs: char,
+    /// Log more, without restarting
+    pub verbosity: char,
+    /// Open the metrics screen
+    pub metrics: char,
+    /// Open the provider statistics screen
+    pub provider_stats: char,
+    /// Open the session statistics screen
+    pub session_stats: char,
+    /// Open the portfolio screen
+    pub portfolio: char,
+    /// Open the watchlist screen
+    pub watchlist: char,
+    /// Open the price chart of the FROM asset, or of the pair once both assets are chosen
+    pub price_chart: char,
+    /// Open the depth preview of the pair on on-chain aggregators
+    pub depth: char,
+    /// Toggle watch mode, showing the best rate live
+    pub watch: char,
+    /// Set the target rate of a limit order on the pair, checked in watch mode
+    pub limit: char,
+    /// Pin the best quote, comparing the next ones with it
+    pub pin: char,
+    /// Fetch the quotes again now
+    pub requote: char,
+    /// Cancel the quote request in flight
+    pub cancel: char,
+    /// Acknowledge the error shown in the status bar
+    pub dismiss: char,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            quit: 'q',
+            from_asset: 'f',
+            to_asset: 't',
+            amount: 'm',
+            chain: '@',
+            watch_address: 'w',
+            fee_speed: 'g',
+            slippage: '%',
+            export: 'e',
+            receipt: 'r',
+            history: 'h',
+            save_draft: 's',
+            drafts: 'd',
+            settings: ',',
+            logs: 'l',
+            verbosity: 'v',
+            metrics: '#',
+            provider_stats: 'p',
+            session_stats: 'i',
+            portfolio: 'b',
+            watchlist: 'a',
+            price_chart: 'n',
+            depth: 'y',
+            watch: 'o',
+            limit: 'z',
+            pin: '.',
+            requote: 'u',
+            cancel: 'c',
+            dismiss: 'x',
+        }
+    }
+}
+
+/// Where keys are read: the component focused, in the mode it is in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Context {
+    /// Browsing the asset table
+    #[default]
+    Assets,
+    /// Choosing the FROM asset in the asset table
+    FromSelection,
+    /// Choosing the TO asset in the asset table
+    ToSelection,
+    /// Typing the FROM amount
+    Amount,
+    /// Browsing the quotes
+    Quotes,
+    /// In a popup or screen over the form
+    Popup,
+}
+
+impl Context {
+    /// Every context
+    pub const ALL: [Self; 6] = [
+        Self::Assets,
+        Self::FromSelection,
+        Self::ToSelection,
+        Self::Amount,
+        Self::Quotes,
+        Self::Popup,
+    ];
+
+    /// Name of the context
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Assets => "assets",
+            Self::FromSelection => "from-selection",
+            Self::ToSelection => "to-selection",
+            Self::Amount => "amount",
+            Self::Quotes => "quotes",
+            Self::Popup => "popup",
+        }
+    }
+}
+
+impl FromStr for Context {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|context| context.name() == s)
+            .ok_or_else(|| format!("unknown key context {s}"))
+    }
+}
+
+/// Contexts of the asset table
+const ASSETS: &[Context] = &[Context::Assets, Context::FromSelection, Context::ToSelection];
+/// Contexts of the asset and quotes tables, which share most keys
+const TABLES: &[Context] = &[
+    Context::Assets,
+    Context::FromSelection,
+    Context::ToSelection,
+    Context::Quotes,
+];
+/// Contexts editing the form, where undo and redo work
+const FORM: &[Context] = &[
+    Context::Assets,
+    Context::FromSelection,
+    Context::ToSelection,
+    Context::Amount,
+    Context::Quotes,
+];
+
+/// Key doing an action in some contexts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    /// Key, as shown to the user
+    pub key: String,
+    /// Message naming the action
+    pub action: &'static str,
+    /// Contexts the key does the action in
+    pub contexts: &'static [Context],
+}
+
+impl Keymap {
+    /// Every binding, the configured keys and the fixed ones, the most useful first
+    pub fn bindings(&self) -> Vec<Binding> {
+        fn bind(key: impl ToString, action: &'static str, contexts: &'static [Context]) -> Binding {
+            Binding {
+                key: key.to_string(),
+                action,
+                contexts,
+            }
+        }
+        vec![
+            bind(glyphs::up_down(), "key-navigate", TABLES),
+            bind("Enter", "key-choose-from", &[Context::Assets, Context::FromSelection]),
+            bind("Enter", "key-choose-to", &[Context::ToSelection]),
+            bind("Tab", "key-choose-to", &[Context::Assets, Context::FromSelection]),
+            bind("Enter", "key-choose-quote", &[Context::Quotes]),
+            bind("0-9", "key-type-amount", &[Context::Amount]),
+            bind("%", "key-percent", &[Context::Amount]),
+            bind("M", "key-max", &[Context::Amount]),
+            bind("Enter", "key-submit-amount", &[Context::Amount]),
+            bind(
+                "Esc",
+                "key-back",
+                &[Context::FromSelection, Context::ToSelection, Context::Amount, Context::Quotes],
+            ),
+            bind("Esc", "key-close", &[Context::Popup]),
+            bind(glyphs::up_down(), "key-step", &[Context::Amount]),
+            bind(self.quit, "key-quit", TABLES),
+            bind(self.from_asset, "key-from", ASSETS),
+            bind(self.to_asset, "key-to", ASSETS),
+            bind(self.amount, "key-amount", ASSETS),
+            bind(self.chain, "key-chain", ASSETS),
+            bind(self.watch_address, "key-watch-address", ASSETS),
+            bind(self.receipt, "key-receipt", &[Context::Quotes]),
+            bind(self.export, "key-export", &[Context::Quotes]),
+            bind(self.requote, "key-requote", TABLES),
+            bind(self.cancel, "key-cancel", TABLES),
+            bind(self.pin, "key-pin", TABLES),
+            bind(self.fee_speed, "key-fee-speed", TABLES),
+            bind(self.slippage, "key-slippage", TABLES),
+            bind(self.watch, "key-watch", TABLES),
+            bind(self.limit, "key-limit", TABLES),
+            bind(self.dismiss, "key-dismiss", TABLES),
+            bind("Tab", "key-error-detail", &[Context::Quotes]),
+            bind("^z", "key-undo", FORM),
+            bind("^y", "key-redo", FORM),
+            bind(self.save_draft, "key-save-draft", TABLES),
+            bind(self.drafts, "key-drafts", TABLES),
+            bind(self.history, "key-history", TABLES),
+            bind(self.portfolio, "key-portfolio", TABLES),
+            bind(self.watchlist, "key-watchlist", TABLES),
+            bind(self.price_chart, "key-price-chart", TABLES),
+            bind(self.depth, "key-depth", TABLES),
+            bind(self.provider_stats, "key-provider-stats", TABLES),
+            bind(self.session_stats, "key-session-stats", TABLES),
+            bind(self.metrics, "key-metrics", TABLES),
+            bind(self.logs, "key-logs", TABLES),
+            bind(self.verbosity, "key-verbosity", TABLES),
+            bind(self.settings, "key-settings", TABLES),
+            bind('?', "key-which-key", TABLES),
+        ]
+    }
+
+    /// Bindings doing something in `context`
+    pub fn hints(&self, context: Context) -> Vec<Binding> {
+        self.bindings()
+            .into_iter()
+            .filter(|binding| binding.contexts.contains(&context))
+            .collect()
+    }
+}
@@ -0,0 +1,101 @@
+//! ## Layout
+//!
+//! Layout helpers shared by the view
+
+use std::ops::Range;
+
+use tuirealm::ratatui::layout::{Constraint, Flex, Layout, Rect};
+use tuirealm::ratatui::widgets::TableState;
+
+use crate::config::Section;
+use crate::ui::id::Id;
+
+/// Area of a popup centered in `area`, `width` percent wide and `height` rows tall
+pub fn popup_area(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::horizontal([Constraint::Percentage(width)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+/// Rows of a table of `len` one-row rows to build for a frame showing `height` of them: those in view once the
+/// selection of `state` is scrolled into it, moving as little as ratatui would. `state` keeps the scroll across
+/// frames, and the state returned renders the rows of the window alone
+pub fn table_window(state: &mut TableState, len: usize, height: usize) -> (Range<usize>, TableState) {
+    let height = height.max(1);
+    let mut offset = state.offset().min(len.saturating_sub(height));
+    if let Some(selected) = state.selected().filter(|selected| *selected < len) {
+        offset = offset.clamp(selected.saturating_sub(height - 1), selected);
+    }
+    *state.offset_mut() = offset;
+    let window = offset..(offset + height).min(len);
+    let selected = state.selected().filter(|selected| window.contains(selected));
+    (window.clone(), TableState::default().with_selected(selected.map(|selected| selected - window.start)))
+}
+
+/// Areas of `sections` stacked top to bottom in `area`, each as tall as `height` constrains it
+pub fn stack(sections: &[Section], area: Rect, height: impl Fn(Section) -> Constraint) -> Vec<(Section, Rect)> {
+    let areas = Layout::vertical(sections.iter().map(|section| height(*section))).split(area);
+    sections.iter().copied().zip(areas.iter().copied()).collect()
+}
+
+/// Component drawn in `section`
+pub fn component(section: Section) -> Id {
+    match section {
+        Section::Assets => Id::AssetTable,
+        Section::InstructionsBar => Id::InstructionsBar,
+        Section::Amount => Id::AmountInput,
+        Section::Instructions => Id::Instructions,
+        Section::Quotes => Id::QuotesTable,
+        Section::SwapPlan => Id::SwapPlan,
+        Section::Fees => Id::FeeBreakdown,
+        Section::Status => Id::StatusBar,
+        Section::Summary => Id::SummaryBar,
+        Section::Help => Id::HelpBar,
+        Section::Footer => Id::Footer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Window and selection within it of `len` rows, `height` shown, with `selected` selected
+    fn window(selected: usize, len: usize, height: usize) -> (Range<usize>, Option<usize>) {
+        let mut state = TableState::default().with_selected(Some(selected));
+        let (window, state) = table_window(&mut state, len, height);
+        (window, state.selected())
+    }
+
+    #[test]
+    fn window_starts_at_the_first_row_selected() {
+        assert_eq!(window(0, 20, 5), (0..5, Some(0)));
+    }
+
+    #[test]
+    fn window_scrolls_to_the_last_row_selected() {
+        assert_eq!(window(19, 20, 5), (15..20, Some(4)));
+    }
+
+    #[test]
+    fn window_taller_than_the_rows_shows_them_all() {
+        assert_eq!(window(2, 3, 10), (0..3, Some(2)));
+    }
+
+    #[test]
+    fn window_without_height_still_shows_the_selection() {
+        assert_eq!(window(7, 20, 0), (7..8, Some(0)));
+    }
+
+    #[test]
+    fn window_keeps_its_scroll_while_the_selection_is_in_view() {
+        let mut state = TableState::default().with_selected(Some(19));
+        table_window(&mut state, 20, 5);
+        state.select(Some(16));
+        let (window, state) = table_window(&mut state, 20, 5);
+        assert_eq!((window, state.selected()), (15..20, Some(1)));
+    }
+}
@@ -2,22 +2,101 @@
 //!
 //! Application model
 
-use std::time::Duration;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::mpsc::{self, Sender};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use tuirealm::event::NoUserEvent;
-use tuirealm::props::{AttrValue, Attribute};
+use chrono::{DateTime, Utc};
+use tracing::{debug, debug_span, info, warn};
+use tracing_subscriber::filter::LevelFilter;
+use tuirealm::props::{AttrValue, Attribute, PropPayload, PropValue};
 use tuirealm::ratatui::layout::{Constraint, Direction, Layout};
-use tuirealm::terminal::{TerminalAdapter, TerminalBridge};
-use tuirealm::{Application, EventListenerCfg, Update};
+use tuirealm::terminal::{CrosstermInputListener, TerminalAdapter, TerminalBridge};
+use tuirealm::{
+    Application, ApplicationError, EventListenerCfg, PollStrategy, State, StateValue, Sub, SubClause, SubEventClause, Update,
+};
 
+use crate::cli::{find_asset, Prefill};
+use crate::config::{expand_home, AutoQuote, Bell, Config, Export, Refresh, Section, FIAT_CURRENCIES};
+use crate::errors::SwapError;
+use crate::i18n::{tr, tr_args};
+use crate::models::alert::Alert;
+use crate::models::amount::Amount;
+use crate::models::asset::{Asset, AssetId};
+use crate::models::chart::{PricePoint, Timeframe};
+use crate::models::draft::Draft;
+use crate::models::fee::FeeSpeed;
+use crate::models::history::{HistoryEntry, HistorySummary};
+use crate::models::limit::LimitOrder;
+use crate::models::order::Order;
+use crate::models::provider::{Capabilities, ProviderInfo};
+use crate::models::quote::{Quote, QuoteFailure, QuoteProgress, QuoteRequest};
+use crate::models::schedule::Schedule;
+use crate::models::status::{Severity, Status};
+use crate::models::wallet::WatchOnlyWallet;
+use crate::services::cancel::CancelToken;
+use crate::services::export::Column;
+use crate::services::providers::{Provider, NAMES};
+use crate::services::rpc::RpcEndpoints;
+use crate::crash;
+use crate::logging;
+use crate::services::{drafts, export, history, notify, schedules, session};
+use crate::ui::components::amount_input::AmountInput;
 use crate::ui::components::asset_table::AssetTable;
+use crate::ui::components::confirm_popup::ConfirmPopup;
+use crate::ui::components::depth_view::DepthView;
+use crate::ui::components::draft_picker::DraftPicker;
+use crate::ui::components::dropdown::{Choice, Dropdown};
+use crate::ui::components::due_swaps::DueSwaps;
+use crate::ui::components::error_popup::ErrorPopup;
+use crate::ui::components::fee_breakdown::FeeBreakdown;
+use crate::ui::components::footer::Footer;
 use crate::ui::components::header::Header;
 use crate::ui::components::help_bar::HelpBar;
+use crate::ui::components::history_table::HistoryTable;
 use crate::ui::components::instructions::Instructions;
 use crate::ui::components::instructions_bar::InstructionsBar;
+use crate::ui::components::log_viewer::LogViewer;
+use crate::ui::components::metrics_view::MetricsView;
+use crate::ui::components::multi_select::{Checklist, MultiSelect};
+use crate::ui::components::portfolio::Portfolio;
+use crate::ui::components::prompt::{Prompt, Purpose};
+use crate::ui::components::price_chart::PriceChart;
+use crate::ui::components::provider_detail::ProviderDetail;
+use crate::ui::components::provider_stats::ProviderStats;
+use crate::ui::components::session_stats::SessionStats;
+use crate::ui::components::onboarding_wizard::OnboardingWizard;
+use crate::ui::components::quotes_table::QuotesTable;
+use crate::ui::components::settings_form::SettingsForm;
+use crate::ui::components::slider::{Setting, Slider};
+use crate::ui::components::status_bar::StatusBar;
 use crate::ui::components::summary_bar::SummaryBar;
+use crate::ui::components::swap_plan::SwapPlan;
+use crate::ui::components::text_viewer::TextViewer;
+use crate::ui::components::watch_address_input::WatchAddressInput;
+use crate::ui::components::watch_panel::WatchPanel;
+use crate::ui::components::watchlist::Watchlist;
+use crate::ui::components::which_key::WhichKey;
+use crate::ui::control::{self, Control, ControlSocket};
+use crate::ui::event::UserEvent;
+use crate::ui::glyphs;
 use crate::ui::id::Id;
+use crate::ui::keymap::Context;
+use crate::ui::layout::{self, popup_area, stack};
 use crate::ui::msg::Msg;
+use crate::ui::port::{ServicePort, TickPort};
+use crate::ui::recording::{EventSource, RecordingPort, ReplayPort};
+use crate::ui::scripting::Scripts;
+use crate::ui::trace::Trace;
+use crate::ui::worker::{ServiceRequest, Worker};
+use crate::ui::state::{Action, AppState};
+use crate::ui::theme::{Theme, ThemeInjector};
+use crate::ui::title;
 
 /// Application model
 pub struct Model<T>
@@ -25,60 +104,338 @@ where
     T: TerminalAdapter,
 {
     /// Application
-    pub app: Application<Id, Msg, NoUserEvent>,
+    pub app: Application<Id, Msg, UserEvent>,
     /// Indicates that the application must quit
     pub quit: bool,
     /// Tells whether to redraw interface
     pub redraw: bool,
+    /// Shortest interval between two frames
+    frame: Duration,
+    /// When the last frame was drawn
+    drawn_at: Instant,
+    /// When a visual bell reversed the screen, until its colors are restored
+    flashed_at: Option<Instant>,
     /// Used to draw to terminal
     pub terminal: TerminalBridge<T>,
+    /// Window title last set, `None` while the terminal's title is left alone
+    window_title: Option<String>,
+    /// Theme injected into the components as they are mounted
+    theme: Rc<Cell<Theme>>,
+    /// Configuration the settings screen starts from
+    config: Config,
+    /// Node endpoints used by the chain services
+    rpc: RpcEndpoints,
+    /// Background refresh intervals
+    refresh: Refresh,
+    /// Quoting as the form is edited
+    auto_quote: AutoQuote,
+    /// Amount typed and not yet confirmed, quoted once the typing pauses when auto-quoting
+    typed_amount: Option<String>,
+    /// Last edit of the pair or amount when auto-quoting, holding the quotes back until the edits pause
+    edited_at: Option<Instant>,
+    /// Where exported files go
+    export: Export,
+    /// Last balance lookup of the FROM asset
+    balance_fetched_at: Instant,
+    /// Last network fee estimate of the FROM asset
+    fees_estimated_at: Instant,
+    /// Cancels the quote request in flight, if any
+    quotes_cancel: Option<CancelToken>,
+    /// Cancels the reverse quote request of the best quote in flight, if any
+    reverse_cancel: Option<CancelToken>,
+    /// Cancels the tasks of the FROM asset: balance, network fee and allowance lookups
+    from_cancel: CancelToken,
+    /// Cancels the tasks of the pair and amount quoted: quote requests and provider retries
+    pair_cancel: CancelToken,
+    /// Cancels the ENS resolution of the watch address popup, if any
+    ens_cancel: Option<CancelToken>,
+    /// Cancels the balance and price lookups of the portfolio screen, if open
+    portfolio_cancel: Option<CancelToken>,
+    /// Cancels the quote requests of the watchlist screen, if open
+    watchlist_cancel: Option<CancelToken>,
+    /// Last quote requests of the watchlist, while its screen is open
+    watchlist_quoted_at: Option<Instant>,
+    /// Cancels the price history lookup of the chart screen, if open
+    chart_cancel: Option<CancelToken>,
+    /// Cancels the quote requests of the depth preview, if open
+    depth_cancel: Option<CancelToken>,
+    /// Cancels the capability discovery of the provider details, if open
+    discovery_cancel: Option<CancelToken>,
+    /// Alerts on the best rate, with whether each one held at the last refresh of its pair
+    alerts: Vec<(Alert, bool)>,
+    /// Limit order on the pair, until its target is met or it is cancelled
+    limit_order: Option<LimitOrder>,
+    /// Best quote pinned as the reference the next ones are compared with, until the pair or amount changes
+    pinned: Option<Quote>,
+    /// Watch-only addresses used for balance lookups
+    wallet: WatchOnlyWallet,
+    /// Assets listed in the asset table
+    assets: Vec<Asset>,
+    /// Providers given by the embedding application, asked besides the configured ones
+    extra_providers: Vec<Arc<dyn Provider>>,
+    /// Time each provider asked has to quote, by name, shown in the metrics screen
+    timeouts: BTreeMap<String, Duration>,
+    /// When the session started, its history entries being summarized in the session statistics screen
+    started_at: DateTime<Utc>,
+    /// Best quote of each request of the session, with when, charted once watching
+    best_quotes: Vec<(Instant, Quote)>,
+    /// Names of the providers asked with what they are, shown in the provider statistics screen
+    provider_info: Vec<(String, ProviderInfo)>,
+    /// Swap form state, changed through `dispatch`
+    state: AppState,
+    /// Worker doing the IO in the background
+    worker: Worker,
+    /// Channel background services report their results on
+    service_tx: Sender<UserEvent>,
+    /// Socket scripts drive the application through, if listened on
+    control: Option<ControlSocket>,
+    /// User scripts reacting to events, if any
+    scripts: Option<Scripts>,
+    /// Trace the frames and transitions are written to, if any
+    trace: Option<Trace>,
 }
 
 impl<T> Model<T>
 where
     T: TerminalAdapter,
 {
-    /// Create a new model with the given terminal adapter
-    pub fn new(terminal_adapter: T) -> Self {
+    /// Create a new model with the given terminal adapter and configuration, handling the events of `source`
+    pub fn new(terminal_adapter: T, config: Config, source: EventSource) -> Self {
+        // Background services report back through this channel
+        let (service_tx, service_rx) = mpsc::channel();
+
         // Initialize the application with the event listener configuration
-        let app = Application::init(
-            EventListenerCfg::default()
-                .crossterm_input_listener(Duration::from_millis(20), 3)
-                .poll_timeout(Duration::from_millis(10))
-                .tick_interval(Duration::from_secs(1)),
-        );
+        let timing = config.timing;
+        let input_poll = timing.input_poll_interval();
+        let input = Box::new(CrosstermInputListener::<UserEvent>::new(input_poll));
+        let services = Box::new(ServicePort::new(service_rx));
+        let ticks = Box::new(TickPort::new(timing.tick_interval()));
+        // The main loop waits at most a frame for an event, so that a frame held back by the cap is drawn on time;
+        // a port fed by the caller is polled in short steps, so that it can settle the events it sent without waiting
+        let wait = match source {
+            EventSource::Port(_) => Duration::from_millis(10),
+            _ => timing.frame_interval(),
+        };
+        let listener = EventListenerCfg::default().poll_timeout(wait);
+        let listener = match source {
+            EventSource::Live => listener
+                .add_port(input, input_poll, 3)
+                .add_port(services, Duration::from_millis(50), 8)
+                .add_port(ticks, Duration::from_millis(100), 1),
+            EventSource::Record(recorder) => listener
+                .add_port(Box::new(RecordingPort::new(input, recorder.clone())), input_poll, 3)
+                .add_port(Box::new(RecordingPort::new(services, recorder.clone())), Duration::from_millis(50), 8)
+                .add_port(Box::new(RecordingPort::new(ticks, recorder)), Duration::from_millis(100), 1),
+            // The services' own results are dropped, the recorded ones stand for them
+            EventSource::Replay(replay) => listener
+                .add_port(input, input_poll, 3)
+                .add_port(Box::new(ReplayPort::new(replay)), Duration::from_millis(10), 16),
+            EventSource::Port(port) => listener.add_port(port, Duration::from_millis(1), 16),
+        };
+        let mut app = Application::init(listener);
+        let theme = Rc::new(Cell::new(config.theme));
+        app.add_injector(Box::new(ThemeInjector::new(theme.clone())));
+        let providers = config.providers();
+        let timeouts = timeouts(&providers);
+        let provider_info = provider_info(&config, &providers);
+        let worker = Worker::spawn(config.rpc.clone(), Arc::new(providers), config.selection, service_tx.clone());
 
         let mut model = Self {
             app,
             quit: false,
             redraw: true,
-            terminal: TerminalBridge::init(terminal_adapter).expect("Cannot initialize terminal"),
+            frame: timing.frame_interval(),
+            drawn_at: Instant::now(),
+            flashed_at: None,
+            terminal: TerminalBridge::new(terminal_adapter),
+            window_title: None,
+            theme,
+            config: config.clone(),
+            rpc: config.rpc.clone(),
+            refresh: config.refresh,
+            auto_quote: config.auto_quote,
+            typed_amount: None,
+            edited_at: None,
+            export: config.export.clone(),
+            balance_fetched_at: Instant::now(),
+            fees_estimated_at: Instant::now(),
+            quotes_cancel: None,
+            reverse_cancel: None,
+            from_cancel: worker.scope(),
+            pair_cancel: worker.scope(),
+            ens_cancel: None,
+            portfolio_cancel: None,
+            watchlist_cancel: None,
+            watchlist_quoted_at: None,
+            chart_cancel: None,
+            depth_cancel: None,
+            discovery_cancel: None,
+            alerts: config.alerts.iter().map(|alert| (alert.clone(), false)).collect(),
+            limit_order: None,
+            pinned: None,
+            wallet: WatchOnlyWallet::new(),
+            assets: config.assets.clone(),
+            extra_providers: vec![],
+            timeouts,
+            started_at: Utc::now(),
+            best_quotes: vec![],
+            provider_info,
+            state: AppState::default(),
+            worker,
+            service_tx,
+            control: None,
+            scripts: None,
+            trace: None,
         };
 
         // Mount components
-        model.mount_components();
+        model.mount_components(config);
 
         model
     }
 
     /// Mount all components
-    fn mount_components(&mut self) {
+    fn mount_components(&mut self, config: Config) {
         // Mount the header component and make it active
         assert!(self
             .app
-            .mount(Id::Header, Box::new(Header::new()), Vec::default())
+            .mount(
+                Id::Header,
+                Box::new(Header::new()),
+                vec![Sub::new(SubEventClause::WindowResize, SubClause::Always)]
+            )
             .is_ok());
 
         // Mount the instructions bar component (visual only)
         assert!(self
             .app
-            .mount(Id::InstructionsBar, Box::new(InstructionsBar::new()), Vec::default())
+            .mount(Id::InstructionsBar, Box::new(InstructionsBar::new(&config.keymap)), Vec::default())
             .is_ok());
 
         // Mount the asset table component
         assert!(self
             .app
-            .mount(Id::AssetTable, Box::new(AssetTable::new()), Vec::default())
+            .mount(
+                Id::AssetTable,
+                Box::new(AssetTable::new(config.assets.clone(), config.keymap.clone())),
+                Vec::default()
+            )
+            .is_ok());
+
+        // Mount the amount input, listening for balance lookups
+        assert!(self
+            .app
+            .mount(
+                Id::AmountInput,
+                Box::new(AmountInput::new()),
+                vec![
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::BalanceLoaded(
+                            AssetId::default(),
+                            Amount::default(),
+                        )),
+                        SubClause::Always,
+                    ),
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::BalanceFailed(
+                            AssetId::default(),
+                            String::new(),
+                        )),
+                        SubClause::Always,
+                    ),
+                ],
+            )
+            .is_ok());
+
+        // Mount the quotes table, listening for quote results
+        assert!(self
+            .app
+            .mount(
+                Id::QuotesTable,
+                Box::new(QuotesTable::new(config.keymap.clone(), config.selection)),
+                vec![
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::QuotesLoaded(
+                            QuoteRequest::default(),
+                            Vec::default(),
+                            None,
+                            Vec::default(),
+                        )),
+                        SubClause::Always,
+                    ),
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::ProviderFailed(QuoteFailure {
+                            provider: String::new(),
+                            error: SwapError::Config(String::new()),
+                        })),
+                        SubClause::Always,
+                    ),
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::QuoteProgress(String::new(), QuoteProgress::default())),
+                        SubClause::Always,
+                    ),
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::ProviderRetried(
+                            String::new(),
+                            String::new(),
+                            Err(SwapError::Config(String::new())),
+                        )),
+                        SubClause::Always,
+                    ),
+                    Sub::new(SubEventClause::Discriminant(UserEvent::Control(Control::Quit)), SubClause::Always),
+                    // Refresh the quotes on time even while another component has the keyboard
+                    Sub::new(SubEventClause::Tick, SubClause::Always),
+                ],
+            )
+            .is_ok());
+
+        // Mount the fee breakdown, listening for fee estimates
+        assert!(self
+            .app
+            .mount(
+                Id::FeeBreakdown,
+                Box::new(FeeBreakdown::new()),
+                vec![
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::NetworkFeeLoaded(AssetId::default(), Vec::default())),
+                        SubClause::Always,
+                    ),
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::NetworkFeeFailed(
+                            AssetId::default(),
+                            String::new(),
+                        )),
+                        SubClause::Always,
+                    ),
+                ],
+            )
+            .is_ok());
+
+        // Mount the swap plan, listening for allowance checks
+        assert!(self
+            .app
+            .mount(
+                Id::SwapPlan,
+                Box::new(SwapPlan::new()),
+                vec![
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::AllowanceLoaded(
+                            AssetId::default(),
+                            String::new(),
+                            Amount::default(),
+                        )),
+                        SubClause::Always,
+                    ),
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::AllowanceFailed(
+                            AssetId::default(),
+                            String::new(),
+                            String::new(),
+                        )),
+                        SubClause::Always,
+                    ),
+                ],
+            )
             .is_ok());
 
         // Mount the dynamic instructions component
@@ -90,22 +447,1443 @@ where
         // Mount the summary bar component
         assert!(self
             .app
-            .mount(Id::SummaryBar, Box::new(SummaryBar::new()), Vec::default())
+            .mount(
+                Id::SummaryBar,
+                Box::new(SummaryBar::new()),
+                vec![Sub::new(
+                    SubEventClause::Discriminant(UserEvent::ReverseQuoted(String::new(), None)),
+                    SubClause::Always,
+                )],
+            )
+            .is_ok());
+
+        // Mount the status bar, clearing its notices on time and reporting provider failures
+        assert!(self
+            .app
+            .mount(
+                Id::StatusBar,
+                Box::new(StatusBar::new(config.keymap.dismiss)),
+                vec![
+                    Sub::new(SubEventClause::Tick, SubClause::Always),
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::ProviderFailed(QuoteFailure {
+                            provider: String::new(),
+                            error: SwapError::Config(String::new()),
+                        })),
+                        SubClause::Always,
+                    ),
+                ],
+            )
+            .is_ok());
+
+        // Mount the help bar component (visual only)
+        assert!(self
+            .app
+            .mount(Id::HelpBar, Box::new(HelpBar::new(&config.keymap)), Vec::default())
+            .is_ok());
+
+        // Mount the footer, following the clock
+        assert!(self
+            .app
+            .mount(Id::Footer, Box::new(Footer::new()), vec![Sub::new(SubEventClause::Tick, SubClause::Always)])
+            .is_ok());
+
+        // Mount the keys popup, seeing every key pressed and the clock without taking the keyboard
+        let which_key = WhichKey::new(&config.keymap, config.which_key.idle_delay());
+        assert!(self
+            .app
+            .mount(Id::WhichKey, Box::new(which_key), vec![Sub::new(SubEventClause::Any, SubClause::Always)])
+            .is_ok());
+
+        self.show_settings(&config);
+        self.show_log_level();
+        self.show_provider_flags();
+        self.show_network();
+
+        // Make the asset table active to receive keyboard events
+        assert!(self.app.active(&Id::AssetTable).is_ok());
+    }
+
+    /// Show the theme, fiat currency, quote refresh interval, slippage tolerance and profile of the settings
+    fn show_settings(&mut self, config: &Config) {
+        // Components mounted from now on get the theme injected, those mounted already get it set
+        self.theme.set(config.theme);
+        for id in Id::ALL {
+            if self.app.mounted(&id) {
+                for (attr, value) in config.theme.attrs(&id) {
+                    let _ = self.app.attr(&id, attr, value);
+                }
+            }
+        }
+        let _ = self.app.attr(&Id::AssetTable, Attribute::Custom("fiat"), AttrValue::String(config.fiat.clone()));
+        for id in [Id::QuotesTable, Id::WatchPanel] {
+            let _ = self.app.attr(
+                &id,
+                Attribute::Custom("refresh_interval"),
+                AttrValue::Number(config.refresh.quotes as isize),
+            );
+        }
+        let reduce_motion = AttrValue::Flag(config.reduce_motion);
+        let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("reduce_motion"), reduce_motion);
+        let _ = self.app.attr(
+            &Id::QuotesTable,
+            Attribute::Custom("slippage"),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(config.slippage))),
+        );
+        let _ = self.app.attr(
+            &Id::Header,
+            Attribute::Custom("profile"),
+            AttrValue::String(config.profile.clone().unwrap_or_default()),
+        );
+    }
+
+    /// Mark the providers that may hold the funds or ask for identity verification in the quotes table, greying out
+    /// those not serving the user's jurisdiction
+    fn show_provider_flags(&mut self) {
+        let flagged = self
+            .provider_info
+            .iter()
+            .filter(|(_, info)| info.is_flagged())
+            .map(|(name, _)| PropValue::Str(name.clone()))
+            .collect();
+        let flagged = AttrValue::Payload(PropPayload::Vec(flagged));
+        let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("flagged"), flagged);
+        let jurisdiction = self.config.jurisdiction.clone().unwrap_or_default();
+        let restricted: Vec<String> = self
+            .provider_info
+            .iter()
+            .filter(|(name, _)| self.restriction(name).is_some())
+            .map(|(name, _)| name.clone())
+            .collect();
+        // The worker picks the best quotes watched and refreshed without the restricted providers either
+        self.worker.set_restricted(restricted.clone());
+        let _ = self
+            .app
+            .attr(&Id::QuotesTable, Attribute::Custom("jurisdiction"), AttrValue::String(jurisdiction));
+        let restricted = AttrValue::Payload(PropPayload::Vec(restricted.into_iter().map(PropValue::Str).collect()));
+        let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("restricted"), restricted);
+    }
+
+    /// Jurisdiction of the user, if `provider` does not serve it
+    fn restriction(&self, provider: &str) -> Option<&str> {
+        let jurisdiction = self.config.jurisdiction.as_deref()?;
+        let (_, info) = self.provider_info.iter().find(|(name, _)| name == provider)?;
+        info.restricts(jurisdiction).then_some(jurisdiction)
+    }
+
+    /// Show the log level in the header when not the default one, and in the log screen
+    fn show_log_level(&mut self) {
+        let level = logging::level();
+        let shown = if level == LevelFilter::INFO { String::new() } else { level.to_string() };
+        let _ = self.app.attr(&Id::Header, Attribute::Custom("log_level"), AttrValue::String(shown));
+        if self.app.mounted(&Id::LogViewer) {
+            let _ = self.app.attr(&Id::LogViewer, Attribute::Custom("log_level"), AttrValue::String(level.to_string()));
+        }
+    }
+
+    /// Apply settings edited while running; the keymap, assets and network settings
+    /// only change on restart
+    fn apply_settings(&mut self, config: Config) {
+        self.refresh = config.refresh;
+        self.auto_quote = config.auto_quote;
+        self.show_settings(&config);
+        self.config = config;
+        self.rebuild_providers();
+    }
+
+    /// Ask `providers` for quotes as well as the configured ones
+    pub fn add_providers(&mut self, providers: Vec<Arc<dyn Provider>>) {
+        self.extra_providers.extend(providers);
+        self.rebuild_providers();
+    }
+
+    /// Accept commands on a control socket at `path`
+    pub fn listen_control(&mut self, path: &Path) -> Result<(), SwapError> {
+        self.control = Some(control::listen(path, self.assets.clone(), self.service_tx.clone())?);
+        Ok(())
+    }
+
+    /// Write the frames drawn and the state transitions to a trace at `path`
+    pub fn trace_to(&mut self, path: &Path) -> Result<(), SwapError> {
+        self.trace = Some(Trace::create(path)?);
+        info!(path = %path.display(), "tracing");
+        Ok(())
+    }
+
+    /// Load the user scripts of `dir`, reporting scripts that fail to load in the status bar
+    pub fn load_scripts(&mut self, dir: &Path) {
+        match Scripts::load(dir, self.assets.clone(), self.export.dir(), self.service_tx.clone()) {
+            Ok(scripts) => self.scripts = scripts,
+            Err(err) => {
+                warn!(%err, "scripts not loaded");
+                self.show_status(Status::warning(tr_args("status-scripts-not-loaded", &[("error", err.to_string())])));
+            }
+        }
+    }
+
+    /// Let the scripts react to an event, reporting their errors in the status bar
+    fn run_scripts(&mut self, event: impl FnOnce(&Scripts) -> Result<(), String>) {
+        if let Some(Err(err)) = self.scripts.as_ref().map(event) {
+            warn!(%err, "script failed");
+            self.show_status(Status::warning(err));
+        }
+    }
+
+    /// Run a command received on the control socket, as the keys it stands for would
+    fn run_control(&mut self, control: Control) -> Option<Msg> {
+        info!(%control, "control command");
+        // Assets were checked against the listed ones on receipt
+        let asset = |id: &str| find_asset(&self.assets, id).ok();
+        let prefill = match control {
+            Control::SetPair(from, to) => Prefill {
+                from: asset(&from),
+                to: asset(&to),
+                ..Prefill::default()
+            },
+            Control::SetFrom(from) => Prefill {
+                from: asset(&from),
+                ..Prefill::default()
+            },
+            Control::SetTo(to) => Prefill {
+                to: asset(&to),
+                ..Prefill::default()
+            },
+            Control::SetAmount(amount) => {
+                let _ = self.app.attr(&Id::AmountInput, Attribute::Value, AttrValue::String(amount.clone()));
+                return Some(Msg::AmountSubmitted(amount));
+            }
+            Control::Quote => return Some(Msg::Requote),
+            Control::Quit => return Some(Msg::AppClose),
+            // Handled by the quotes table, which holds the quotes
+            Control::ExportCsv => return None,
+        };
+        self.prefill(prefill);
+        None
+    }
+
+    /// Providers asked for quotes: the configured ones, then the added ones
+    fn rebuild_providers(&mut self) {
+        let mut providers = self.config.providers();
+        providers.extend(self.extra_providers.iter().map(|provider| Box::new(Arc::clone(provider)) as Box<dyn Provider>));
+        self.timeouts = timeouts(&providers);
+        self.provider_info = provider_info(&self.config, &providers);
+        self.worker.set_providers(Arc::new(providers));
+        self.show_provider_flags();
+        self.show_network();
+    }
+
+    /// Show how the network is reached and how many providers are asked in the footer
+    fn show_network(&mut self) {
+        let network = if self.config.offline {
+            "offline"
+        } else if self.config.proxy.is_some() {
+            "proxy"
+        } else {
+            "online"
+        };
+        let _ = self.app.attr(&Id::Footer, Attribute::Custom("network"), AttrValue::String(network.to_string()));
+        let providers = AttrValue::Length(self.provider_info.len());
+        let _ = self.app.attr(&Id::Footer, Attribute::Custom("providers"), providers);
+    }
+
+    /// Fill the form with values given on the command line, fetching quotes when complete
+    pub fn prefill(&mut self, prefill: Prefill) {
+        let mut messages = vec![];
+        if let Some(provider) = prefill.provider {
+            let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("preferred_provider"), AttrValue::String(provider));
+        }
+        if let Some((index, asset)) = prefill.from {
+            if let Some(address) = prefill.address {
+                self.wallet.register(asset.chain, address);
+            }
+            let _ = self.app.attr(&Id::AssetTable, Attribute::Custom("from_index"), AttrValue::Number(index as isize));
+            messages.push(Msg::AssetChosenAsFrom(index, asset));
+        }
+        if let Some((index, asset)) = prefill.to {
+            let _ = self.app.attr(&Id::AssetTable, Attribute::Custom("to_index"), AttrValue::Number(index as isize));
+            messages.push(Msg::AssetChosenAsTo(index, asset));
+        }
+        if let Some(amount) = prefill.amount {
+            let _ = self.app.attr(&Id::AmountInput, Attribute::Value, AttrValue::String(amount.to_string()));
+            messages.push(Msg::AmountSubmitted(amount.to_string()));
+        }
+
+        for msg in messages {
+            let mut msg = Some(msg);
+            while msg.is_some() {
+                msg = self.update(msg);
+            }
+        }
+    }
+
+    /// Current form values
+    fn draft(&self) -> Draft {
+        Draft {
+            from: self.state.from_asset.as_ref().map(Asset::id),
+            to: self.state.to_asset.as_ref().map(Asset::id),
+            amount: self.state.amount.map(|amount| amount.to_string()),
+            address: self
+                .state
+                .from_asset
+                .as_ref()
+                .and_then(|asset| self.wallet.address(asset.chain))
+                .map(str::to_string),
+            provider: self.state.route.as_ref().map(|route| route.provider.clone()),
+        }
+    }
+
+    /// Save the form so that the next launch can restore it
+    pub fn save_session(&self) {
+        let _ = session::save(&self.draft());
+    }
+
+    /// State summary for crash reports, without API keys or node credentials
+    pub fn crash_state(&self) -> String {
+        let config = &self.config;
+        let rpc = &self.rpc;
+        [
+            format!("profile: {}", config.profile.as_deref().unwrap_or("(none)")),
+            format!("providers: {}", config.providers.join(", ")),
+            format!("api keys set: {}", config.api_keys.keys().cloned().collect::<Vec<_>>().join(", ")),
+            format!("proxy: {}", config.proxy.as_deref().map_or_else(|| "(none)".to_string(), crash::redact_url)),
+            format!("offline: {}", config.offline),
+            format!(
+                "rpc: bitcoin={} ethereum={} solana={} arbitrum={}",
+                crash::redact_url(&rpc.bitcoin),
+                crash::redact_url(&rpc.ethereum),
+                crash::redact_url(&rpc.solana),
+                crash::redact_url(&rpc.arbitrum)
+            ),
+            format!("log level: {}", logging::level()),
+            format!("focus: {}", self.app.focus().map_or_else(|| "(none)".to_string(), Id::to_string)),
+            format!("form: {}", self.draft().summary()),
+            format!("route: {}", self.state.route.as_ref().map_or("(none)", |route| route.provider.as_str())),
+            format!("watching: {}", self.state.watching),
+        ]
+        .join("\n")
+    }
+
+    /// Offer to restore the form left unfinished by the previous session, if any
+    pub fn offer_session_restore(&mut self) {
+        let Some(draft) = session::load() else {
+            return;
+        };
+        let popup = ConfirmPopup::new(
+            tr_args("confirm-restore-session", &[("draft", draft.summary())]),
+            Msg::RestoreSession(draft),
+            Msg::DiscardSession,
+        );
+        assert!(self
+            .app
+            .remount(Id::ConfirmPopup, Box::new(popup), Vec::default())
+            .is_ok());
+        assert!(self.app.active(&Id::ConfirmPopup).is_ok());
+    }
+
+    /// List the scheduled swaps come due since the last launch, to load one in the form; whether any was due
+    pub fn remind_schedules(&mut self) -> bool {
+        let due = match schedules::due(Utc::now()) {
+            Ok(due) => due,
+            Err(err) => {
+                warn!(%err, "scheduled swaps not checked");
+                self.show_status(Status {
+                    severity: err.severity(),
+                    text: tr_args("status-schedule-failed", &[("error", err.to_string())]),
+                });
+                return false;
+            }
+        };
+        let Some((_, draft, schedule)) = due.first() else {
+            return false;
+        };
+        info!(count = due.len(), "scheduled swaps due");
+        if self.config.notifications.desktop {
+            let args = [
+                ("frequency", tr_args("frequency", &[("frequency", schedule.frequency.to_string())])),
+                ("draft", draft.summary()),
+                ("more", (due.len() - 1).to_string()),
+            ];
+            let body = if due.len() > 1 { "notify-schedules-due-body" } else { "notify-schedule-due-body" };
+            notify::send("schedules", &tr("notify-schedule-due"), &tr_args(body, &args));
+        }
+        assert!(self
+            .app
+            .remount(Id::DueSwaps, Box::new(DueSwaps::new(due)), Vec::default())
+            .is_ok());
+        assert!(self.app.active(&Id::DueSwaps).is_ok());
+        true
+    }
+
+    /// Walk through the main settings, when the config file was just created
+    pub fn start_onboarding(&mut self) {
+        let wizard = OnboardingWizard::new(self.config.clone());
+        assert!(self
+            .app
+            .remount(Id::OnboardingWizard, Box::new(wizard), Vec::default())
+            .is_ok());
+        assert!(self.app.active(&Id::OnboardingWizard).is_ok());
+    }
+
+    /// Fetch the balance of the watched address for the FROM asset, if any
+    fn fetch_from_balance(&mut self) {
+        let Some(asset) = self.state.from_asset.clone() else {
+            return;
+        };
+        let Some(address) = self.wallet.address(asset.chain).map(str::to_string) else {
+            return;
+        };
+        self.balance_fetched_at = Instant::now();
+
+        let _ = self.app.attr(
+            &Id::AmountInput,
+            Attribute::Custom("balance_loading"),
+            AttrValue::Flag(true),
+        );
+
+        self.worker.send(ServiceRequest::Balance { asset, address }, &self.from_cancel);
+    }
+
+    /// Estimate the network fee of depositing the FROM asset
+    fn estimate_network_fee(&mut self) {
+        let Some(asset) = self.state.from_asset.clone() else {
+            return;
+        };
+        self.fees_estimated_at = Instant::now();
+
+        self.worker.send(ServiceRequest::NetworkFees(asset), &self.from_cancel);
+    }
+
+    /// Hold the quotes back until the edits of the form pause, when auto-quoting
+    fn debounce_quotes(&mut self) {
+        if self.auto_quote.enabled {
+            self.edited_at = Some(Instant::now());
+            // The quotes in flight answer the form before the edit
+            self.cancel_pair_tasks();
+        }
+    }
+
+    /// Abort the tasks of the FROM asset replaced, so that their results do not land on the new one
+    fn cancel_from_tasks(&mut self) {
+        self.from_cancel.cancel();
+        self.from_cancel = self.worker.scope();
+    }
+
+    /// Abort the quote requests and retries of the pair and amount quoted so far
+    fn cancel_pair_tasks(&mut self) {
+        self.pair_cancel.cancel();
+        self.pair_cancel = self.worker.scope();
+        self.reverse_cancel = None;
+        let _ = self.app.attr(&Id::SummaryBar, Attribute::Custom("round_trip"), AttrValue::String(String::new()));
+        // The request in flight, cancelled with its pair, no longer keeps the table waiting
+        if self.quotes_cancel.take().is_some() {
+            self.dispatch(Action::QuotesSettled);
+            let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("cancelled"), AttrValue::Flag(true));
+        }
+    }
+
+    /// Take the FROM amount into the form and show it
+    fn set_amount(&mut self, amount: String) {
+        self.dispatch(Action::AmountSubmitted(amount.clone()));
+        let _ = self.app.attr(&Id::FeeBreakdown, Attribute::Custom("from_amount"), AttrValue::String(amount.clone()));
+        let _ = self.app.attr(&Id::SummaryBar, Attribute::Custom("from_amount"), AttrValue::String(amount));
+    }
+
+    /// Fetch quotes from every provider once the pair and amount are known
+    fn request_quotes(&mut self) {
+        let (Some(from), Some(to), Some(amount)) = (self.state.from_asset.clone(), self.state.to_asset.clone(), self.state.amount) else {
+            return;
+        };
+        let request = QuoteRequest { from, to, amount };
+
+        self.cancel_pair_tasks();
+        self.dispatch(Action::QuotesRequested);
+        self.update_swap_plan();
+
+        let _ = self.app.attr(
+            &Id::QuotesTable,
+            Attribute::Custom("pending"),
+            AttrValue::String(request.key()),
+        );
+
+        info!(request = %request.key(), "requesting quotes");
+        self.fetch_quotes(request);
+    }
+
+    /// Fetch the quotes of the current request again, keeping the route shown if still offered
+    fn refresh_quotes(&mut self) {
+        let (Some(from), Some(to), Some(amount)) = (self.state.from_asset.clone(), self.state.to_asset.clone(), self.state.amount) else {
+            return;
+        };
+        let request = QuoteRequest { from, to, amount };
+
+        if let Some(route) = &self.state.route {
+            let _ = self.app.attr(
+                &Id::QuotesTable,
+                Attribute::Custom("preferred_provider"),
+                AttrValue::String(route.provider.clone()),
+            );
+        }
+        let _ = self.app.attr(
+            &Id::QuotesTable,
+            Attribute::Custom("refreshing"),
+            AttrValue::String(request.key()),
+        );
+        self.dispatch(Action::QuotesRefreshing);
+
+        debug!(request = %request.key(), "refreshing quotes");
+        self.fetch_quotes(request);
+    }
+
+    /// Ask every provider for a quote in the background, cancelling the request in flight
+    fn fetch_quotes(&mut self, request: QuoteRequest) {
+        let cancel = self.pair_cancel.child();
+        if let Some(previous) = self.quotes_cancel.replace(cancel.clone()) {
+            previous.cancel();
+        }
+        self.worker.send(ServiceRequest::Quotes(request), &cancel);
+    }
+
+    /// Quote the amount out of the best quote back to the FROM asset in the background, to show the round trip
+    fn fetch_reverse(&mut self, best: &Quote) {
+        let (Some(from), Some(to)) = (self.state.from_asset.clone(), self.state.to_asset.clone()) else {
+            return;
+        };
+        let request = QuoteRequest {
+            from: to,
+            to: from,
+            amount: best.amount_out,
+        };
+        let cancel = self.pair_cancel.child();
+        if let Some(previous) = self.reverse_cancel.replace(cancel.clone()) {
+            previous.cancel();
+        }
+        debug!(request = %request.key(), "quoting back");
+        let key = best.request_key();
+        self.worker.send(ServiceRequest::ReverseQuote { key, request }, &cancel);
+    }
+
+    /// Show the best quote back of the request keyed `key` in the summary bar, unless the form changed since
+    fn show_round_trip(&mut self, key: &str, back: Option<Quote>) {
+        let (Some(from), Some(to), Some(amount)) = (self.state.from_asset.clone(), self.state.to_asset.clone(), self.state.amount) else {
+            return;
+        };
+        if (QuoteRequest { from, to, amount }).key() != key {
+            return;
+        }
+        let round_trip = match back {
+            Some(back) => {
+                // Change from the amount sent to the amount returned, negative when the round trip loses
+                let sent = amount.to_f64();
+                let spread = if sent > 0.0 { (back.amount_out.to_f64() - sent) / sent * 100.0 } else { 0.0 };
+                info!(provider = %back.provider, amount = %back.amount_out, spread, "quoted back");
+                let args = [
+                    ("amount", back.amount_out.to_string()),
+                    ("asset", back.to.label()),
+                    ("provider", back.provider.clone()),
+                    ("spread", format!("{spread:+.2}")),
+                ];
+                tr_args("summary-round-trip", &args)
+            }
+            None => tr("summary-no-round-trip"),
+        };
+        let _ = self.app.attr(&Id::SummaryBar, Attribute::Custom("round_trip"), AttrValue::String(round_trip));
+    }
+
+    /// Ask a single provider for the current request again in the background
+    fn retry_provider(&mut self, name: String) {
+        let (Some(from), Some(to), Some(amount)) = (self.state.from_asset.clone(), self.state.to_asset.clone(), self.state.amount) else {
+            return;
+        };
+        let request = QuoteRequest { from, to, amount };
+        self.worker.send(ServiceRequest::RetryProvider { request, provider: name }, &self.pair_cancel);
+    }
+
+    /// Change the swap form state
+    fn dispatch(&mut self, action: Action) {
+        debug!(?action, "dispatch");
+        if let Some(trace) = &mut self.trace {
+            trace.transition(&action);
+        }
+        self.state.reduce(action);
+    }
+
+    /// Undo or redo a form edit, showing the form it leads to in the components
+    fn restore_form(&mut self, action: Action) -> Option<Msg> {
+        let nothing = tr(match action {
+            Action::Undo => "status-nothing-to-undo",
+            _ => "status-nothing-to-redo",
+        });
+        let previous = self.state.form();
+        self.dispatch(action);
+        let form = self.state.form();
+        if form == previous {
+            self.show_status(Status::info(nothing));
+            return None;
+        }
+
+        // Clear both roles first, for an asset to move from one role to the other
+        let index = |asset: &Option<Asset>| {
+            asset
+                .as_ref()
+                .and_then(|asset| self.assets.iter().position(|listed| listed == asset))
+                .map_or(-1, |index| index as isize)
+        };
+        let (from_index, to_index) = (index(&form.from_asset), index(&form.to_asset));
+        let _ = self.app.attr(&Id::AssetTable, Attribute::Custom("to_index"), AttrValue::Number(-1));
+        let _ = self.app.attr(&Id::AssetTable, Attribute::Custom("from_index"), AttrValue::Number(-1));
+        if from_index >= 0 {
+            let _ = self.app.attr(&Id::AssetTable, Attribute::Custom("from_index"), AttrValue::Number(from_index));
+        }
+        if to_index >= 0 {
+            let _ = self.app.attr(&Id::AssetTable, Attribute::Custom("to_index"), AttrValue::Number(to_index));
+        }
+
+        let label = |asset: &Option<Asset>| asset.as_ref().map(Asset::label).unwrap_or_default();
+        let amount = form.amount.map(|amount| amount.to_string()).unwrap_or_default();
+        let to_amount = match &form.route {
+            Some(route) if !self.state.quotes_outdated => route.amount_out.to_string(),
+            _ => "?".to_string(),
+        };
+        let _ = self.app.attr(&Id::SummaryBar, Attribute::Custom("from_ticker"), AttrValue::String(label(&form.from_asset)));
+        let _ = self.app.attr(&Id::SummaryBar, Attribute::Custom("to_ticker"), AttrValue::String(label(&form.to_asset)));
+        let _ = self.app.attr(&Id::SummaryBar, Attribute::Custom("from_amount"), AttrValue::String(amount.clone()));
+        let _ = self.app.attr(&Id::SummaryBar, Attribute::Custom("to_amount"), AttrValue::String(to_amount));
+        let _ = self.app.attr(&Id::AmountInput, Attribute::Value, AttrValue::String(amount.clone()));
+        let _ = self.app.attr(&Id::FeeBreakdown, Attribute::Custom("from_amount"), AttrValue::String(amount));
+
+        if form.from_asset != previous.from_asset {
+            self.show_from_asset(form.from_asset.as_ref());
+            self.cancel_from_tasks();
+            self.fetch_from_balance();
+            self.estimate_network_fee();
+        }
+
+        if self.state.quotes_outdated {
+            // The quotes requested on the next refresh pick the restored provider again
+            match &form.route {
+                Some(route) => {
+                    let _ = self.app.attr(
+                        &Id::QuotesTable,
+                        Attribute::Custom("preferred_provider"),
+                        AttrValue::String(route.provider.clone()),
+                    );
+                }
+                None if form.amount.is_none() || form.to_asset.is_none() => {
+                    // No request is complete, the quotes shown answer another one
+                    if let Some(cancel) = self.quotes_cancel.take() {
+                        cancel.cancel();
+                    }
+                    self.dispatch(Action::QuotesSettled);
+                    let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("clear"), AttrValue::Flag(true));
+                }
+                None => {}
+            }
+        } else {
+            self.check_allowance();
+        }
+        self.update_swap_plan();
+
+        // Go back to the step the form is at
+        let step = match (&form.from_asset, &form.to_asset, form.amount) {
+            (None, _, _) => 0,    // SelectFromAsset
+            (_, None, _) => 1,    // SelectToAsset
+            (_, _, None) => 2,    // SelectFromAmount
+            _ => 3,               // CompareQuotes
+        };
+        let _ = self.app.attr(&Id::Instructions, Attribute::Custom("state"), AttrValue::Number(step));
+        match step {
+            0 | 1 => Some(Msg::ExitAmountMode),
+            2 => Some(Msg::EnterAmountMode),
+            _ => None,
+        }
+    }
+
+    /// Show the outcome of an action in the status bar
+    fn show_status(&mut self, status: Status) {
+        let severity = match status.severity {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        let _ = self.app.attr(&Id::StatusBar, Attribute::Custom(severity), AttrValue::String(status.text));
+    }
+
+    /// Cancel the quote request in flight, if any
+    fn cancel_quotes(&mut self) {
+        let Some(cancel) = self.quotes_cancel.take() else {
+            return;
+        };
+        cancel.cancel();
+        self.dispatch(Action::QuotesSettled);
+        let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("cancelled"), AttrValue::Flag(true));
+        self.show_status(Status::info(tr("status-quote-cancelled")));
+        info!("quote request cancelled");
+    }
+
+    /// Show the route of a quote in the swap plan, checking the allowance it needs
+    fn select_route(&mut self, quote: Quote) {
+        self.dispatch(Action::RouteSelected(quote));
+        self.check_allowance();
+        self.update_swap_plan();
+    }
+
+    /// Check the allowance of the FROM token for the route's spender, if it needs one
+    fn check_allowance(&mut self) {
+        let (Some(asset), Some(spender)) = (
+            self.state.from_asset.clone().filter(|asset| asset.contract.is_some() && asset.chain.is_evm()),
+            self.state.route.as_ref().and_then(|route| route.spender.clone()),
+        ) else {
+            return;
+        };
+        let Some(owner) = self.wallet.address(asset.chain).map(str::to_string) else {
+            self.dispatch(Action::AllowanceChecked(asset.id(), spender, Err(tr("plan-no-watched-address"))));
+            return;
+        };
+
+        self.worker.send(ServiceRequest::Allowance { asset, owner, spender }, &self.from_cancel);
+    }
+
+    /// Steps needed to execute the selected route
+    fn plan_steps(&self) -> Vec<String> {
+        let Some(route) = &self.state.route else {
+            return vec![];
+        };
+        let from = route.from.label();
+        let to = route.to.label();
+        let mut steps = vec![];
+
+        let is_token = self.state.from_asset.as_ref().is_some_and(|asset| asset.contract.is_some());
+        if let Some(spender) = route.spender.as_deref().filter(|_| is_token) {
+            let ellipsis = glyphs::ellipsis().to_string();
+            let address = spender.chars().take(10).collect::<String>();
+            let args = [("provider", route.provider.clone()), ("address", address), ("ellipsis", ellipsis.clone())];
+            let spender = tr_args("plan-spender", &args);
+            let (from, spender) = (("from", from.clone()), ("spender", spender));
+            steps.push(match &self.state.allowance {
+                None => tr_args("plan-checking-allowance", &[from, spender, ("ellipsis", ellipsis)]),
+                Some(Ok(allowance)) if allowance.raw() >= route.amount_in.raw() => {
+                    tr_args("plan-approved", &[from, spender, ("allowance", allowance.to_string())])
+                }
+                Some(Ok(allowance)) => {
+                    let args = [
+                        from,
+                        spender,
+                        ("amount", route.amount_in.to_string()),
+                        ("allowance", allowance.to_string()),
+                    ];
+                    tr_args("plan-approval-required", &args)
+                }
+                Some(Err(err)) => tr_args("plan-allowance-unknown", &[spender, ("error", err.clone())]),
+            });
+        }
+
+        let args = [
+            ("amount_in", route.amount_in.to_string()),
+            ("from", from),
+            ("amount_out", route.amount_out.to_string()),
+            ("to", to),
+            ("provider", route.provider.clone()),
+        ];
+        steps.push(tr_args(if route.spender.is_some() { "plan-swap" } else { "plan-deposit" }, &args));
+        steps
+    }
+
+    /// Refresh the steps shown in the swap plan
+    fn update_swap_plan(&mut self) {
+        let steps = self.plan_steps().into_iter().map(PropValue::Str).collect();
+        let _ = self.app.attr(
+            &Id::SwapPlan,
+            Attribute::Custom("steps"),
+            AttrValue::Payload(PropPayload::Vec(steps)),
+        );
+    }
+
+    /// Request the quotes of a changed pair or amount, once for all the changes handled since the
+    /// last call and the edits paused when auto-quoting, and refresh the balance and network fees of the FROM asset once their interval elapsed
+    pub fn refresh(&mut self) {
+        if self.edited_at.is_some_and(|at| at.elapsed() >= self.auto_quote.debounce_interval()) {
+            self.edited_at = None;
+            if let Some(amount) = self.typed_amount.take() {
+                self.set_amount(amount);
+            }
+        }
+        if self.state.quotes_outdated && self.edited_at.is_none() {
+            self.request_quotes();
+        }
+        let due = |at: Instant, interval: Option<Duration>| interval.is_some_and(|interval| at.elapsed() >= interval);
+        if due(self.balance_fetched_at, self.refresh.balance_interval()) {
+            self.fetch_from_balance();
+        }
+        if due(self.fees_estimated_at, self.refresh.fees_interval()) {
+            self.estimate_network_fee();
+        }
+        if self.watchlist_quoted_at.is_some_and(|at| due(at, self.refresh.watchlist_interval())) {
+            self.quote_watchlist();
+        }
+    }
+
+    /// Handle the events polled with `strategy`, refresh background data, then redraw if anything changed and the
+    /// frame cap allows
+    pub fn tick(&mut self, strategy: PollStrategy) -> Result<(), ApplicationError> {
+        // We'll let the component system handle all key events
+        // to avoid conflicting with component-level key handling
+        let messages = self.app.tick(strategy)?;
+        if !messages.is_empty() {
+            // Redraw if at least one message was processed
+            self.redraw = true;
+            for msg in messages.into_iter() {
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = self.update(msg);
+                }
+            }
+            // Describe the state in case of a crash
+            crash::set_state(self.crash_state());
+        }
+
+        // Refresh background data
+        self.refresh();
+
+        if self.flashed_at.is_some_and(|at| at.elapsed() >= notify::FLASH) {
+            self.unflash();
+        }
+
+        // Redraw, unless a frame was just drawn: the changes are drawn together with the next ones
+        if self.redraw && self.drawn_at.elapsed() >= self.frame {
+            self.view();
+            self.redraw = false;
+        }
+        Ok(())
+    }
+
+    /// Ring the bell set, a visual one flashing the screen until a later tick
+    fn ring(&mut self) {
+        let bell = self.config.bell();
+        notify::ring(bell);
+        if bell == Bell::Visual {
+            self.flashed_at = Some(Instant::now());
+        }
+    }
+
+    /// Restore the colors of the screen flashed by a visual bell, if it still is
+    pub fn unflash(&mut self) {
+        if self.flashed_at.take().is_some() {
+            notify::unflash();
+        }
+    }
+
+    /// Enter watch mode, refreshing the quotes of the pair on an interval
+    pub fn start_watch(&mut self) {
+        self.dispatch(Action::WatchToggled(true));
+        let panel = WatchPanel::new(self.refresh.quotes, self.best_quotes.clone());
+        assert!(self
+            .app
+            .remount(
+                Id::WatchPanel,
+                Box::new(panel),
+                vec![
+                    Sub::new(
+                        SubEventClause::Discriminant(UserEvent::QuotesLoaded(
+                            QuoteRequest::default(),
+                            Vec::default(),
+                            None,
+                            Vec::default(),
+                        )),
+                        SubClause::Always,
+                    ),
+                    Sub::new(SubEventClause::Tick, SubClause::Always),
+                ],
+            )
             .is_ok());
+        info!(interval = self.refresh.quotes, "watch mode started");
+        // Outdated quotes are requested anyway
+        if !self.state.quotes_outdated {
+            self.refresh_quotes();
+        }
+    }
+
+    /// Trigger the alerts on the pair of the best quote that it just crossed, and highlight
+    /// the ones still holding
+    fn check_alerts(&mut self, best: &Quote) {
+        let rate = best.rate();
+        let mut holding = vec![];
+        let mut triggered = vec![];
+        for (alert, held) in self.alerts.iter_mut().filter(|(alert, _)| alert.watches(best)) {
+            let crossed = alert.crossed(rate);
+            if crossed && !*held {
+                info!(%alert, rate, provider = %best.provider, "alert triggered");
+                triggered.push(alert.clone());
+                if self.config.notifications.desktop {
+                    let args = [
+                        ("alert", alert.to_string()),
+                        ("rate", format!("{rate:.6}")),
+                        ("provider", best.provider.clone()),
+                    ];
+                    notify::send(&format!("alert {alert}"), &tr("notify-alert"), &tr_args("notify-alert-body", &args));
+                }
+            }
+            if crossed {
+                let args = [
+                    ("alert", alert.to_string()),
+                    ("rate", format!("{rate:.6}")),
+                    ("provider", best.provider.clone()),
+                ];
+                holding.push(tr_args("watch-alert-holding", &args));
+            }
+            *held = crossed;
+        }
+        if !triggered.is_empty() {
+            self.ring();
+        }
+        for alert in triggered {
+            self.run_scripts(|scripts| scripts.alert_triggered(&alert, rate, &best.provider));
+        }
+        let _ = self.app.attr(
+            &Id::WatchPanel,
+            Attribute::Custom("alert"),
+            AttrValue::String(holding.join(", ")),
+        );
+    }
+
+    /// Alert once the best quote meets the target of the limit order on its pair, asking to create the swap when
+    /// configured to, or show how far the rate is from the target
+    fn check_limit_order(&mut self, best: &Quote) {
+        // An order on another pair is kept for when it is quoted again
+        let Some(order) = self.limit_order.as_ref().filter(|order| order.watches(best)) else {
+            let _ = self.app.attr(&Id::WatchPanel, Attribute::Custom("limit"), AttrValue::String(String::new()));
+            return;
+        };
+        let rate = best.rate();
+        if !order.met(rate) {
+            let distance = order.distance(rate);
+            let _ = self.app.attr(
+                &Id::WatchPanel,
+                Attribute::Custom("limit"),
+                AttrValue::String(format!("{order} ({distance:+.2}% from the target)")),
+            );
+            return;
+        }
+
+        // A limit order is filled once
+        let Some(order) = self.limit_order.take() else {
+            return;
+        };
+        info!(%order, rate, provider = %best.provider, "limit order met");
+        let _ = self.app.attr(&Id::WatchPanel, Attribute::Custom("limit"), AttrValue::String(String::new()));
+        self.ring();
+        if self.config.notifications.desktop {
+            let args = [
+                ("order", order.to_string()),
+                ("rate", format!("{rate:.6}")),
+                ("provider", best.provider.clone()),
+            ];
+            notify::send(&format!("limit {order}"), &tr("notify-limit-met"), &tr_args("notify-limit-met-body", &args));
+        }
+        if !self.config.limit_orders.create {
+            let args = [
+                ("order", order.to_string()),
+                ("rate", format!("{rate:.6}")),
+                ("provider", best.provider.clone()),
+            ];
+            self.show_status(Status::info(tr_args("status-limit-met", &args)));
+            return;
+        }
+        let popup = ConfirmPopup::new(
+            tr_args(
+                "confirm-limit-fill",
+                &[
+                    ("order", order.to_string()),
+                    ("amount_in", best.amount_in.to_string()),
+                    ("from", best.from.ticker.clone()),
+                    ("amount_out", best.amount_out.to_string()),
+                    ("to", best.to.ticker.clone()),
+                    ("provider", best.provider.clone()),
+                ],
+            ),
+            Msg::FillLimitOrder(best.clone()),
+            Msg::SkipLimitFill,
+        );
+        assert!(self
+            .app
+            .remount(Id::ConfirmPopup, Box::new(popup), Vec::default())
+            .is_ok());
+        assert!(self.app.active(&Id::ConfirmPopup).is_ok());
+    }
+
+    /// Warning to confirm before creating a swap worth more than its provider's KYC threshold, if it is
+    fn kyc_warning(&self, quote: &Quote) -> Option<String> {
+        let (_, info) = self.provider_info.iter().find(|(name, _)| *name == quote.provider)?;
+        let threshold = info.kyc_threshold?;
+        let price = self.assets.iter().find(|asset| asset.id() == quote.from)?.price_value()?;
+        let value = quote.amount_in.to_f64() * price;
+        if !info.requires_kyc(value) {
+            return None;
+        }
+        warn!(provider = %quote.provider, value, threshold, "swap above the KYC threshold");
+        let args = [
+            ("provider", quote.provider.clone()),
+            ("threshold", format!("{threshold:.0}")),
+            ("fiat", self.config.fiat.to_uppercase()),
+            ("value", format!("{value:.0}")),
+        ];
+        Some(tr_args("confirm-kyc", &args))
+    }
+
+    /// Simulate a swap at `quote`, recording it in the history with a receipt
+    fn simulate_swap(&mut self, quote: Quote) {
+        // Pay out to the watched address on the TO chain, if any
+        let destination = self.wallet.address(quote.to.chain).map(str::to_string);
+        let order = Order::simulate(quote, destination);
+        let _ = history::append(&HistoryEntry::simulated(&order));
+        self.ring();
+        if self.config.notifications.desktop {
+            let quote = &order.quote;
+            let args = [
+                ("amount_in", quote.amount_in.to_string()),
+                ("from", quote.from.label()),
+                ("arrow", glyphs::arrow().to_string()),
+                ("amount_out", quote.amount_out.to_string()),
+                ("to", quote.to.label()),
+                ("provider", quote.provider.clone()),
+            ];
+            let body = tr_args("notify-swap-simulated-body", &args);
+            notify::send(&format!("order {}", order.id), &tr("notify-swap-simulated"), &body);
+        }
+        match export::write_receipt(&self.export.receipts_dir(), &order) {
+            Ok(path) => {
+                let saved = tr_args("status-receipt-saved", &[("path", path.display().to_string())]);
+                self.show_status(Status::info(saved));
+                // Preview the receipt as saved
+                if let Ok(text) = fs::read_to_string(&path) {
+                    self.open_text_viewer(tr_args("viewer-receipt", &[("id", order.id.to_string())]), &text);
+                }
+            }
+            Err(err) => self.show_status(Status {
+                severity: err.severity(),
+                text: tr_args("status-receipt-failed", &[("error", err.to_string())]),
+            }),
+        }
+    }
+
+    /// Open the text viewer titled `title` over what has focus, showing `text`
+    fn open_text_viewer(&mut self, title: String, text: &str) {
+        assert!(self
+            .app
+            .remount(Id::TextViewer, Box::new(TextViewer::new(title, text)), Vec::default())
+            .is_ok());
+        assert!(self.app.active(&Id::TextViewer).is_ok());
+    }
+
+    /// Pin the last best quote of the request, or unpin the quote pinned
+    fn toggle_pin(&mut self) {
+        if let Some(pinned) = self.pinned.take() {
+            info!(provider = %pinned.provider, "quote unpinned");
+            let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("pinned"), AttrValue::String(String::new()));
+            self.show_status(Status::info(tr("status-quote-unpinned")));
+            return;
+        }
+        let best = self.best_quotes.last().map(|(_, best)| best.clone()).filter(|best| self.is_current(best));
+        let Some(best) = best else {
+            self.show_status(Status::warning(tr("status-nothing-to-pin")));
+            return;
+        };
+        info!(provider = %best.provider, amount = %best.amount_out, "quote pinned");
+        let amount = format!("{} {}", best.amount_out, best.to.label());
+        let args = [("amount", amount), ("provider", best.provider.clone())];
+        self.show_status(Status::info(tr_args("status-quote-pinned", &args)));
+        self.pinned = Some(best.clone());
+        self.compare_pinned(Some(&best));
+    }
+
+    /// Whether a quote answers the pair and amount of the form
+    fn is_current(&self, quote: &Quote) -> bool {
+        self.state.from_asset.as_ref().is_some_and(|from| from.id() == quote.from)
+            && self.state.to_asset.as_ref().is_some_and(|to| to.id() == quote.to)
+            && self.state.amount == Some(quote.amount_in)
+    }
+
+    /// Show the best quote of the last refresh against the pinned one, unpinning it once the pair or amount changed
+    fn compare_pinned(&mut self, best: Option<&Quote>) {
+        let Some(pinned) = &self.pinned else {
+            return;
+        };
+        let text = if !self.is_current(pinned) {
+            info!(provider = %pinned.provider, "pinned quote dropped");
+            self.pinned = None;
+            String::new()
+        } else {
+            let mut args = vec![
+                ("amount", pinned.amount_out.to_string()),
+                ("asset", pinned.to.label()),
+                ("provider", pinned.provider.clone()),
+            ];
+            let pinned_amount = pinned.amount_out.to_f64();
+            match best {
+                Some(best) if pinned_amount > 0.0 => {
+                    let delta = (best.amount_out.to_f64() - pinned_amount) / pinned_amount * 100.0;
+                    args.push(("delta", format!("{delta:+.2}")));
+                    tr_args("quotes-pinned", &args)
+                }
+                _ => tr_args("quotes-pinned-alone", &args),
+            }
+        };
+        let _ = self.app.attr(&Id::QuotesTable, Attribute::Custom("pinned"), AttrValue::String(text));
+    }
+
+    /// Open the popup setting the target rate of the limit order on the pair
+    fn open_limit_input(&mut self) {
+        let (Some(from), Some(to)) = (&self.state.from_asset, &self.state.to_asset) else {
+            self.show_status(Status::warning(tr("status-choose-pair")));
+            return;
+        };
+        let current = self
+            .limit_order
+            .as_ref()
+            .filter(|order| order.from == from.id() && order.to == to.id())
+            .map(|order| order.rate);
+        let best = self
+            .best_quotes
+            .iter()
+            .rev()
+            .find(|(_, quote)| quote.from == from.id() && quote.to == to.id())
+            .map(|(_, quote)| quote.rate());
+        let title = tr_args("prompt-limit", &[("to", to.ticker.clone()), ("from", from.ticker.clone())]);
+        let prompt = Prompt::new(Purpose::LimitRate, title)
+            .value(current.map(|rate| rate.to_string()).unwrap_or_default())
+            .placeholder(match best {
+                Some(best) => tr_args("prompt-limit-best", &[("rate", format!("{best:.6}"))]),
+                None => tr("prompt-limit-example"),
+            });
+        self.open_prompt(prompt);
+    }
+
+    /// Show the FROM asset next to the amount, whose step it sets, and in the fee breakdown
+    fn show_from_asset(&mut self, asset: Option<&Asset>) {
+        let id = asset.map(|asset| asset.id().to_string()).unwrap_or_default();
+        let _ = self.app.attr(&Id::AmountInput, Attribute::Custom("from_asset"), AttrValue::String(id.clone()));
+        let step = asset.map(Asset::step).map_or(PropPayload::None, |step| {
+            PropPayload::Tup2((PropValue::U128(step.raw()), PropValue::U8(step.decimals())))
+        });
+        let _ = self.app.attr(&Id::AmountInput, Attribute::Custom("step"), AttrValue::Payload(step));
+        let _ = self.app.attr(&Id::FeeBreakdown, Attribute::Custom("from_asset"), AttrValue::String(id));
+    }
+
+    /// Open `prompt` over the body, taking the keyboard
+    fn open_prompt(&mut self, prompt: Prompt) {
+        assert!(self
+            .app
+            .remount(Id::Prompt, Box::new(prompt), Vec::default())
+            .is_ok());
+        assert!(self.app.active(&Id::Prompt).is_ok());
+    }
+
+    /// Handle the line typed in the prompt for `purpose`, closing it unless the line is turned down
+    fn answer_prompt(&mut self, purpose: Purpose, line: String) -> Option<Msg> {
+        let error = match purpose {
+            Purpose::DraftName if line.is_empty() => return None,
+            Purpose::DraftName => {
+                let saved = drafts::save(&line, &self.draft());
+                saved.err().map(|err| tr_args("prompt-draft-failed", &[("error", err.to_string())]))
+            }
+            Purpose::LimitRate if line.is_empty() => {
+                self.set_limit_order(None);
+                None
+            }
+            Purpose::LimitRate => match line.parse::<f64>() {
+                Ok(rate) if rate.is_finite() && rate > 0.0 => {
+                    self.set_limit_order(Some(rate));
+                    None
+                }
+                _ => Some(tr("prompt-invalid-rate")),
+            },
+            Purpose::ExportPath(..) if line.is_empty() => return None,
+            Purpose::ExportPath(quotes, columns) => {
+                let path = expand_home(&line);
+                match export::write_quotes_csv_to(&path, &quotes, self.network_fee().as_deref(), &columns) {
+                    Ok(()) => {
+                        let args = [("path", path.display().to_string())];
+                        self.show_status(Status::info(tr_args("status-exported", &args)));
+                        None
+                    }
+                    Err(err) => Some(tr_args("status-export-failed", &[("error", err.to_string())])),
+                }
+            }
+        };
+        match error {
+            Some(error) => {
+                let _ = self.app.attr(&Id::Prompt, Attribute::Custom("error"), AttrValue::String(error));
+                None
+            }
+            None => Some(Msg::ClosePrompt),
+        }
+    }
+
+    /// Assets listed with `ticker`, one per chain, with their index in the asset table
+    fn listings(&self, ticker: &str) -> Vec<(usize, Asset)> {
+        self.assets
+            .iter()
+            .enumerate()
+            .filter(|(_, asset)| asset.ticker == ticker)
+            .map(|(index, asset)| (index, asset.clone()))
+            .collect()
+    }
+
+    /// Index of the network fee preset picked in `FeeSpeed::ALL`
+    fn fee_speed_index(&self) -> usize {
+        FeeSpeed::ALL.iter().position(|speed| *speed == self.state.fee_speed).unwrap_or_default()
+    }
+
+    /// Network fee estimated for the pair, as shown in the fee breakdown
+    fn network_fee(&self) -> Option<String> {
+        match self.app.state(&Id::FeeBreakdown) {
+            Ok(State::One(StateValue::String(fee))) => Some(fee),
+            _ => None,
+        }
+    }
+
+    /// Place a limit order on the pair at `rate`, watching its quotes, or cancel the one placed
+    fn set_limit_order(&mut self, rate: Option<f64>) {
+        let (Some(from), Some(to)) = (&self.state.from_asset, &self.state.to_asset) else {
+            return;
+        };
+        let Some(rate) = rate else {
+            if let Some(order) = self.limit_order.take() {
+                info!(%order, "limit order cancelled");
+                let _ = self.app.attr(&Id::WatchPanel, Attribute::Custom("limit"), AttrValue::String(String::new()));
+                self.show_status(Status::info(tr_args("status-limit-cancelled", &[("order", order.to_string())])));
+            }
+            return;
+        };
+        let order = LimitOrder::new(from.id(), to.id(), rate);
+        info!(%order, "limit order placed");
+        self.show_status(Status::info(tr_args("status-limit-placed", &[("order", order.to_string())])));
+        let label = order.to_string();
+        self.limit_order = Some(order);
+        if !self.state.watching {
+            self.start_watch();
+        }
+        let _ = self.app.attr(&Id::WatchPanel, Attribute::Custom("limit"), AttrValue::String(label));
+    }
+
+    /// Leave watch mode
+    fn stop_watch(&mut self) {
+        // A refresh in flight only served the panel
+        if self.state.refreshing_quotes {
+            self.cancel_quotes();
+        }
+        self.dispatch(Action::WatchToggled(false));
+        let _ = self.app.umount(&Id::WatchPanel);
+        info!("watch mode stopped");
+    }
+
+    /// Resolve an ENS name in the background, instead of the one being resolved
+    fn resolve_ens_name(&mut self, name: String) {
+        let cancel = self.worker.scope();
+        if let Some(previous) = self.ens_cancel.replace(cancel.clone()) {
+            previous.cancel();
+        }
+        self.worker.send(ServiceRequest::ResolveEns(name), &cancel);
+    }
+
+    /// Open the portfolio screen, looking up the balances of the watched addresses and the prices of the listed assets
+    fn open_portfolio(&mut self) {
+        let cancel = self.worker.scope();
+        if let Some(previous) = self.portfolio_cancel.replace(cancel.clone()) {
+            previous.cancel();
+        }
+        let watched: Vec<(Asset, String)> = self
+            .assets
+            .iter()
+            .filter_map(|asset| Some((asset.clone(), self.wallet.address(asset.chain)?.to_string())))
+            .collect();
+        let recorded = self.config.holdings().unwrap_or_default();
+        let portfolio = Portfolio::new(
+            recorded,
+            watched.iter().map(|(asset, _)| asset.clone()).collect(),
+            &self.config.fiat,
+        );
+        let subs = vec![
+            Sub::new(
+                SubEventClause::Discriminant(UserEvent::BalanceLoaded(AssetId::default(), Amount::default())),
+                SubClause::Always,
+            ),
+            Sub::new(
+                SubEventClause::Discriminant(UserEvent::BalanceFailed(AssetId::default(), String::new())),
+                SubClause::Always,
+            ),
+            Sub::new(SubEventClause::Discriminant(UserEvent::PricesLoaded(Vec::default())), SubClause::Always),
+            Sub::new(SubEventClause::Discriminant(UserEvent::PricesFailed(String::new())), SubClause::Always),
+        ];
+        assert!(self.app.remount(Id::Portfolio, Box::new(portfolio), subs).is_ok());
+        assert!(self.app.active(&Id::Portfolio).is_ok());
+
+        for (asset, address) in watched {
+            self.worker.send(ServiceRequest::Balance { asset, address }, &cancel);
+        }
+        let request = ServiceRequest::Prices {
+            assets: self.assets.clone(),
+            fiat: self.config.fiat.clone(),
+        };
+        self.worker.send(request, &cancel);
+    }
+
+    /// Ask for the quotes of every pair of the watchlist, instead of those being fetched
+    fn quote_watchlist(&mut self) {
+        let cancel = self.worker.scope();
+        if let Some(previous) = self.watchlist_cancel.replace(cancel.clone()) {
+            previous.cancel();
+        }
+        self.watchlist_quoted_at = Some(Instant::now());
+        let _ = self.app.attr(&Id::Watchlist, Attribute::Custom("refreshing"), AttrValue::Flag(true));
+        for request in self.config.watchlist().unwrap_or_default() {
+            self.worker.send(ServiceRequest::WatchlistQuotes(request), &cancel);
+        }
+    }
+
+    /// Stop quoting the watchlist and close its screen
+    fn close_watchlist(&mut self) {
+        if let Some(cancel) = self.watchlist_cancel.take() {
+            cancel.cancel();
+        }
+        self.watchlist_quoted_at = None;
+        let _ = self.app.umount(&Id::Watchlist);
+        assert!(self.app.active(&Id::AssetTable).is_ok());
+    }
+
+    /// Open the price chart of the FROM asset in the fiat currency, or of its rate in the TO asset once chosen
+    fn open_price_chart(&mut self) {
+        let Some(from) = self.state.from_asset.clone() else {
+            self.show_status(Status::warning(tr("status-choose-chart-asset")));
+            return;
+        };
+        let to = self.state.to_asset.clone();
+        let (subject, unit) = match &to {
+            Some(to) => (from.label(), to.label()),
+            None => (from.label(), self.config.fiat.to_uppercase()),
+        };
+        let timeframe = Timeframe::default();
+        let chart = PriceChart::new(subject, unit, timeframe);
+        let subs = vec![
+            Sub::new(
+                SubEventClause::Discriminant(UserEvent::PriceHistoryLoaded(timeframe, Vec::<PricePoint>::new())),
+                SubClause::Always,
+            ),
+            Sub::new(
+                SubEventClause::Discriminant(UserEvent::PriceHistoryFailed(timeframe, String::new())),
+                SubClause::Always,
+            ),
+        ];
+        assert!(self.app.remount(Id::PriceChart, Box::new(chart), subs).is_ok());
+        assert!(self.app.active(&Id::PriceChart).is_ok());
+        self.fetch_price_history(timeframe);
+    }
+
+    /// Fetch the history charted over `timeframe`, instead of the one being fetched
+    fn fetch_price_history(&mut self, timeframe: Timeframe) {
+        let Some(from) = self.state.from_asset.clone() else {
+            return;
+        };
+        let cancel = self.worker.scope();
+        if let Some(previous) = self.chart_cancel.replace(cancel.clone()) {
+            previous.cancel();
+        }
+        let request = ServiceRequest::PriceHistory {
+            from,
+            to: self.state.to_asset.clone(),
+            fiat: self.config.fiat.clone(),
+            timeframe,
+        };
+        self.worker.send(request, &cancel);
+    }
+
+    /// Open the depth preview of the pair and amount of the form, asking the on-chain aggregators for multiples of
+    /// the amount
+    fn open_depth(&mut self) {
+        let (Some(from), Some(to), Some(amount)) = (self.state.from_asset.clone(), self.state.to_asset.clone(), self.state.amount) else {
+            self.show_status(Status::warning(tr("status-choose-pair-amount")));
+            return;
+        };
+        let request = QuoteRequest { from, to, amount };
+        let cancel = self.worker.scope();
+        if let Some(previous) = self.depth_cancel.replace(cancel.clone()) {
+            previous.cancel();
+        }
+        let subs = vec![Sub::new(
+            SubEventClause::Discriminant(UserEvent::DepthLoaded(String::new(), Vec::default())),
+            SubClause::Always,
+        )];
+        let view = DepthView::new(request.clone());
+        assert!(self.app.remount(Id::DepthView, Box::new(view), subs).is_ok());
+        assert!(self.app.active(&Id::DepthView).is_ok());
+        self.worker.send(ServiceRequest::Depth(request), &cancel);
+    }
+
+    /// Summarize the swap in the window title of the terminal from now on
+    pub(crate) fn manage_title(&mut self) {
+        self.window_title = Some(String::new());
+    }
+
+    /// Summary of the form and its best quote, e.g. `xoswap: 0.1 BTC→ETH · best 5.02 via 0x`
+    fn title(&self) -> String {
+        let (Some(from), Some(to)) = (&self.state.from_asset, &self.state.to_asset) else {
+            return tr("title");
+        };
+        let pair = match self.state.amount {
+            Some(amount) => format!("{amount} {}{}{}", from.label(), glyphs::arrow(), to.label()),
+            None => format!("{}{}{}", from.label(), glyphs::arrow(), to.label()),
+        };
+        let best = self.best_quotes.last().map(|(_, best)| best).filter(|best| self.is_current(best));
+        match best {
+            Some(best) if !self.state.quotes_outdated => tr_args(
+                "title-best",
+                &[
+                    ("pair", pair),
+                    ("amount", best.amount_out.to_string()),
+                    ("provider", best.provider.clone()),
+                ],
+            ),
+            _ => tr_args("title-pair", &[("pair", pair)]),
+        }
+    }
 
-        // Mount the help bar component (visual only)
-        assert!(self
-            .app
-            .mount(Id::HelpBar, Box::new(HelpBar::new()), Vec::default())
-            .is_ok());
+    /// Set the window title, when managed and changed
+    fn show_title(&mut self) {
+        let Some(shown) = &self.window_title else {
+            return;
+        };
+        let title = self.title();
+        if *shown != title {
+            title::set(&title);
+            self.window_title = Some(title);
+        }
+    }
 
-        // Make the asset table active to receive keyboard events
-        assert!(self.app.active(&Id::AssetTable).is_ok());
+    /// Where the keyboard is, and so which keys do something
+    fn key_context(&self) -> Context {
+        match self.app.focus() {
+            Some(Id::AssetTable) => match self.app.query(&Id::AssetTable, Attribute::Custom("mode")) {
+                Ok(Some(AttrValue::String(mode))) if mode == "from" => Context::FromSelection,
+                Ok(Some(AttrValue::String(mode))) if mode == "to" => Context::ToSelection,
+                _ => Context::Assets,
+            },
+            Some(Id::AmountInput) => Context::Amount,
+            Some(Id::QuotesTable) => Context::Quotes,
+            _ => Context::Popup,
+        }
     }
 
     /// Render the UI
     pub fn view(&mut self) {
-        assert!(self
+        self.drawn_at = Instant::now();
+        // Hint the keys of the component focused, in its current mode
+        let context = self.key_context().name().to_string();
+        let _ = self.app.attr(&Id::HelpBar, Attribute::Custom("context"), AttrValue::String(context.clone()));
+        let _ = self.app.attr(&Id::WhichKey, Attribute::Custom("context"), AttrValue::String(context));
+        self.show_title();
+        // A status bar themed with a border takes its two lines
+        let status_height = if self.config.theme.status_bar.border.is_some() { 3 } else { 1 };
+        let columns = &self.config.layout;
+        let frame = self
             .terminal
             .draw(|f| {
                 // First, split the screen vertically for the header and the rest
@@ -124,42 +1902,177 @@ where
                 // Render the header
                 self.app.view(&Id::Header, f, main_chunks[0]);
 
-                // Split the rest horizontally for sidebar and main content
+                // Split the rest horizontally for the sidebar and the main column, the only one with sections
+                // taking the whole width
+                let sidebar = match (columns.sidebar.is_empty(), columns.main.is_empty()) {
+                    (true, _) => 0,
+                    (_, true) => 100,
+                    _ => 30,
+                };
                 let body_chunks = Layout::default()
                     .direction(Direction::Horizontal)
-                    .constraints(
-                        [
-                            Constraint::Percentage(30), // Sidebar (asset table)
-                            Constraint::Percentage(70), // Main content
-                        ]
-                        .as_ref(),
-                    )
+                    .constraints([Constraint::Percentage(sidebar), Constraint::Percentage(100 - sidebar)])
                     .split(main_chunks[1]);
 
-                // Render the asset table as the sidebar
-                self.app.view(&Id::AssetTable, f, body_chunks[0]);
+                // Stack the sections of each column in the configured order
+                let height = |section| match section {
+                    Section::Assets | Section::Quotes => Constraint::Min(1),
+                    Section::Amount => Constraint::Length(3),
+                    Section::SwapPlan => Constraint::Length(4),
+                    Section::Fees => Constraint::Length(5),
+                    Section::Status => Constraint::Length(status_height),
+                    Section::InstructionsBar
+                    | Section::Instructions
+                    | Section::Summary
+                    | Section::Help
+                    | Section::Footer => Constraint::Length(1),
+                };
+                let mut sections = stack(&columns.sidebar, body_chunks[0], height);
+                sections.extend(stack(&columns.main, body_chunks[1], height));
+                for (section, area) in &sections {
+                    self.app.view(&layout::component(*section), f, *area);
+                }
 
-                // Split the main content vertically
-                let main_content_chunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints(
-                        [
-                            Constraint::Length(1),  // Instructions Bar
-                            Constraint::Min(1),     // Main area (instructions)
-                            Constraint::Length(1),  // Summary Bar
-                            Constraint::Length(1),  // Help Bar
-                        ]
-                        .as_ref(),
-                    )
-                    .split(body_chunks[1]);
-
-                // Render the instruction components
-                self.app.view(&Id::InstructionsBar, f, main_content_chunks[0]);
-                self.app.view(&Id::Instructions, f, main_content_chunks[1]);
-                self.app.view(&Id::SummaryBar, f, main_content_chunks[2]);
-                self.app.view(&Id::HelpBar, f, main_content_chunks[3]);
-            })
-            .is_ok());
+                // Render the live best rate over the swap plan and fee breakdown, else over the quotes
+                if self.app.mounted(&Id::WatchPanel) {
+                    let area_of = |wanted| {
+                        let found = sections.iter().find(|(section, _)| *section == wanted);
+                        found.map(|(_, area)| *area)
+                    };
+                    let area = match (area_of(Section::SwapPlan), area_of(Section::Fees)) {
+                        (Some(plan), Some(fees)) => plan.union(fees),
+                        (plan, fees) => plan.or(fees).or(area_of(Section::Quotes)).unwrap_or_default(),
+                    };
+                    self.app.view(&Id::WatchPanel, f, area);
+                }
+
+                // Render the history screen over the body
+                if self.app.mounted(&Id::HistoryTable) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::HistoryTable, f, popup_area(main_chunks[1], 90, height));
+                }
+
+                // Render the settings screen over the body
+                if self.app.mounted(&Id::SettingsForm) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::SettingsForm, f, popup_area(main_chunks[1], 60, height));
+                }
+
+                // Render the log screen over the body
+                if self.app.mounted(&Id::LogViewer) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::LogViewer, f, popup_area(main_chunks[1], 90, height));
+                }
+
+                // Render the metrics screen over the body
+                if self.app.mounted(&Id::MetricsView) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::MetricsView, f, popup_area(main_chunks[1], 80, height));
+                }
+
+                // Render the provider statistics screen over the body
+                if self.app.mounted(&Id::ProviderStats) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::ProviderStats, f, popup_area(main_chunks[1], 70, height));
+                }
+                if self.app.mounted(&Id::ProviderDetail) {
+                    self.app.view(&Id::ProviderDetail, f, popup_area(main_chunks[1], 70, 15));
+                }
+
+                // Render the session statistics screen over the body
+                if self.app.mounted(&Id::SessionStats) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::SessionStats, f, popup_area(main_chunks[1], 70, height));
+                }
+
+                // Render the portfolio screen over the body
+                if self.app.mounted(&Id::Portfolio) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::Portfolio, f, popup_area(main_chunks[1], 80, height));
+                }
+
+                // Render the watchlist screen over the body
+                if self.app.mounted(&Id::Watchlist) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::Watchlist, f, popup_area(main_chunks[1], 80, height));
+                }
+
+                // Render the price chart over the whole body
+                if self.app.mounted(&Id::PriceChart) {
+                    self.app.view(&Id::PriceChart, f, main_chunks[1]);
+                }
+
+                // Render the depth preview over the body
+                if self.app.mounted(&Id::DepthView) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::DepthView, f, popup_area(main_chunks[1], 80, height));
+                }
+
+                // Render the drafts picker over the body
+                if self.app.mounted(&Id::DraftPicker) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::DraftPicker, f, popup_area(main_chunks[1], 70, height));
+                }
+
+                // Render the swaps due over the body
+                if self.app.mounted(&Id::DueSwaps) {
+                    let height = main_chunks[1].height.saturating_sub(2).min(12);
+                    self.app.view(&Id::DueSwaps, f, popup_area(main_chunks[1], 70, height));
+                }
+
+                // Render the prompt over the body
+                if self.app.mounted(&Id::Prompt) {
+                    self.app.view(&Id::Prompt, f, popup_area(f.area(), 60, 3));
+                }
+
+                // Render the slider over the body
+                if self.app.mounted(&Id::Slider) {
+                    self.app.view(&Id::Slider, f, popup_area(f.area(), 60, 3));
+                }
+
+                // Render the dropdown over the body, or over the settings screen it was opened from
+                if self.app.mounted(&Id::Dropdown) {
+                    self.app.view(&Id::Dropdown, f, popup_area(f.area(), 40, f.area().height));
+                }
+
+                // Render the checklist over the body, or over the settings screen it was opened from
+                if self.app.mounted(&Id::MultiSelect) {
+                    self.app.view(&Id::MultiSelect, f, popup_area(f.area(), 50, f.area().height));
+                }
+
+                // Render the text viewer over the body
+                if self.app.mounted(&Id::TextViewer) {
+                    let height = main_chunks[1].height.saturating_sub(2);
+                    self.app.view(&Id::TextViewer, f, popup_area(main_chunks[1], 80, height));
+                }
+
+                // Render the first-run wizard over the body
+                if self.app.mounted(&Id::OnboardingWizard) {
+                    self.app.view(&Id::OnboardingWizard, f, popup_area(f.area(), 60, 12));
+                }
+
+                // Render the details of a provider failure over the body
+                if self.app.mounted(&Id::ErrorPopup) {
+                    self.app.view(&Id::ErrorPopup, f, popup_area(f.area(), 70, 12));
+                }
+
+                // Render the confirmation popup over the body
+                if self.app.mounted(&Id::ConfirmPopup) {
+                    self.app.view(&Id::ConfirmPopup, f, popup_area(f.area(), 60, 3));
+                }
+
+                // Render the watch-only address popup on top of everything
+                if self.app.mounted(&Id::WatchAddressInput) {
+                    self.app.view(&Id::WatchAddressInput, f, popup_area(f.area(), 60, 3));
+                }
+
+                // Render the keys available over the bottom of the body, when open
+                self.app.view(&Id::WhichKey, f, main_chunks[1]);
+            });
+        assert!(frame.is_ok());
+        if let (Ok(frame), Some(trace)) = (frame, &mut self.trace) {
+            trace.frame(frame.buffer);
+        }
     }
 }
 
@@ -170,6 +2083,9 @@ where
 {
     fn update(&mut self, msg: Option<Msg>) -> Option<Msg> {
         if let Some(msg) = msg {
+            let _span = debug_span!("update", msg = %msg.name()).entered();
+            debug!("message");
+
             // Set redraw flag
             self.redraw = true;
 
@@ -179,11 +2095,11 @@ where
                     self.quit = true;
                     None
                 }
-                Msg::AssetSelected(index) => {
+                Msg::AssetSelected(_) => {
                     // Asset was highlighted
                     None
                 }
-                Msg::AssetChosenAsFrom(index, ticker) => {
+                Msg::AssetChosenAsFrom(_, asset) => {
                     // Asset was selected as FROM asset
                     self.redraw = true;
                     
@@ -191,8 +2107,17 @@ where
                     let _ = self.app.attr(
                         &Id::SummaryBar,
                         Attribute::Custom("from_ticker"),
-                        AttrValue::String(ticker)
+                        AttrValue::String(asset.label())
                     );
+
+                    // Show the FROM asset and its balance next to the amount
+                    self.show_from_asset(Some(&asset));
+
+                    self.dispatch(Action::FromChosen(asset));
+                    self.debounce_quotes();
+                    self.cancel_from_tasks();
+                    self.fetch_from_balance();
+                    self.estimate_network_fee();
                     
                     // Update instructions state to select TO asset
                     let _ = self.app.attr(
@@ -203,7 +2128,7 @@ where
                     
                     None
                 }
-                Msg::AssetChosenAsTo(index, ticker) => {
+                Msg::AssetChosenAsTo(_, asset) => {
                     // Asset was selected as TO asset
                     self.redraw = true;
                     
@@ -211,8 +2136,10 @@ where
                     let _ = self.app.attr(
                         &Id::SummaryBar,
                         Attribute::Custom("to_ticker"),
-                        AttrValue::String(ticker)
+                        AttrValue::String(asset.label())
                     );
+                    self.dispatch(Action::ToChosen(asset));
+                    self.debounce_quotes();
                     
                     // Update instructions state to select FROM amount
                     let _ = self.app.attr(
@@ -221,7 +2148,7 @@ where
                         AttrValue::Number(2) // SelectFromAmount
                     );
                     
-                    None
+                    Some(Msg::EnterAmountMode)
                 }
                 Msg::EnterFromAssetMode => {
                     // Entering FROM asset selection mode
@@ -254,6 +2181,778 @@ where
                     self.redraw = true;
                     None
                 }
+                Msg::EnterAmountMode => {
+                    // Give the keyboard to the amount field
+                    assert!(self.app.active(&Id::AmountInput).is_ok());
+                    None
+                }
+                Msg::ExitAmountMode => {
+                    // Give the keyboard back to the asset table
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::AmountEdited(amount) => {
+                    if self.auto_quote.enabled {
+                        self.typed_amount = Some(amount);
+                        self.debounce_quotes();
+                    }
+                    None
+                }
+                Msg::AmountSubmitted(amount) => {
+                    // A confirmed amount is quoted right away
+                    self.typed_amount = None;
+                    self.edited_at = None;
+                    self.set_amount(amount);
+                    Some(Msg::ExitAmountMode)
+                }
+                Msg::QuotesReceived(best, _) if self.state.refreshing_quotes => {
+                    // A refresh keeps the step and focus, only updating the route
+                    self.dispatch(Action::QuotesSettled);
+                    self.quotes_cancel = None;
+                    self.run_scripts(|scripts| scripts.quotes_received(best.as_ref()));
+                    self.compare_pinned(best.as_ref());
+                    if let Some(best) = best {
+                        self.best_quotes.push((Instant::now(), best.clone()));
+                        let _ = self.app.attr(
+                            &Id::SummaryBar,
+                            Attribute::Custom("to_amount"),
+                            AttrValue::String(best.amount_out.to_string())
+                        );
+                        self.fetch_reverse(&best);
+                        self.select_route(best);
+                    }
+                    None
+                }
+                Msg::QuotesReceived(best, offers) => {
+                    self.quotes_cancel = None;
+                    self.run_scripts(|scripts| scripts.quotes_received(best.as_ref()));
+                    self.compare_pinned(best.as_ref());
+                    // Show the best offer in the summary bar
+                    if let Some(best) = best {
+                        let _ = history::append(&HistoryEntry::quoted(&best, &offers));
+                        self.best_quotes.push((Instant::now(), best.clone()));
+                        let _ = self.app.attr(
+                            &Id::SummaryBar,
+                            Attribute::Custom("to_amount"),
+                            AttrValue::String(best.amount_out.to_string())
+                        );
+                        self.fetch_reverse(&best);
+                        self.select_route(best);
+                    }
+
+                    // Move on to the comparison step, unless quoted while the amount is being typed
+                    let _ = self.app.attr(
+                        &Id::Instructions,
+                        Attribute::Custom("state"),
+                        AttrValue::Number(3) // CompareQuotes
+                    );
+                    if !self.auto_quote.enabled || self.app.focus() != Some(&Id::AmountInput) {
+                        assert!(self.app.active(&Id::QuotesTable).is_ok());
+                    }
+                    None
+                }
+                Msg::ReverseQuoted(key, back) => {
+                    self.reverse_cancel = None;
+                    self.show_round_trip(&key, back);
+                    None
+                }
+                Msg::QuoteChosen(quote) => {
+                    let _ = self.app.attr(
+                        &Id::SummaryBar,
+                        Attribute::Custom("to_amount"),
+                        AttrValue::String(quote.amount_out.to_string())
+                    );
+                    self.dispatch(Action::RouteChosen(quote));
+                    self.check_allowance();
+                    self.update_swap_plan();
+                    None
+                }
+                Msg::Undo => self.restore_form(Action::Undo),
+                Msg::Redo => self.restore_form(Action::Redo),
+                Msg::ExportQuotes(quotes) => {
+                    let network_fee = self.network_fee();
+                    let (dir, columns) = (self.export.dir(), &self.export.columns);
+                    let written = export::write_quotes_csv(&dir, &quotes, network_fee.as_deref(), columns);
+                    self.show_status(match written {
+                        Ok(path) => Status::info(tr_args("status-exported", &[("path", path.display().to_string())])),
+                        Err(err) => Status {
+                            severity: err.severity(),
+                            text: tr_args("status-export-failed", &[("error", err.to_string())]),
+                        },
+                    });
+                    None
+                }
+                Msg::SimulateSwap(quote) => {
+                    if let Some(jurisdiction) = self.restriction(&quote.provider) {
+                        warn!(provider = %quote.provider, jurisdiction, "swap through a restricted provider refused");
+                        let args = [("provider", quote.provider.clone()), ("jurisdiction", jurisdiction.to_string())];
+                        self.show_status(Status::warning(tr_args("status-restricted", &args)));
+                        return None;
+                    }
+                    let Some(warning) = self.kyc_warning(&quote) else {
+                        self.simulate_swap(quote);
+                        return None;
+                    };
+                    let popup = ConfirmPopup::new(warning, Msg::SwapDespiteKyc(quote), Msg::SkipKycSwap);
+                    assert!(self
+                        .app
+                        .remount(Id::ConfirmPopup, Box::new(popup), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::ConfirmPopup).is_ok());
+                    None
+                }
+                Msg::SwapDespiteKyc(quote) => {
+                    let _ = self.app.umount(&Id::ConfirmPopup);
+                    assert!(self.app.active(&Id::QuotesTable).is_ok());
+                    self.simulate_swap(quote);
+                    None
+                }
+                Msg::SkipKycSwap => {
+                    let _ = self.app.umount(&Id::ConfirmPopup);
+                    assert!(self.app.active(&Id::QuotesTable).is_ok());
+                    None
+                }
+                Msg::OpenHistory => {
+                    let table = HistoryTable::new(history::load());
+                    assert!(self
+                        .app
+                        .remount(Id::HistoryTable, Box::new(table), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::HistoryTable).is_ok());
+                    None
+                }
+                Msg::CloseHistory => {
+                    let _ = self.app.umount(&Id::HistoryTable);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::ReopenHistoryEntry(entry) => {
+                    let _ = self.app.umount(&Id::HistoryTable);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+
+                    // Assets no longer listed are left out of the draft
+                    let prefill = Prefill::from_draft(&Draft::from(&entry), &self.assets);
+                    self.prefill(prefill);
+                    None
+                }
+                Msg::AllowanceChecked(asset, spender, allowance) => {
+                    self.dispatch(Action::AllowanceChecked(asset, spender, allowance));
+                    self.update_swap_plan();
+                    None
+                }
+                Msg::RestoreSession(draft) => {
+                    let _ = self.app.umount(&Id::ConfirmPopup);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    let prefill = Prefill::from_draft(&draft, &self.assets);
+                    self.prefill(prefill);
+                    None
+                }
+                Msg::DiscardSession => {
+                    let _ = self.app.umount(&Id::ConfirmPopup);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    let _ = session::clear();
+                    None
+                }
+                Msg::OpenDraftNameInput => {
+                    let title = tr_args("prompt-draft", &[("draft", self.draft().summary())]);
+                    self.open_prompt(Prompt::new(Purpose::DraftName, title).placeholder(tr("prompt-draft-example")));
+                    None
+                }
+                Msg::OpenExportPrompt(quotes, columns) => {
+                    let path = export::quotes_csv_path(&self.export.dir(), quotes.first()?);
+                    let title = tr("prompt-export");
+                    let purpose = Purpose::ExportPath(quotes, columns);
+                    self.open_prompt(Prompt::new(purpose, title).value(path.display().to_string()));
+                    None
+                }
+                Msg::PromptSubmitted(purpose, line) => self.answer_prompt(purpose, line),
+                Msg::ClosePrompt => {
+                    let _ = self.app.umount(&Id::Prompt);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::OpenSlider(setting) => {
+                    let value = match setting {
+                        Setting::Slippage => self.config.slippage,
+                        Setting::FeeSpeed => self.fee_speed_index() as f64,
+                    };
+                    assert!(self
+                        .app
+                        .remount(Id::Slider, Box::new(Slider::new(setting, value)), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::Slider).is_ok());
+                    None
+                }
+                Msg::SliderSubmitted(setting, value) => {
+                    match setting {
+                        // For the session only, the settings screen saves it
+                        Setting::Slippage => {
+                            self.config.slippage = value;
+                            let _ = self.app.attr(
+                                &Id::QuotesTable,
+                                Attribute::Custom("slippage"),
+                                AttrValue::Payload(PropPayload::One(PropValue::F64(value))),
+                            );
+                        }
+                        Setting::FeeSpeed => {
+                            let index = (value as usize).min(FeeSpeed::ALL.len() - 1);
+                            self.dispatch(Action::FeeSpeedPicked(FeeSpeed::ALL[index]));
+                            let _ = self.app.attr(
+                                &Id::FeeBreakdown,
+                                Attribute::Custom("fee_speed"),
+                                AttrValue::Number(self.fee_speed_index() as isize)
+                            );
+                        }
+                    }
+                    Some(Msg::CloseSlider)
+                }
+                Msg::CloseSlider => {
+                    let _ = self.app.umount(&Id::Slider);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::OpenDropdown(choice) => {
+                    let dropdown = match &choice {
+                        Choice::Chain(asset) => {
+                            let listings = self.listings(&asset.ticker);
+                            let chains = listings.iter().map(|(_, listed)| listed.chain.to_string()).collect();
+                            let selected = listings.iter().position(|(_, listed)| listed.chain == asset.chain);
+                            let title = tr_args("dropdown-chain", &[("asset", asset.ticker.clone())]);
+                            Dropdown::new(choice.clone(), title, chains).selected(selected.unwrap_or_default())
+                        }
+                        Choice::Fiat(fiat) => {
+                            let currencies = FIAT_CURRENCIES.iter().map(|fiat| fiat.to_uppercase()).collect();
+                            let selected = FIAT_CURRENCIES.iter().position(|listed| listed == fiat);
+                            Dropdown::new(choice.clone(), tr("dropdown-fiat"), currencies)
+                                .selected(selected.unwrap_or_default())
+                        }
+                    };
+                    assert!(self
+                        .app
+                        .remount(Id::Dropdown, Box::new(dropdown), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::Dropdown).is_ok());
+                    None
+                }
+                Msg::DropdownSubmitted(choice, index) => {
+                    let _ = self.app.umount(&Id::Dropdown);
+                    match choice {
+                        Choice::Chain(asset) => {
+                            assert!(self.app.active(&Id::AssetTable).is_ok());
+                            let (index, asset) = self.listings(&asset.ticker).into_iter().nth(index)?;
+                            // Choose the listing for the role the table is choosing, as Enter would
+                            let to = matches!(
+                                self.app.query(&Id::AssetTable, Attribute::Custom("mode")),
+                                Ok(Some(AttrValue::String(mode))) if mode == "to"
+                            );
+                            let (attr, msg) = if to {
+                                ("to_index", Msg::AssetChosenAsTo(index, asset))
+                            } else {
+                                ("from_index", Msg::AssetChosenAsFrom(index, asset))
+                            };
+                            let index_value = AttrValue::Number(index as isize);
+                            let _ = self.app.attr(&Id::AssetTable, Attribute::Custom(attr), index_value);
+                            Some(msg)
+                        }
+                        Choice::Fiat(_) => {
+                            let fiat = AttrValue::String(FIAT_CURRENCIES.get(index)?.to_string());
+                            let _ = self.app.attr(&Id::SettingsForm, Attribute::Custom("fiat"), fiat);
+                            assert!(self.app.active(&Id::SettingsForm).is_ok());
+                            None
+                        }
+                    }
+                }
+                Msg::OpenMultiSelect(checklist) => {
+                    let (title, options) = match &checklist {
+                        Checklist::Providers(enabled) => {
+                            let enabled = |name: &str| enabled.iter().any(|p| p.eq_ignore_ascii_case(name));
+                            let options = NAMES.iter().map(|name| (name.to_string(), enabled(name))).collect();
+                            (tr("checklist-providers"), options)
+                        }
+                        Checklist::ExportColumns(_) => {
+                            let options = Column::ALL
+                                .iter()
+                                .map(|column| (column.name().to_string(), self.export.columns.contains(column)))
+                                .collect();
+                            (tr("checklist-export-columns"), options)
+                        }
+                    };
+                    let checklist = MultiSelect::new(checklist, title, options);
+                    assert!(self
+                        .app
+                        .remount(Id::MultiSelect, Box::new(checklist), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::MultiSelect).is_ok());
+                    None
+                }
+                Msg::MultiSelectSubmitted(checklist, ticked) => match checklist {
+                    Checklist::Providers(_) => {
+                        // Kept in the usual provider order
+                        let providers = ticked.iter().filter_map(|index| NAMES.get(*index));
+                        let providers = providers.map(|name| PropValue::Str(name.to_string())).collect();
+                        let providers = AttrValue::Payload(PropPayload::Vec(providers));
+                        let _ = self.app.attr(&Id::SettingsForm, Attribute::Custom("providers"), providers);
+                        Some(Msg::CloseMultiSelect)
+                    }
+                    Checklist::ExportColumns(_) if ticked.is_empty() => {
+                        let error = AttrValue::String(tr("checklist-no-columns"));
+                        let _ = self.app.attr(&Id::MultiSelect, Attribute::Custom("error"), error);
+                        None
+                    }
+                    Checklist::ExportColumns(quotes) => {
+                        let _ = self.app.umount(&Id::MultiSelect);
+                        // The next export starts from the columns ticked this time
+                        let columns = ticked.iter().filter_map(|index| Column::ALL.get(*index));
+                        self.export.columns = columns.copied().collect();
+                        Some(Msg::OpenExportPrompt(quotes, self.export.columns.clone()))
+                    }
+                },
+                Msg::CloseMultiSelect => {
+                    let _ = self.app.umount(&Id::MultiSelect);
+                    // Providers are ticked over the settings screen
+                    let back = if self.app.mounted(&Id::SettingsForm) { Id::SettingsForm } else { Id::AssetTable };
+                    assert!(self.app.active(&back).is_ok());
+                    None
+                }
+                Msg::CloseDropdown => {
+                    let _ = self.app.umount(&Id::Dropdown);
+                    // The fiat currency is picked over the settings screen
+                    let back = if self.app.mounted(&Id::SettingsForm) { Id::SettingsForm } else { Id::AssetTable };
+                    assert!(self.app.active(&back).is_ok());
+                    None
+                }
+                Msg::OpenDrafts => {
+                    let drafts = drafts::load().and_then(|drafts| Ok((drafts, schedules::load()?)));
+                    let (drafts, schedules) = match drafts {
+                        Ok(drafts) => drafts,
+                        Err(err) => {
+                            self.show_status(Status {
+                                severity: err.severity(),
+                                text: tr_args("status-drafts-failed", &[("error", err.to_string())]),
+                            });
+                            return None;
+                        }
+                    };
+                    let picker = DraftPicker::new(drafts, schedules);
+                    assert!(self
+                        .app
+                        .remount(Id::DraftPicker, Box::new(picker), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::DraftPicker).is_ok());
+                    None
+                }
+                Msg::CloseDrafts => {
+                    let _ = self.app.umount(&Id::DraftPicker);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::LoadDraft(name, draft) => {
+                    let _ = self.app.umount(&Id::DraftPicker);
+                    let _ = self.app.umount(&Id::DueSwaps);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    // Loading a scheduled draft takes care of its swap due
+                    let _ = schedules::advance(&name, Utc::now());
+                    let prefill = Prefill::from_draft(&draft, &self.assets);
+                    self.prefill(prefill);
+                    None
+                }
+                Msg::DeleteDraft(name) => {
+                    if let Err(err) = drafts::remove(&name) {
+                        self.show_status(Status {
+                            severity: err.severity(),
+                            text: tr_args("status-drafts-failed", &[("error", err.to_string())]),
+                        });
+                        return None;
+                    }
+                    let _ = schedules::set(&name, None);
+                    None
+                }
+                Msg::CloseDueSwaps => {
+                    let _ = self.app.umount(&Id::DueSwaps);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::ScheduleDraft(name, frequency) => {
+                    let schedule = frequency.map(|frequency| Schedule::new(frequency, Utc::now()));
+                    if let Err(err) = schedules::set(&name, schedule) {
+                        self.show_status(Status {
+                            severity: err.severity(),
+                            text: tr_args("status-schedule-failed", &[("error", err.to_string())]),
+                        });
+                    }
+                    None
+                }
+                Msg::OpenSettings => {
+                    let form = SettingsForm::new(self.config.clone());
+                    assert!(self
+                        .app
+                        .remount(Id::SettingsForm, Box::new(form), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::SettingsForm).is_ok());
+                    None
+                }
+                Msg::CloseSettings => {
+                    let _ = self.app.umount(&Id::SettingsForm);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::SaveSettings(config) => match config.save_settings(&self.config) {
+                    Ok(()) => {
+                        self.apply_settings(*config);
+                        Some(Msg::CloseSettings)
+                    }
+                    Err(err) => {
+                        let _ = self.app.attr(
+                            &Id::SettingsForm,
+                            Attribute::Custom("status"),
+                            AttrValue::String(tr_args("settings-save-failed", &[("error", err.to_string())]))
+                        );
+                        None
+                    }
+                },
+                Msg::SwitchProfile(profile) => {
+                    match Config::load(profile.as_deref()) {
+                        Ok(config) => {
+                            self.apply_settings(config);
+                            let form = SettingsForm::new(self.config.clone());
+                            assert!(self
+                                .app
+                                .remount(Id::SettingsForm, Box::new(form), Vec::default())
+                                .is_ok());
+                            assert!(self.app.active(&Id::SettingsForm).is_ok());
+                        }
+                        Err(err) => {
+                            let _ = self.app.attr(
+                                &Id::SettingsForm,
+                                Attribute::Custom("status"),
+                                AttrValue::String(err.to_string())
+                            );
+                        }
+                    }
+                    None
+                }
+                Msg::OpenLogs => {
+                    assert!(self
+                        .app
+                        .remount(Id::LogViewer, Box::new(LogViewer::new()), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::LogViewer).is_ok());
+                    None
+                }
+                Msg::CloseLogs => {
+                    let _ = self.app.umount(&Id::LogViewer);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::OpenCrashReport => {
+                    let report = crash::latest_report()
+                        .and_then(|path| fs::read_to_string(&path).ok().map(|text| (path, text)));
+                    match report {
+                        Some((path, text)) => {
+                            let name = path.file_name().unwrap_or_default().to_string_lossy();
+                            self.open_text_viewer(tr_args("viewer-crash-report", &[("name", name.to_string())]), &text);
+                        }
+                        None => self.show_status(Status::info(tr("status-no-crash-report"))),
+                    }
+                    None
+                }
+                Msg::CloseTextViewer => {
+                    // Focus goes back to what the viewer was opened over
+                    let _ = self.app.umount(&Id::TextViewer);
+                    None
+                }
+                // The status bar reports it itself, keeping it for its details
+                Msg::ProviderFailed(QuoteFailure { provider, error }) => {
+                    if self.config.notifications.desktop {
+                        let summary = tr_args("notify-provider-failed", &[("provider", provider.clone())]);
+                        notify::send(&format!("provider {provider}"), &summary, &error.root().to_string());
+                    }
+                    None
+                }
+                Msg::OpenErrorDetail(failure) => {
+                    assert!(self
+                        .app
+                        .remount(Id::ErrorPopup, Box::new(ErrorPopup::new(failure)), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::ErrorPopup).is_ok());
+                    None
+                }
+                Msg::CloseErrorDetail => {
+                    let _ = self.app.umount(&Id::ErrorPopup);
+                    assert!(self.app.active(&Id::QuotesTable).is_ok());
+                    None
+                }
+                Msg::RetryProvider(provider) => {
+                    let _ = self.app.umount(&Id::ErrorPopup);
+                    assert!(self.app.active(&Id::QuotesTable).is_ok());
+                    let _ = self.app.attr(
+                        &Id::QuotesTable,
+                        Attribute::Custom("retrying"),
+                        AttrValue::String(provider.clone()),
+                    );
+                    self.retry_provider(provider);
+                    None
+                }
+                Msg::ShowStatus(status) => {
+                    self.show_status(status);
+                    None
+                }
+                Msg::DismissStatus => {
+                    let _ = self.app.attr(&Id::StatusBar, Attribute::Custom("dismiss"), AttrValue::Flag(true));
+                    if self.app.focus() == Some(&Id::StatusBar) {
+                        assert!(self.app.active(&Id::QuotesTable).is_ok());
+                    }
+                    None
+                }
+                Msg::FocusStatus => {
+                    let detail = self.app.query(&Id::StatusBar, Attribute::Custom("detail"));
+                    if matches!(detail, Ok(Some(AttrValue::Flag(true)))) {
+                        assert!(self.app.active(&Id::StatusBar).is_ok());
+                    }
+                    None
+                }
+                Msg::BlurStatus => {
+                    assert!(self.app.active(&Id::QuotesTable).is_ok());
+                    None
+                }
+                Msg::CancelQuotes => {
+                    self.cancel_quotes();
+                    None
+                }
+                Msg::Control(control) => self.run_control(control),
+                Msg::Requote => {
+                    info!("re-quote requested");
+                    if self.state.route.is_some() {
+                        self.refresh_quotes();
+                    } else {
+                        self.request_quotes();
+                    }
+                    None
+                }
+                Msg::RefreshQuotes => {
+                    self.refresh_quotes();
+                    None
+                }
+                Msg::BestQuoteWatched(best) => {
+                    self.check_alerts(&best);
+                    self.check_limit_order(&best);
+                    None
+                }
+                Msg::ToggleWatch => {
+                    if self.state.watching {
+                        self.stop_watch();
+                    } else {
+                        self.start_watch();
+                    }
+                    None
+                }
+                Msg::OpenMetrics => {
+                    assert!(self
+                        .app
+                        .remount(Id::MetricsView, Box::new(MetricsView::new(self.timeouts.clone())), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::MetricsView).is_ok());
+                    None
+                }
+                Msg::CloseMetrics => {
+                    let _ = self.app.umount(&Id::MetricsView);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::OpenProviderStats => {
+                    let stats = ProviderStats::new(self.provider_info.clone());
+                    assert!(self.app.remount(Id::ProviderStats, Box::new(stats), Vec::default()).is_ok());
+                    assert!(self.app.active(&Id::ProviderStats).is_ok());
+                    None
+                }
+                Msg::CloseProviderStats => {
+                    let _ = self.app.umount(&Id::ProviderStats);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::OpenProviderDetail(name, info) => {
+                    let fiat = self.config.fiat.to_uppercase();
+                    let detail = ProviderDetail::new(name.clone(), info, fiat, self.config.jurisdiction.clone());
+                    let discovered = UserEvent::ProviderDiscovered(String::new(), Capabilities::default());
+                    let subs = vec![Sub::new(SubEventClause::Discriminant(discovered), SubClause::Always)];
+                    assert!(self.app.remount(Id::ProviderDetail, Box::new(detail), subs).is_ok());
+                    assert!(self.app.active(&Id::ProviderDetail).is_ok());
+                    let cancel = self.worker.scope();
+                    if let Some(previous) = self.discovery_cancel.replace(cancel.clone()) {
+                        previous.cancel();
+                    }
+                    let request = ServiceRequest::Discover {
+                        provider: name,
+                        assets: self.assets.clone(),
+                        pair: self.state.from_asset.clone().zip(self.state.to_asset.clone()),
+                    };
+                    self.worker.send(request, &cancel);
+                    None
+                }
+                Msg::CloseProviderDetail => {
+                    if let Some(cancel) = self.discovery_cancel.take() {
+                        cancel.cancel();
+                    }
+                    let _ = self.app.umount(&Id::ProviderDetail);
+                    assert!(self.app.active(&Id::ProviderStats).is_ok());
+                    None
+                }
+                Msg::OpenSessionStats => {
+                    let entries = history::load();
+                    let summary = HistorySummary::new(entries.iter().filter(|entry| entry.date >= self.started_at));
+                    let stats = SessionStats::new(summary, self.started_at);
+                    assert!(self.app.remount(Id::SessionStats, Box::new(stats), Vec::default()).is_ok());
+                    assert!(self.app.active(&Id::SessionStats).is_ok());
+                    None
+                }
+                Msg::CloseSessionStats => {
+                    let _ = self.app.umount(&Id::SessionStats);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::OpenPortfolio => {
+                    self.open_portfolio();
+                    None
+                }
+                Msg::ClosePortfolio => {
+                    if let Some(cancel) = self.portfolio_cancel.take() {
+                        cancel.cancel();
+                    }
+                    let _ = self.app.umount(&Id::Portfolio);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::OpenWatchlist => {
+                    let requests = self.config.watchlist().unwrap_or_default();
+                    let watchlist = Watchlist::new(requests, self.refresh.watchlist);
+                    let subs = vec![Sub::new(
+                        SubEventClause::Discriminant(UserEvent::WatchlistQuoted(QuoteRequest::default(), None)),
+                        SubClause::Always,
+                    )];
+                    assert!(self.app.remount(Id::Watchlist, Box::new(watchlist), subs).is_ok());
+                    assert!(self.app.active(&Id::Watchlist).is_ok());
+                    self.quote_watchlist();
+                    None
+                }
+                Msg::OpenPriceChart => {
+                    self.open_price_chart();
+                    None
+                }
+                Msg::ClosePriceChart => {
+                    if let Some(cancel) = self.chart_cancel.take() {
+                        cancel.cancel();
+                    }
+                    let _ = self.app.umount(&Id::PriceChart);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::ChartTimeframeChanged(timeframe) => {
+                    self.fetch_price_history(timeframe);
+                    None
+                }
+                Msg::OpenDepth => {
+                    self.open_depth();
+                    None
+                }
+                Msg::PinQuote => {
+                    self.toggle_pin();
+                    None
+                }
+                Msg::OpenLimitInput => {
+                    self.open_limit_input();
+                    None
+                }
+                Msg::FillLimitOrder(quote) => {
+                    let _ = self.app.umount(&Id::ConfirmPopup);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    Some(Msg::SimulateSwap(quote))
+                }
+                Msg::SkipLimitFill => {
+                    let _ = self.app.umount(&Id::ConfirmPopup);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::CloseDepth => {
+                    if let Some(cancel) = self.depth_cancel.take() {
+                        cancel.cancel();
+                    }
+                    let _ = self.app.umount(&Id::DepthView);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::CloseWatchlist => {
+                    self.close_watchlist();
+                    None
+                }
+                Msg::LoadWatchedPair(draft) => {
+                    self.close_watchlist();
+                    let prefill = Prefill::from_draft(&draft, &self.assets);
+                    self.prefill(prefill);
+                    None
+                }
+                Msg::RaiseLogLevel => {
+                    let level = logging::raise_level();
+                    info!(%level, "log level changed");
+                    self.show_log_level();
+                    None
+                }
+                Msg::FinishOnboarding(config) => match config.save_settings(&self.config) {
+                    Ok(()) => {
+                        self.apply_settings(*config);
+                        Some(Msg::SkipOnboarding)
+                    }
+                    Err(err) => {
+                        let _ = self.app.attr(
+                            &Id::OnboardingWizard,
+                            Attribute::Custom("error"),
+                            AttrValue::String(err.to_string())
+                        );
+                        None
+                    }
+                },
+                Msg::SkipOnboarding => {
+                    let _ = self.app.umount(&Id::OnboardingWizard);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::ExitQuotesMode => {
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::OpenWatchAddressInput(chain) => {
+                    let input = WatchAddressInput::new(chain, self.wallet.address(chain));
+                    assert!(self
+                        .app
+                        .remount(Id::WatchAddressInput, Box::new(input), Vec::default())
+                        .is_ok());
+                    assert!(self.app.active(&Id::WatchAddressInput).is_ok());
+                    None
+                }
+                Msg::CloseWatchAddressInput => {
+                    if let Some(cancel) = self.ens_cancel.take() {
+                        cancel.cancel();
+                    }
+                    let _ = self.app.umount(&Id::WatchAddressInput);
+                    assert!(self.app.active(&Id::AssetTable).is_ok());
+                    None
+                }
+                Msg::WatchAddressSubmitted(chain, address) => {
+                    self.wallet.register(chain, address);
+
+                    // Refresh the balance if the address belongs to the FROM asset
+                    if self.state.from_asset.as_ref().map(|asset| asset.chain) == Some(chain) {
+                        self.fetch_from_balance();
+                        self.dispatch(Action::AllowanceReset);
+                        self.check_allowance();
+                        self.update_swap_plan();
+                    }
+
+                    Some(Msg::CloseWatchAddressInput)
+                }
+                Msg::ResolveEnsName(name) => {
+                    self.resolve_ens_name(name);
+                    None
+                }
                 Msg::None => None,
             }
         } else {
@@ -261,3 +2960,19 @@ where
         }
     }
 }
+
+/// Time each provider has to quote, by name, for those giving up at some point
+/// What each provider is, by name, with the KYC thresholds of `config`
+fn provider_info(config: &Config, providers: &[Box<dyn Provider>]) -> Vec<(String, ProviderInfo)> {
+    providers
+        .iter()
+        .map(|provider| (provider.name().to_string(), config.provider_info(provider.as_ref())))
+        .collect()
+}
+
+fn timeouts(providers: &[Box<dyn Provider>]) -> BTreeMap<String, Duration> {
+    providers
+        .iter()
+        .filter_map(|provider| Some((provider.name().to_string(), provider.timeout()?)))
+        .collect()
+}
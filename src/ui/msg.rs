@@ -2,23 +2,229 @@
 //! 
 //! Application messages
 
+use crate::config::Config;
+use crate::models::amount::Amount;
+use crate::models::asset::{Asset, AssetId};
+use crate::models::chain::Chain;
+use crate::models::chart::Timeframe;
+use crate::models::draft::Draft;
+use crate::models::history::HistoryEntry;
+use crate::models::provider::ProviderInfo;
+use crate::models::quote::{Quote, QuoteFailure};
+use crate::models::schedule::Frequency;
+use crate::models::status::Status;
+use crate::services::export::Column;
+use crate::ui::components::dropdown::Choice;
+use crate::ui::components::multi_select::Checklist;
+use crate::ui::components::prompt::Purpose;
+use crate::ui::components::slider::Setting;
+use crate::ui::control::Control;
+
 /// Messages for the application
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum Msg {
     /// Application should close
     AppClose,
     /// Asset was highlighted (but not selected)
     AssetSelected(usize),
     /// Asset was chosen as the FROM asset (Enter pressed)
-        AssetChosenAsFrom(usize, String),
-        /// Asset was chosen as the TO asset (Tab pressed)
-        AssetChosenAsTo(usize, String),
+    AssetChosenAsFrom(usize, Asset),
+    /// Asset was chosen as the TO asset (Tab pressed)
+    AssetChosenAsTo(usize, Asset),
     /// Enter FROM asset selection mode
     EnterFromAssetMode,
     /// Enter TO asset selection mode
     EnterToAssetMode,
     /// Exit asset selection mode
     ExitAssetSelectionMode,
+    /// Focus the FROM amount field
+    EnterAmountMode,
+    /// Leave the FROM amount field
+    ExitAmountMode,
+    /// FROM amount was typed, not yet confirmed
+    AmountEdited(String),
+    /// FROM amount was confirmed (Enter pressed)
+    AmountSubmitted(String),
+    /// Open the watch-only address popup for a chain
+    OpenWatchAddressInput(Chain),
+    /// Close the watch-only address popup without saving
+    CloseWatchAddressInput,
+    /// A watch-only address was entered for a chain
+    WatchAddressSubmitted(Chain, String),
+    /// Resolve an ENS name entered as a watch-only address
+    ResolveEnsName(String),
+    /// Leave the quotes table
+    ExitQuotesMode,
+    /// Quotes were received; carries the best one (or the preferred provider's), if any, and every offer
+    QuotesReceived(Option<Quote>, Vec<Quote>),
+    /// A quote was picked
+    QuoteChosen(Quote),
+    /// The amount out of the best quote of a request, by key, was quoted back, with the best quote back if any
+    ReverseQuoted(String, Option<Quote>),
+    /// Revert the last edit of the assets, amount or chosen quote
+    Undo,
+    /// Apply the last edit reverted again
+    Redo,
+    /// Allowance of the asset for a spender was checked
+    AllowanceChecked(AssetId, String, Result<Amount, String>),
+    /// Export the displayed quotes to CSV
+    ExportQuotes(Vec<Quote>),
+    /// Ask for the file the displayed quotes are exported to, with these columns
+    OpenExportPrompt(Vec<Quote>, Vec<Column>),
+    /// Simulate a swap with a quote and save its receipt
+    SimulateSwap(Quote),
+    /// Create the swap at the quote although its value crosses the KYC threshold of the provider
+    SwapDespiteKyc(Quote),
+    /// Leave the swap above the KYC threshold of the provider uncreated
+    SkipKycSwap,
+    /// Open the history screen
+    OpenHistory,
+    /// Close the history screen
+    CloseHistory,
+    /// Start a new swap prefilled from a history entry
+    ReopenHistoryEntry(HistoryEntry),
+    /// Restore the form left unfinished by the previous session
+    RestoreSession(Draft),
+    /// Forget the form left unfinished by the previous session
+    DiscardSession,
+    /// Open the popup naming a new draft
+    OpenDraftNameInput,
+    /// Open the drafts picker
+    OpenDrafts,
+    /// Close the drafts picker
+    CloseDrafts,
+    /// Fill the form from the draft with this name
+    LoadDraft(String, Draft),
+    /// Repeat a saved draft every so often, or stop repeating it
+    ScheduleDraft(String, Option<Frequency>),
+    /// Dismiss the scheduled swaps due without loading any
+    CloseDueSwaps,
+    /// Delete the draft with this name
+    DeleteDraft(String),
+    /// Open the settings screen
+    OpenSettings,
+    /// Close the settings screen without saving
+    CloseSettings,
+    /// Save edited settings to the config file and apply them
+    SaveSettings(Box<Config>),
+    /// Switch to another config profile, or to the top-level settings
+    SwitchProfile(Option<String>),
+    /// Open the log screen
+    OpenLogs,
+    /// Close the log screen
+    CloseLogs,
+    /// Open the latest crash report in the text viewer
+    OpenCrashReport,
+    /// Show or hide the live best rate panel
+    ToggleWatch,
+    /// Fetch the quotes shown again, their refresh interval having elapsed
+    RefreshQuotes,
+    /// Fetch the quotes again now, at the user's request
+    Requote,
+    /// Drop the quote request in flight
+    CancelQuotes,
+    /// Best quote of a refresh in watch mode, to check the alerts against
+    BestQuoteWatched(Quote),
+    /// A provider failed to quote
+    ProviderFailed(QuoteFailure),
+    /// Ask a provider that failed for a quote again
+    RetryProvider(String),
+    /// Show the details of a provider failure
+    OpenErrorDetail(QuoteFailure),
+    /// Close the details of a provider failure
+    CloseErrorDetail,
+    /// Show the outcome of an action in the status bar
+    ShowStatus(Status),
+    /// Acknowledge the error shown in the status bar
+    DismissStatus,
+    /// Select the error shown in the status bar, when it details a provider failure
+    FocusStatus,
+    /// Go back to the quotes from the error shown in the status bar
+    BlurStatus,
+    /// Open the metrics screen
+    OpenMetrics,
+    /// Close the metrics screen
+    CloseMetrics,
+    /// Open the provider statistics screen
+    OpenProviderStats,
+    /// Close the provider statistics screen
+    CloseProviderStats,
+    /// Open the details of a provider, by name
+    OpenProviderDetail(String, ProviderInfo),
+    /// Close the details of a provider, back to the provider statistics screen
+    CloseProviderDetail,
+    /// Open the session statistics screen
+    OpenSessionStats,
+    /// Close the session statistics screen
+    CloseSessionStats,
+    /// Open the portfolio screen
+    OpenPortfolio,
+    /// Close the portfolio screen
+    ClosePortfolio,
+    /// Open the watchlist screen
+    OpenWatchlist,
+    /// Close the watchlist screen
+    CloseWatchlist,
+    /// Load a pair of the watchlist in the form
+    LoadWatchedPair(Draft),
+    /// Open the price chart screen
+    OpenPriceChart,
+    /// Close the price chart screen
+    ClosePriceChart,
+    /// Chart the price over another timeframe
+    ChartTimeframeChanged(Timeframe),
+    /// Open the depth preview
+    OpenDepth,
+    /// Close the depth preview
+    CloseDepth,
+    /// Pin the best quote as the reference the next ones are compared with, or unpin it
+    PinQuote,
+    /// Open the popup setting the target rate of the limit order
+    OpenLimitInput,
+    /// Create the swap at the quote that met the target of the limit order
+    FillLimitOrder(Quote),
+    /// Leave the limit order met without creating the swap
+    SkipLimitFill,
+    /// Log more, going back to errors only after the most verbose level
+    RaiseLogLevel,
+    /// Run a command received on the control socket
+    Control(Control),
+    /// Save the settings chosen in the first-run wizard
+    FinishOnboarding(Box<Config>),
+    /// Leave the first-run wizard, keeping the defaults
+    SkipOnboarding,
+    /// A line was typed in the prompt for this purpose
+    PromptSubmitted(Purpose, String),
+    /// Close the prompt without answering
+    ClosePrompt,
+    /// Open the slider adjusting this setting
+    OpenSlider(Setting),
+    /// A value was picked on the slider adjusting this setting
+    SliderSubmitted(Setting, f64),
+    /// Close the slider, keeping the setting as it was
+    CloseSlider,
+    /// Open the dropdown picking this
+    OpenDropdown(Choice),
+    /// The option at this index was picked in the dropdown
+    DropdownSubmitted(Choice, usize),
+    /// Close the dropdown without picking
+    CloseDropdown,
+    /// Open the checklist ticking this
+    OpenMultiSelect(Checklist),
+    /// The options at these indexes were ticked in the checklist
+    MultiSelectSubmitted(Checklist, Vec<usize>),
+    /// Close the checklist, leaving what it ticks as it was
+    CloseMultiSelect,
+    /// Close the text viewer, back to the screen it was opened from
+    CloseTextViewer,
     /// No operation message
     None,
-}
\ No newline at end of file
+}
+
+impl Msg {
+    /// Variant name, for the logs; payloads may hold secrets such as API keys
+    pub fn name(&self) -> String {
+        let debug = format!("{self:?}");
+        debug.split(['(', ' ', '{']).next().unwrap_or_default().to_string()
+    }
+}
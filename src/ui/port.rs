@@ -0,0 +1,55 @@
+//! ## Port
+//!
+//! Event ports forwarding background service results and ticks to the application
+
+use std::sync::mpsc::Receiver;
+use std::time::{Duration, Instant};
+
+use tuirealm::listener::{ListenerResult, Poll};
+use tuirealm::Event;
+
+use crate::ui::event::UserEvent;
+
+/// Port polling the channel background services write their results to
+pub struct ServicePort {
+    receiver: Receiver<UserEvent>,
+}
+
+impl ServicePort {
+    /// Create a new port reading from the given channel
+    pub fn new(receiver: Receiver<UserEvent>) -> Self {
+        Self { receiver }
+    }
+}
+
+impl Poll<UserEvent> for ServicePort {
+    fn poll(&mut self) -> ListenerResult<Option<Event<UserEvent>>> {
+        Ok(self.receiver.try_recv().ok().map(Event::User))
+    }
+}
+
+/// Port raising a tick on an interval, unlike the listener's own ticks recorded like any event
+pub struct TickPort {
+    interval: Duration,
+    last: Instant,
+}
+
+impl TickPort {
+    /// Create a new port ticking every `interval`
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: Instant::now(),
+        }
+    }
+}
+
+impl Poll<UserEvent> for TickPort {
+    fn poll(&mut self) -> ListenerResult<Option<Event<UserEvent>>> {
+        if self.last.elapsed() < self.interval {
+            return Ok(None);
+        }
+        self.last = Instant::now();
+        Ok(Some(Event::Tick))
+    }
+}
@@ -0,0 +1,205 @@
+//! ## Recording
+//!
+//! Record of the events the UI handled, one JSON line each, and its replay
+
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{LineWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tuirealm::event::KeyEvent;
+use tuirealm::listener::{ListenerResult, Poll};
+use tuirealm::Event;
+
+use crate::errors::SwapError;
+use crate::models::draft::Draft;
+use crate::ui::event::UserEvent;
+
+/// Where the events of a session come from
+pub enum EventSource {
+    /// The terminal and the background services
+    Live,
+    /// The terminal and the background services, writing each event down
+    Record(Recorder),
+    /// A record, keys pressed on the terminal being handled as well
+    Replay(Replay),
+    /// A port alone, e.g. the events scripted by a test
+    Port(Box<dyn Poll<UserEvent>>),
+}
+
+/// How the session started, written on the first line
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    /// Form filled from the command line
+    pub form: Draft,
+    /// Whether watch mode was on
+    pub watch: bool,
+}
+
+/// An event handled by the UI
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recorded {
+    Key(KeyEvent),
+    Paste(String),
+    Resize(u16, u16),
+    Tick,
+    /// Result of a background service
+    Service(Box<UserEvent>),
+}
+
+impl Recorded {
+    /// The event as recorded; mouse and focus events are not handled by the UI
+    fn from_event(event: &Event<UserEvent>) -> Option<Self> {
+        match event {
+            Event::Keyboard(key) => Some(Self::Key(*key)),
+            Event::Paste(text) => Some(Self::Paste(text.clone())),
+            Event::WindowResize(width, height) => Some(Self::Resize(*width, *height)),
+            Event::Tick => Some(Self::Tick),
+            Event::User(event) => Some(Self::Service(Box::new(event.clone()))),
+            _ => None,
+        }
+    }
+
+    fn into_event(self) -> Event<UserEvent> {
+        match self {
+            Self::Key(key) => Event::Keyboard(key),
+            Self::Paste(text) => Event::Paste(text),
+            Self::Resize(width, height) => Event::WindowResize(width, height),
+            Self::Tick => Event::Tick,
+            Self::Service(event) => Event::User(*event),
+        }
+    }
+}
+
+/// An event with the time it was handled at, in milliseconds since the session started
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    at: u64,
+    event: Recorded,
+}
+
+/// Writes the events handled to a file, shared by the ports they come from
+#[derive(Clone)]
+pub struct Recorder {
+    file: Arc<Mutex<LineWriter<File>>>,
+    started: Instant,
+}
+
+impl Recorder {
+    /// Start a record at `path`, replacing any previous one
+    pub fn create(path: &Path, header: &Header) -> Result<Self, SwapError> {
+        let mut file = LineWriter::new(File::create(path).map_err(|err| SwapError::Io(err.to_string()))?);
+        let line = serde_json::to_string(header).map_err(|err| SwapError::Io(err.to_string()))?;
+        writeln!(file, "{line}").map_err(|err| SwapError::Io(err.to_string()))?;
+        Ok(Self {
+            file: Arc::new(Mutex::new(file)),
+            started: Instant::now(),
+        })
+    }
+
+    /// Append an event; a record that can no longer be written is given up on, not the session
+    fn write(&self, event: &Event<UserEvent>) {
+        let Some(event) = Recorded::from_event(event) else {
+            return;
+        };
+        let entry = Entry {
+            at: self.started.elapsed().as_millis() as u64,
+            event,
+        };
+        if let (Ok(line), Ok(mut file)) = (serde_json::to_string(&entry), self.file.lock()) {
+            let _ = writeln!(file, "{line}");
+        }
+    }
+}
+
+/// Port recording the events of another port as it yields them
+pub struct RecordingPort {
+    port: Box<dyn Poll<UserEvent>>,
+    recorder: Recorder,
+}
+
+impl RecordingPort {
+    /// Record the events of `port` with `recorder`
+    pub fn new(port: Box<dyn Poll<UserEvent>>, recorder: Recorder) -> Self {
+        Self { port, recorder }
+    }
+}
+
+impl Poll<UserEvent> for RecordingPort {
+    fn poll(&mut self) -> ListenerResult<Option<Event<UserEvent>>> {
+        let event = self.port.poll()?;
+        if let Some(event) = &event {
+            self.recorder.write(event);
+        }
+        Ok(event)
+    }
+}
+
+/// A record read back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    pub header: Header,
+    entries: VecDeque<Entry>,
+}
+
+impl Replay {
+    /// Read the record at `path`
+    pub fn load(path: &Path) -> Result<Self, SwapError> {
+        let content = fs::read_to_string(path).map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))?;
+        let mut lines = content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+        let invalid = |number: usize, err: serde_json::Error| {
+            SwapError::Io(format!("{} line {}: {err}", path.display(), number + 1))
+        };
+        let header = match lines.next() {
+            Some((number, line)) => serde_json::from_str(line).map_err(|err| invalid(number, err))?,
+            None => return Err(SwapError::Io(format!("{}: empty record", path.display()))),
+        };
+        let entries = lines
+            .map(|(number, line)| serde_json::from_str(line).map_err(|err| invalid(number, err)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { header, entries })
+    }
+
+    /// Number of events recorded
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no event was recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Port yielding the events of a record in order, at the pace they were recorded
+pub struct ReplayPort {
+    entries: VecDeque<Entry>,
+    /// When the first event was asked for
+    started: Option<Instant>,
+}
+
+impl ReplayPort {
+    /// Replay the events of `replay`
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            entries: replay.entries,
+            started: None,
+        }
+    }
+}
+
+impl Poll<UserEvent> for ReplayPort {
+    fn poll(&mut self) -> ListenerResult<Option<Event<UserEvent>>> {
+        let elapsed = self.started.get_or_insert_with(Instant::now).elapsed();
+        match self.entries.front() {
+            Some(entry) if Duration::from_millis(entry.at) <= elapsed => {
+                Ok(self.entries.pop_front().map(|entry| entry.event.into_event()))
+            }
+            _ => Ok(None),
+        }
+    }
+}
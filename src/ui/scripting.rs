@@ -0,0 +1,189 @@
+//! ## Scripting
+//!
+//! User scripts reacting to events, built with the `scripting` feature. Every `*.rhai` file of the scripts directory
+//! is loaded on start; it may define:
+//! - `on_quotes(quote)`, called when quotes arrive with the best one, or `()` when no provider quoted
+//! - `on_alert(alert)`, called when an alert triggers, with its pair, thresholds, rate and provider
+//!
+//! and call `set_pair(from, to)`, `set_from(asset)`, `set_to(asset)`, `set_amount(amount)`, `quote()`,
+//! `export_csv()` and `write_file(name, text)`, which writes to the export directory.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+
+use crate::errors::SwapError;
+use crate::models::alert::Alert;
+use crate::models::asset::Asset;
+use crate::models::quote::Quote;
+use crate::ui::event::UserEvent;
+
+pub use engine::Scripts;
+
+/// Scripts directory, next to the config file
+pub fn default_dir() -> Option<PathBuf> {
+    crate::config::Config::path().and_then(|path| path.parent().map(|dir| dir.join("scripts")))
+}
+
+#[cfg(feature = "scripting")]
+mod engine {
+    use std::fs;
+
+    use rhai::{CallFnOptions, Dynamic, Engine, EvalAltResult, Map, AST};
+    use tracing::{debug, info};
+
+    use super::*;
+    use crate::cli::find_asset;
+    use crate::ui::control::Control;
+
+    /// Operations a script may run per call, so that a loop cannot hang the UI
+    const MAX_OPERATIONS: u64 = 100_000;
+
+    /// Scripts loaded, with the engine exposing the API to them
+    pub struct Scripts {
+        engine: Engine,
+        scripts: Vec<(String, AST)>,
+    }
+
+    impl Scripts {
+        /// Load the scripts of `dir`, `None` when there are none; `tx` carries their commands to the UI
+        pub fn load(
+            dir: &Path,
+            assets: Vec<Asset>,
+            export_dir: PathBuf,
+            tx: Sender<UserEvent>,
+        ) -> Result<Option<Self>, SwapError> {
+            let Ok(entries) = fs::read_dir(dir) else {
+                return Ok(None);
+            };
+            let mut paths: Vec<_> = entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|extension| extension == "rhai"))
+                .collect();
+            if paths.is_empty() {
+                return Ok(None);
+            }
+            paths.sort();
+
+            let engine = engine(assets, export_dir, tx);
+            let mut scripts = vec![];
+            for path in paths {
+                let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                let ast = engine
+                    .compile_file(path.clone())
+                    .map_err(|err| SwapError::Config(format!("script {name}: {err}")))?;
+                engine.run_ast(&ast).map_err(|err| SwapError::Config(format!("script {name}: {err}")))?;
+                info!(script = %name, "script loaded");
+                scripts.push((name, ast));
+            }
+            Ok(Some(Self { engine, scripts }))
+        }
+
+        /// Quotes arrived, `best` being the one selected
+        pub fn quotes_received(&self, best: Option<&Quote>) -> Result<(), String> {
+            let quote = best.map_or(Dynamic::UNIT, |quote| quote_map(quote).into());
+            self.call("on_quotes", quote)
+        }
+
+        /// An alert triggered at `rate`, best offered by `provider`
+        pub fn alert_triggered(&self, alert: &Alert, rate: f64, provider: &str) -> Result<(), String> {
+            let mut map = Map::new();
+            map.insert("from".into(), alert.from.clone().into());
+            map.insert("to".into(), alert.to.clone().into());
+            map.insert("above".into(), alert.above.map_or(Dynamic::UNIT, Dynamic::from));
+            map.insert("below".into(), alert.below.map_or(Dynamic::UNIT, Dynamic::from));
+            map.insert("rate".into(), rate.into());
+            map.insert("provider".into(), provider.into());
+            self.call("on_alert", map.into())
+        }
+
+        /// Call `function` in every script defining it, stopping at the first error
+        fn call(&self, function: &str, argument: Dynamic) -> Result<(), String> {
+            for (name, ast) in &self.scripts {
+                if !ast.iter_functions().any(|f| f.name == function && f.params.len() == 1) {
+                    continue;
+                }
+                debug!(script = %name, function, "calling script");
+                // The top level ran once on load, whatever the hook returns is ignored
+                let options = CallFnOptions::new().eval_ast(false);
+                let _: Dynamic = self
+                    .engine
+                    .call_fn_with_options(options, &mut Default::default(), ast, function, (argument.clone(),))
+                    .map_err(|err| format!("script {name}: {err}"))?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Engine with the API of the scripts
+    fn engine(assets: Vec<Asset>, export_dir: PathBuf, tx: Sender<UserEvent>) -> Engine {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.on_print(|text| info!(text, "script"));
+        engine.on_debug(|text, _, _| debug!(text, "script"));
+
+        let send = move |control: Control| -> Result<(), Box<EvalAltResult>> {
+            tx.send(UserEvent::Control(control)).map_err(|_| "the application is closing".into())
+        };
+        let id = move |query: &str| -> Result<String, Box<EvalAltResult>> {
+            find_asset(&assets, query).map(|(_, asset)| asset.id().to_string()).map_err(Into::into)
+        };
+
+        let (send_pair, id_pair) = (send.clone(), id.clone());
+        engine.register_fn("set_pair", move |from: &str, to: &str| send_pair(Control::SetPair(id_pair(from)?, id_pair(to)?)));
+        let (send_from, id_from) = (send.clone(), id.clone());
+        engine.register_fn("set_from", move |asset: &str| send_from(Control::SetFrom(id_from(asset)?)));
+        let send_to = send.clone();
+        engine.register_fn("set_to", move |asset: &str| send_to(Control::SetTo(id(asset)?)));
+        let send_amount = send.clone();
+        engine.register_fn("set_amount", move |amount: &str| send_amount(Control::SetAmount(amount.to_string())));
+        let send_quote = send.clone();
+        engine.register_fn("quote", move || send_quote(Control::Quote));
+        engine.register_fn("export_csv", move || send(Control::ExportCsv));
+        engine.register_fn("write_file", move |name: &str, text: &str| -> Result<(), Box<EvalAltResult>> {
+            // Only files of the export directory
+            if name.is_empty() || Path::new(name).file_name() != Some(name.as_ref()) {
+                return Err(format!("write_file: {name:?} is not a file name").into());
+            }
+            fs::create_dir_all(&export_dir)
+                .and_then(|_| fs::write(export_dir.join(name), text))
+                .map_err(|err| format!("write_file {name}: {err}").into())
+        });
+        engine
+    }
+
+    /// A quote as seen by scripts
+    fn quote_map(quote: &Quote) -> Map {
+        let mut map = Map::new();
+        map.insert("provider".into(), quote.provider.clone().into());
+        map.insert("from".into(), quote.from.to_string().into());
+        map.insert("to".into(), quote.to.to_string().into());
+        map.insert("amount_in".into(), quote.amount_in.to_string().into());
+        map.insert("amount_out".into(), quote.amount_out.to_string().into());
+        map.insert("rate".into(), quote.rate().into());
+        map.insert("eta_secs".into(), quote.eta.map_or(Dynamic::UNIT, |eta| (eta.as_secs() as i64).into()));
+        map
+    }
+}
+
+/// No scripts without the `scripting` feature
+#[cfg(not(feature = "scripting"))]
+mod engine {
+    use super::*;
+
+    pub struct Scripts;
+
+    impl Scripts {
+        pub fn load(_: &Path, _: Vec<Asset>, _: PathBuf, _: Sender<UserEvent>) -> Result<Option<Self>, SwapError> {
+            Ok(None)
+        }
+
+        pub fn quotes_received(&self, _: Option<&Quote>) -> Result<(), String> {
+            Ok(())
+        }
+
+        pub fn alert_triggered(&self, _: &Alert, _: f64, _: &str) -> Result<(), String> {
+            Ok(())
+        }
+    }
+}
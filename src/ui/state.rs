@@ -0,0 +1,326 @@
+//! ## State
+//!
+//! State of the swap form, only changed by reducing actions
+
+use crate::models::amount::Amount;
+use crate::models::asset::{Asset, AssetId};
+use crate::models::fee::FeeSpeed;
+use crate::models::quote::Quote;
+
+/// Changes to the swap form state
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// An asset was chosen as the FROM asset
+    FromChosen(Asset),
+    /// An asset was chosen as the TO asset
+    ToChosen(Asset),
+    /// An amount of the FROM asset was typed, not yet parsed
+    AmountSubmitted(String),
+    /// Quotes were requested for the pair and amount
+    QuotesRequested,
+    /// The quotes of the pair and amount are fetched again
+    QuotesRefreshing,
+    /// The quotes awaited arrived or will not come
+    QuotesSettled,
+    /// A quote's route is shown in the swap plan
+    RouteSelected(Quote),
+    /// The allowance of the FROM asset for a spender was checked
+    AllowanceChecked(AssetId, String, Result<Amount, String>),
+    /// The allowance shown no longer holds
+    AllowanceReset,
+    /// A network fee preset was picked
+    FeeSpeedPicked(FeeSpeed),
+    /// Watch mode was turned on or off
+    WatchToggled(bool),
+    /// A quote was picked by the user; unlike a route selected on arrival, it can be undone
+    RouteChosen(Quote),
+    /// The last form edit is reverted
+    Undo,
+    /// The last form edit reverted is applied again
+    Redo,
+}
+
+impl Action {
+    /// Whether the action edits the form, leaving a step to undo
+    fn edits_form(&self) -> bool {
+        matches!(
+            self,
+            Self::FromChosen(_) | Self::ToChosen(_) | Self::AmountSubmitted(_) | Self::RouteChosen(_)
+        )
+    }
+}
+
+/// Values of the swap form that undo and redo restore
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Form {
+    pub from_asset: Option<Asset>,
+    pub to_asset: Option<Asset>,
+    pub amount: Option<Amount>,
+    pub route: Option<Quote>,
+}
+
+impl Form {
+    /// Whether the quotes of `other` were asked for another pair or amount
+    pub fn quotes_differ(&self, other: &Form) -> bool {
+        (&self.from_asset, &self.to_asset, self.amount) != (&other.from_asset, &other.to_asset, other.amount)
+    }
+}
+
+/// Form edits kept for undo
+const HISTORY: usize = 50;
+
+/// Swap form state
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AppState {
+    /// Selected FROM asset
+    pub from_asset: Option<Asset>,
+    /// Selected TO asset
+    pub to_asset: Option<Asset>,
+    /// Confirmed FROM amount
+    pub amount: Option<Amount>,
+    /// Selected network fee preset
+    pub fee_speed: FeeSpeed,
+    /// Quote whose route is shown in the swap plan
+    pub route: Option<Quote>,
+    /// Allowance of the FROM token for the route's spender; `None` while unknown
+    pub allowance: Option<Result<Amount, String>>,
+    /// Whether the live best rate panel is shown
+    pub watching: bool,
+    /// Whether the pair or amount changed since quotes were last requested
+    pub quotes_outdated: bool,
+    /// Whether the quotes awaited refresh the ones shown, rather than answer a new request
+    pub refreshing_quotes: bool,
+    /// Forms before each edit, the last one first to undo
+    pub undo: Vec<Form>,
+    /// Forms reverted by undo, the last one first to redo
+    pub redo: Vec<Form>,
+}
+
+impl AppState {
+    /// Apply an action; side effects are left to the caller, which reads the new state
+    pub fn reduce(&mut self, action: Action) {
+        let before = action.edits_form().then(|| self.form());
+        match action {
+            Action::FromChosen(asset) => {
+                // An amount typed for another asset has other decimals
+                if self.from_asset.as_ref().map(|from| from.decimals) != Some(asset.decimals) {
+                    self.amount = None;
+                }
+                self.from_asset = Some(asset);
+                self.quotes_outdated = true;
+            }
+            Action::ToChosen(asset) => {
+                self.to_asset = Some(asset);
+                self.quotes_outdated = true;
+            }
+            Action::AmountSubmitted(amount) => {
+                let decimals = self.from_asset.as_ref().map_or(18, |asset| asset.decimals);
+                self.amount = Amount::parse(&amount, decimals).filter(|amount| amount.raw() > 0);
+                self.quotes_outdated = true;
+            }
+            Action::QuotesRequested => {
+                // The route shown belongs to the previous request
+                self.route = None;
+                self.quotes_outdated = false;
+                self.refreshing_quotes = false;
+            }
+            Action::QuotesRefreshing => self.refreshing_quotes = true,
+            Action::QuotesSettled => self.refreshing_quotes = false,
+            Action::RouteSelected(quote) => {
+                self.allowance = None;
+                self.route = Some(quote);
+            }
+            Action::AllowanceChecked(asset, spender, allowance) => {
+                // Discard checks made for a route no longer shown
+                let current = self.route.as_ref().is_some_and(|route| {
+                    route.from == asset && route.spender.as_deref() == Some(spender.as_str())
+                });
+                if current {
+                    self.allowance = Some(allowance);
+                }
+            }
+            Action::AllowanceReset => self.allowance = None,
+            Action::FeeSpeedPicked(speed) => self.fee_speed = speed,
+            Action::WatchToggled(watching) => self.watching = watching,
+            Action::RouteChosen(quote) => {
+                self.allowance = None;
+                self.route = Some(quote);
+            }
+            Action::Undo => {
+                if let Some(form) = self.undo.pop() {
+                    let current = self.restore(form);
+                    self.redo.push(current);
+                }
+            }
+            Action::Redo => {
+                if let Some(form) = self.redo.pop() {
+                    let current = self.restore(form);
+                    self.undo.push(current);
+                }
+            }
+        }
+
+        // An edit that changed nothing leaves nothing to undo
+        if let Some(before) = before.filter(|before| *before != self.form()) {
+            if self.undo.len() == HISTORY {
+                self.undo.remove(0);
+            }
+            self.undo.push(before);
+            self.redo.clear();
+        }
+    }
+
+    /// Current values of the form
+    pub fn form(&self) -> Form {
+        Form {
+            from_asset: self.from_asset.clone(),
+            to_asset: self.to_asset.clone(),
+            amount: self.amount,
+            route: self.route.clone(),
+        }
+    }
+
+    /// Put back the values of a form, returning the ones replaced
+    fn restore(&mut self, form: Form) -> Form {
+        let current = self.form();
+        if form.quotes_differ(&current) {
+            self.quotes_outdated = true;
+        }
+        if form.route != current.route {
+            self.allowance = None;
+        }
+        self.from_asset = form.from_asset;
+        self.to_asset = form.to_asset;
+        self.amount = form.amount;
+        self.route = form.route;
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::config::Config;
+
+    /// Listed asset with the given id
+    fn asset(id: &str) -> Asset {
+        Config::default().assets.into_iter().find(|asset| asset.id().to_string() == id).unwrap()
+    }
+
+    /// Offer of 1.5 ETH for 0.1 BTC, routed through `spender` when given
+    fn quote(provider: &str, spender: Option<&str>) -> Quote {
+        Quote {
+            provider: provider.to_string(),
+            from: asset("btc-bitcoin").id(),
+            to: asset("eth-ethereum").id(),
+            amount_in: Amount::parse("0.1", 8).unwrap(),
+            amount_out: Amount::parse("1.5", 18).unwrap(),
+            spender: spender.map(str::to_string),
+            eta: Some(Duration::from_secs(600)),
+            route_fee: None,
+            quoted_at: Utc::now(),
+        }
+    }
+
+    /// State with 0.1 BTC for ETH filled in
+    fn filled() -> AppState {
+        let mut state = AppState::default();
+        state.reduce(Action::FromChosen(asset("btc-bitcoin")));
+        state.reduce(Action::ToChosen(asset("eth-ethereum")));
+        state.reduce(Action::AmountSubmitted("0.1".to_string()));
+        state
+    }
+
+    #[test]
+    fn choosing_assets_outdates_quotes() {
+        let mut state = AppState::default();
+        state.reduce(Action::FromChosen(asset("btc-bitcoin")));
+        assert_eq!(state.from_asset, Some(asset("btc-bitcoin")));
+        assert!(state.quotes_outdated);
+        state.reduce(Action::QuotesRequested);
+        state.reduce(Action::ToChosen(asset("eth-ethereum")));
+        assert_eq!(state.to_asset, Some(asset("eth-ethereum")));
+        assert!(state.quotes_outdated);
+    }
+
+    #[test]
+    fn amount_is_parsed_with_the_decimals_of_the_from_asset() {
+        let mut state = filled();
+        assert_eq!(state.amount, Amount::parse("0.1", 8));
+        state.reduce(Action::AmountSubmitted("0".to_string()));
+        assert_eq!(state.amount, None);
+        state.reduce(Action::AmountSubmitted("abc".to_string()));
+        assert_eq!(state.amount, None);
+    }
+
+    #[test]
+    fn from_asset_with_other_decimals_clears_the_amount() {
+        let mut state = filled();
+        state.reduce(Action::FromChosen(asset("eth-ethereum")));
+        assert_eq!(state.amount, None);
+    }
+
+    #[test]
+    fn requesting_quotes_drops_the_route_shown() {
+        let mut state = filled();
+        state.reduce(Action::RouteSelected(quote("SideShift", None)));
+        state.reduce(Action::QuotesRequested);
+        assert_eq!(state.route, None);
+        assert!(!state.quotes_outdated);
+        assert!(!state.refreshing_quotes);
+    }
+
+    #[test]
+    fn refresh_ends_when_quotes_settle_or_are_cancelled() {
+        let mut state = filled();
+        state.reduce(Action::QuotesRequested);
+        state.reduce(Action::QuotesRefreshing);
+        assert!(state.refreshing_quotes);
+        state.reduce(Action::QuotesSettled);
+        assert!(!state.refreshing_quotes);
+    }
+
+    #[test]
+    fn allowance_of_the_route_shown_is_kept_errors_included() {
+        let mut state = filled();
+        let route = quote("ParaSwap", Some("0xdef1"));
+        let from = route.from.clone();
+        state.reduce(Action::RouteSelected(route));
+        state.reduce(Action::AllowanceChecked(from.clone(), "0xdef1".to_string(), Err("no node".to_string())));
+        assert_eq!(state.allowance, Some(Err("no node".to_string())));
+        // A check made for another spender is stale
+        state.reduce(Action::AllowanceChecked(from, "0xother".to_string(), Ok(Amount::new(1, 8))));
+        assert_eq!(state.allowance, Some(Err("no node".to_string())));
+        state.reduce(Action::AllowanceReset);
+        assert_eq!(state.allowance, None);
+    }
+
+    #[test]
+    fn undo_and_redo_restore_form_edits() {
+        let mut state = filled();
+        state.reduce(Action::QuotesRequested);
+        state.reduce(Action::AmountSubmitted("0.2".to_string()));
+        state.reduce(Action::Undo);
+        assert_eq!(state.amount, Amount::parse("0.1", 8));
+        assert!(state.quotes_outdated);
+        state.reduce(Action::Redo);
+        assert_eq!(state.amount, Amount::parse("0.2", 8));
+        // A new edit drops what was undone
+        state.reduce(Action::Undo);
+        state.reduce(Action::ToChosen(asset("sol-solana")));
+        state.reduce(Action::Redo);
+        assert_eq!(state.amount, Amount::parse("0.1", 8));
+    }
+
+    #[test]
+    fn edits_changing_nothing_leave_nothing_to_undo() {
+        let mut state = filled();
+        let steps = state.undo.len();
+        state.reduce(Action::AmountSubmitted("0.1".to_string()));
+        assert_eq!(state.undo.len(), steps);
+    }
+}
@@ -1,18 +1,496 @@
 //! ## Theme
 //! 
-//! Theme constants for the application
+//! Theme of the application, configurable in the `[theme]` section, and the look components override in its
+//! `[theme.<component>]` sections. Components draw with the styles of roles, e.g. the header row of tables, which
+//! are injected into them as attributes as they are mounted, then set again as the theme changes
 
-use tuirealm::props::{Alignment, Borders, Color, TextModifiers};
+use std::cell::Cell;
+use std::rc::Rc;
 
-pub mod colors {
-    use super::Color;
+use serde::{Deserialize, Deserializer};
+use tui_realm_stdlib::props::{INPUT_INVALID_STYLE, INPUT_PLACEHOLDER_STYLE};
+use tui_realm_stdlib::Input;
+use tuirealm::props::{Alignment, BorderType, Borders, Color, Style, TextModifiers};
+use tuirealm::ratatui::widgets::Block;
+use tuirealm::utils::parser::parse_color;
+use tuirealm::{AttrValue, Attribute, Injector, MockComponent, Props};
 
-    pub const PRIMARY: Color = Color::Cyan;
-    pub const SECONDARY: Color = Color::LightGreen;
-    pub const BACKGROUND: Color = Color::Reset;
-    pub const TEXT: Color = Color::White;
-    pub const HIGHLIGHT: Color = Color::Yellow;
-    pub const ERROR: Color = Color::Red;
+use crate::ui::glyphs;
+use crate::ui::id::Id;
+
+/// Colors of the application, and the look of the components overriding their own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    /// Application title
+    #[serde(deserialize_with = "color")]
+    pub primary: Color,
+    /// Contextual instructions
+    #[serde(deserialize_with = "color")]
+    pub secondary: Color,
+    /// Swap summary
+    #[serde(deserialize_with = "color")]
+    pub text: Color,
+    /// Key hints
+    #[serde(deserialize_with = "color")]
+    pub highlight: Color,
+    /// Asset table
+    pub asset_table: Overrides,
+    /// Quotes table
+    pub quotes_table: Overrides,
+    /// Status bar, bordered when given a border
+    pub status_bar: Overrides,
+    /// Styles of the roles components draw with
+    pub styles: Styles,
+}
+
+impl Theme {
+    /// Attributes theming component `id`: the styles of the roles, then its colors and overrides
+    pub fn attrs(&self, id: &Id) -> Vec<(Attribute, AttrValue)> {
+        let mut attrs: Vec<(Attribute, AttrValue)> = Role::ALL
+            .into_iter()
+            .map(|role| (role.attr(), AttrValue::Style(self.styles.get(role))))
+            .collect();
+        let foreground = match id {
+            Id::Header => Some(self.primary),
+            Id::Instructions => Some(self.secondary),
+            Id::SummaryBar => Some(self.text),
+            Id::InstructionsBar => Some(self.highlight),
+            _ => None,
+        };
+        attrs.extend(foreground.map(|color| (Attribute::Foreground, AttrValue::Color(color))));
+        let overrides = match id {
+            Id::AssetTable => self.asset_table,
+            Id::QuotesTable => self.quotes_table,
+            Id::StatusBar => self.status_bar,
+            _ => Overrides::NONE,
+        };
+        attrs.extend(overrides.attrs());
+        attrs
+    }
+}
+
+/// What a style is used for, components drawing with the style of a role rather than colors of their own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// Plain text
+    Text,
+    /// Secondary text: labels, missing values, unfocused borders
+    Muted,
+    /// Placeholders and rows that cannot be picked
+    Disabled,
+    /// Header row of tables
+    Header,
+    /// Highlighted row
+    Selection,
+    /// Borders of screens and focused panels
+    Border,
+    /// Borders of popups asking for input
+    Prompt,
+    /// Charts and live panels
+    Accent,
+    /// Gains, successes and best values
+    Positive,
+    /// Losses, failures and alerts
+    Negative,
+    /// Warnings and what needs attention
+    Warning,
+    /// Errors and invalid input
+    Error,
+    /// FROM asset
+    From,
+    /// TO asset
+    To,
+}
+
+impl Role {
+    /// Every role
+    pub const ALL: [Role; 14] = [
+        Role::Text,
+        Role::Muted,
+        Role::Disabled,
+        Role::Header,
+        Role::Selection,
+        Role::Border,
+        Role::Prompt,
+        Role::Accent,
+        Role::Positive,
+        Role::Negative,
+        Role::Warning,
+        Role::Error,
+        Role::From,
+        Role::To,
+    ];
+
+    /// Attribute the style of the role is set as
+    pub fn attr(self) -> Attribute {
+        Attribute::Custom(match self {
+            Role::Text => "style-text",
+            Role::Muted => "style-muted",
+            Role::Disabled => "style-disabled",
+            Role::Header => "style-header",
+            Role::Selection => "style-selection",
+            Role::Border => "style-border",
+            Role::Prompt => "style-prompt",
+            Role::Accent => "style-accent",
+            Role::Positive => "style-positive",
+            Role::Negative => "style-negative",
+            Role::Warning => "style-warning",
+            Role::Error => "style-error",
+            Role::From => "style-from",
+            Role::To => "style-to",
+        })
+    }
+}
+
+/// Styles of the roles, in the `[theme.styles]` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Styles {
+    #[serde(deserialize_with = "plain_style")]
+    pub text: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub muted: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub disabled: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub header: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub selection: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub border: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub prompt: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub accent: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub positive: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub negative: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub warning: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub error: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub from: Style,
+    #[serde(deserialize_with = "plain_style")]
+    pub to: Style,
+}
+
+impl Styles {
+    /// Styles of the components before theming
+    pub const DEFAULT: Styles = Styles {
+        text: Style::new().fg(Color::White),
+        muted: Style::new().fg(Color::Gray),
+        disabled: Style::new().fg(Color::DarkGray),
+        header: Style::new().fg(Color::White).bg(Color::DarkGray).add_modifier(TextModifiers::BOLD),
+        selection: Style::new().fg(Color::Black).bg(Color::Rgb(255, 255, 220)),
+        border: Style::new().fg(Color::LightYellow),
+        prompt: Style::new().fg(Color::Yellow),
+        accent: Style::new().fg(Color::LightCyan),
+        positive: Style::new().fg(Color::LightGreen),
+        negative: Style::new().fg(Color::LightRed),
+        warning: Style::new().fg(Color::LightYellow),
+        error: Style::new().fg(Color::Red),
+        from: Style::new().fg(Color::Black).bg(Color::Rgb(255, 180, 180)),
+        to: Style::new().fg(Color::Black).bg(Color::Rgb(180, 255, 180)),
+    };
+
+    /// Style of `role`
+    pub fn get(&self, role: Role) -> Style {
+        match role {
+            Role::Text => self.text,
+            Role::Muted => self.muted,
+            Role::Disabled => self.disabled,
+            Role::Header => self.header,
+            Role::Selection => self.selection,
+            Role::Border => self.border,
+            Role::Prompt => self.prompt,
+            Role::Accent => self.accent,
+            Role::Positive => self.positive,
+            Role::Negative => self.negative,
+            Role::Warning => self.warning,
+            Role::Error => self.error,
+            Role::From => self.from,
+            Role::To => self.to,
+        }
+    }
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Style of `role` set in `props`, else the one before theming
+pub fn style_of(props: &Props, role: Role) -> Style {
+    match props.get(role.attr()) {
+        Some(AttrValue::Style(style)) => style,
+        _ => Styles::DEFAULT.get(role),
+    }
+}
+
+/// Color of `role` set in `props`, for what only takes a color, e.g. the text of a cell
+pub fn color_of(props: &Props, role: Role) -> Color {
+    style_of(props, role).fg.unwrap_or(Color::Reset)
+}
+
+/// Give `input` the text, placeholder and invalid styles of the roles set in its props, and the border color of
+/// `border`; inputs take those as attributes of their own
+pub fn style_input(input: &mut Input, border: Role) {
+    let role = |input: &Input, role: Role| match input.query(role.attr()) {
+        Some(AttrValue::Style(style)) => style,
+        _ => Styles::DEFAULT.get(role),
+    };
+    let (text, disabled, error, border) =
+        (role(input, Role::Text), role(input, Role::Disabled), role(input, Role::Error), role(input, border));
+    let borders = Borders::default()
+        .modifiers(BorderType::Rounded)
+        .color(border.fg.unwrap_or(Color::Reset));
+    input.attr(Attribute::Borders, AttrValue::Borders(borders));
+    input.attr(Attribute::Foreground, AttrValue::Color(text.fg.unwrap_or(Color::Reset)));
+    input.attr(Attribute::Custom(INPUT_PLACEHOLDER_STYLE), AttrValue::Style(disabled));
+    input.attr(Attribute::Custom(INPUT_INVALID_STYLE), AttrValue::Style(error));
+}
+
+/// Injects the theme into the components as they are mounted, sharing it with the model setting it
+pub struct ThemeInjector {
+    theme: Rc<Cell<Theme>>,
+}
+
+impl ThemeInjector {
+    /// Create an injector of `theme`, as it is when components are mounted
+    pub fn new(theme: Rc<Cell<Theme>>) -> Self {
+        Self { theme }
+    }
+}
+
+impl Injector<Id> for ThemeInjector {
+    fn inject(&self, id: &Id) -> Vec<(Attribute, AttrValue)> {
+        self.theme.get().attrs(id)
+    }
+}
+
+/// Look of a component overriding its own, each setting left out keeping it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Overrides {
+    /// Border: `plain`, `rounded`, `double` or `thick`
+    #[serde(deserialize_with = "border_type")]
+    pub border: Option<BorderType>,
+    /// Alignment of the title: `left`, `center` or `right`
+    #[serde(deserialize_with = "alignment")]
+    pub title_alignment: Option<Alignment>,
+    /// Style of the highlighted row, modifiers then colors, e.g. `bold black on yellow`
+    #[serde(deserialize_with = "style")]
+    pub highlight: Option<Style>,
+}
+
+impl Overrides {
+    /// Look of the component kept
+    pub const NONE: Overrides = Overrides {
+        border: None,
+        title_alignment: None,
+        highlight: None,
+    };
+
+    /// Attributes setting the overrides on a component, read back by [`block`] and [`highlight`]
+    pub fn attrs(&self) -> Vec<(Attribute, AttrValue)> {
+        let mut attrs = vec![];
+        if let Some(border) = self.border {
+            attrs.push((Attribute::Borders, AttrValue::Borders(Borders::default().modifiers(border))));
+        }
+        if let Some(alignment) = self.title_alignment {
+            attrs.push((Attribute::Custom("title_alignment"), AttrValue::Alignment(alignment)));
+        }
+        if let Some(style) = self.highlight {
+            attrs.push((Attribute::Custom("highlight"), AttrValue::Style(style)));
+        }
+        attrs
+    }
+}
+
+/// `block` with the border and title alignment overridden in `props`
+pub fn block<'a>(props: &Props, mut block: Block<'a>) -> Block<'a> {
+    if let Some(AttrValue::Borders(borders)) = props.get(Attribute::Borders) {
+        block = block.border_set(glyphs::border_of(borders.modifiers));
+    }
+    if let Some(AttrValue::Alignment(alignment)) = props.get(Attribute::Custom("title_alignment")) {
+        block = block.title_alignment(alignment);
+    }
+    block
+}
+
+/// `style` of a highlighted row, patched with the highlight overridden in `props`
+pub fn highlight(props: &Props, style: Style) -> Style {
+    match props.get(Attribute::Custom("highlight")) {
+        Some(AttrValue::Style(highlight)) => style.patch(highlight),
+        _ => style,
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        PRESETS[0].1
+    }
+}
+
+/// Themes offered on first run, by name
+pub const PRESETS: [(&str, Theme); 4] = [
+    (
+        "default",
+        Theme {
+            primary: Color::Cyan,
+            secondary: Color::Green,
+            text: Color::White,
+            highlight: Color::Yellow,
+            asset_table: Overrides::NONE,
+            quotes_table: Overrides::NONE,
+            status_bar: Overrides::NONE,
+            styles: Styles::DEFAULT,
+        },
+    ),
+    (
+        "ocean",
+        Theme {
+            primary: Color::Blue,
+            secondary: Color::LightCyan,
+            text: Color::White,
+            highlight: Color::Cyan,
+            asset_table: Overrides::NONE,
+            quotes_table: Overrides::NONE,
+            status_bar: Overrides::NONE,
+            styles: Styles::DEFAULT,
+        },
+    ),
+    (
+        "forest",
+        Theme {
+            primary: Color::Green,
+            secondary: Color::LightGreen,
+            text: Color::White,
+            highlight: Color::LightYellow,
+            asset_table: Overrides::NONE,
+            quotes_table: Overrides::NONE,
+            status_bar: Overrides::NONE,
+            styles: Styles::DEFAULT,
+        },
+    ),
+    (
+        "mono",
+        Theme {
+            primary: Color::White,
+            secondary: Color::Gray,
+            text: Color::White,
+            highlight: Color::White,
+            asset_table: Overrides::NONE,
+            quotes_table: Overrides::NONE,
+            status_bar: Overrides::NONE,
+            styles: Styles::DEFAULT,
+        },
+    ),
+];
+
+/// Named colors offered by the settings screen, by config name
+pub const PALETTE: [(&str, Color); 12] = [
+    ("cyan", Color::Cyan),
+    ("green", Color::Green),
+    ("yellow", Color::Yellow),
+    ("white", Color::White),
+    ("magenta", Color::Magenta),
+    ("blue", Color::Blue),
+    ("red", Color::Red),
+    ("gray", Color::Gray),
+    ("lightcyan", Color::LightCyan),
+    ("lightgreen", Color::LightGreen),
+    ("lightyellow", Color::LightYellow),
+    ("lightmagenta", Color::LightMagenta),
+];
+
+/// Config name of a palette color
+pub fn color_name(color: Color) -> Option<&'static str> {
+    PALETTE.iter().find(|(_, c)| *c == color).map(|(name, _)| *name)
+}
+
+/// Parse a color name (`cyan`, `lightgreen`...) or hex code (`#ff8800`)
+fn color<'de, D>(deserializer: D) -> Result<Color, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+    parse_color(&name).ok_or_else(|| serde::de::Error::custom(format!("unknown color: {name}")))
+}
+
+/// Parse a style, left empty by an empty string
+fn plain_style<'de, D>(deserializer: D) -> Result<Style, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    style(deserializer).map(Option::unwrap_or_default)
+}
+
+/// Parse a border type (`plain`, `rounded`, `double`, `thick`)
+fn border_type<'de, D>(deserializer: D) -> Result<Option<BorderType>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+    let border = match name.to_lowercase().as_str() {
+        "plain" => BorderType::Plain,
+        "rounded" => BorderType::Rounded,
+        "double" => BorderType::Double,
+        "thick" => BorderType::Thick,
+        _ => return Err(serde::de::Error::custom(format!("unknown border: {name}"))),
+    };
+    Ok(Some(border))
+}
+
+/// Parse an alignment (`left`, `center`, `right`)
+fn alignment<'de, D>(deserializer: D) -> Result<Option<Alignment>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+    let alignment = match name.to_lowercase().as_str() {
+        "left" => Alignment::Left,
+        "center" => Alignment::Center,
+        "right" => Alignment::Right,
+        _ => return Err(serde::de::Error::custom(format!("unknown alignment: {name}"))),
+    };
+    Ok(Some(alignment))
+}
+
+/// Parse a style: modifiers (`bold`, `dim`, `italic`, `underlined`, `reversed`), a foreground color, then `on` and
+/// a background color, each optional, e.g. `bold black on yellow`
+fn style<'de, D>(deserializer: D) -> Result<Option<Style>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let text = String::deserialize(deserializer)?;
+    let mut style = Style::default();
+    let mut background = false;
+    for word in text.split_whitespace() {
+        let modifier = match word.to_lowercase().as_str() {
+            "on" => {
+                background = true;
+                continue;
+            }
+            "bold" => TextModifiers::BOLD,
+            "dim" => TextModifiers::DIM,
+            "italic" => TextModifiers::ITALIC,
+            "underlined" => TextModifiers::UNDERLINED,
+            "reversed" => TextModifiers::REVERSED,
+            _ => {
+                let color = parse_color(word)
+                    .ok_or_else(|| serde::de::Error::custom(format!("unknown color or modifier: {word}")))?;
+                style = if background { style.bg(color) } else { style.fg(color) };
+                continue;
+            }
+        };
+        style = style.add_modifier(modifier);
+    }
+    Ok(Some(style))
 }
 
 pub mod style {
@@ -25,4 +503,4 @@ pub mod style {
     pub fn default_borders() -> Borders {
         Borders::default()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,35 @@
+//! ## Title
+//!
+//! Window title of the terminal, summarizing the swap being compared; the title the terminal had is pushed on its
+//! title stack on start and popped back on exit
+
+use std::io::{self, Write};
+
+use crossterm::execute;
+use crossterm::terminal::SetTitle;
+use tracing::warn;
+
+/// Push the current title on the terminal's title stack (XTWINOPS), for [`restore`]
+pub fn save() {
+    write("\x1b[22;0t");
+}
+
+/// Set the title with an OSC sequence
+pub fn set(title: &str) {
+    if let Err(err) = execute!(io::stdout(), SetTitle(title)) {
+        warn!(%err, "terminal title not set");
+    }
+}
+
+/// Pop the title [`save`] pushed, for terminals keeping a title stack
+pub fn restore() {
+    write("\x1b[23;0t");
+}
+
+/// Write a control sequence to the terminal
+fn write(sequence: &str) {
+    let mut stdout = io::stdout();
+    if let Err(err) = stdout.write_all(sequence.as_bytes()).and_then(|_| stdout.flush()) {
+        warn!(%err, "terminal title stack not updated");
+    }
+}
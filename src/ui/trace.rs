@@ -0,0 +1,97 @@
+//! ## Trace
+//!
+//! Trace of a session in the [asciinema v2](https://docs.asciinema.org/manual/asciicast/v2/) format: the changes of
+//! each frame drawn as output events and the state transitions as markers, for `xoswap play` or `asciinema play`
+
+use std::fs::File;
+use std::io::{LineWriter, Write};
+use std::path::Path;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use serde_json::json;
+use tuirealm::ratatui::backend::{Backend, CrosstermBackend};
+use tuirealm::ratatui::buffer::Buffer;
+
+use crate::errors::SwapError;
+use crate::ui::state::Action;
+
+/// Hide the cursor and clear the screen, before the first frame and after a resize
+const CLEAR: &str = "\x1b[?25l\x1b[2J";
+
+/// Writes the frames and transitions of a session to a file
+pub struct Trace {
+    file: LineWriter<File>,
+    started: Instant,
+    /// Last frame written, empty before the first one
+    previous: Buffer,
+    /// Events held until the header is written with the first frame
+    pending: Vec<String>,
+}
+
+impl Trace {
+    /// Start a trace at `path`, replacing any previous one; the header is written with the first frame
+    pub fn create(path: &Path) -> Result<Self, SwapError> {
+        let file = File::create(path).map_err(|err| SwapError::Io(format!("{}: {err}", path.display())))?;
+        Ok(Self {
+            file: LineWriter::new(file),
+            started: Instant::now(),
+            previous: Buffer::default(),
+            pending: vec![],
+        })
+    }
+
+    /// Write the cells of `frame` that changed since the last frame
+    pub fn frame(&mut self, frame: &Buffer) {
+        let mut output = vec![];
+        if frame.area != self.previous.area {
+            if self.previous.area.is_empty() {
+                let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+                let header = json!({
+                    "version": 2,
+                    "width": frame.area.width,
+                    "height": frame.area.height,
+                    "timestamp": timestamp,
+                    "title": "xoswap",
+                });
+                self.write_line(&header.to_string());
+                for line in std::mem::take(&mut self.pending) {
+                    self.write_line(&line);
+                }
+            } else {
+                self.event("r", &format!("{}x{}", frame.area.width, frame.area.height));
+            }
+            output.extend_from_slice(CLEAR.as_bytes());
+            self.previous = Buffer::empty(frame.area);
+        }
+
+        let updates = self.previous.diff(frame);
+        if updates.is_empty() {
+            return;
+        }
+        if CrosstermBackend::new(&mut output).draw(updates.into_iter()).is_err() {
+            return;
+        }
+        self.event("o", &String::from_utf8_lossy(&output));
+        self.previous = frame.clone();
+    }
+
+    /// Mark a state transition
+    pub fn transition(&mut self, action: &Action) {
+        self.event("m", &format!("{action:?}"));
+    }
+
+    /// Append an event at the current time
+    fn event(&mut self, kind: &str, data: &str) {
+        let line = json!([self.started.elapsed().as_secs_f64(), kind, data]).to_string();
+        if self.previous.area.is_empty() {
+            self.pending.push(line);
+        } else {
+            self.write_line(&line);
+        }
+    }
+
+    /// Append a line; a trace that can no longer be written is given up on, not the session
+    fn write_line(&mut self, line: &str) {
+        let _ = writeln!(self.file, "{line}");
+    }
+}
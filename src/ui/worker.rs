@@ -0,0 +1,362 @@
+//! ## Worker
+//!
+//! Background worker doing the IO of the UI: the model sends it typed requests over a channel, and it replies with
+//! responses on the channel the service port forwards to the components, so that rendering never waits on the network.
+//! Each request comes with a cancellation token: the responses of a cancelled request are dropped, and every request
+//! is cancelled once the worker is dropped with the model
+
+use std::sync::mpsc::{self, Sender};
+use std::sync::Arc;
+use std::thread;
+
+use tracing::{debug, info, warn};
+
+use crate::errors::SwapError;
+use crate::models::asset::Asset;
+use crate::models::chart::Timeframe;
+use crate::models::quote::{Quote, QuoteFailure, QuoteProgress, QuoteRequest};
+use crate::models::selection::Selection;
+use crate::services::cancel::CancelToken;
+use crate::services::providers::Provider;
+use crate::services::rpc::RpcEndpoints;
+use crate::services::{allowance, balance, discovery, ens, fees, prices, quote};
+use crate::ui::event::UserEvent;
+
+/// Responses of the worker, handed to the components as user events
+pub type ServiceResponse = UserEvent;
+
+/// Providers asked for quotes, shared with the requests in flight
+pub type Providers = Arc<Vec<Box<dyn Provider>>>;
+
+/// Work asked of the worker
+pub enum ServiceRequest {
+    /// Fetch the balance of `address` in `asset`
+    Balance { asset: Asset, address: String },
+    /// Estimate the network fees of depositing `asset`
+    NetworkFees(Asset),
+    /// Ask every provider for a quote
+    Quotes(QuoteRequest),
+    /// Ask a single provider for a quote again
+    RetryProvider { request: QuoteRequest, provider: String },
+    /// Fetch the allowance granted by `owner` to `spender` in `asset`
+    Allowance { asset: Asset, owner: String, spender: String },
+    /// Resolve an ENS name to an address
+    ResolveEns(String),
+    /// Fetch the current prices of `assets` in `fiat`
+    Prices { assets: Vec<Asset>, fiat: String },
+    /// Fetch the price history of `from` in `fiat`, or of its rate in `to` when given
+    PriceHistory {
+        from: Asset,
+        to: Option<Asset>,
+        fiat: String,
+        timeframe: Timeframe,
+    },
+    /// Ask the on-chain aggregators for quotes of multiples of the amount
+    Depth(QuoteRequest),
+    /// Ask every provider for a quote of a pair of the watchlist, replying with the best one only
+    WatchlistQuotes(QuoteRequest),
+    /// Ask every provider for a quote swapping the amount out of the best quote of the request keyed `key` back,
+    /// replying with the best one only
+    ReverseQuote { key: String, request: QuoteRequest },
+    /// Discover what `provider` can do among `assets`, asking for its limits on `pair` when it swaps it
+    Discover {
+        provider: String,
+        assets: Vec<Asset>,
+        pair: Option<(Asset, Asset)>,
+    },
+    /// Ask these providers for quotes from now on
+    Providers(Providers),
+    /// Never pick the quotes of these providers as the best from now on, e.g. those not serving the user's jurisdiction
+    Restricted(Vec<String>),
+}
+
+/// Handle to the worker, stopping once dropped with the model
+pub struct Worker {
+    requests: Sender<(ServiceRequest, CancelToken)>,
+    /// Parent of the tokens of every request
+    root: CancelToken,
+}
+
+impl Worker {
+    /// Start a worker reaching the nodes of `rpc` and asking `providers`, picking quotes with `selection`, replying
+    /// on `responses`
+    pub fn spawn(
+        rpc: RpcEndpoints,
+        providers: Providers,
+        selection: Selection,
+        responses: Sender<ServiceResponse>,
+    ) -> Self {
+        let (requests, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let mut services = Services {
+                rpc,
+                providers,
+                selection,
+                restricted: vec![],
+            };
+            for (request, cancel) in receiver {
+                match request {
+                    ServiceRequest::Providers(providers) => services.providers = providers,
+                    ServiceRequest::Restricted(restricted) => services.restricted = restricted,
+                    // Each request runs on its own thread, so that a slow provider holds nothing else back
+                    request => {
+                        let services = services.clone();
+                        let responses = responses.clone();
+                        thread::spawn(move || services.handle(request, &cancel, &responses));
+                    }
+                }
+            }
+        });
+        Self {
+            requests,
+            root: CancelToken::new(),
+        }
+    }
+
+    /// Token for a group of requests, cancelled together or when the worker is dropped
+    pub fn scope(&self) -> CancelToken {
+        self.root.child()
+    }
+
+    /// Queue a request, dropping its responses once `cancel` is cancelled
+    pub fn send(&self, request: ServiceRequest, cancel: &CancelToken) {
+        let _ = self.requests.send((request, cancel.clone()));
+    }
+
+    /// Ask `providers` for quotes from now on, the requests in flight keeping theirs
+    pub fn set_providers(&self, providers: Providers) {
+        self.send(ServiceRequest::Providers(providers), &self.root);
+    }
+
+    /// Never pick the quotes of `restricted` providers as the best from now on, the requests in flight keeping theirs
+    pub fn set_restricted(&self, restricted: Vec<String>) {
+        self.send(ServiceRequest::Restricted(restricted), &self.root);
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        self.root.cancel();
+    }
+}
+
+/// What the requests are served with
+#[derive(Clone)]
+pub struct Services {
+    pub rpc: RpcEndpoints,
+    pub providers: Providers,
+    /// Picks the quote routed through
+    pub selection: Selection,
+    /// Providers whose quotes are never picked as the best
+    pub restricted: Vec<String>,
+}
+
+impl Services {
+    /// Ask every provider for a quote of `request`, the best picked among those of the providers not restricted
+    fn fetch_quotes(
+        &self,
+        request: &QuoteRequest,
+        cancel: &CancelToken,
+        on_progress: impl FnMut(QuoteProgress),
+    ) -> (Vec<Quote>, Option<usize>, Vec<QuoteFailure>) {
+        let (quotes, best, failures) =
+            quote::fetch_quotes_from_all_providers(&self.providers, request, &self.selection, cancel, on_progress);
+        let best = if self.restricted.is_empty() {
+            best
+        } else {
+            self.selection.select_allowed(&quotes, &self.restricted)
+        };
+        (quotes, best, failures)
+    }
+
+    /// Serve `request`, sending its responses on `responses` until `cancel` is cancelled
+    pub fn handle(&self, request: ServiceRequest, cancel: &CancelToken, responses: &Sender<ServiceResponse>) {
+        // The responses of a cancelled request must not land
+        let reply = |response| {
+            if !cancel.is_cancelled() {
+                let _ = responses.send(response);
+            }
+        };
+        match request {
+            ServiceRequest::Balance { asset, address } => {
+                reply(match balance::fetch_balance(&self.rpc, &asset, &address) {
+                    Ok(amount) => UserEvent::BalanceLoaded(asset.id(), amount),
+                    Err(err) => {
+                        warn!(asset = %asset.id(), %err, "balance lookup failed");
+                        UserEvent::BalanceFailed(asset.id(), err.to_string())
+                    }
+                })
+            }
+            ServiceRequest::NetworkFees(asset) => reply(match fees::estimate_network_fees(&self.rpc, &asset) {
+                Ok(fees) => UserEvent::NetworkFeeLoaded(asset.id(), fees),
+                Err(err) => {
+                    warn!(asset = %asset.id(), %err, "network fee estimate failed");
+                    UserEvent::NetworkFeeFailed(asset.id(), err.to_string())
+                }
+            }),
+            ServiceRequest::Quotes(request) => {
+                let key = request.key();
+                let on_progress = |progress| reply(UserEvent::QuoteProgress(key.clone(), progress));
+                let (quotes, best, failures) = self.fetch_quotes(&request, cancel, on_progress);
+                if cancel.is_cancelled() {
+                    debug!(request = %key, "quote request cancelled");
+                    return;
+                }
+                // Not supporting the pair is no failure worth signaling
+                for failure in failures.iter().filter(|failure| !failure.error.is_unsupported()) {
+                    reply(UserEvent::ProviderFailed(failure.clone()));
+                }
+                reply(UserEvent::QuotesLoaded(request, quotes, best, failures));
+            }
+            ServiceRequest::RetryProvider { request, provider } => {
+                info!(provider, request = %request.key(), "retrying provider");
+                let quote = match self.providers.iter().find(|candidate| candidate.name() == provider) {
+                    Some(candidate) => quote::fetch_quote(candidate.as_ref(), &request, cancel),
+                    None => Err(SwapError::Config(format!("{provider} is no longer enabled"))),
+                };
+                if let Err(error) = &quote {
+                    let failure = QuoteFailure {
+                        provider: provider.clone(),
+                        error: error.clone(),
+                    };
+                    reply(UserEvent::ProviderFailed(failure));
+                }
+                reply(UserEvent::ProviderRetried(request.key(), provider, quote));
+            }
+            ServiceRequest::Allowance { asset, owner, spender } => {
+                reply(match allowance::fetch_allowance(&self.rpc, &asset, &owner, &spender) {
+                    Ok(amount) => UserEvent::AllowanceLoaded(asset.id(), spender, amount),
+                    Err(err) => {
+                        warn!(asset = %asset.id(), %spender, %err, "allowance check failed");
+                        UserEvent::AllowanceFailed(asset.id(), spender, err.to_string())
+                    }
+                })
+            }
+            ServiceRequest::ResolveEns(name) => reply(match ens::resolve(&self.rpc, &name) {
+                Ok(address) => UserEvent::EnsResolved(name, address),
+                Err(err) => {
+                    warn!(%name, %err, "ENS resolution failed");
+                    UserEvent::EnsFailed(name, err.to_string())
+                }
+            }),
+            ServiceRequest::Prices { assets, fiat } => reply(match prices::fetch_prices(&assets, &fiat) {
+                Ok(prices) => UserEvent::PricesLoaded(prices),
+                Err(err) => {
+                    warn!(%fiat, %err, "price lookup failed");
+                    UserEvent::PricesFailed(err.to_string())
+                }
+            }),
+            ServiceRequest::PriceHistory {
+                from,
+                to,
+                fiat,
+                timeframe,
+            } => reply(match prices::fetch_history(&from, to.as_ref(), &fiat, timeframe) {
+                Ok(points) => UserEvent::PriceHistoryLoaded(timeframe, points),
+                Err(err) => {
+                    warn!(asset = %from.id(), %timeframe, %err, "price history lookup failed");
+                    UserEvent::PriceHistoryFailed(timeframe, err.to_string())
+                }
+            }),
+            ServiceRequest::Depth(request) => {
+                let depth = quote::fetch_depth(&self.providers, &request, cancel);
+                reply(UserEvent::DepthLoaded(request.key(), depth));
+            }
+            ServiceRequest::WatchlistQuotes(request) => {
+                let (mut quotes, best, _) = self.fetch_quotes(&request, cancel, |_| {});
+                let best = best.map(|index| quotes.swap_remove(index));
+                reply(UserEvent::WatchlistQuoted(request, best));
+            }
+            ServiceRequest::ReverseQuote { key, request } => {
+                let (mut quotes, best, _) = self.fetch_quotes(&request, cancel, |_| {});
+                let back = best.map(|index| quotes.swap_remove(index));
+                reply(UserEvent::ReverseQuoted(key, back));
+            }
+            ServiceRequest::Discover { provider, assets, pair } => {
+                let Some(candidate) = self.providers.iter().find(|candidate| candidate.name() == provider) else {
+                    debug!(provider, "provider no longer enabled, not discovered");
+                    return;
+                };
+                let pair = pair.as_ref().map(|(from, to)| (from, to));
+                reply(UserEvent::ProviderDiscovered(provider, discovery::discover(candidate.as_ref(), &assets, pair)));
+            }
+            ServiceRequest::Providers(_) | ServiceRequest::Restricted(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::config::Config;
+    use crate::models::amount::Amount;
+
+    /// Provider offering `amount_out` ETH for any request
+    struct Offer(&'static str, &'static str);
+
+    impl Provider for Offer {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn supports(&self, _from: &Asset, _to: &Asset) -> bool {
+            true
+        }
+
+        fn quote(&self, request: &QuoteRequest) -> Result<Quote, SwapError> {
+            Ok(Quote {
+                provider: self.0.to_string(),
+                from: request.from.id(),
+                to: request.to.id(),
+                amount_in: request.amount,
+                amount_out: Amount::parse(self.1, 18).unwrap(),
+                spender: None,
+                eta: Some(Duration::from_secs(600)),
+                route_fee: None,
+                quoted_at: Utc::now(),
+            })
+        }
+    }
+
+    /// Request of 0.1 BTC for ETH
+    fn request() -> QuoteRequest {
+        let asset = |id: &str| Config::default().assets.into_iter().find(|asset| asset.id().to_string() == id).unwrap();
+        QuoteRequest {
+            from: asset("btc-bitcoin"),
+            to: asset("eth-ethereum"),
+            amount: Amount::parse("0.1", 8).unwrap(),
+        }
+    }
+
+    #[test]
+    fn best_quote_skips_restricted_providers() {
+        let providers: Vec<Box<dyn Provider>> =
+            vec![Box::new(Offer("Restricted", "2")), Box::new(Offer("Allowed", "1"))];
+        let services = Services {
+            rpc: RpcEndpoints::default(),
+            providers: Arc::new(providers),
+            selection: Selection::default(),
+            restricted: vec!["Restricted".to_string()],
+        };
+        let (responses, received) = mpsc::channel();
+        services.handle(ServiceRequest::Quotes(request()), &CancelToken::new(), &responses);
+        services.handle(ServiceRequest::WatchlistQuotes(request()), &CancelToken::new(), &responses);
+        drop(responses);
+
+        let events: Vec<UserEvent> = received.into_iter().collect();
+        let loaded = events.iter().find_map(|event| match event {
+            UserEvent::QuotesLoaded(_, quotes, best, _) => Some(quotes[best.unwrap()].provider.clone()),
+            _ => None,
+        });
+        assert_eq!(loaded.as_deref(), Some("Allowed"));
+        let watched = events.iter().find_map(|event| match event {
+            UserEvent::WatchlistQuoted(_, best) => best.as_ref().map(|quote| quote.provider.clone()),
+            _ => None,
+        });
+        assert_eq!(watched.as_deref(), Some("Allowed"));
+    }
+}
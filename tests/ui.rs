@@ -0,0 +1,258 @@
+//! Snapshot tests of every step of the swap workflow, rendered on a test terminal
+//!
+//! Snapshots live in `tests/snapshots`; run with `UPDATE_SNAPSHOTS=1` to accept changes.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Once;
+use std::time::Duration;
+
+use chrono::Utc;
+use tuirealm::event::Key;
+use xoswap::cli::Prefill;
+use xoswap::config::Config;
+use xoswap::errors::{NetworkError, SwapError, ValidationError};
+use xoswap::models::amount::Amount;
+use xoswap::models::asset::Asset;
+use xoswap::models::quote::{Quote, QuoteFailure, QuoteProgress, QuoteRequest};
+use xoswap::ui::event::UserEvent;
+use xoswap::ui::harness::Harness;
+
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 36;
+
+/// Spinner frames, which depend on how long the test took
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Start the application on the default config, keeping history files out of the user's data directory
+fn harness() -> Harness {
+    static DATA_DIR: Once = Once::new();
+    DATA_DIR.call_once(|| env::set_var("XDG_DATA_HOME", env!("CARGO_TARGET_TMPDIR")));
+    Harness::new(Config::default(), WIDTH, HEIGHT)
+}
+
+/// Listed asset with the given id
+fn asset(id: &str) -> Asset {
+    Config::default()
+        .assets
+        .into_iter()
+        .find(|asset| asset.id().to_string() == id)
+        .expect("listed asset")
+}
+
+/// Request of 0.1 BTC for ETH
+fn request() -> QuoteRequest {
+    QuoteRequest {
+        from: asset("btc-bitcoin"),
+        to: asset("eth-ethereum"),
+        amount: Amount::parse("0.1", 8).unwrap(),
+    }
+}
+
+/// Offer of `amount_out` ETH for the request
+fn quote(provider: &str, amount_out: &str, spender: Option<&str>) -> Quote {
+    let request = request();
+    Quote {
+        provider: provider.to_string(),
+        from: request.from.id(),
+        to: request.to.id(),
+        amount_in: request.amount,
+        amount_out: Amount::parse(amount_out, 18).unwrap(),
+        spender: spender.map(str::to_string),
+        eta: Some(Duration::from_secs(600)),
+        route_fee: None,
+        quoted_at: Utc::now(),
+    }
+}
+
+/// The form filled with 0.1 BTC for ETH, quotes requested
+fn filled() -> Harness {
+    let mut harness = harness();
+    let prefill = Prefill {
+        from: Some((0, asset("btc-bitcoin"))),
+        to: Some((1, asset("eth-ethereum"))),
+        amount: Some(request().amount),
+        ..Prefill::default()
+    };
+    harness.prefill(prefill);
+    harness
+}
+
+/// Failure of a provider rate limiting the requests
+fn rate_limited() -> QuoteFailure {
+    QuoteFailure {
+        provider: "ChangeNow".to_string(),
+        error: SwapError::provider(
+            "ChangeNow",
+            NetworkError::Status {
+                status: 429,
+                body: "{\"error\":\"rate limit exceeded\"}".to_string(),
+            }
+            .into(),
+        ),
+    }
+}
+
+/// The filled form with the quotes of two providers and the failure of a third
+fn quoted() -> Harness {
+    let mut harness = filled();
+    let quotes = vec![
+        quote("SideShift", "4.1", None),
+        quote("ParaSwap", "4.15", Some("0x216b4b4ba9f3e719726886d34a177484278bfcae")),
+    ];
+    harness.service(UserEvent::QuotesLoaded(request(), quotes, Some(1), vec![rate_limited()]));
+    harness
+}
+
+/// Compare the screen to the snapshot `name`, or replace it when `UPDATE_SNAPSHOTS` is set
+fn assert_snapshot(harness: &Harness, name: &str) {
+    let screen: String = harness
+        .screen()
+        .chars()
+        .map(|c| if SPINNER.contains(&c) { '*' } else { c })
+        .collect();
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots").join(format!("{name}.txt"));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, screen + "\n").unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("no snapshot {name}; run with UPDATE_SNAPSHOTS=1 to create it"));
+    assert_eq!(
+        screen,
+        expected.trim_end_matches('\n'),
+        "snapshot {name} differs; run with UPDATE_SNAPSHOTS=1 to accept the change"
+    );
+}
+
+#[test]
+fn select_from_asset() {
+    let harness = harness();
+    assert_snapshot(&harness, "select_from_asset");
+}
+
+#[test]
+fn select_to_asset() {
+    let mut harness = harness();
+    harness.press(Key::Enter);
+    assert_snapshot(&harness, "select_to_asset");
+}
+
+#[test]
+fn choose_chain() {
+    let mut harness = harness();
+    harness.press(Key::Down);
+    harness.press(Key::Char('@'));
+    harness.press(Key::Char('a'));
+    assert_snapshot(&harness, "choose_chain");
+}
+
+#[test]
+fn enter_amount() {
+    let mut harness = harness();
+    harness.press(Key::Enter);
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+    harness.type_text("0.1");
+    assert_snapshot(&harness, "enter_amount");
+}
+
+#[test]
+fn fetch_quotes() {
+    let mut harness = filled();
+    harness.service(UserEvent::QuoteProgress(
+        request().key(),
+        QuoteProgress {
+            answered: 1,
+            failed: 0,
+            total: 3,
+        },
+    ));
+    assert_snapshot(&harness, "fetch_quotes");
+}
+
+#[test]
+fn compare_quotes() {
+    let harness = quoted();
+    assert_snapshot(&harness, "compare_quotes");
+}
+
+#[test]
+fn choose_quote() {
+    let mut harness = quoted();
+    harness.press(Key::Up);
+    harness.press(Key::Enter);
+    assert_snapshot(&harness, "choose_quote");
+}
+
+#[test]
+fn adjust_slippage() {
+    let mut harness = quoted();
+    harness.press(Key::Char('%'));
+    harness.press(Key::Right);
+    assert_snapshot(&harness, "adjust_slippage");
+}
+
+#[test]
+fn choose_export_columns() {
+    let mut harness = quoted();
+    harness.press(Key::Char('e'));
+    harness.press(Key::Down);
+    harness.press(Key::Char(' '));
+    assert_snapshot(&harness, "choose_export_columns");
+}
+
+#[test]
+fn view_crash_report() {
+    let mut harness = harness();
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("xoswap-tui").join("crashes");
+    fs::create_dir_all(&dir).unwrap();
+    let report = (1..=40).map(|line| format!("frame {line}: xoswap::ui::model::Model::update")).collect::<Vec<_>>();
+    fs::write(dir.join("crash-20260101-000000.txt"), report.join("\n")).unwrap();
+    harness.press(Key::Char('l'));
+    harness.press(Key::Char('c'));
+    harness.press(Key::PageDown);
+    harness.press(Key::Char('/'));
+    for c in "frame 3".chars() {
+        harness.press(Key::Char(c));
+    }
+    harness.press(Key::Enter);
+    assert_snapshot(&harness, "view_crash_report");
+}
+
+#[test]
+fn provider_error_details() {
+    let mut harness = quoted();
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+    assert_snapshot(&harness, "provider_error_details");
+}
+
+#[test]
+fn status_error_details() {
+    let mut harness = quoted();
+    harness.service(UserEvent::ProviderFailed(rate_limited()));
+    harness.press(Key::Tab);
+    harness.press(Key::Enter);
+    assert_snapshot(&harness, "status_error_details");
+}
+
+#[test]
+fn unsupported_pair() {
+    let mut harness = filled();
+    let failures = vec![QuoteFailure {
+        provider: "SideShift".to_string(),
+        error: ValidationError::Unsupported("pair".to_string()).into(),
+    }];
+    harness.service(UserEvent::QuotesLoaded(request(), vec![], None, failures));
+    assert_snapshot(&harness, "unsupported_pair");
+}
+
+#[test]
+fn undo_amount() {
+    let mut harness = quoted();
+    harness.press_ctrl('z');
+    assert_snapshot(&harness, "undo_amount");
+}